@@ -13,10 +13,29 @@
 */
 
 use super::operator::{BinaryOp, UnaryOp};
+use super::pattern::MatchArm;
+use super::stmt::{Param, Stmt};
+use super::Type;
+use crate::lexer::Span;
 
+/// An expression node with its location in the source
 #[derive(Debug, Clone, PartialEq)]
-pub enum Expr {
+pub struct Expr {
+    pub kind: ExprKind,
+    pub span: Span,
+}
+
+impl Expr {
+    pub fn new(kind: ExprKind, span: Span) -> Self {
+        Expr { kind, span }
+    }
+}
+
+#[derive(Debug, Clone, PartialEq)]
+pub enum ExprKind {
     Literal(Literal),
+    /// `"Hello ${name}"`, the text and the expressions of the string in order
+    Interpolated(Vec<InterpolatedPart>),
     Variable(String),
     Binary {
         left: Box<Expr>,
@@ -36,11 +55,46 @@ pub enum Expr {
         index: Box<Expr>,
     },
     ArrayLiteral(Vec<Expr>),
+    /// `object.field`
+    FieldAccess {
+        object: Box<Expr>,
+        field: String,
+    },
+    /// `Point { x: 1, y: 2 }`, fields in the order they are written
+    StructLiteral {
+        name: String,
+        fields: Vec<(String, Expr)>,
+    },
+    /// `Shape::Circle(1)`, or `Shape::Empty` for a variant without fields
+    Variant {
+        enum_name: String,
+        variant: String,
+        args: Vec<Expr>,
+    },
+    /// `match (subject) { pattern => value, ... }`
+    Match {
+        subject: Box<Expr>,
+        arms: Vec<MatchArm<Expr>>,
+    },
+    /// `func(Int x) -> Int { return x * 2; }`, an anonymous function
+    Lambda {
+        params: Vec<Param>,
+        return_type: Option<Type>,
+        body: Box<Stmt>,
+    },
+}
+
+/// A piece of an interpolated string
+#[derive(Debug, Clone, PartialEq)]
+pub enum InterpolatedPart {
+    Text(String),
+    Expr(Expr),
 }
 
 #[derive(Debug, Clone, PartialEq)]
 pub enum Literal {
     Number(i64),
+    Float(f64),
     String(String),
     Char(char),
     Bool(bool),
@@ -12,21 +12,63 @@
  * -------------------------------------------------------------------------
 */
 
+use std::fmt;
+
 mod expr;
 mod stmt;
+mod pattern;
 pub mod operator;
 
 // Re-exports
-pub use expr::{Expr, Literal};
-pub use stmt::{Stmt, Param};
+pub use expr::{Expr, ExprKind, InterpolatedPart, Literal};
+pub use stmt::{Stmt, StmtKind, Param, Variant, AssignTarget, Accessor};
+pub use pattern::{Pattern, MatchArm};
 pub use operator::{BinaryOp, UnaryOp};
 
 #[derive(Debug, Clone, PartialEq)]
 pub enum Type {
     Int,
+    Float,
     String,
     Char,
     Bool,
     Array(Box<Type>),
+    /// A struct or an enum, by name
+    Named(String),
+    /// `Func<(Int, Int), Int>`, or `Func<(Int)>` for a function without a return value
+    Function {
+        params: Vec<Type>,
+        return_type: Box<Type>,
+    },
     Void,
 }
+
+impl Type {
+    /// True for Int and Float, which arithmetic operators accept
+    pub fn is_numeric(&self) -> bool {
+        matches!(self, Type::Int | Type::Float)
+    }
+}
+
+impl fmt::Display for Type {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        match self {
+            Type::Int => write!(f, "Int"),
+            Type::Float => write!(f, "Float"),
+            Type::String => write!(f, "String"),
+            Type::Char => write!(f, "Char"),
+            Type::Bool => write!(f, "Bool"),
+            Type::Array(inner) => write!(f, "Array<{}>", inner),
+            Type::Named(name) => write!(f, "{}", name),
+            Type::Function { params, return_type } => {
+                let params: Vec<String> = params.iter().map(Type::to_string).collect();
+                write!(f, "Func<({})", params.join(", "))?;
+                if **return_type != Type::Void {
+                    write!(f, ", {}", return_type)?;
+                }
+                write!(f, ">")
+            }
+            Type::Void => write!(f, "Void"),
+        }
+    }
+}
@@ -12,7 +12,7 @@
  * -------------------------------------------------------------------------
 */
 
-#[derive(Debug, Clone, PartialEq)]
+#[derive(Debug, Clone, Copy, PartialEq)]
 pub enum BinaryOp {
     Add, Sub, Mul, Div, Mod, Pow,
 
@@ -21,7 +21,7 @@ pub enum BinaryOp {
     And, Or,
 }
 
-#[derive(Debug, Clone, PartialEq)]
+#[derive(Debug, Clone, Copy, PartialEq)]
 pub enum UnaryOp {
     Minus,
     Not,
@@ -47,4 +47,34 @@ impl BinaryOp {
     pub fn precedence(&self) -> u8 {
         self.precedence_and_associativity().0
     }
+
+    /// The operator as written in the source
+    pub fn symbol(&self) -> &'static str {
+        match self {
+            BinaryOp::Add => "+",
+            BinaryOp::Sub => "-",
+            BinaryOp::Mul => "*",
+            BinaryOp::Div => "/",
+            BinaryOp::Mod => "%",
+            BinaryOp::Pow => "**",
+            BinaryOp::Equal => "==",
+            BinaryOp::NotEqual => "!=",
+            BinaryOp::Less => "<",
+            BinaryOp::Greater => ">",
+            BinaryOp::LessEqual => "<=",
+            BinaryOp::GreaterEqual => ">=",
+            BinaryOp::And => "&&",
+            BinaryOp::Or => "||",
+        }
+    }
+}
+
+impl UnaryOp {
+    /// The operator as written in the source
+    pub fn symbol(&self) -> &'static str {
+        match self {
+            UnaryOp::Minus => "-",
+            UnaryOp::Not => "!",
+        }
+    }
 }
\ No newline at end of file
@@ -0,0 +1,81 @@
+/*
+ * -------------------------------------------------------------------------
+ * RemyLang — pattern implementation
+ * File : src/ast/pattern.rs
+ *
+ * Description :
+ *   Patterns of match arms, tested against a value and binding the
+ *   parts they name.
+ *
+ * Author  : Samuel 'Meeast' Bleau
+ * Created : 2026-10-16
+ *
+ * -------------------------------------------------------------------------
+*/
+
+use std::fmt;
+use super::expr::Literal;
+use crate::lexer::Span;
+
+#[derive(Debug, Clone, PartialEq)]
+pub enum Pattern {
+    /// `_`, matching anything
+    Wildcard,
+    /// A name, matching anything and binding the value to it
+    Binding(String),
+    /// A literal, matching an equal value
+    Literal(Literal),
+    /// `Shape::Rect(w, h)`, matching a variant whose fields match the inner patterns
+    Variant {
+        enum_name: String,
+        variant: String,
+        fields: Vec<Pattern>,
+    },
+}
+
+impl Pattern {
+    /// Names bound by the pattern, from left to right
+    pub fn bindings(&self) -> Vec<&str> {
+        match self {
+            Pattern::Binding(name) => vec![name],
+            Pattern::Variant { fields, .. } => fields.iter().flat_map(Pattern::bindings).collect(),
+            Pattern::Wildcard | Pattern::Literal(_) => Vec::new(),
+        }
+    }
+
+    /// True if the pattern matches every value
+    pub fn is_irrefutable(&self) -> bool {
+        matches!(self, Pattern::Wildcard | Pattern::Binding(_))
+    }
+}
+
+impl fmt::Display for Pattern {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        match self {
+            Pattern::Wildcard => write!(f, "_"),
+            Pattern::Binding(name) => write!(f, "{}", name),
+            Pattern::Literal(Literal::Number(n)) => write!(f, "{}", n),
+            Pattern::Literal(Literal::Float(x)) => write!(f, "{:?}", x),
+            Pattern::Literal(Literal::String(s)) => write!(f, "{:?}", s),
+            Pattern::Literal(Literal::Char(c)) => write!(f, "{:?}", c),
+            Pattern::Literal(Literal::Bool(b)) => write!(f, "{}", if *b { "True" } else { "False" }),
+            Pattern::Variant { enum_name, variant, fields } => {
+                write!(f, "{}::{}", enum_name, variant)?;
+                if !fields.is_empty() {
+                    let fields: Vec<String> = fields.iter().map(Pattern::to_string).collect();
+                    write!(f, "({})", fields.join(", "))?;
+                }
+                Ok(())
+            }
+        }
+    }
+}
+
+/// One `pattern => body` arm of a match, whose body is an expression or a statement
+#[derive(Debug, Clone, PartialEq)]
+pub struct MatchArm<T> {
+    pub pattern: Pattern,
+    pub body: T,
+    /// Location of the pattern
+    pub span: Span,
+}
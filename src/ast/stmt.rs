@@ -13,18 +13,36 @@
 */
 
 use super::expr::Expr;
+use super::operator::BinaryOp;
+use super::pattern::MatchArm;
 use super::Type;
+use crate::lexer::Span;
 
+/// A statement node with its location in the source
 #[derive(Debug, Clone, PartialEq)]
-pub enum Stmt {
+pub struct Stmt {
+    pub kind: StmtKind,
+    pub span: Span,
+}
+
+impl Stmt {
+    pub fn new(kind: StmtKind, span: Span) -> Self {
+        Stmt { kind, span }
+    }
+}
+
+#[derive(Debug, Clone, PartialEq)]
+pub enum StmtKind {
     Expression(Expr),
     Let {
         name: String,
         type_annotation: Option<Type>,
         value: Expr,
     },
+    /// `target = value`, or `target op= value` for a compound assignment
     Assignment {
-        name: String,
+        target: AssignTarget,
+        op: Option<BinaryOp>,
         value: Expr,
     },
     Block(Vec<Stmt>),
@@ -34,16 +52,113 @@ pub enum Stmt {
         else_branch: Option<Box<Stmt>>,
     },
     Return(Option<Expr>),
+    While {
+        condition: Expr,
+        body: Box<Stmt>,
+    },
+    For {
+        initializer: Option<Box<Stmt>>,
+        condition: Option<Expr>,
+        increment: Option<Box<Stmt>>,
+        body: Box<Stmt>,
+    },
+    ForIn {
+        variable: String,
+        iterable: Expr,
+        body: Box<Stmt>,
+    },
+    Break,
+    Continue,
     FunctionDecl {
         name: String,
         params: Vec<Param>,
         return_type: Option<Type>,
         body: Box<Stmt>,
     },
+    /// `struct Point { Int x; Int y; }`
+    StructDecl {
+        name: String,
+        fields: Vec<Param>,
+    },
+    /// `enum Shape { Circle(Int), Rect(Int, Int), Empty }`
+    EnumDecl {
+        name: String,
+        variants: Vec<Variant>,
+    },
+    /// `impl Point { ... }`, whose methods are `FunctionDecl` statements
+    /// Methods see the value they are called on as `self`.
+    Impl {
+        name: String,
+        methods: Vec<Stmt>,
+    },
+    /// `match (subject) { pattern => { ... } ... }`, whose arms are blocks,
+    /// expression statements or assignments
+    Match {
+        subject: Expr,
+        arms: Vec<MatchArm<Stmt>>,
+    },
 }
 
 #[derive(Debug, Clone, PartialEq)]
 pub struct Param {
     pub name: String,
     pub type_annotation: Type,
-}
\ No newline at end of file
+}
+
+/// A variant of an enum, with the types of its fields
+#[derive(Debug, Clone, PartialEq)]
+pub struct Variant {
+    pub name: String,
+    pub fields: Vec<Type>,
+}
+
+/// The place written by an assignment
+#[derive(Debug, Clone, PartialEq)]
+pub enum AssignTarget {
+    Variable(String),
+    /// `array[index]`, where the array is itself a target, as in `a[i][j]`
+    Index {
+        array: Box<AssignTarget>,
+        index: Box<Expr>,
+    },
+    /// `object.field`, where the object is itself a target, as in `a[i].x`
+    Field {
+        object: Box<AssignTarget>,
+        field: String,
+    },
+}
+
+/// One step from a variable to the place an assignment writes
+#[derive(Debug, Clone, Copy, PartialEq)]
+pub enum Accessor<'a> {
+    Index(&'a Expr),
+    Field(&'a str),
+}
+
+impl AssignTarget {
+    /// Name of the variable the target is part of
+    pub fn name(&self) -> &str {
+        match self {
+            AssignTarget::Variable(name) => name,
+            AssignTarget::Index { array, .. } => array.name(),
+            AssignTarget::Field { object, .. } => object.name(),
+        }
+    }
+
+    /// Steps from the variable inward, `[i, x]` for `a[i].x`
+    pub fn path(&self) -> Vec<Accessor<'_>> {
+        match self {
+            AssignTarget::Variable(_) => Vec::new(),
+            AssignTarget::Index { array, index } => {
+                let mut path = array.path();
+                path.push(Accessor::Index(index));
+                path
+            }
+            AssignTarget::Field { object, field } => {
+                let mut path = object.path();
+                path.push(Accessor::Field(field));
+                path
+            }
+        }
+    }
+}
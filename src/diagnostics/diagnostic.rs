@@ -0,0 +1,163 @@
+/*
+ * -------------------------------------------------------------------------
+ * RemyLang — diagnostics
+ * File : src/diagnostics/diagnostic.rs
+ *
+ * Description :
+ *   A message about the source code, built from the errors of every
+ *   stage of the pipeline.
+ *
+ * Author  : Samuel 'Meeast' Bleau
+ * Created : 2026-10-16
+ *
+ * -------------------------------------------------------------------------
+*/
+
+use std::fmt;
+use crate::lexer::{LexError, Span, Token};
+use crate::parser::ParseError;
+use crate::semantic::TypeError;
+use crate::vm::{RuntimeError, StackFrame};
+
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub enum Severity {
+    Error,
+    Warning,
+}
+
+impl fmt::Display for Severity {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        match self {
+            Severity::Error => write!(f, "error"),
+            Severity::Warning => write!(f, "warning"),
+        }
+    }
+}
+
+/// A message pointing at a location in the source
+#[derive(Debug, Clone, PartialEq)]
+pub struct Diagnostic {
+    pub severity: Severity,
+    /// Stage that produced the message: "parse", "type", "runtime"...
+    pub category: &'static str,
+    pub message: String,
+    pub span: Option<Span>,
+    pub notes: Vec<String>,
+    pub help: Vec<String>,
+    /// Calls that led to a runtime error, outermost first
+    pub trace: Vec<StackFrame>,
+}
+
+impl Diagnostic {
+    /// Creates an error diagnostic
+    pub fn error(category: &'static str, message: impl Into<String>) -> Self {
+        Diagnostic {
+            severity: Severity::Error,
+            category,
+            message: message.into(),
+            span: None,
+            notes: Vec::new(),
+            help: Vec::new(),
+            trace: Vec::new(),
+        }
+    }
+
+    /// Points the diagnostic at a location
+    pub fn with_span(mut self, span: Option<&Span>) -> Self {
+        self.span = span.cloned();
+        self
+    }
+
+    /// Adds a note explaining the error
+    pub fn with_note(mut self, note: impl Into<String>) -> Self {
+        self.notes.push(note.into());
+        self
+    }
+
+    /// Adds a suggestion to fix the error
+    pub fn with_help(mut self, help: impl Into<String>) -> Self {
+        self.help.push(help.into());
+        self
+    }
+}
+
+impl From<&ParseError> for Diagnostic {
+    fn from(error: &ParseError) -> Self {
+        // Characters the lexer could not make sense of reach the parser as Invalid tokens
+        if let Some(Token::Invalid(c)) = error.found() {
+            let diagnostic = Diagnostic::error("lex", format!("Unexpected character '{}'", c))
+                .with_span(Some(error.span()));
+            return match c {
+                '&' => diagnostic.with_help("use '&&' for a logical and"),
+                '|' => diagnostic.with_help("use '||' for a logical or"),
+                _ => diagnostic,
+            };
+        }
+        if let ParseError::Lex { error: lex_error, span } = error {
+            let diagnostic = Diagnostic::error("lex", error.message()).with_span(Some(span));
+            return match lex_error {
+                LexError::IntegerTooLarge(_) => {
+                    diagnostic.with_note(format!("the largest Int is {}", i64::MAX))
+                }
+                LexError::FloatTooLarge(_) => {
+                    diagnostic.with_note(format!("the largest Float is about {:e}", f64::MAX))
+                }
+                LexError::UnterminatedString => diagnostic.with_help("close the string with '\"'"),
+                LexError::UnterminatedChar => diagnostic.with_help("close the character literal with a quote"),
+                LexError::InvalidCharLiteral(_) => {
+                    diagnostic.with_note("character literals hold exactly one character, like 'a'")
+                }
+                LexError::UnterminatedComment => diagnostic.with_help("close the comment with '*/'"),
+                LexError::InvalidEscape(_) => diagnostic.with_note(
+                    "valid escapes are \\n, \\t, \\r, \\\\, \\\", \\', \\$, \\0 and \\u{XXXX}",
+                ),
+            };
+        }
+        Diagnostic::error("parse", error.message()).with_span(Some(error.span()))
+    }
+}
+
+impl From<&TypeError> for Diagnostic {
+    fn from(error: &TypeError) -> Self {
+        let diagnostic = Diagnostic::error("type", error.message()).with_span(error.span());
+        match error.kind() {
+            TypeError::UndefinedVariable(name) => {
+                diagnostic.with_help(format!("declare it before using it, like 'Int {} = 0;'", name))
+            }
+            TypeError::MissingReturn(_) => {
+                diagnostic.with_help("add a return statement at the end of the function")
+            }
+            TypeError::EmptyArrayLiteral => {
+                diagnostic.with_help("give the array a type annotation, like 'Array<Int> a = [];'")
+            }
+            TypeError::RecursiveStruct { .. } => {
+                diagnostic.with_help("hold the values in an array, which may be empty, like 'Array<Node> children;'")
+            }
+            TypeError::VoidValueNotAllowed => {
+                diagnostic.with_note("functions without a return type produce no value")
+            }
+            TypeError::NonExhaustiveMatch { .. } => {
+                diagnostic.with_help("add an arm for each missing pattern, or a '_' arm matching everything else")
+            }
+            TypeError::UnreachableArm { .. } => diagnostic.with_help("remove the arm, or move it before the arms hiding it"),
+            TypeError::BindingNamedLikeVariant { name, enum_name } => Diagnostic {
+                severity: Severity::Warning,
+                ..diagnostic.with_help(format!("write '{}::{}' to match the variant", enum_name, name))
+            },
+            _ => diagnostic,
+        }
+    }
+}
+
+impl From<&RuntimeError> for Diagnostic {
+    fn from(error: &RuntimeError) -> Self {
+        let mut diagnostic = Diagnostic::error("runtime", error.message()).with_span(error.span());
+        diagnostic.trace = error.trace().unwrap_or_default().to_vec();
+        match error.kind() {
+            RuntimeError::StackOverflow { .. } => {
+                diagnostic.with_note("this is usually caused by a recursion that never stops")
+            }
+            _ => diagnostic,
+        }
+    }
+}
@@ -0,0 +1,21 @@
+/*
+ * -------------------------------------------------------------------------
+ * RemyLang — diagnostics module
+ * File : src/diagnostics/mod.rs
+ *
+ * Description :
+ *   Error reporting shared by every stage: errors are turned into
+ *   diagnostics and rendered with the source code they point at.
+ *
+ * Author  : Samuel 'Meeast' Bleau
+ * Created : 2026-10-16
+ *
+ * -------------------------------------------------------------------------
+*/
+
+mod diagnostic;
+mod render;
+
+// Re-exports
+pub use diagnostic::{Diagnostic, Severity};
+pub use render::{render, report, use_color};
@@ -0,0 +1,120 @@
+/*
+ * -------------------------------------------------------------------------
+ * RemyLang — diagnostic rendering
+ * File : src/diagnostics/render.rs
+ *
+ * Description :
+ *   Renders diagnostics rustc-style, with the offending source line and
+ *   a caret under the span.
+ *
+ * Author  : Samuel 'Meeast' Bleau
+ * Created : 2026-10-16
+ *
+ * -------------------------------------------------------------------------
+*/
+
+use std::io::IsTerminal;
+use super::diagnostic::{Diagnostic, Severity};
+use crate::vm::trace::format_traceback;
+
+const RESET: &str = "\x1b[0m";
+const BOLD: &str = "\x1b[1m";
+const RED: &str = "\x1b[1;31m";
+const YELLOW: &str = "\x1b[1;33m";
+const BLUE: &str = "\x1b[1;34m";
+
+/// True if diagnostics should be coloured
+/// Colour is used when stderr, where they are reported, is a terminal,
+/// unless NO_COLOR is set.
+pub fn use_color() -> bool {
+    std::io::stderr().is_terminal() && std::env::var_os("NO_COLOR").is_none()
+}
+
+/// Print a diagnostic to stderr
+pub fn report(diagnostic: &Diagnostic, source: &str) {
+    eprint!("{}", render(diagnostic, source, use_color()));
+}
+
+/// Render a diagnostic about `source`
+///
+/// # Example
+/// ```
+/// use remylang::diagnostics::{render, Diagnostic};
+/// use remylang::lexer::Span;
+///
+/// let span = Span::new("main.remy".into(), 8, 12, 1, 9);
+/// let diagnostic = Diagnostic::error("type", "Expected Int, found Bool").with_span(Some(&span));
+/// let text = render(&diagnostic, "Int x = True;", false);
+/// assert_eq!(text, "error[type]: Expected Int, found Bool\n --> main.remy:1:9\n  |\n1 | Int x = True;\n  |         ^^^^\n");
+/// ```
+pub fn render(diagnostic: &Diagnostic, source: &str, color: bool) -> String {
+    let paint = |style: &str, text: &str| {
+        if color {
+            format!("{}{}{}", style, text, RESET)
+        } else {
+            text.to_string()
+        }
+    };
+    let severity_style = match diagnostic.severity {
+        Severity::Error => RED,
+        Severity::Warning => YELLOW,
+    };
+
+    // The calls that led to the error come first, as in Python
+    let mut out = String::new();
+    if !diagnostic.trace.is_empty() {
+        out.push_str(&format_traceback(&diagnostic.trace, diagnostic.span.as_ref(), Some(source)));
+    }
+    out.push_str(&format!(
+        "{}{}\n",
+        paint(severity_style, &format!("{}[{}]", diagnostic.severity, diagnostic.category)),
+        paint(BOLD, &format!(": {}", diagnostic.message)),
+    ));
+
+    let Some(span) = &diagnostic.span else {
+        for note in &diagnostic.notes {
+            out.push_str(&format!("{}: {}\n", paint(BOLD, "note"), note));
+        }
+        for help in &diagnostic.help {
+            out.push_str(&format!("{}: {}\n", paint(BOLD, "help"), help));
+        }
+        return out;
+    };
+
+    let line_number = span.line.to_string();
+    let gutter = " ".repeat(line_number.len());
+    let line = source.lines().nth(span.line.saturating_sub(1)).unwrap_or("");
+
+    out.push_str(&format!("{}{} {}\n", gutter, paint(BLUE, "-->"), span));
+    out.push_str(&format!("{} {}\n", gutter, paint(BLUE, "|")));
+    out.push_str(&format!("{} {}\n", paint(BLUE, &format!("{} |", line_number)), line));
+    out.push_str(&format!(
+        "{} {} {}\n",
+        gutter,
+        paint(BLUE, "|"),
+        paint(severity_style, &underline(line, span.column, span.len())),
+    ));
+
+    for note in &diagnostic.notes {
+        out.push_str(&format!("{} {} {}: {}\n", gutter, paint(BLUE, "="), paint(BOLD, "note"), note));
+    }
+    for help in &diagnostic.help {
+        out.push_str(&format!("{} {} {}: {}\n", gutter, paint(BLUE, "="), paint(BOLD, "help"), help));
+    }
+    out
+}
+
+/// Carets under `length` characters of `line` starting at 1-based `column`
+/// Tabs before the span are kept so the carets line up. Spans going past
+/// the end of the line are cut there, and at least one caret is drawn.
+fn underline(line: &str, column: usize, length: usize) -> String {
+    let start = column.saturating_sub(1);
+    let indent: String = line
+        .chars()
+        .take(start)
+        .map(|c| if c == '\t' { '\t' } else { ' ' })
+        .collect();
+    let available = line.chars().count().saturating_sub(start);
+    let carets = length.min(available).max(1);
+    format!("{}{}", indent, "^".repeat(carets))
+}
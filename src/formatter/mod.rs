@@ -0,0 +1,45 @@
+/*
+ * -------------------------------------------------------------------------
+ * RemyLang — formatter module
+ * File : src/formatter/mod.rs
+ *
+ * Description :
+ *   Source code formatter: programs are parsed and printed back in a
+ *   canonical layout, keeping their comments.
+ *
+ * Author  : Samuel 'Meeast' Bleau
+ * Created : 2026-10-16
+ *
+ * -------------------------------------------------------------------------
+*/
+
+mod printer;
+
+use crate::lexer::Lexer;
+use crate::parser::{ParseError, Parser};
+use printer::Printer;
+
+/// Formats a program, returning its parse errors if it has any
+/// Formatting is idempotent: formatted code is left unchanged.
+///
+/// # Example
+/// ```
+/// use remylang::formatter::format_source;
+///
+/// let code = "func Add(Int a,Int b)->Int{return (a+b)*2; // doubled\n}";
+/// assert_eq!(
+///     format_source(code, "add.remy").unwrap(),
+///     "func Add(Int a, Int b) -> Int {\n    return (a + b) * 2; // doubled\n}\n"
+/// );
+/// ```
+pub fn format_source(code: &str, filename: &str) -> Result<String, Vec<ParseError>> {
+    let mut lexer = Lexer::with_file(code.to_string(), filename);
+    let tokens = lexer.tokenize();
+    let comments = lexer.comments().to_vec();
+
+    let (ast, errors) = Parser::new(tokens.clone()).parse_recovering();
+    if !errors.is_empty() {
+        return Err(errors);
+    }
+    Ok(Printer::new(code, tokens, comments).program(&ast))
+}
@@ -0,0 +1,677 @@
+/*
+ * -------------------------------------------------------------------------
+ * RemyLang — formatter printer
+ * File : src/formatter/printer.rs
+ *
+ * Description :
+ *   Prints a syntax tree back as source code, putting the comments of the
+ *   original source back around the statements they were next to.
+ *
+ * Author  : Samuel 'Meeast' Bleau
+ * Created : 2026-10-16
+ *
+ * -------------------------------------------------------------------------
+*/
+
+use crate::ast::{AssignTarget, Expr, ExprKind, InterpolatedPart, Literal, Param, Pattern, Stmt, StmtKind, Type, UnaryOp};
+use crate::lexer::{Comment, Span, SpannedToken, Token};
+
+/// One level of indentation
+const INDENT: &str = "    ";
+
+pub(super) struct Printer {
+    out: String,
+    indent: usize,
+    /// Tokens of the source, to find the fields of structs and enums
+    tokens: Vec<SpannedToken>,
+    comments: Vec<Comment>,
+    /// Index of the first comment not written yet
+    next_comment: usize,
+    /// Character offsets at which the lines of the source start
+    line_starts: Vec<usize>,
+    /// Float literals of the source with their text, to print them as written
+    floats: Vec<(f64, String)>,
+    /// Index of the first float literal not written yet
+    next_float: usize,
+}
+
+impl Printer {
+    pub(super) fn new(code: &str, tokens: Vec<SpannedToken>, comments: Vec<Comment>) -> Self {
+        let line_starts = std::iter::once(0)
+            .chain(code.chars().enumerate().filter(|(_, c)| *c == '\n').map(|(i, _)| i + 1))
+            .collect();
+        let chars: Vec<char> = code.chars().collect();
+        let floats = tokens
+            .iter()
+            .filter_map(|t| match t.token {
+                Token::FloatLit(x) => Some((x, chars[t.span.start..t.span.end].iter().collect())),
+                _ => None,
+            })
+            .collect();
+        Printer {
+            out: String::new(),
+            indent: 0,
+            tokens,
+            comments,
+            next_comment: 0,
+            line_starts,
+            floats,
+            next_float: 0,
+        }
+    }
+
+    /// Prints a whole program
+    /// Declarations are separated from their neighbours by a blank line.
+    pub(super) fn program(mut self, statements: &[Stmt]) -> String {
+        self.items(
+            statements,
+            usize::MAX,
+            stmt_range,
+            |previous, next| is_declaration(previous) || is_declaration(next),
+            Self::statement,
+        );
+        if !self.out.is_empty() {
+            self.out.push('\n');
+        }
+        self.out
+    }
+
+    // LAYOUT AND COMMENTS
+
+    /// 1-based line of the source holding a character offset
+    fn line_of(&self, offset: usize) -> usize {
+        self.line_starts.partition_point(|&start| start <= offset)
+    }
+
+    /// True if the source has a blank line between `last_line` and `offset`
+    fn gap(&self, last_line: Option<usize>, offset: usize) -> bool {
+        last_line.is_some_and(|line| self.line_of(offset) > line + 1)
+    }
+
+    /// Starts a new line at the current indentation, after a blank line if `blank`
+    fn begin_line(&mut self, blank: bool) {
+        if !self.out.is_empty() {
+            self.out.push('\n');
+            if blank {
+                self.out.push('\n');
+            }
+        }
+        for _ in 0..self.indent {
+            self.out.push_str(INDENT);
+        }
+    }
+
+    /// Takes the next comment not written yet, if `accept` agrees
+    fn take_comment(&mut self, accept: impl Fn(&Self, &Comment) -> bool) -> Option<Comment> {
+        let comment = self.comments.get(self.next_comment).filter(|c| accept(self, c))?.clone();
+        self.next_comment += 1;
+        Some(comment)
+    }
+
+    fn comment_before(&mut self, offset: usize) -> Option<Comment> {
+        self.take_comment(|_, c| c.span.start < offset)
+    }
+
+    fn has_comment_before(&self, offset: usize) -> bool {
+        self.comments.get(self.next_comment).is_some_and(|c| c.span.start < offset)
+    }
+
+    /// Writes items on lines of their own, with the comments around them
+    /// `range` gives the start and end offsets of an item in the source and
+    /// `separated` tells if two neighbours need a blank line between them.
+    /// Comments left before `close` are written after the last item.
+    fn items<T>(
+        &mut self,
+        items: &[T],
+        close: usize,
+        range: impl Fn(&T) -> (usize, usize),
+        separated: impl Fn(&T, &T) -> bool,
+        print: impl Fn(&mut Self, &T),
+    ) {
+        // Source line of the last thing written, to keep the blank lines
+        let mut last_line = None;
+        for (i, item) in items.iter().enumerate() {
+            let (start, end) = range(item);
+            // The blank line goes before the comments leading to the item
+            let mut blank = i > 0 && separated(&items[i - 1], item);
+            while let Some(comment) = self.comment_before(start) {
+                self.begin_line(blank || self.gap(last_line, comment.span.start));
+                self.out.push_str(&comment.text);
+                last_line = Some(self.line_of(comment.span.end - 1));
+                blank = false;
+            }
+            self.begin_line(blank || self.gap(last_line, start));
+            print(self, item);
+
+            // Comments on its last line, and those inside it that nothing took
+            let next = items.get(i + 1).map_or(close, |next| range(next).0);
+            let end_line = self.line_of(end.saturating_sub(1));
+            let mut after_line_comment = false;
+            last_line = Some(end_line);
+            while let Some(comment) = self.take_comment(|printer, c| {
+                c.span.start < end || (c.span.start < next && printer.line_of(c.span.start) == end_line)
+            }) {
+                if after_line_comment {
+                    self.begin_line(false);
+                } else {
+                    self.out.push(' ');
+                }
+                self.out.push_str(&comment.text);
+                after_line_comment = comment.text.starts_with("//");
+                last_line = last_line.max(Some(self.line_of(comment.span.end - 1)));
+            }
+        }
+        while let Some(comment) = self.comment_before(close) {
+            self.begin_line(self.gap(last_line, comment.span.start));
+            self.out.push_str(&comment.text);
+            last_line = Some(self.line_of(comment.span.end - 1));
+        }
+    }
+
+    /// Writes items between braces, or `{}` if there is nothing to put inside
+    fn braced<T>(
+        &mut self,
+        items: &[T],
+        close: usize,
+        range: impl Fn(&T) -> (usize, usize),
+        separated: impl Fn(&T, &T) -> bool,
+        print: impl Fn(&mut Self, &T),
+    ) {
+        if items.is_empty() && !self.has_comment_before(close) {
+            self.out.push_str("{}");
+            return;
+        }
+        self.out.push('{');
+        self.indent += 1;
+        self.items(items, close, range, separated, print);
+        self.indent -= 1;
+        self.begin_line(false);
+        self.out.push('}');
+    }
+
+    /// Source ranges of the fields of a struct or the variants of an enum
+    /// Each one ends with `separator`, except maybe the last variant.
+    fn member_ranges(&self, decl: &Span, separator: Token) -> Vec<(usize, usize)> {
+        let mut ranges = Vec::new();
+        let mut start = None;
+        let mut depth = 0;
+        let mut last_end = 0;
+        let body = self
+            .tokens
+            .iter()
+            .filter(|t| t.span.start >= decl.start && t.span.end <= decl.end)
+            .skip_while(|t| t.token != Token::LeftBrace)
+            .skip(1);
+        for t in body {
+            match t.token {
+                Token::LeftParen => depth += 1,
+                Token::RightParen => depth -= 1,
+                _ => {}
+            }
+            if depth == 0 && (t.token == separator || t.token == Token::RightBrace) {
+                if let Some(start) = start.take() {
+                    let end = if t.token == separator { t.span.end } else { last_end };
+                    ranges.push((start, end));
+                }
+            } else {
+                start.get_or_insert(t.span.start);
+            }
+            last_end = t.span.end;
+        }
+        ranges
+    }
+
+    /// Writes the comments between the block of an if and its `else`,
+    /// then what separates them from the keyword
+    /// Comments on the line of the `}` stay on it, the others on lines of their own.
+    fn else_comments(&mut self, then_branch: &Stmt) {
+        let else_start = self
+            .tokens
+            .iter()
+            .find(|t| t.token == Token::Else && t.span.start >= then_branch.span.end)
+            .map_or(then_branch.span.end, |t| t.span.start);
+        let block_line = self.line_of(then_branch.span.end.saturating_sub(1));
+        let mut own_line = false;
+        while let Some(comment) = self.comment_before(else_start) {
+            if own_line || self.line_of(comment.span.start) > block_line {
+                self.begin_line(false);
+            } else {
+                self.out.push(' ');
+            }
+            self.out.push_str(&comment.text);
+            own_line = comment.text.starts_with("//");
+        }
+        if own_line {
+            self.begin_line(false);
+        } else {
+            self.out.push(' ');
+        }
+    }
+
+    // STATEMENTS
+
+    fn statement(&mut self, stmt: &Stmt) {
+        match &stmt.kind {
+            // A match at the start of a statement would be read as a match statement
+            StmtKind::Expression(expr) if matches!(leftmost(expr).kind, ExprKind::Match { .. }) => {
+                self.operand(expr, true);
+                self.out.push(';');
+            }
+            StmtKind::Expression(_) | StmtKind::Let { .. } | StmtKind::Assignment { .. } => {
+                self.simple_statement(stmt);
+                self.out.push(';');
+            }
+            StmtKind::Block(_) => self.block(stmt),
+            StmtKind::If { condition, then_branch, else_branch } => {
+                self.out.push_str("if (");
+                self.expr(condition);
+                self.out.push_str(") ");
+                self.block(then_branch);
+                if let Some(else_branch) = else_branch {
+                    self.else_comments(then_branch);
+                    // A block, or the next if of an `else if`
+                    self.out.push_str("else ");
+                    self.statement(else_branch);
+                }
+            }
+            StmtKind::Return(value) => {
+                self.out.push_str("return");
+                if let Some(value) = value {
+                    self.out.push(' ');
+                    self.expr(value);
+                }
+                self.out.push(';');
+            }
+            StmtKind::While { condition, body } => {
+                self.out.push_str("while (");
+                self.expr(condition);
+                self.out.push_str(") ");
+                self.block(body);
+            }
+            StmtKind::For { initializer, condition, increment, body } => {
+                self.out.push_str("for (");
+                if let Some(initializer) = initializer {
+                    self.simple_statement(initializer);
+                }
+                self.out.push(';');
+                if let Some(condition) = condition {
+                    self.out.push(' ');
+                    self.expr(condition);
+                }
+                self.out.push(';');
+                if let Some(increment) = increment {
+                    self.out.push(' ');
+                    self.simple_statement(increment);
+                }
+                self.out.push_str(") ");
+                self.block(body);
+            }
+            StmtKind::ForIn { variable, iterable, body } => {
+                // Without parentheses, the '{' of a struct literal would start the body
+                if has_bare_struct_literal(iterable) {
+                    self.out.push_str(&format!("for ({} in ", variable));
+                    self.expr(iterable);
+                    self.out.push_str(") ");
+                } else {
+                    self.out.push_str(&format!("for {} in ", variable));
+                    self.expr(iterable);
+                    self.out.push(' ');
+                }
+                self.block(body);
+            }
+            StmtKind::Break => self.out.push_str("break;"),
+            StmtKind::Continue => self.out.push_str("continue;"),
+            StmtKind::FunctionDecl { name, params, return_type, body } => {
+                self.out.push_str(&format!("func {}", name));
+                self.signature(params, return_type);
+                self.block(body);
+            }
+            StmtKind::StructDecl { name, fields } => {
+                self.out.push_str(&format!("struct {} ", name));
+                let fields: Vec<(&Param, (usize, usize))> =
+                    fields.iter().zip(self.member_ranges(&stmt.span, Token::Semicolon)).collect();
+                self.braced(&fields, stmt.span.end, |&(_, range)| range, |_, _| false, |printer, (field, _)| {
+                    printer.out.push_str(&format!("{} {};", field.type_annotation, field.name));
+                });
+            }
+            StmtKind::EnumDecl { name, variants } => {
+                self.out.push_str(&format!("enum {} ", name));
+                let variants: Vec<_> = variants.iter().zip(self.member_ranges(&stmt.span, Token::Comma)).collect();
+                self.braced(&variants, stmt.span.end, |&(_, range)| range, |_, _| false, |printer, (variant, _)| {
+                    printer.out.push_str(&variant.name);
+                    if !variant.fields.is_empty() {
+                        let fields: Vec<String> = variant.fields.iter().map(Type::to_string).collect();
+                        printer.out.push_str(&format!("({})", fields.join(", ")));
+                    }
+                    printer.out.push(',');
+                });
+            }
+            StmtKind::Impl { name, methods } => {
+                self.out.push_str(&format!("impl {} ", name));
+                self.braced(methods, stmt.span.end, stmt_range, |_, _| true, Self::statement);
+            }
+            StmtKind::Match { subject, arms } => {
+                self.out.push_str("match (");
+                self.expr(subject);
+                self.out.push_str(") ");
+                self.braced(
+                    arms,
+                    stmt.span.end,
+                    |arm| (arm.span.start, arm.body.span.end),
+                    |_, _| false,
+                    |printer, arm| {
+                        printer.pattern(&arm.pattern);
+                        printer.out.push_str(" => ");
+                        if matches!(arm.body.kind, StmtKind::Block(_)) {
+                            printer.block(&arm.body);
+                        } else {
+                            printer.simple_statement(&arm.body);
+                            printer.out.push(',');
+                        }
+                    },
+                );
+            }
+        }
+    }
+
+    /// Writes a declaration, an assignment or an expression, without its ';'
+    fn simple_statement(&mut self, stmt: &Stmt) {
+        match &stmt.kind {
+            StmtKind::Let { name, type_annotation, value } => {
+                if let Some(ty) = type_annotation {
+                    self.out.push_str(&format!("{} ", ty));
+                }
+                self.out.push_str(&format!("{} = ", name));
+                self.expr(value);
+            }
+            StmtKind::Assignment { target, op, value } => {
+                self.target(target);
+                match op {
+                    Some(op) => self.out.push_str(&format!(" {}= ", op.symbol())),
+                    None => self.out.push_str(" = "),
+                }
+                self.expr(value);
+            }
+            StmtKind::Expression(expr) => self.expr(expr),
+            _ => self.statement(stmt),
+        }
+    }
+
+    /// Writes a block, the body of a function, loop or branch
+    fn block(&mut self, stmt: &Stmt) {
+        match &stmt.kind {
+            StmtKind::Block(statements) => {
+                self.braced(statements, stmt.span.end, stmt_range, |_, _| false, Self::statement)
+            }
+            _ => self.statement(stmt),
+        }
+    }
+
+    /// Writes `(Int a, Int b) -> Int `, up to the body of a function
+    fn signature(&mut self, params: &[Param], return_type: &Option<Type>) {
+        let params: Vec<String> = params
+            .iter()
+            .map(|param| format!("{} {}", param.type_annotation, param.name))
+            .collect();
+        self.out.push_str(&format!("({})", params.join(", ")));
+        if let Some(return_type) = return_type {
+            self.out.push_str(&format!(" -> {}", return_type));
+        }
+        self.out.push(' ');
+    }
+
+    fn target(&mut self, target: &AssignTarget) {
+        match target {
+            AssignTarget::Variable(name) => self.out.push_str(name),
+            AssignTarget::Index { array, index } => {
+                self.target(array);
+                self.out.push('[');
+                self.expr(index);
+                self.out.push(']');
+            }
+            AssignTarget::Field { object, field } => {
+                self.target(object);
+                self.out.push_str(&format!(".{}", field));
+            }
+        }
+    }
+
+    fn pattern(&mut self, pattern: &Pattern) {
+        match pattern {
+            Pattern::Literal(literal) => self.literal(literal),
+            Pattern::Variant { enum_name, variant, fields } if !fields.is_empty() => {
+                self.out.push_str(&format!("{}::{}(", enum_name, variant));
+                for (i, field) in fields.iter().enumerate() {
+                    if i > 0 {
+                        self.out.push_str(", ");
+                    }
+                    self.pattern(field);
+                }
+                self.out.push(')');
+            }
+            _ => self.out.push_str(&pattern.to_string()),
+        }
+    }
+
+    // EXPRESSIONS
+
+    fn expr(&mut self, expr: &Expr) {
+        match &expr.kind {
+            ExprKind::Literal(literal) => self.literal(literal),
+            ExprKind::Interpolated(parts) => {
+                self.out.push('"');
+                for part in parts {
+                    match part {
+                        InterpolatedPart::Text(text) => self.out.push_str(&escape(text, '"')),
+                        InterpolatedPart::Expr(expr) => {
+                            self.out.push_str("${");
+                            self.expr(expr);
+                            self.out.push('}');
+                        }
+                    }
+                }
+                self.out.push('"');
+            }
+            ExprKind::Variable(name) => self.out.push_str(name),
+            ExprKind::Binary { left, op, right } => {
+                let (precedence, left_associative) = op.precedence_and_associativity();
+                // An operand binding less tightly than the operator needs parentheses,
+                // as does one binding as tightly on the side associativity doesn't favour
+                let needs_parentheses = |operand: &Expr, tie: bool| {
+                    matches!(&operand.kind, ExprKind::Binary { op, .. }
+                        if op.precedence() < precedence || (op.precedence() == precedence && tie))
+                };
+                self.operand(left, needs_parentheses(left, !left_associative));
+                self.out.push_str(&format!(" {} ", op.symbol()));
+                self.operand(right, needs_parentheses(right, left_associative));
+            }
+            ExprKind::Unary { op, right } => {
+                self.out.push_str(op.symbol());
+                let double_minus = *op == UnaryOp::Minus
+                    && matches!(right.kind, ExprKind::Unary { op: UnaryOp::Minus, .. } | ExprKind::Literal(Literal::Number(i64::MIN..=-1)));
+                self.operand(right, double_minus || matches!(right.kind, ExprKind::Binary { .. }));
+            }
+            ExprKind::Call { callee, args } => {
+                // `Shape::Empty(1)` would give the variant a field
+                let variant = matches!(&callee.kind, ExprKind::Variant { args, .. } if args.is_empty());
+                self.operand(callee, variant || is_operation(callee));
+                self.out.push('(');
+                self.list(args);
+                self.out.push(')');
+            }
+            ExprKind::ArrayAccess { array, index } => {
+                self.operand(array, is_operation(array));
+                self.out.push('[');
+                self.expr(index);
+                self.out.push(']');
+            }
+            ExprKind::ArrayLiteral(elements) => {
+                self.out.push('[');
+                self.list(elements);
+                self.out.push(']');
+            }
+            ExprKind::FieldAccess { object, field } => {
+                self.operand(object, is_operation(object));
+                self.out.push_str(&format!(".{}", field));
+            }
+            ExprKind::StructLiteral { name, fields } => {
+                self.out.push_str(name);
+                if fields.is_empty() {
+                    self.out.push_str(" {}");
+                    return;
+                }
+                self.out.push_str(" { ");
+                for (i, (field, value)) in fields.iter().enumerate() {
+                    if i > 0 {
+                        self.out.push_str(", ");
+                    }
+                    self.out.push_str(&format!("{}: ", field));
+                    self.expr(value);
+                }
+                self.out.push_str(" }");
+            }
+            ExprKind::Variant { enum_name, variant, args } => {
+                self.out.push_str(&format!("{}::{}", enum_name, variant));
+                if !args.is_empty() {
+                    self.out.push('(');
+                    self.list(args);
+                    self.out.push(')');
+                }
+            }
+            ExprKind::Match { subject, arms } => {
+                self.out.push_str("match (");
+                self.expr(subject);
+                self.out.push_str(") ");
+                self.braced(
+                    arms,
+                    expr.span.end,
+                    |arm| (arm.span.start, arm.body.span.end),
+                    |_, _| false,
+                    |printer, arm| {
+                        printer.pattern(&arm.pattern);
+                        printer.out.push_str(" => ");
+                        printer.expr(&arm.body);
+                        printer.out.push(',');
+                    },
+                );
+            }
+            ExprKind::Lambda { params, return_type, body } => {
+                self.out.push_str("func");
+                self.signature(params, return_type);
+                self.block(body);
+            }
+        }
+    }
+
+    /// Writes an expression inside another, between parentheses if `parenthesized`
+    fn operand(&mut self, expr: &Expr, parenthesized: bool) {
+        if parenthesized {
+            self.out.push('(');
+        }
+        self.expr(expr);
+        if parenthesized {
+            self.out.push(')');
+        }
+    }
+
+    /// Writes expressions separated by commas
+    fn list(&mut self, exprs: &[Expr]) {
+        for (i, expr) in exprs.iter().enumerate() {
+            if i > 0 {
+                self.out.push_str(", ");
+            }
+            self.expr(expr);
+        }
+    }
+
+    fn literal(&mut self, literal: &Literal) {
+        let text = match literal {
+            Literal::Number(n) => n.to_string(),
+            Literal::Float(x) => self.float(*x),
+            Literal::String(s) => format!("\"{}\"", escape(s, '"')),
+            Literal::Char(c) => format!("'{}'", escape(&c.to_string(), '\'')),
+            Literal::Bool(true) => "True".to_string(),
+            Literal::Bool(false) => "False".to_string(),
+        };
+        self.out.push_str(&text);
+    }
+
+    /// Text of a float literal as written in the source
+    /// Literals are printed in source order, so the search starts after the last one found.
+    fn float(&mut self, x: f64) -> String {
+        // A negative literal is a negated float token in a pattern
+        let (sign, value) = if x.is_sign_negative() { ("-", -x) } else { ("", x) };
+        match self.floats[self.next_float..].iter().position(|(literal, _)| *literal == value) {
+            Some(offset) => {
+                self.next_float += offset + 1;
+                format!("{}{}", sign, self.floats[self.next_float - 1].1)
+            }
+            None => format!("{:?}", x),
+        }
+    }
+}
+
+fn stmt_range(stmt: &Stmt) -> (usize, usize) {
+    (stmt.span.start, stmt.span.end)
+}
+
+/// True for the declarations kept apart by blank lines at the top level
+fn is_declaration(stmt: &Stmt) -> bool {
+    matches!(
+        stmt.kind,
+        StmtKind::FunctionDecl { .. } | StmtKind::StructDecl { .. } | StmtKind::EnumDecl { .. } | StmtKind::Impl { .. }
+    )
+}
+
+/// True for operators, which need parentheses before a call, an index or a field
+fn is_operation(expr: &Expr) -> bool {
+    matches!(expr.kind, ExprKind::Binary { .. } | ExprKind::Unary { .. })
+}
+
+/// The expression written first in `expr`
+fn leftmost(expr: &Expr) -> &Expr {
+    match &expr.kind {
+        ExprKind::Binary { left: inner, .. }
+        | ExprKind::Call { callee: inner, .. }
+        | ExprKind::ArrayAccess { array: inner, .. }
+        | ExprKind::FieldAccess { object: inner, .. } => leftmost(inner),
+        _ => expr,
+    }
+}
+
+/// True if a struct literal of the expression is not enclosed in delimiters
+fn has_bare_struct_literal(expr: &Expr) -> bool {
+    match &expr.kind {
+        ExprKind::StructLiteral { .. } => true,
+        ExprKind::Binary { left, right, .. } => has_bare_struct_literal(left) || has_bare_struct_literal(right),
+        ExprKind::Unary { right: inner, .. }
+        | ExprKind::Call { callee: inner, .. }
+        | ExprKind::ArrayAccess { array: inner, .. }
+        | ExprKind::FieldAccess { object: inner, .. } => has_bare_struct_literal(inner),
+        _ => false,
+    }
+}
+
+/// Text of a string or character literal, with the escape sequences the lexer decodes
+fn escape(text: &str, quote: char) -> String {
+    let mut escaped = String::new();
+    let mut chars = text.chars().peekable();
+    while let Some(c) = chars.next() {
+        match c {
+            '\\' => escaped.push_str("\\\\"),
+            '\n' => escaped.push_str("\\n"),
+            '\t' => escaped.push_str("\\t"),
+            '\r' => escaped.push_str("\\r"),
+            '\0' => escaped.push_str("\\0"),
+            // `${` would start an interpolation
+            '$' if chars.peek() == Some(&'{') => escaped.push_str("\\$"),
+            c if c == quote => {
+                escaped.push('\\');
+                escaped.push(c);
+            }
+            c if c.is_control() => escaped.push_str(&format!("\\u{{{:x}}}", c as u32)),
+            c => escaped.push(c),
+        }
+    }
+    escaped
+}
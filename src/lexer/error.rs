@@ -0,0 +1,58 @@
+/*
+ * -------------------------------------------------------------------------
+ * RemyLang — lexer error handling
+ * File : src/lexer/error.rs
+ *
+ * Description :
+ *   Errors found while tokenizing, carried to the parser by Error tokens.
+ *
+ * Author  : Samuel 'Meeast' Bleau
+ * Created : 2026-10-16
+ *
+ * -------------------------------------------------------------------------
+*/
+
+use std::fmt;
+
+#[derive(Debug, Clone, PartialEq)]
+pub enum LexError {
+    /// Integer literal that does not fit in an Int
+    IntegerTooLarge(String),
+    /// Float literal too large to be represented
+    FloatTooLarge(String),
+    /// String literal without its closing '"'
+    UnterminatedString,
+    /// Character literal without its closing '\''
+    UnterminatedChar,
+    /// Character literal holding no character or several, as written between its quotes
+    InvalidCharLiteral(String),
+    /// Block comment without its closing "*/"
+    UnterminatedComment,
+    /// Backslash sequence that stands for no character, as written
+    InvalidEscape(String),
+}
+
+impl fmt::Display for LexError {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        match self {
+            LexError::IntegerTooLarge(literal) => {
+                write!(f, "Integer literal {} is too large for an Int", literal)
+            }
+            LexError::FloatTooLarge(literal) => {
+                write!(f, "Float literal {} is too large for a Float", literal)
+            }
+            LexError::UnterminatedString => write!(f, "Unterminated string literal"),
+            LexError::UnterminatedChar => write!(f, "Unterminated character literal"),
+            LexError::InvalidCharLiteral(text) if text.is_empty() => write!(f, "Empty character literal"),
+            LexError::InvalidCharLiteral(text) => {
+                write!(f, "Character literal '{}' holds more than one character", text)
+            }
+            LexError::UnterminatedComment => write!(f, "Unterminated block comment"),
+            LexError::InvalidEscape(sequence) => {
+                write!(f, "Invalid escape sequence '{}'", sequence)
+            }
+        }
+    }
+}
+
+impl std::error::Error for LexError {}
@@ -12,7 +12,10 @@
  * -------------------------------------------------------------------------
 */
 
-use super::token::Token;
+use std::rc::Rc;
+use super::error::LexError;
+use super::span::Span;
+use super::token::{Comment, SpannedToken, StringPart, Token};
 
 /// Main lexer structure
 pub struct Lexer {
@@ -21,6 +24,8 @@ pub struct Lexer {
     current_char: Option<char>, //Sort of either char or None
     line: usize,
     column: usize,
+    file: Rc<str>,
+    comments: Vec<Comment>,
 }
 
 impl Lexer {
@@ -35,58 +40,88 @@ impl Lexer {
     /// let lexer = Lexer::new("Int nb = 42;".to_string());
     /// ```
     pub fn new(input: String) -> Self {
+        Self::with_file(input, "<input>")
+    }
+
+    /// Creates a new Lexer whose spans refer to the given file name
+    ///
+    /// # Example
+    /// ```
+    /// use remylang::lexer::Lexer;
+    /// let mut lexer = Lexer::with_file("Int nb = 42;".to_string(), "main.remy");
+    /// assert_eq!(lexer.next_token().span.to_string(), "main.remy:1:1");
+    /// ```
+    pub fn with_file(input: String, file: &str) -> Self {
         let mut lexer = Lexer {
             input: input.chars().collect(),
             position: 0,
             current_char: None,
             line: 1,
-            column: 0,
+            column: 1,
+            file: Rc::from(file),
+            comments: Vec::new(),
         };
-        lexer.current_char = lexer.input.get(0).cloned();
+        lexer.current_char = lexer.input.first().cloned();
         lexer
     }
 
     /// Advances to the next character in the input
     fn advance(&mut self) {
+        if self.current_char.is_none() {
+            return;
+        }
+
         // Handle newline tracking for error reporting
         if let Some('\n') = self.current_char {
             self.line += 1;
-            self.column = 0;
+            self.column = 1;
         } else {
             self.column += 1;
         }
 
         // Move to next character
-        if self.position + 1 >= self.input.len() {
-            self.current_char = None;
-        } else {
-            self.position += 1;
-            self.current_char = Some(self.input[self.position]);
-        }
+        self.position += 1;
+        self.current_char = self.input.get(self.position).cloned();
     }
 
     /// Peeks at the next character without consuming it
     fn peek(&self) -> Option<char> {
-        if self.position + 1 >= self.input.len() {
-            None
-        } else {
-            Some(self.input[self.position + 1])
-        }
-            
+        self.input.get(self.position + 1).cloned()
     }
 
-    // NOTE: peek_ahead() can be added later if needed for complex multi-char operators
-    // /// Peeks ahead n characters without consuming them
-    // fn peek_ahead(&self, n: usize) -> Option<char> {
-    //     if self.position + n >= self.input.len() {
-    //         None
-    //     } else {
-    //         Some(self.input[self.position + n])
-    //     }
-    // }
+    /// Peeks ahead n characters without consuming them
+    fn peek_ahead(&self, n: usize) -> Option<char> {
+        self.input.get(self.position + n).cloned()
+    }
 
     // WHITESPACE AND COMMENTS HANDLING
 
+    /// Skips whitespace and comments until the start of the next token
+    /// A block comment that is never closed is returned as an error token.
+    /// Comments are kept aside, see `comments`.
+    fn skip_trivia(&mut self) -> Option<SpannedToken> {
+        loop {
+            self.skip_whitespace();
+            let (start, line, column) = (self.position, self.line, self.column);
+            match (self.current_char, self.peek()) {
+                (Some('/'), Some('/')) => self.skip_line_comment(),
+                (Some('/'), Some('*')) => {
+                    self.advance();
+                    self.advance();
+                    if !self.skip_block_comment() {
+                        let span = Span::new(self.file.clone(), start, self.position, line, column);
+                        return Some(SpannedToken::new(Token::Error(LexError::UnterminatedComment), span));
+                    }
+                }
+                _ => return None,
+            }
+            self.comments.push(Comment {
+                text: self.input[start..self.position].iter().collect(),
+                span: Span::new(self.file.clone(), start, self.position, line, column),
+            });
+        }
+    }
+
     /// Skips whitespace characters
     fn skip_whitespace(&mut self) {
         while let Some(c) = self.current_char {
@@ -109,24 +144,65 @@ impl Lexer {
         }
     }
 
-    /// Skips a block comment
-    fn skip_block_comment(&mut self) {
+    /// Skips a block comment, returning false if the input ends before it is closed
+    fn skip_block_comment(&mut self) -> bool {
         while let Some(c) = self.current_char {
             if c == '*' && self.peek() == Some('/') {
                 self.advance();
                 self.advance();
-                break;
-            } else {
-                self.advance();
+                return true;
             }
+            self.advance();
         }
+        false
     }
 
     // TOKEN RECOGNITION - IDENTIFIERS AND KEYWORDS
 
-    /// Reads an integer literal
+    /// Reads an integer literal, or a float literal such as `3.14` or `1e-3`
     fn read_number(&mut self) -> Token {
         let mut val = String::new();
+        self.read_digits(&mut val);
+
+        // A '.' only starts a fraction when a digit follows it
+        let mut is_float = false;
+        if self.current_char == Some('.') && self.peek().is_some_and(|c| c.is_ascii_digit()) {
+            is_float = true;
+            val.push('.');
+            self.advance();
+            self.read_digits(&mut val);
+        }
+
+        if let Some(e @ ('e' | 'E')) = self.current_char {
+            let sign = self.peek().filter(|c| matches!(c, '+' | '-'));
+            let digit = if sign.is_some() { self.peek_ahead(2) } else { self.peek() };
+            if digit.is_some_and(|c| c.is_ascii_digit()) {
+                is_float = true;
+                val.push(e);
+                self.advance();
+                if let Some(sign) = sign {
+                    val.push(sign);
+                    self.advance();
+                }
+                self.read_digits(&mut val);
+            }
+        }
+
+        if is_float {
+            match val.parse::<f64>() {
+                Ok(x) if x.is_finite() => Token::FloatLit(x),
+                _ => Token::Error(LexError::FloatTooLarge(val)),
+            }
+        } else {
+            match val.parse::<i64>() {
+                Ok(n) => Token::Number(n),
+                Err(_) => Token::Error(LexError::IntegerTooLarge(val)),
+            }
+        }
+    }
+
+    /// Appends the digits starting at the current character to `val`
+    fn read_digits(&mut self, val: &mut String) {
         while let Some(c) = self.current_char {
             if c.is_ascii_digit() {
                 val.push(c);
@@ -135,7 +211,6 @@ impl Lexer {
                 break;
             }
         }
-        Token::Number(val.parse::<i64>().unwrap())
     }
 
     /// Reads an identifier or keyword
@@ -156,64 +231,236 @@ impl Lexer {
     fn keyword_or_identifier(&self, ident: String) -> Token {
         match ident.as_str() {
             "Int" => Token::Int,
+            "Float" => Token::Float,
             "String" => Token::String,
             "Char" => Token::Char,
             "Bool" => Token::Bool,
             "Array" => Token::Array,
+            "Func" => Token::FuncType,
             "True" => Token::True,
             "False" => Token::False,
             "func" => Token::Func,
             "return" => Token::Return,
             "if" => Token::If,
             "else" => Token::Else,
+            "while" => Token::While,
+            "for" => Token::For,
+            "in" => Token::In,
+            "break" => Token::Break,
+            "continue" => Token::Continue,
+            "struct" => Token::Struct,
+            "impl" => Token::Impl,
+            "enum" => Token::Enum,
+            "match" => Token::Match,
             _ => Token::Identifier(ident),
         }
     }
 
     // TOKEN RECOGNITION - STRINGS AND CHARACTERS
 
-    /// Reads a string literal
+    /// Reads a string literal, decoding its escape sequences
+    /// Strings may span several lines. After an invalid escape sequence,
+    /// reading goes on to the closing '"' so the next token is found.
+    /// A string containing `${expression}` gives an InterpolatedString.
     fn read_string(&mut self) -> Token {
+        self.advance(); // Skip opening "
+
         let mut val = String::new();
-        self.advance();
-        while let Some(c) = self.current_char {
-            if c != '"' {
-                val.push(c);
-                self.advance();
-            } else {
-                break;
+        let mut parts = Vec::new();
+        let mut error = None;
+        loop {
+            match self.current_char {
+                None => return Token::Error(LexError::UnterminatedString),
+                Some('"') => break,
+                Some('$') if self.peek() == Some('{') => {
+                    self.advance();
+                    self.advance();
+                    if !val.is_empty() {
+                        parts.push(StringPart::Text(std::mem::take(&mut val)));
+                    }
+                    match self.read_interpolation() {
+                        Some(tokens) => parts.push(StringPart::Code(tokens)),
+                        None => return Token::Error(LexError::UnterminatedString),
+                    }
+                }
+                Some('\\') => match self.read_escape() {
+                    Ok(c) => val.push(c),
+                    Err(e) => {
+                        error.get_or_insert(e);
+                    }
+                },
+                Some(c) => {
+                    val.push(c);
+                    self.advance();
+                }
             }
         }
-        self.advance();
-        Token::StringLit(val)
+        self.advance(); // Skip closing "
+
+        if let Some(e) = error {
+            return Token::Error(e);
+        }
+        if parts.is_empty() {
+            return Token::StringLit(val);
+        }
+        if !val.is_empty() {
+            parts.push(StringPart::Text(val));
+        }
+        Token::InterpolatedString(parts)
     }
 
-    /// Reads a character literal
+    /// Reads the tokens of an interpolated expression, after its `${`
+    /// They end with an EOF token in place of the closing '}'. Returns None
+    /// if the input ends first.
+    fn read_interpolation(&mut self) -> Option<Vec<SpannedToken>> {
+        let mut tokens = Vec::new();
+        let mut depth = 0;
+        loop {
+            let token = self.next_token();
+            match token.token {
+                Token::EOF => return None,
+                Token::LeftBrace => depth += 1,
+                Token::RightBrace if depth == 0 => {
+                    tokens.push(SpannedToken::new(Token::EOF, token.span));
+                    return Some(tokens);
+                }
+                Token::RightBrace => depth -= 1,
+                _ => {}
+            }
+            tokens.push(token);
+        }
+    }
+
+    /// Reads a character literal, decoding an escape sequence
     fn read_character(&mut self) -> Token {
         self.advance(); // Skip opening '
-        
-        let ch = if let Some(c) = self.current_char {
-            self.advance();
-            c
-        } else {
-            return Token::Invalid('\''); // Empty character literal
+        let start = self.position;
+
+        let ch = match self.current_char {
+            None | Some('\n') => return Token::Error(LexError::UnterminatedChar),
+            Some('\'') => {
+                self.advance();
+                return Token::Error(LexError::InvalidCharLiteral(String::new()));
+            }
+            Some('\\') => self.read_escape(),
+            Some(c) => {
+                self.advance();
+                Ok(c)
+            }
         };
-        
-        // Expect closing '
+
+        // Expect closing ', skipping any extra characters on the same line
         if self.current_char != Some('\'') {
-            return Token::Invalid('\''); // Missing closing '
+            while let Some(c) = self.current_char {
+                if c == '\'' {
+                    let text = self.input[start..self.position].iter().collect();
+                    self.advance();
+                    return Token::Error(LexError::InvalidCharLiteral(text));
+                }
+                if c == '\n' {
+                    break;
+                }
+                self.advance();
+            }
+            return Token::Error(LexError::UnterminatedChar);
         }
         self.advance(); // Skip closing '
-        
-        Token::CharLit(ch)
+
+        match ch {
+            Ok(c) => Token::CharLit(c),
+            Err(e) => Token::Error(e),
+        }
+    }
+
+    /// Reads an escape sequence starting at a '\', returning its character
+    /// Supported: \n \t \r \\ \" \' \$ \0 and \u{XXXX}, a Unicode scalar value
+    /// given by 1 to 6 hexadecimal digits.
+    fn read_escape(&mut self) -> Result<char, LexError> {
+        self.advance(); // Skip '\'
+
+        let Some(c) = self.current_char else {
+            return Err(LexError::InvalidEscape("\\".to_string()));
+        };
+        self.advance();
+        match c {
+            'n' => Ok('\n'),
+            't' => Ok('\t'),
+            'r' => Ok('\r'),
+            '0' => Ok('\0'),
+            '\\' | '"' | '\'' | '$' => Ok(c),
+            'u' => {
+                let mut sequence = "\\u".to_string();
+                if self.current_char != Some('{') {
+                    return Err(LexError::InvalidEscape(sequence));
+                }
+                sequence.push('{');
+                self.advance();
+
+                let mut digits = String::new();
+                while let Some(d) = self.current_char.filter(|d| d.is_ascii_hexdigit()) {
+                    digits.push(d);
+                    self.advance();
+                }
+                sequence.push_str(&digits);
+                if self.current_char != Some('}') {
+                    return Err(LexError::InvalidEscape(sequence));
+                }
+                sequence.push('}');
+                self.advance();
+
+                u32::from_str_radix(&digits, 16)
+                    .ok()
+                    .filter(|_| digits.len() <= 6)
+                    .and_then(char::from_u32)
+                    .ok_or(LexError::InvalidEscape(sequence))
+            }
+            other => Err(LexError::InvalidEscape(format!("\\{}", other))),
+        }
     }
 
     // TOKENIZATION LOGIC
 
-    /// Retrieves the next token from the input
-    pub fn next_token(&mut self) -> Token {
-        self.skip_whitespace();
+    /// Retrieves the next token from the input, along with its span
+    pub fn next_token(&mut self) -> SpannedToken {
+        if let Some(error) = self.skip_trivia() {
+            return error;
+        }
 
+        let (start, line, column) = (self.position, self.line, self.column);
+        let token = self.scan_token();
+        let span = Span::new(self.file.clone(), start, self.position, line, column);
+        SpannedToken::new(token, span)
+    }
+
+    /// Tokenizes the whole input, including the trailing EOF token
+    pub fn tokenize(&mut self) -> Vec<SpannedToken> {
+        let mut tokens = Vec::new();
+        loop {
+            let token = self.next_token();
+            let is_eof = token.token == Token::EOF;
+            tokens.push(token);
+            if is_eof {
+                break;
+            }
+        }
+        tokens
+    }
+
+    /// Comments skipped so far, in the order they appear
+    ///
+    /// # Example
+    /// ```
+    /// use remylang::lexer::Lexer;
+    /// let mut lexer = Lexer::new("Int nb = 42; // the answer".to_string());
+    /// lexer.tokenize();
+    /// assert_eq!(lexer.comments()[0].text, "// the answer");
+    /// ```
+    pub fn comments(&self) -> &[Comment] {
+        &self.comments
+    }
+
+    /// Scans a single token starting at the current character
+    fn scan_token(&mut self) -> Token {
         match self.current_char {
             None => Token::EOF,
             Some(ch) if ch.is_ascii_digit() => self.read_number(),
@@ -259,14 +506,7 @@ impl Lexer {
             },
             Some('/') => {
                 self.advance();
-                if self.current_char == Some('/') {
-                    self.skip_line_comment();
-                    self.next_token() // Recursively get next token after comment
-                } else if self.current_char == Some('*') {
-                    self.advance();
-                    self.skip_block_comment();
-                    self.next_token() // Recursively get next token after comment
-                } else if self.current_char == Some('=') {
+                if self.current_char == Some('=') {
                     self.advance();
                     Token::SlashEqual
                 } else {
@@ -287,6 +527,9 @@ impl Lexer {
                 if self.current_char == Some('=') {
                     self.advance();
                     Token::EqualEqual
+                } else if self.current_char == Some('>') {
+                    self.advance();
+                    Token::FatArrow
                 } else {
                     Token::Equal
                 }
@@ -370,7 +613,16 @@ impl Lexer {
             },
             Some(':') => {
                 self.advance();
-                Token::Colon
+                if self.current_char == Some(':') {
+                    self.advance();
+                    Token::DoubleColon
+                } else {
+                    Token::Colon
+                }
+            },
+            Some('.') => {
+                self.advance();
+                Token::Dot
             },
             Some(ch) => {
                 self.advance();
@@ -384,11 +636,11 @@ impl Lexer {
 
 
 impl Iterator for Lexer {
-    type Item = Token;
+    type Item = SpannedToken;
 
     fn next(&mut self) -> Option<Self::Item> {
         let token = self.next_token();
-        if token == Token::EOF {
+        if token.token == Token::EOF {
             None
         } else {
             Some(token)
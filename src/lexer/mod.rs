@@ -14,8 +14,13 @@
 
 
 mod token;
+mod span;
+mod error;
+#[allow(clippy::module_inception)]
 mod lexer;
 
 // Re-export for external use
+pub use error::LexError;
 pub use lexer::Lexer;
-pub use token::Token;
\ No newline at end of file
+pub use span::Span;
+pub use token::{Comment, SpannedToken, StringPart, Token};
@@ -0,0 +1,64 @@
+/*
+ * -------------------------------------------------------------------------
+ * RemyLang — source spans
+ * File : src/lexer/span.rs
+ *
+ * Description :
+ *   Source location tracking shared by tokens, AST nodes and errors.
+ *
+ * Author  : Samuel 'Meeast' Bleau
+ * Created : 2026-10-16
+ *
+ * -------------------------------------------------------------------------
+*/
+
+use std::fmt;
+use std::rc::Rc;
+
+/// A region of source code
+///
+/// `start` and `end` are character offsets into the source (end exclusive),
+/// `line` and `column` are 1-based and point at the first character.
+#[derive(Debug, Clone, PartialEq, Eq, Hash, Default)]
+pub struct Span {
+    pub file: Rc<str>,
+    pub start: usize,
+    pub end: usize,
+    pub line: usize,
+    pub column: usize,
+}
+
+impl Span {
+    /// Creates a new span
+    pub fn new(file: Rc<str>, start: usize, end: usize, line: usize, column: usize) -> Self {
+        Span { file, start, end, line, column }
+    }
+
+    /// Returns a span covering both `self` and `other`
+    /// `self` is expected to come first in the source.
+    pub fn to(&self, other: &Span) -> Span {
+        Span {
+            file: self.file.clone(),
+            start: self.start,
+            end: other.end.max(self.end),
+            line: self.line,
+            column: self.column,
+        }
+    }
+
+    /// Number of characters covered by the span
+    pub fn len(&self) -> usize {
+        self.end.saturating_sub(self.start)
+    }
+
+    /// Returns true if the span covers no characters
+    pub fn is_empty(&self) -> bool {
+        self.len() == 0
+    }
+}
+
+impl fmt::Display for Span {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        write!(f, "{}:{}:{}", self.file, self.line, self.column)
+    }
+}
@@ -12,21 +12,30 @@
  * -------------------------------------------------------------------------
 */
 
+use super::error::LexError;
+use super::span::Span;
+
 #[derive(Debug, Clone, PartialEq)]
 
 pub enum Token {
     // Literals
     Number(i64),
+    FloatLit(f64),
     StringLit(String),
+    /// String literal containing `${expression}` parts
+    InterpolatedString(Vec<StringPart>),
     CharLit(char),
     Identifier(String), // variable or function name
 
     // Keywords
     Int,
+    Float,
     String,
     Char,
     Bool,
     Array,
+    /// `Func`, the type of functions, unlike `func` which declares one
+    FuncType,
     True,
     False,
 
@@ -34,11 +43,15 @@ pub enum Token {
     Return,
     If,
     Else,
-    //TODO: Implement loop keywords
-    // While,
-    // For,
-    // Break,
-    // Continue,
+    While,
+    For,
+    In,
+    Break,
+    Continue,
+    Struct,
+    Impl,
+    Enum,
+    Match,
 
     // Operators - Arithmetic
     Plus,
@@ -81,10 +94,15 @@ pub enum Token {
     Semicolon,
     Comma,
     Colon,
+    DoubleColon,
+    Dot,
     Arrow,
+    FatArrow,
 
     EOF,
     Invalid(char),
+    /// Malformed token, reported by the parser
+    Error(LexError),
 }
 
 impl Token {
@@ -95,21 +113,27 @@ impl Token {
         matches!(
             self,
             Token::Int
+                | Token::Float
                 | Token::String
                 | Token::Char
                 | Token::Bool
                 | Token::Array
+                | Token::FuncType
                 | Token::True
                 | Token::False
                 | Token::Func
                 | Token::Return
                 | Token::If
                 | Token::Else
-                // TODO: Uncomment when loops are implemented
-                // | Token::While
-                // | Token::For
-                // | Token::Break
-                // | Token::Continue
+                | Token::While
+                | Token::For
+                | Token::In
+                | Token::Break
+                | Token::Continue
+                | Token::Struct
+                | Token::Impl
+                | Token::Enum
+                | Token::Match
         )
     }
 
@@ -119,7 +143,13 @@ impl Token {
     pub fn is_literal(&self) -> bool {
         matches!(
             self,
-            Token::Number(_) | Token::StringLit(_) | Token::CharLit(_) | Token::True | Token::False
+            Token::Number(_)
+                | Token::FloatLit(_)
+                | Token::StringLit(_)
+                | Token::InterpolatedString(_)
+                | Token::CharLit(_)
+                | Token::True
+                | Token::False
         )
     }
 
@@ -147,3 +177,42 @@ impl Token {
         )
     }
 }
+
+/// A piece of an interpolated string literal
+#[derive(Debug, Clone, PartialEq)]
+pub enum StringPart {
+    /// Text, with its escape sequences decoded
+    Text(String),
+    /// Tokens of an expression between `${` and `}`, ending with an EOF
+    /// token at the closing '}' so that they can be parsed on their own
+    Code(Vec<SpannedToken>),
+}
+
+/// A token together with its location in the source
+#[derive(Debug, Clone, PartialEq)]
+pub struct SpannedToken {
+    pub token: Token,
+    pub span: Span,
+}
+
+impl SpannedToken {
+    /// Creates a new spanned token
+    pub fn new(token: Token, span: Span) -> Self {
+        SpannedToken { token, span }
+    }
+}
+
+/// A comment, kept out of the tokens for the tools that need it, like the formatter
+#[derive(Debug, Clone, PartialEq)]
+pub struct Comment {
+    /// Text of the comment, including its `//` or `/* */` delimiters
+    pub text: String,
+    pub span: Span,
+}
+
+/// Allows comparing a spanned token against a bare token, ignoring the span
+impl PartialEq<Token> for SpannedToken {
+    fn eq(&self, other: &Token) -> bool {
+        self.token == *other
+    }
+}
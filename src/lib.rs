@@ -15,4 +15,11 @@
 pub mod lexer;
 pub mod ast;
 pub mod parser;
+pub mod semantic;
 pub mod vm;
+pub mod diagnostics;
+pub mod llvm_backend;
+pub mod repl;
+pub mod formatter;
+pub mod lsp;
+pub mod stdlib;
@@ -0,0 +1,1186 @@
+/*
+ * -------------------------------------------------------------------------
+ * RemyLang — LLVM code generation
+ * File : src/llvm_backend/codegen.rs
+ *
+ * Description :
+ *   Lowers type-checked RemyLang programs to LLVM IR. Top-level code
+ *   becomes the `main` function and top-level variables become globals.
+ *
+ * Author  : Samuel 'Meeast' Bleau
+ * Created : 2026-10-16
+ *
+ * -------------------------------------------------------------------------
+*/
+
+use inkwell::basic_block::BasicBlock;
+use inkwell::builder::Builder;
+use inkwell::context::Context;
+use inkwell::intrinsics::Intrinsic;
+use inkwell::module::{Linkage, Module};
+use inkwell::types::BasicTypeEnum;
+use inkwell::values::{BasicMetadataValueEnum, BasicValueEnum, FloatValue, FunctionValue, IntValue, PointerValue};
+use inkwell::{FloatPredicate, IntPredicate};
+use crate::ast::*;
+use crate::lexer::Span;
+use crate::llvm_backend::context::{CodegenContext, FunctionInfo, FunctionState, LoopBlocks, Variable};
+use crate::llvm_backend::error::{CodegenError, CodegenResult};
+use crate::llvm_backend::types::{descriptor, LlvmTypes};
+use crate::vm::builtin;
+use crate::vm::RuntimeError;
+
+/// A generated value with its RemyLang type
+/// Void values are represented by a placeholder that is never used.
+type Typed<'ctx> = (BasicValueEnum<'ctx>, Type);
+
+/// Generates an LLVM module from a program
+pub struct CodeGen<'ctx> {
+    context: &'ctx Context,
+    module: Module<'ctx>,
+    builder: Builder<'ctx>,
+    types: LlvmTypes<'ctx>,
+    symbols: CodegenContext<'ctx>,
+}
+
+impl<'ctx> CodeGen<'ctx> {
+    /// Create a code generator for a new module
+    pub fn new(context: &'ctx Context, module_name: &str) -> Self {
+        CodeGen {
+            context,
+            module: context.create_module(module_name),
+            builder: context.create_builder(),
+            types: LlvmTypes::new(context),
+            symbols: CodegenContext::new(),
+        }
+    }
+
+    /// Generate the module for a type-checked program
+    pub fn compile_program(mut self, program: &[Stmt]) -> CodegenResult<Module<'ctx>> {
+        self.declare_runtime();
+
+        // Structs and enums are only declared at the top level
+        for stmt in program {
+            let feature = match stmt.kind {
+                StmtKind::StructDecl { .. } | StmtKind::Impl { .. } => "Structs",
+                StmtKind::EnumDecl { .. } => "Enums",
+                _ => continue,
+            };
+            return Err(CodegenError::Unsupported {
+                feature: feature.to_string(),
+                span: stmt.span.clone(),
+            });
+        }
+
+        // Signatures first, so functions can call each other in any order
+        for stmt in program {
+            if let StmtKind::FunctionDecl { name, params, return_type, .. } = &stmt.kind {
+                self.declare_function(name, params, return_type.as_ref().unwrap_or(&Type::Void));
+            }
+        }
+
+        let main = self.module.add_function("main", self.types.i32.fn_type(&[], false), None);
+        let entry = self.context.append_basic_block(main, "entry");
+        self.builder.position_at_end(entry);
+        self.symbols.enter_function(FunctionState::new(main, None));
+
+        for stmt in program {
+            self.compile_stmt(stmt)?;
+        }
+        self.builder.build_return(Some(&self.types.i32.const_zero()))?;
+
+        self.module
+            .verify()
+            .map_err(|e| CodegenError::Llvm(e.to_string()))?;
+        Ok(self.module)
+    }
+
+    // ========================================================================
+    // DECLARATIONS
+    // ========================================================================
+
+    /// Declare the functions of the native runtime
+    fn declare_runtime(&self) {
+        let t = &self.types;
+        let void = self.context.void_type();
+        let declarations = [
+            ("remy_runtime_error", void.fn_type(&[t.ptr.into()], false)),
+            ("remy_print_int", void.fn_type(&[t.int.into()], false)),
+            ("remy_print_float", void.fn_type(&[t.float.into()], false)),
+            ("remy_print_bool", void.fn_type(&[t.bool.into()], false)),
+            ("remy_print_char", void.fn_type(&[t.char.into()], false)),
+            ("remy_print_str", void.fn_type(&[t.ptr.into()], false)),
+            ("remy_print_array", void.fn_type(&[t.ptr.into(), t.ptr.into()], false)),
+            ("remy_print_space", void.fn_type(&[], false)),
+            ("remy_print_newline", void.fn_type(&[], false)),
+            ("remy_str_concat", t.ptr.fn_type(&[t.ptr.into(), t.ptr.into()], false)),
+            ("remy_str_eq", t.bool.fn_type(&[t.ptr.into(), t.ptr.into()], false)),
+            ("remy_array_new", t.ptr.fn_type(&[t.int.into()], false)),
+            ("remy_array_len", t.int.fn_type(&[t.ptr.into()], false)),
+            ("remy_array_get", t.int.fn_type(&[t.ptr.into(), t.int.into()], false)),
+            ("remy_array_set", void.fn_type(&[t.ptr.into(), t.int.into(), t.int.into()], false)),
+            ("remy_array_eq", t.bool.fn_type(&[t.ptr.into(), t.ptr.into(), t.ptr.into()], false)),
+            ("remy_array_copy", t.ptr.fn_type(&[t.ptr.into(), t.ptr.into()], false)),
+            ("remy_pow", t.int.fn_type(&[t.int.into(), t.int.into()], false)),
+        ];
+
+        for (name, ty) in declarations {
+            self.module.add_function(name, ty, Some(Linkage::External));
+        }
+    }
+
+    /// Declare a RemyLang function
+    /// Symbols are prefixed so they cannot clash with `main` or the runtime.
+    fn declare_function(&mut self, name: &str, params: &[Param], return_type: &Type) {
+        let param_types: Vec<Type> = params.iter().map(|p| p.type_annotation.clone()).collect();
+        let fn_type = self.types.function(&param_types, return_type);
+        let value = self.module.add_function(&format!("remy.{}", name), fn_type, None);
+
+        for (param, llvm_param) in params.iter().zip(value.get_param_iter()) {
+            llvm_param.set_name(&param.name);
+        }
+
+        self.symbols.declare_function(name, FunctionInfo {
+            value,
+            params: param_types,
+            return_type: return_type.clone(),
+        });
+    }
+
+    // ========================================================================
+    // STATEMENTS
+    // ========================================================================
+
+    fn compile_stmt(&mut self, stmt: &Stmt) -> CodegenResult<()> {
+        let span = &stmt.span;
+        match &stmt.kind {
+            StmtKind::Expression(expr) => {
+                self.compile_expr(expr)?;
+            }
+
+            StmtKind::Let { name, type_annotation, value } => {
+                let (value, ty) = self.compile_owned(value, type_annotation.as_ref())?;
+                let ptr = self.allocate_variable(name, &ty, span)?;
+                self.builder.build_store(ptr, value)?;
+                self.symbols.define(name, Variable { ptr, ty });
+            }
+
+            StmtKind::Assignment { target, op, value } => {
+                let variable = self.variable(target.name(), span)?;
+                let mut indices = Vec::new();
+                for accessor in target.path() {
+                    let Accessor::Index(index) = accessor else {
+                        return Err(CodegenError::Unsupported {
+                            feature: "Structs".to_string(),
+                            span: span.clone(),
+                        });
+                    };
+                    indices.push(self.compile_expr(index)?.0.into_int_value());
+                }
+                let ty = element_type(&variable.ty, indices.len(), span)?;
+
+                let value = match op {
+                    Some(op) => {
+                        let current = self.load_target(&variable, &indices, &ty)?;
+                        let right = self.compile_expr(value)?;
+                        self.compile_binary((current, ty.clone()), *op, right)?.0
+                    }
+                    None => self.compile_owned(value, Some(&ty))?.0,
+                };
+                self.store_target(&variable, &indices, &ty, value)?;
+            }
+
+            StmtKind::Block(statements) => {
+                self.symbols.push_scope();
+                let result = statements.iter().try_for_each(|s| self.compile_stmt(s));
+                self.symbols.pop_scope();
+                result?;
+            }
+
+            StmtKind::If { condition, then_branch, else_branch } => {
+                let condition = self.compile_condition(condition)?;
+                let function = self.current_function();
+                let then_block = self.context.append_basic_block(function, "then");
+                let else_block = self.context.append_basic_block(function, "else");
+                let merge_block = self.context.append_basic_block(function, "endif");
+
+                self.builder.build_conditional_branch(condition, then_block, else_block)?;
+
+                self.builder.position_at_end(then_block);
+                self.compile_stmt(then_branch)?;
+                self.builder.build_unconditional_branch(merge_block)?;
+
+                self.builder.position_at_end(else_block);
+                if let Some(else_branch) = else_branch {
+                    self.compile_stmt(else_branch)?;
+                }
+                self.builder.build_unconditional_branch(merge_block)?;
+
+                self.builder.position_at_end(merge_block);
+            }
+
+            StmtKind::Return(value) => {
+                let return_type = match &self.symbols.function_state().return_type {
+                    Some(ty) => ty.clone(),
+                    None => {
+                        return Err(CodegenError::Unsupported {
+                            feature: "Return statements outside of functions".to_string(),
+                            span: span.clone(),
+                        });
+                    }
+                };
+                match value {
+                    Some(expr) if return_type != Type::Void => {
+                        let (value, _) = self.compile_expr_expecting(expr, &return_type)?;
+                        self.builder.build_return(Some(&value))?;
+                    }
+                    Some(expr) => {
+                        self.compile_expr(expr)?;
+                        self.builder.build_return(None)?;
+                    }
+                    None => {
+                        self.builder.build_return(None)?;
+                    }
+                }
+                self.start_unreachable_block("after_return");
+            }
+
+            StmtKind::While { condition, body } => {
+                let function = self.current_function();
+                let cond_block = self.context.append_basic_block(function, "while_cond");
+                let body_block = self.context.append_basic_block(function, "while_body");
+                let exit_block = self.context.append_basic_block(function, "while_end");
+
+                self.builder.build_unconditional_branch(cond_block)?;
+                self.builder.position_at_end(cond_block);
+                let condition = self.compile_condition(condition)?;
+                self.builder.build_conditional_branch(condition, body_block, exit_block)?;
+
+                self.builder.position_at_end(body_block);
+                self.compile_loop_body(body, cond_block, exit_block)?;
+                self.builder.build_unconditional_branch(cond_block)?;
+
+                self.builder.position_at_end(exit_block);
+            }
+
+            StmtKind::For { initializer, condition, increment, body } => {
+                // The initializer's variables are scoped to the loop
+                self.symbols.push_scope();
+                let result = self.compile_for(
+                    initializer.as_deref(),
+                    condition.as_ref(),
+                    increment.as_deref(),
+                    body,
+                );
+                self.symbols.pop_scope();
+                result?;
+            }
+
+            StmtKind::ForIn { variable, iterable, body } => {
+                self.symbols.push_scope();
+                let result = self.compile_for_in(variable, iterable, body, span);
+                self.symbols.pop_scope();
+                result?;
+            }
+
+            StmtKind::Break | StmtKind::Continue => {
+                let blocks = self.symbols.current_loop().ok_or_else(|| CodegenError::Unsupported {
+                    feature: "Loop control statements outside of loops".to_string(),
+                    span: span.clone(),
+                })?;
+                let target = if matches!(stmt.kind, StmtKind::Break) {
+                    blocks.break_block
+                } else {
+                    blocks.continue_block
+                };
+                self.builder.build_unconditional_branch(target)?;
+                self.start_unreachable_block("after_jump");
+            }
+
+            StmtKind::FunctionDecl { name, params, body, .. } => {
+                if !self.symbols.is_global_scope() {
+                    return Err(CodegenError::Unsupported {
+                        feature: "Nested functions".to_string(),
+                        span: span.clone(),
+                    });
+                }
+                self.compile_function(name, params, body)?;
+            }
+
+            StmtKind::StructDecl { .. } | StmtKind::Impl { .. } => {
+                return Err(CodegenError::Unsupported {
+                    feature: "Structs".to_string(),
+                    span: span.clone(),
+                });
+            }
+
+            StmtKind::EnumDecl { .. } => {
+                return Err(CodegenError::Unsupported {
+                    feature: "Enums".to_string(),
+                    span: span.clone(),
+                });
+            }
+
+            StmtKind::Match { .. } => {
+                return Err(CodegenError::Unsupported {
+                    feature: "Pattern matching".to_string(),
+                    span: span.clone(),
+                });
+            }
+        }
+        Ok(())
+    }
+
+    /// Generate a C-style for loop, once its scope has been opened
+    fn compile_for(
+        &mut self,
+        initializer: Option<&Stmt>,
+        condition: Option<&Expr>,
+        increment: Option<&Stmt>,
+        body: &Stmt,
+    ) -> CodegenResult<()> {
+        if let Some(init) = initializer {
+            self.compile_stmt(init)?;
+        }
+
+        let function = self.current_function();
+        let cond_block = self.context.append_basic_block(function, "for_cond");
+        let body_block = self.context.append_basic_block(function, "for_body");
+        let inc_block = self.context.append_basic_block(function, "for_inc");
+        let exit_block = self.context.append_basic_block(function, "for_end");
+
+        self.builder.build_unconditional_branch(cond_block)?;
+        self.builder.position_at_end(cond_block);
+        match condition {
+            Some(cond) => {
+                let cond = self.compile_condition(cond)?;
+                self.builder.build_conditional_branch(cond, body_block, exit_block)?;
+            }
+            None => {
+                self.builder.build_unconditional_branch(body_block)?;
+            }
+        }
+
+        self.builder.position_at_end(body_block);
+        self.compile_loop_body(body, inc_block, exit_block)?;
+        self.builder.build_unconditional_branch(inc_block)?;
+
+        self.builder.position_at_end(inc_block);
+        if let Some(inc) = increment {
+            self.compile_stmt(inc)?;
+        }
+        self.builder.build_unconditional_branch(cond_block)?;
+
+        self.builder.position_at_end(exit_block);
+        Ok(())
+    }
+
+    /// Generate a for-in loop over an array, once its scope has been opened
+    fn compile_for_in(
+        &mut self,
+        variable: &str,
+        iterable: &Expr,
+        body: &Stmt,
+        span: &Span,
+    ) -> CodegenResult<()> {
+        // The loop runs over a copy, as the body may modify the array
+        let (array, array_type) = self.compile_owned(iterable, None)?;
+        let elem_type = match array_type {
+            Type::Array(elem) => *elem,
+            other => {
+                return Err(CodegenError::Unsupported {
+                    feature: format!("Iterations over {}", other),
+                    span: span.clone(),
+                });
+            }
+        };
+
+        let length = self.call_runtime("remy_array_len", &[array.into()])?
+            .expect("remy_array_len returns a value")
+            .into_int_value();
+        let cursor = self.create_entry_alloca(self.types.int.into(), "cursor")?;
+        self.builder.build_store(cursor, self.types.int.const_zero())?;
+
+        let function = self.current_function();
+        let cond_block = self.context.append_basic_block(function, "for_in_cond");
+        let body_block = self.context.append_basic_block(function, "for_in_body");
+        let inc_block = self.context.append_basic_block(function, "for_in_inc");
+        let exit_block = self.context.append_basic_block(function, "for_in_end");
+
+        self.builder.build_unconditional_branch(cond_block)?;
+        self.builder.position_at_end(cond_block);
+        let index = self.builder.build_load(self.types.int, cursor, "index")?.into_int_value();
+        let in_bounds = self.builder.build_int_compare(IntPredicate::SLT, index, length, "in_bounds")?;
+        self.builder.build_conditional_branch(in_bounds, body_block, exit_block)?;
+
+        // Each iteration gets a fresh variable
+        self.builder.position_at_end(body_block);
+        self.symbols.push_scope();
+        let slot = self.call_runtime("remy_array_get", &[array.into(), index.into()])?
+            .expect("remy_array_get returns a value")
+            .into_int_value();
+        let item = self.narrow_from_slot(slot, &elem_type)?;
+        let ptr = self.create_entry_alloca(item.get_type(), variable)?;
+        self.builder.build_store(ptr, item)?;
+        self.symbols.define(variable, Variable { ptr, ty: elem_type });
+        let result = self.compile_loop_body(body, inc_block, exit_block);
+        self.symbols.pop_scope();
+        result?;
+        self.builder.build_unconditional_branch(inc_block)?;
+
+        self.builder.position_at_end(inc_block);
+        let index = self.builder.build_load(self.types.int, cursor, "index")?.into_int_value();
+        let next = self.builder.build_int_add(index, self.types.int.const_int(1, false), "next")?;
+        self.builder.build_store(cursor, next)?;
+        self.builder.build_unconditional_branch(cond_block)?;
+
+        self.builder.position_at_end(exit_block);
+        Ok(())
+    }
+
+    /// Generate a loop body with its `continue` and `break` targets
+    fn compile_loop_body(
+        &mut self,
+        body: &Stmt,
+        continue_block: BasicBlock<'ctx>,
+        break_block: BasicBlock<'ctx>,
+    ) -> CodegenResult<()> {
+        self.symbols.function_state().loops.push(LoopBlocks { continue_block, break_block });
+        let result = self.compile_stmt(body);
+        self.symbols.function_state().loops.pop();
+        result
+    }
+
+    /// Generate the body of a function declared beforehand
+    fn compile_function(&mut self, name: &str, params: &[Param], body: &Stmt) -> CodegenResult<()> {
+        let info = self.symbols.function(name).cloned().expect("function is declared");
+        let resume_block = self.builder.get_insert_block();
+
+        let entry = self.context.append_basic_block(info.value, "entry");
+        self.builder.position_at_end(entry);
+        let previous = self.symbols.enter_function(
+            FunctionState::new(info.value, Some(info.return_type.clone())),
+        );
+
+        self.symbols.push_scope();
+        let result = self.compile_function_body(&info, params, body);
+
+        self.symbols.leave_function(previous);
+        if let Some(block) = resume_block {
+            self.builder.position_at_end(block);
+        }
+        result
+    }
+
+    fn compile_function_body(&mut self, info: &FunctionInfo<'ctx>, params: &[Param], body: &Stmt) -> CodegenResult<()> {
+        // Parameters are copied to stack slots, so they can be reassigned
+        for ((param, ty), value) in params.iter().zip(&info.params).zip(info.value.get_param_iter()) {
+            let ptr = self.create_entry_alloca(value.get_type(), &param.name)?;
+            self.builder.build_store(ptr, value)?;
+            self.symbols.define(&param.name, Variable { ptr, ty: ty.clone() });
+        }
+
+        self.compile_stmt(body)?;
+
+        // The type checker guarantees that non-Void functions return
+        if info.return_type == Type::Void {
+            self.builder.build_return(None)?;
+        } else {
+            self.builder.build_unreachable()?;
+        }
+        Ok(())
+    }
+
+    // ========================================================================
+    // EXPRESSIONS
+    // ========================================================================
+
+    /// Generate an expression whose type is known from its context
+    /// This is only needed for empty array literals.
+    fn compile_expr_expecting(&mut self, expr: &Expr, expected: &Type) -> CodegenResult<Typed<'ctx>> {
+        match (&expr.kind, expected) {
+            (ExprKind::ArrayLiteral(elements), Type::Array(_)) if elements.is_empty() => {
+                let array = self.call_runtime("remy_array_new", &[self.types.int.const_zero().into()])?
+                    .expect("remy_array_new returns a value");
+                Ok((array, expected.clone()))
+            }
+            _ => self.compile_expr(expr),
+        }
+    }
+
+    /// Generate a value that is stored in a variable, an array or a parameter
+    /// Arrays are values in RemyLang: unless the expression builds a new
+    /// array, the stored array is a copy, so writing to it changes nothing else.
+    fn compile_owned(&mut self, expr: &Expr, expected: Option<&Type>) -> CodegenResult<Typed<'ctx>> {
+        let (value, ty) = match expected {
+            Some(ty) => self.compile_expr_expecting(expr, ty)?,
+            None => self.compile_expr(expr)?,
+        };
+        let value = match (&expr.kind, &ty) {
+            (ExprKind::ArrayLiteral(_), _) => value,
+            (_, Type::Array(elem)) => {
+                let desc = self.string_constant(&descriptor(elem), "desc")?;
+                self.call_runtime("remy_array_copy", &[value.into(), desc.into()])?
+                    .expect("remy_array_copy returns a value")
+            }
+            _ => value,
+        };
+        Ok((value, ty))
+    }
+
+    fn compile_condition(&mut self, expr: &Expr) -> CodegenResult<IntValue<'ctx>> {
+        let (value, _) = self.compile_expr(expr)?;
+        Ok(value.into_int_value())
+    }
+
+    /// Generate an expression
+    /// Functions are not values in compiled programs, so expressions giving
+    /// one, like a call returning a function, are rejected here.
+    fn compile_expr(&mut self, expr: &Expr) -> CodegenResult<Typed<'ctx>> {
+        let typed = self.compile_expr_kind(expr)?;
+        if let Type::Function { .. } = typed.1 {
+            return Err(CodegenError::Unsupported {
+                feature: "First-class functions".to_string(),
+                span: expr.span.clone(),
+            });
+        }
+        Ok(typed)
+    }
+
+    fn compile_expr_kind(&mut self, expr: &Expr) -> CodegenResult<Typed<'ctx>> {
+        let span = &expr.span;
+        match &expr.kind {
+            ExprKind::Literal(lit) => self.compile_literal(lit),
+
+            ExprKind::Variable(name) if self.symbols.lookup(name).is_none() && self.symbols.function(name).is_some() => {
+                Err(CodegenError::Unsupported {
+                    feature: "First-class functions".to_string(),
+                    span: span.clone(),
+                })
+            }
+
+            ExprKind::Variable(name) => {
+                let variable = self.variable(name, span)?;
+                let ty = self.types.basic(&variable.ty).expect("variables are never Void");
+                let value = self.builder.build_load(ty, variable.ptr, name)?;
+                Ok((value, variable.ty))
+            }
+
+            ExprKind::Binary { left, op: op @ (BinaryOp::And | BinaryOp::Or), right } => {
+                self.compile_logical(left, *op, right)
+            }
+
+            ExprKind::Binary { left, op, right } => {
+                let left = self.compile_expr(left)?;
+                let right = self.compile_expr(right)?;
+                self.compile_binary(left, *op, right)
+            }
+
+            ExprKind::Unary { op, right } => {
+                let (value, ty) = self.compile_expr(right)?;
+                // Minus is the only unary operator on Floats
+                if ty == Type::Float {
+                    let result = self.builder.build_float_neg(value.into_float_value(), "neg")?;
+                    return Ok((result.into(), ty));
+                }
+                let value = value.into_int_value();
+                let result = match op {
+                    UnaryOp::Minus => {
+                        let zero = value.get_type().const_zero();
+                        self.build_checked("llvm.ssub.with.overflow", zero, value, "negation")?
+                    }
+                    UnaryOp::Not => self.builder.build_not(value, "not")?,
+                };
+                Ok((result.into(), ty))
+            }
+
+            ExprKind::Call { callee, args } => self.compile_call(callee, args, span),
+
+            ExprKind::ArrayLiteral(elements) => {
+                let length = self.types.int.const_int(elements.len() as u64, false);
+                let array = self.call_runtime("remy_array_new", &[length.into()])?
+                    .expect("remy_array_new returns a value");
+
+                let mut elem_type = Type::Void;
+                for (i, element) in elements.iter().enumerate() {
+                    let expected = (i > 0).then_some(&elem_type);
+                    let (value, ty) = self.compile_owned(element, expected)?;
+                    let slot = self.widen_to_slot(value, &ty)?;
+                    let index = self.types.int.const_int(i as u64, false);
+                    self.call_runtime("remy_array_set", &[array.into(), index.into(), slot.into()])?;
+                    elem_type = ty;
+                }
+                Ok((array, Type::Array(Box::new(elem_type))))
+            }
+
+            ExprKind::ArrayAccess { array, index } => {
+                let (array, array_type) = self.compile_expr(array)?;
+                let (index, _) = self.compile_expr(index)?;
+                let elem_type = match array_type {
+                    Type::Array(elem) => *elem,
+                    other => {
+                        return Err(CodegenError::Unsupported {
+                            feature: format!("Indexing into {}", other),
+                            span: span.clone(),
+                        });
+                    }
+                };
+                let slot = self.call_runtime("remy_array_get", &[array.into(), index.into()])?
+                    .expect("remy_array_get returns a value")
+                    .into_int_value();
+                Ok((self.narrow_from_slot(slot, &elem_type)?, elem_type))
+            }
+
+            ExprKind::FieldAccess { .. } | ExprKind::StructLiteral { .. } => Err(CodegenError::Unsupported {
+                feature: "Structs".to_string(),
+                span: span.clone(),
+            }),
+
+            ExprKind::Variant { .. } => Err(CodegenError::Unsupported {
+                feature: "Enums".to_string(),
+                span: span.clone(),
+            }),
+
+            ExprKind::Match { .. } => Err(CodegenError::Unsupported {
+                feature: "Pattern matching".to_string(),
+                span: span.clone(),
+            }),
+
+            ExprKind::Interpolated(_) => Err(CodegenError::Unsupported {
+                feature: "String interpolation".to_string(),
+                span: span.clone(),
+            }),
+
+            ExprKind::Lambda { .. } => Err(CodegenError::Unsupported {
+                feature: "First-class functions".to_string(),
+                span: span.clone(),
+            }),
+        }
+    }
+
+    fn compile_literal(&mut self, lit: &Literal) -> CodegenResult<Typed<'ctx>> {
+        Ok(match lit {
+            Literal::Number(n) => (self.types.int.const_int(*n as u64, true).into(), Type::Int),
+            Literal::Float(x) => (self.types.float.const_float(*x).into(), Type::Float),
+            Literal::Bool(b) => (self.types.bool.const_int(*b as u64, false).into(), Type::Bool),
+            Literal::Char(c) => (self.types.char.const_int(*c as u64, false).into(), Type::Char),
+            Literal::String(s) => (self.string_constant(s, "str")?.into(), Type::String),
+        })
+    }
+
+    fn compile_binary(&mut self, left: Typed<'ctx>, op: BinaryOp, right: Typed<'ctx>) -> CodegenResult<Typed<'ctx>> {
+        let (left, ty) = left;
+        let (right, right_type) = right;
+
+        if op == BinaryOp::Add && ty == Type::String {
+            let result = self.call_runtime("remy_str_concat", &[left.into(), right.into()])?
+                .expect("remy_str_concat returns a value");
+            return Ok((result, Type::String));
+        }
+        if matches!(op, BinaryOp::Equal | BinaryOp::NotEqual) {
+            if ty != right_type {
+                // An Int compared with a Float
+                let l = self.to_float(left, &ty)?;
+                let r = self.to_float(right, &right_type)?;
+                return self.compile_equality(l.into(), op, r.into(), &Type::Float);
+            }
+            return self.compile_equality(left, op, right, &ty);
+        }
+        if ty == Type::Float || right_type == Type::Float {
+            let l = self.to_float(left, &ty)?;
+            let r = self.to_float(right, &right_type)?;
+            return self.compile_float_binary(l, op, r);
+        }
+
+        let (l, r) = (left.into_int_value(), right.into_int_value());
+        let b = &self.builder;
+        let int = self.types.int;
+        let result: IntValue = match op {
+            BinaryOp::Add => self.build_checked("llvm.sadd.with.overflow", l, r, "addition")?,
+            BinaryOp::Sub => self.build_checked("llvm.ssub.with.overflow", l, r, "subtraction")?,
+            BinaryOp::Mul => self.build_checked("llvm.smul.with.overflow", l, r, "multiplication")?,
+            BinaryOp::Div => {
+                self.check_nonzero(r, "Division by zero")?;
+                // Only Int::MIN / -1 overflows
+                let is_min = b.build_int_compare(IntPredicate::EQ, l, int.const_int(i64::MIN as u64, true), "is_min")?;
+                let is_minus_one = b.build_int_compare(IntPredicate::EQ, r, int.const_all_ones(), "is_minus_one")?;
+                let overflows = b.build_and(is_min, is_minus_one, "overflows")?;
+                self.fail_if(overflows, &overflow_message("division"))?;
+                b.build_int_signed_div(l, r, "div")?
+            }
+            BinaryOp::Mod => {
+                self.check_nonzero(r, "Modulo by zero")?;
+                // x % -1 is x % 1, which avoids the overflow of Int::MIN % -1
+                let is_minus_one = b.build_int_compare(IntPredicate::EQ, r, int.const_all_ones(), "is_minus_one")?;
+                let divisor = b.build_select(is_minus_one, int.const_int(1, false), r, "divisor")?.into_int_value();
+                b.build_int_signed_rem(l, divisor, "mod")?
+            }
+            BinaryOp::Pow => {
+                return Ok((
+                    self.call_runtime("remy_pow", &[l.into(), r.into()])?
+                        .expect("remy_pow returns a value"),
+                    Type::Int,
+                ));
+            }
+            BinaryOp::Less => b.build_int_compare(IntPredicate::SLT, l, r, "lt")?,
+            BinaryOp::Greater => b.build_int_compare(IntPredicate::SGT, l, r, "gt")?,
+            BinaryOp::LessEqual => b.build_int_compare(IntPredicate::SLE, l, r, "le")?,
+            BinaryOp::GreaterEqual => b.build_int_compare(IntPredicate::SGE, l, r, "ge")?,
+            BinaryOp::Equal | BinaryOp::NotEqual => unreachable!("handled above"),
+            BinaryOp::And | BinaryOp::Or => unreachable!("handled by compile_logical"),
+        };
+
+        let result_type = match op {
+            BinaryOp::Add | BinaryOp::Sub | BinaryOp::Mul | BinaryOp::Div | BinaryOp::Mod => Type::Int,
+            _ => Type::Bool,
+        };
+        Ok((result.into(), result_type))
+    }
+
+    /// Generate an arithmetic or comparison operator on Floats
+    /// Floats follow IEEE 754, so none of them raises a runtime error.
+    fn compile_float_binary(&mut self, l: FloatValue<'ctx>, op: BinaryOp, r: FloatValue<'ctx>) -> CodegenResult<Typed<'ctx>> {
+        let b = &self.builder;
+        let result: BasicValueEnum = match op {
+            BinaryOp::Add => b.build_float_add(l, r, "fadd")?.into(),
+            BinaryOp::Sub => b.build_float_sub(l, r, "fsub")?.into(),
+            BinaryOp::Mul => b.build_float_mul(l, r, "fmul")?.into(),
+            BinaryOp::Div => b.build_float_div(l, r, "fdiv")?.into(),
+            BinaryOp::Mod => b.build_float_rem(l, r, "fmod")?.into(),
+            BinaryOp::Pow => {
+                let function = Intrinsic::find("llvm.pow")
+                    .and_then(|intrinsic| intrinsic.get_declaration(&self.module, &[self.types.float.into()]))
+                    .expect("llvm.pow exists for double");
+                b.build_call(function, &[l.into(), r.into()], "pow")?
+                    .try_as_basic_value()
+                    .basic()
+                    .expect("llvm.pow returns a value")
+            }
+            BinaryOp::Less => b.build_float_compare(FloatPredicate::OLT, l, r, "lt")?.into(),
+            BinaryOp::Greater => b.build_float_compare(FloatPredicate::OGT, l, r, "gt")?.into(),
+            BinaryOp::LessEqual => b.build_float_compare(FloatPredicate::OLE, l, r, "le")?.into(),
+            BinaryOp::GreaterEqual => b.build_float_compare(FloatPredicate::OGE, l, r, "ge")?.into(),
+            BinaryOp::Equal | BinaryOp::NotEqual => unreachable!("handled by compile_equality"),
+            BinaryOp::And | BinaryOp::Or => unreachable!("handled by compile_logical"),
+        };
+
+        let result_type = match op {
+            BinaryOp::Add | BinaryOp::Sub | BinaryOp::Mul | BinaryOp::Div | BinaryOp::Mod | BinaryOp::Pow => Type::Float,
+            _ => Type::Bool,
+        };
+        Ok((result, result_type))
+    }
+
+    /// Compile `&&` or `||`, only evaluating the right operand when needed
+    fn compile_logical(&mut self, left: &Expr, op: BinaryOp, right: &Expr) -> CodegenResult<Typed<'ctx>> {
+        let left = self.compile_condition(left)?;
+        let left_end = self.builder.get_insert_block().expect("builder is positioned in a block");
+
+        let function = self.current_function();
+        let right_block = self.context.append_basic_block(function, "logical_rhs");
+        let merge_block = self.context.append_basic_block(function, "logical_end");
+        if op == BinaryOp::And {
+            self.builder.build_conditional_branch(left, right_block, merge_block)?;
+        } else {
+            self.builder.build_conditional_branch(left, merge_block, right_block)?;
+        }
+
+        self.builder.position_at_end(right_block);
+        let right = self.compile_condition(right)?;
+        // The right operand may have created blocks of its own
+        let right_end = self.builder.get_insert_block().expect("builder is positioned in a block");
+        self.builder.build_unconditional_branch(merge_block)?;
+
+        self.builder.position_at_end(merge_block);
+        let result = self.builder.build_phi(self.types.bool, "logical")?;
+        result.add_incoming(&[(&left, left_end), (&right, right_end)]);
+        Ok((result.as_basic_value(), Type::Bool))
+    }
+
+    /// Compare two values of the same type
+    fn compile_equality(
+        &mut self,
+        left: BasicValueEnum<'ctx>,
+        op: BinaryOp,
+        right: BasicValueEnum<'ctx>,
+        ty: &Type,
+    ) -> CodegenResult<Typed<'ctx>> {
+        let equal = match ty {
+            Type::String => self.call_runtime("remy_str_eq", &[left.into(), right.into()])?
+                .expect("remy_str_eq returns a value")
+                .into_int_value(),
+            Type::Array(elem) => {
+                let desc = self.string_constant(&descriptor(elem), "desc")?;
+                self.call_runtime("remy_array_eq", &[left.into(), right.into(), desc.into()])?
+                    .expect("remy_array_eq returns a value")
+                    .into_int_value()
+            }
+            // NaN is equal to nothing, itself included
+            Type::Float => self.builder.build_float_compare(
+                FloatPredicate::OEQ,
+                left.into_float_value(),
+                right.into_float_value(),
+                "eq",
+            )?,
+            _ => self.builder.build_int_compare(
+                IntPredicate::EQ,
+                left.into_int_value(),
+                right.into_int_value(),
+                "eq",
+            )?,
+        };
+
+        let result = if op == BinaryOp::NotEqual {
+            self.builder.build_not(equal, "ne")?
+        } else {
+            equal
+        };
+        Ok((result.into(), Type::Bool))
+    }
+
+    fn compile_call(&mut self, callee: &Expr, args: &[Expr], span: &Span) -> CodegenResult<Typed<'ctx>> {
+        let name = match &callee.kind {
+            ExprKind::Variable(name) => name,
+            _ => {
+                return Err(CodegenError::Unsupported {
+                    feature: "Calls to expressions".to_string(),
+                    span: span.clone(),
+                });
+            }
+        };
+
+        // Functions and variables named like a builtin hide it
+        let declared = self.symbols.function(name).is_some() || self.symbols.lookup(name).is_some();
+        if builtin::is_builtin(name) && !declared {
+            return match name.as_str() {
+                "int" => self.compile_conversion(&Type::Int, &args[0]),
+                "float" => self.compile_conversion(&Type::Float, &args[0]),
+                "print" | "println" => self.compile_print(name == "println", args),
+                "args" => Err(CodegenError::Unsupported {
+                    feature: "Program arguments".to_string(),
+                    span: span.clone(),
+                }),
+                _ => Err(CodegenError::Unsupported {
+                    feature: format!("The '{}' builtin", name),
+                    span: span.clone(),
+                }),
+            };
+        }
+
+        let info = match self.symbols.function(name) {
+            Some(info) => info.clone(),
+            // A variable holding a function
+            None if self.symbols.lookup(name).is_some() => {
+                return Err(CodegenError::Unsupported {
+                    feature: "First-class functions".to_string(),
+                    span: span.clone(),
+                });
+            }
+            None => {
+                return Err(CodegenError::UndefinedSymbol {
+                    name: name.clone(),
+                    span: span.clone(),
+                });
+            }
+        };
+
+        let mut values: Vec<BasicMetadataValueEnum> = Vec::new();
+        for (arg, ty) in args.iter().zip(&info.params) {
+            let (value, _) = self.compile_owned(arg, Some(ty))?;
+            values.push(value.into());
+        }
+
+        let call = self.builder.build_call(info.value, &values, "")?;
+        match call.try_as_basic_value().basic() {
+            Some(value) => Ok((value, info.return_type)),
+            None => Ok((self.void_value(), Type::Void)),
+        }
+    }
+
+    /// Generate `print` and `println`: arguments separated by spaces
+    fn compile_print(&mut self, newline: bool, args: &[Expr]) -> CodegenResult<Typed<'ctx>> {
+        for (i, arg) in args.iter().enumerate() {
+            if i > 0 {
+                self.call_runtime("remy_print_space", &[])?;
+            }
+            let (value, ty) = self.compile_expr(arg)?;
+            match &ty {
+                Type::Int => self.call_runtime("remy_print_int", &[value.into()])?,
+                Type::Float => self.call_runtime("remy_print_float", &[value.into()])?,
+                Type::Bool => self.call_runtime("remy_print_bool", &[value.into()])?,
+                Type::Char => self.call_runtime("remy_print_char", &[value.into()])?,
+                Type::String => self.call_runtime("remy_print_str", &[value.into()])?,
+                Type::Array(elem) => {
+                    let desc = self.string_constant(&descriptor(elem), "desc")?;
+                    self.call_runtime("remy_print_array", &[value.into(), desc.into()])?
+                }
+                Type::Void => None,
+                Type::Named(_) => unreachable!("programs with structs or enums are rejected before code generation"),
+                Type::Function { .. } => unreachable!("function values are rejected by compile_expr"),
+            };
+        }
+        if newline {
+            self.call_runtime("remy_print_newline", &[])?;
+        }
+        Ok((self.void_value(), Type::Void))
+    }
+
+    /// Generate `int(x)` and `float(x)`, converting `x` to `target`
+    /// Floats are truncated toward zero, and must have an Int equivalent.
+    fn compile_conversion(&mut self, target: &Type, arg: &Expr) -> CodegenResult<Typed<'ctx>> {
+        let (value, ty) = self.compile_expr(arg)?;
+        if &ty == target {
+            return Ok((value, ty));
+        }
+        if target == &Type::Float {
+            return Ok((self.to_float(value, &ty)?.into(), Type::Float));
+        }
+
+        let value = value.into_float_value();
+        let bound = self.types.float.const_float(-(i64::MIN as f64));
+        let min = self.types.float.const_float(i64::MIN as f64);
+        // Ordered comparisons are false for NaN, which is then out of range too
+        let above_min = self.builder.build_float_compare(FloatPredicate::OGE, value, min, "above_min")?;
+        let below_max = self.builder.build_float_compare(FloatPredicate::OLT, value, bound, "below_max")?;
+        let in_range = self.builder.build_and(above_min, below_max, "in_range")?;
+        let out_of_range = self.builder.build_not(in_range, "out_of_range")?;
+        self.fail_if(out_of_range, &RuntimeError::FloatToIntConversion.message())?;
+        let result = self.builder.build_float_to_signed_int(value, self.types.int, "int")?;
+        Ok((result.into(), Type::Int))
+    }
+
+    // ========================================================================
+    // HELPERS
+    // ========================================================================
+
+    /// Convert a number to a Float, for operators mixing Ints and Floats
+    fn to_float(&self, value: BasicValueEnum<'ctx>, ty: &Type) -> CodegenResult<FloatValue<'ctx>> {
+        Ok(match ty {
+            Type::Float => value.into_float_value(),
+            _ => self.builder.build_signed_int_to_float(value.into_int_value(), self.types.float, "float")?,
+        })
+    }
+
+    fn variable(&self, name: &str, span: &Span) -> CodegenResult<Variable<'ctx>> {
+        self.symbols.lookup(name).cloned().ok_or_else(|| CodegenError::UndefinedSymbol {
+            name: name.to_string(),
+            span: span.clone(),
+        })
+    }
+
+    /// Read the value of `variable`, or its element at `indices`, of type `ty`
+    fn load_target(
+        &mut self,
+        variable: &Variable<'ctx>,
+        indices: &[IntValue<'ctx>],
+        ty: &Type,
+    ) -> CodegenResult<BasicValueEnum<'ctx>> {
+        if indices.is_empty() {
+            let llvm_type = self.types.basic(ty).expect("variables are never Void");
+            return Ok(self.builder.build_load(llvm_type, variable.ptr, "current")?);
+        }
+        let (array, index) = self.element_location(variable, indices)?;
+        let slot = self.call_runtime("remy_array_get", &[array.into(), index.into()])?
+            .expect("remy_array_get returns a value")
+            .into_int_value();
+        self.narrow_from_slot(slot, ty)
+    }
+
+    /// Write `value`, of type `ty`, to `variable` or to its element at `indices`
+    fn store_target(
+        &mut self,
+        variable: &Variable<'ctx>,
+        indices: &[IntValue<'ctx>],
+        ty: &Type,
+        value: BasicValueEnum<'ctx>,
+    ) -> CodegenResult<()> {
+        if indices.is_empty() {
+            self.builder.build_store(variable.ptr, value)?;
+            return Ok(());
+        }
+        let (array, index) = self.element_location(variable, indices)?;
+        let slot = self.widen_to_slot(value, ty)?;
+        self.call_runtime("remy_array_set", &[array.into(), index.into(), slot.into()])?;
+        Ok(())
+    }
+
+    /// The array holding the element of `variable` at `indices`, and the
+    /// element's index in it
+    fn element_location(
+        &mut self,
+        variable: &Variable<'ctx>,
+        indices: &[IntValue<'ctx>],
+    ) -> CodegenResult<(PointerValue<'ctx>, IntValue<'ctx>)> {
+        let (&last, path) = indices.split_last().expect("elements have at least one index");
+        let mut array = self.builder.build_load(self.types.ptr, variable.ptr, "array")?.into_pointer_value();
+        for &index in path {
+            let slot = self.call_runtime("remy_array_get", &[array.into(), index.into()])?
+                .expect("remy_array_get returns a value")
+                .into_int_value();
+            array = self.builder.build_int_to_ptr(slot, self.types.ptr, "array")?;
+        }
+        Ok((array, last))
+    }
+
+    /// Storage for a new variable: a global at the top level, a stack slot otherwise
+    fn allocate_variable(&mut self, name: &str, ty: &Type, span: &Span) -> CodegenResult<PointerValue<'ctx>> {
+        let llvm_type = self.types.basic(ty).ok_or_else(|| CodegenError::Unsupported {
+            feature: "Void variables".to_string(),
+            span: span.clone(),
+        })?;
+
+        if self.symbols.is_global_scope() {
+            let global = self.module.add_global(llvm_type, None, &format!("remy.global.{}", name));
+            global.set_initializer(&llvm_type.const_zero());
+            Ok(global.as_pointer_value())
+        } else {
+            self.create_entry_alloca(llvm_type, name)
+        }
+    }
+
+    /// Stack slot in the entry block of the current function, where LLVM
+    /// can promote it to a register
+    fn create_entry_alloca(&self, ty: BasicTypeEnum<'ctx>, name: &str) -> CodegenResult<PointerValue<'ctx>> {
+        let function = self.current_function();
+        let entry = function.get_first_basic_block().expect("function has an entry block");
+        let builder = self.context.create_builder();
+        match entry.get_first_instruction() {
+            Some(instruction) => builder.position_before(&instruction),
+            None => builder.position_at_end(entry),
+        }
+        Ok(builder.build_alloca(ty, name)?)
+    }
+
+    fn current_function(&self) -> FunctionValue<'ctx> {
+        self.builder
+            .get_insert_block()
+            .and_then(|block| block.get_parent())
+            .expect("builder is positioned in a function")
+    }
+
+    /// Continue in a block without predecessors, after a jump or a return
+    fn start_unreachable_block(&self, name: &str) {
+        let function = self.current_function();
+        let block = self.context.append_basic_block(function, name);
+        self.builder.position_at_end(block);
+    }
+
+    /// Report a runtime error unless `value` is non-zero
+    fn check_nonzero(&self, value: IntValue<'ctx>, message: &str) -> CodegenResult<()> {
+        let is_zero = self.builder.build_int_compare(
+            IntPredicate::EQ,
+            value,
+            value.get_type().const_zero(),
+            "is_zero",
+        )?;
+        self.fail_if(is_zero, message)
+    }
+
+    /// Apply an `llvm.*.with.overflow` intrinsic, reporting a runtime error on overflow
+    fn build_checked(
+        &self,
+        intrinsic: &str,
+        left: IntValue<'ctx>,
+        right: IntValue<'ctx>,
+        operation: &str,
+    ) -> CodegenResult<IntValue<'ctx>> {
+        let function = Intrinsic::find(intrinsic)
+            .and_then(|intrinsic| intrinsic.get_declaration(&self.module, &[self.types.int.into()]))
+            .expect("overflow intrinsics exist for i64");
+        let result = self.builder.build_call(function, &[left.into(), right.into()], "checked")?
+            .try_as_basic_value()
+            .basic()
+            .expect("overflow intrinsics return a value")
+            .into_struct_value();
+
+        let value = self.builder.build_extract_value(result, 0, "value")?.into_int_value();
+        let overflows = self.builder.build_extract_value(result, 1, "overflows")?.into_int_value();
+        self.fail_if(overflows, &overflow_message(operation))?;
+        Ok(value)
+    }
+
+    /// Report a runtime error if `condition` holds
+    fn fail_if(&self, condition: IntValue<'ctx>, message: &str) -> CodegenResult<()> {
+        let function = self.current_function();
+        let error_block = self.context.append_basic_block(function, "error");
+        let ok_block = self.context.append_basic_block(function, "ok");
+        self.builder.build_conditional_branch(condition, error_block, ok_block)?;
+
+        self.builder.position_at_end(error_block);
+        let message = self.string_constant(message, "error")?;
+        self.call_runtime("remy_runtime_error", &[message.into()])?;
+        self.builder.build_unreachable()?;
+
+        self.builder.position_at_end(ok_block);
+        Ok(())
+    }
+
+    fn call_runtime(
+        &self,
+        name: &str,
+        args: &[BasicMetadataValueEnum<'ctx>],
+    ) -> CodegenResult<Option<BasicValueEnum<'ctx>>> {
+        let function = self.module.get_function(name).expect("runtime function is declared");
+        let call = self.builder.build_call(function, args, "")?;
+        Ok(call.try_as_basic_value().basic())
+    }
+
+    fn string_constant(&self, value: &str, name: &str) -> CodegenResult<PointerValue<'ctx>> {
+        Ok(self.builder.build_global_string_ptr(value, name)?.as_pointer_value())
+    }
+
+    fn void_value(&self) -> BasicValueEnum<'ctx> {
+        self.types.bool.const_zero().into()
+    }
+
+    /// Widen a value to a 64-bit array slot
+    fn widen_to_slot(&self, value: BasicValueEnum<'ctx>, ty: &Type) -> CodegenResult<IntValue<'ctx>> {
+        let int = self.types.int;
+        Ok(match ty {
+            Type::Int => value.into_int_value(),
+            Type::Float => self.builder.build_bit_cast(value, int, "slot")?.into_int_value(),
+            Type::Bool | Type::Char => self.builder.build_int_z_extend(value.into_int_value(), int, "slot")?,
+            Type::String | Type::Array(_) | Type::Function { .. } => {
+                self.builder.build_ptr_to_int(value.into_pointer_value(), int, "slot")?
+            }
+            Type::Void => int.const_zero(),
+            Type::Named(_) => unreachable!("programs with structs or enums are rejected before code generation"),
+        })
+    }
+
+    /// Read a value of type `ty` back from a 64-bit array slot
+    fn narrow_from_slot(&self, slot: IntValue<'ctx>, ty: &Type) -> CodegenResult<BasicValueEnum<'ctx>> {
+        Ok(match ty {
+            Type::Int => slot.into(),
+            Type::Float => self.builder.build_bit_cast(slot, self.types.float, "elem")?,
+            Type::Bool => self.builder.build_int_truncate(slot, self.types.bool, "elem")?.into(),
+            Type::Char => self.builder.build_int_truncate(slot, self.types.char, "elem")?.into(),
+            Type::String | Type::Array(_) | Type::Function { .. } => {
+                self.builder.build_int_to_ptr(slot, self.types.ptr, "elem")?.into()
+            }
+            Type::Void => self.void_value(),
+            Type::Named(_) => unreachable!("programs with structs or enums are rejected before code generation"),
+        })
+    }
+}
+
+/// Type of the element `depth` indexings into a value of type `ty`
+fn element_type(ty: &Type, depth: usize, span: &Span) -> CodegenResult<Type> {
+    let mut ty = ty;
+    for _ in 0..depth {
+        ty = match ty {
+            Type::Array(elem) => elem,
+            other => {
+                return Err(CodegenError::Unsupported {
+                    feature: format!("Indexing into {}", other),
+                    span: span.clone(),
+                });
+            }
+        };
+    }
+    Ok(ty.clone())
+}
+
+/// Message of the runtime error raised when `operation` overflows
+fn overflow_message(operation: &str) -> String {
+    RuntimeError::IntegerOverflow { operation: operation.to_string() }.message()
+}
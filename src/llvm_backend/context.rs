@@ -0,0 +1,150 @@
+/*
+ * -------------------------------------------------------------------------
+ * RemyLang — code generation context
+ * File : src/llvm_backend/context.rs
+ *
+ * Description :
+ *   Symbols and control flow state tracked while generating LLVM IR.
+ *
+ * Author  : Samuel 'Meeast' Bleau
+ * Created : 2026-10-16
+ *
+ * -------------------------------------------------------------------------
+*/
+
+use std::collections::HashMap;
+use inkwell::basic_block::BasicBlock;
+use inkwell::values::{FunctionValue, PointerValue};
+use crate::ast::Type;
+
+/// Storage of a variable, a global or a stack slot
+#[derive(Debug, Clone)]
+pub struct Variable<'ctx> {
+    pub ptr: PointerValue<'ctx>,
+    pub ty: Type,
+}
+
+/// A declared RemyLang function
+#[derive(Debug, Clone)]
+pub struct FunctionInfo<'ctx> {
+    pub value: FunctionValue<'ctx>,
+    pub params: Vec<Type>,
+    pub return_type: Type,
+}
+
+/// Blocks `continue` and `break` jump to
+#[derive(Debug, Clone, Copy)]
+pub struct LoopBlocks<'ctx> {
+    pub continue_block: BasicBlock<'ctx>,
+    pub break_block: BasicBlock<'ctx>,
+}
+
+/// The function whose body is being generated
+#[derive(Debug, Clone)]
+pub struct FunctionState<'ctx> {
+    pub value: FunctionValue<'ctx>,
+    /// None for the program's entry point, where `return` is not allowed
+    pub return_type: Option<Type>,
+    pub loops: Vec<LoopBlocks<'ctx>>,
+    /// Local scopes, innermost last
+    pub scopes: Vec<HashMap<String, Variable<'ctx>>>,
+}
+
+impl<'ctx> FunctionState<'ctx> {
+    pub fn new(value: FunctionValue<'ctx>, return_type: Option<Type>) -> Self {
+        FunctionState {
+            value,
+            return_type,
+            loops: Vec::new(),
+            scopes: Vec::new(),
+        }
+    }
+}
+
+/// Symbol tables for the module being generated
+pub struct CodegenContext<'ctx> {
+    globals: HashMap<String, Variable<'ctx>>,
+    functions: HashMap<String, FunctionInfo<'ctx>>,
+    current: Option<FunctionState<'ctx>>,
+}
+
+impl<'ctx> CodegenContext<'ctx> {
+    pub fn new() -> Self {
+        CodegenContext {
+            globals: HashMap::new(),
+            functions: HashMap::new(),
+            current: None,
+        }
+    }
+
+    /// Start generating a function, returning the state of the previous one
+    pub fn enter_function(&mut self, state: FunctionState<'ctx>) -> Option<FunctionState<'ctx>> {
+        self.current.replace(state)
+    }
+
+    /// Resume the function that was being generated before
+    pub fn leave_function(&mut self, previous: Option<FunctionState<'ctx>>) {
+        self.current = previous;
+    }
+
+    pub fn function_state(&mut self) -> &mut FunctionState<'ctx> {
+        self.current.as_mut().expect("no function being generated")
+    }
+
+    /// True outside of any function or block, where variables are globals
+    pub fn is_global_scope(&self) -> bool {
+        match &self.current {
+            Some(state) => state.return_type.is_none() && state.scopes.is_empty(),
+            None => true,
+        }
+    }
+
+    pub fn push_scope(&mut self) {
+        self.function_state().scopes.push(HashMap::new());
+    }
+
+    pub fn pop_scope(&mut self) {
+        self.function_state().scopes.pop();
+    }
+
+    /// Define a variable in the innermost scope
+    pub fn define(&mut self, name: &str, variable: Variable<'ctx>) {
+        if self.is_global_scope() {
+            self.globals.insert(name.to_string(), variable);
+        } else {
+            let scopes = &mut self.function_state().scopes;
+            scopes
+                .last_mut()
+                .expect("no local scope")
+                .insert(name.to_string(), variable);
+        }
+    }
+
+    /// Look a variable up from the innermost scope to the globals
+    pub fn lookup(&self, name: &str) -> Option<&Variable<'ctx>> {
+        self.current
+            .iter()
+            .flat_map(|state| state.scopes.iter().rev())
+            .find_map(|scope| scope.get(name))
+            .or_else(|| self.globals.get(name))
+    }
+
+    pub fn declare_function(&mut self, name: &str, info: FunctionInfo<'ctx>) {
+        self.functions.insert(name.to_string(), info);
+    }
+
+    pub fn function(&self, name: &str) -> Option<&FunctionInfo<'ctx>> {
+        self.functions.get(name)
+    }
+
+    /// Innermost loop of the current function
+    pub fn current_loop(&self) -> Option<LoopBlocks<'ctx>> {
+        self.current.as_ref().and_then(|state| state.loops.last().copied())
+    }
+}
+
+impl Default for CodegenContext<'_> {
+    fn default() -> Self {
+        Self::new()
+    }
+}
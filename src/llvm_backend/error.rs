@@ -0,0 +1,80 @@
+/*
+ * -------------------------------------------------------------------------
+ * RemyLang — LLVM backend error handling
+ * File : src/llvm_backend/error.rs
+ *
+ * Description :
+ *   Error types for native code generation.
+ *
+ * Author  : Samuel 'Meeast' Bleau
+ * Created : 2026-10-16
+ *
+ * -------------------------------------------------------------------------
+*/
+
+use std::fmt;
+use inkwell::builder::BuilderError;
+use crate::diagnostics::Diagnostic;
+use crate::lexer::Span;
+
+pub type CodegenResult<T> = Result<T, CodegenError>;
+
+#[derive(Debug, Clone, PartialEq)]
+pub enum CodegenError {
+    /// Construct the native backend cannot compile yet
+    Unsupported { feature: String, span: Span },
+
+    /// Name that is not declared, which the type checker should have caught
+    UndefinedSymbol { name: String, span: Span },
+
+    /// Error reported by LLVM while building or verifying the module
+    Llvm(String),
+
+    /// Error while writing output files or linking the executable
+    Output(String),
+}
+
+impl CodegenError {
+    /// Location of the error, if known
+    pub fn span(&self) -> Option<&Span> {
+        match self {
+            CodegenError::Unsupported { span, .. } | CodegenError::UndefinedSymbol { span, .. } => Some(span),
+            CodegenError::Llvm(_) | CodegenError::Output(_) => None,
+        }
+    }
+
+    /// Description of the error, without its location
+    pub fn message(&self) -> String {
+        match self {
+            CodegenError::Unsupported { feature, .. } => {
+                format!("{} are not supported by the native backend", feature)
+            }
+            CodegenError::UndefinedSymbol { name, .. } => format!("Undefined symbol '{}'", name),
+            CodegenError::Llvm(message) => format!("LLVM: {}", message),
+            CodegenError::Output(message) => message.clone(),
+        }
+    }
+}
+
+impl fmt::Display for CodegenError {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        match self.span() {
+            Some(span) => write!(f, "{}: Codegen error: {}", span, self.message()),
+            None => write!(f, "Codegen error: {}", self.message()),
+        }
+    }
+}
+
+impl From<&CodegenError> for Diagnostic {
+    fn from(error: &CodegenError) -> Self {
+        Diagnostic::error("codegen", error.message()).with_span(error.span())
+    }
+}
+
+impl std::error::Error for CodegenError {}
+
+impl From<BuilderError> for CodegenError {
+    fn from(err: BuilderError) -> Self {
+        CodegenError::Llvm(err.to_string())
+    }
+}
@@ -0,0 +1,237 @@
+/*
+ * -------------------------------------------------------------------------
+ * RemyLang — JIT host runtime
+ * File : src/llvm_backend/host.rs
+ *
+ * Description :
+ *   Rust implementation of the native runtime, called by programs run
+ *   in-process by the JIT. Mirrors runtime.c, but prints through the
+ *   interpreter's formatting so both give the same output.
+ *
+ * Author  : Samuel 'Meeast' Bleau
+ * Created : 2026-10-16
+ *
+ * -------------------------------------------------------------------------
+*/
+
+use std::cell::RefCell;
+use std::ffi::{c_char, CStr, CString};
+use std::io::{self, Write};
+use crate::vm::{builtin, ops};
+use crate::vm::error::RuntimeError;
+use crate::vm::value::Value;
+
+/// Same layout as `RemyArray` in runtime.c
+#[repr(C)]
+pub struct RemyArray {
+    length: i64,
+    items: *mut i64,
+}
+
+thread_local! {
+    /// Output of the running program when it is captured instead of printed
+    static CAPTURE: RefCell<Option<String>> = const { RefCell::new(None) };
+}
+
+/// Start collecting printed text instead of writing it to stdout
+pub fn start_capture() {
+    CAPTURE.with(|capture| *capture.borrow_mut() = Some(String::new()));
+}
+
+/// Stop collecting printed text, returning what was collected
+pub fn finish_capture() -> String {
+    CAPTURE.with(|capture| capture.borrow_mut().take().unwrap_or_default())
+}
+
+fn write(text: &str) {
+    CAPTURE.with(|capture| match &mut *capture.borrow_mut() {
+        Some(buffer) => buffer.push_str(text),
+        None => print!("{}", text),
+    });
+}
+
+/// Report a runtime error and exit, as native executables do
+/// Unwinding through JIT-compiled frames is not possible.
+fn fail(error: RuntimeError) -> ! {
+    let captured = finish_capture();
+    print!("{}", captured);
+    let _ = io::stdout().flush();
+    eprintln!("{}", error);
+    std::process::exit(1);
+}
+
+/// Address of every runtime function, to map the module's declarations to
+pub fn symbols() -> Vec<(&'static str, usize)> {
+    vec![
+        ("remy_runtime_error", remy_runtime_error as *const () as usize),
+        ("remy_print_int", remy_print_int as *const () as usize),
+        ("remy_print_float", remy_print_float as *const () as usize),
+        ("remy_print_bool", remy_print_bool as *const () as usize),
+        ("remy_print_char", remy_print_char as *const () as usize),
+        ("remy_print_str", remy_print_str as *const () as usize),
+        ("remy_print_array", remy_print_array as *const () as usize),
+        ("remy_print_space", remy_print_space as *const () as usize),
+        ("remy_print_newline", remy_print_newline as *const () as usize),
+        ("remy_str_concat", remy_str_concat as *const () as usize),
+        ("remy_str_eq", remy_str_eq as *const () as usize),
+        ("remy_array_new", remy_array_new as *const () as usize),
+        ("remy_array_len", remy_array_len as *const () as usize),
+        ("remy_array_get", remy_array_get as *const () as usize),
+        ("remy_array_set", remy_array_set as *const () as usize),
+        ("remy_array_eq", remy_array_eq as *const () as usize),
+        ("remy_array_copy", remy_array_copy as *const () as usize),
+        ("remy_pow", remy_pow as *const () as usize),
+    ]
+}
+
+// ============================================================================
+// CONVERSIONS
+// ============================================================================
+
+unsafe fn string(s: *const c_char) -> String {
+    CStr::from_ptr(s).to_string_lossy().into_owned()
+}
+
+unsafe fn items<'a>(array: *const RemyArray) -> &'a [i64] {
+    let array = &*array;
+    std::slice::from_raw_parts(array.items, array.length as usize)
+}
+
+/// Value stored in a 64-bit array slot, described as in `types::descriptor`
+unsafe fn slot_value(slot: i64, desc: &[u8]) -> Value {
+    match desc.first() {
+        Some(b'I') => Value::Number(slot),
+        Some(b'F') => Value::Float(f64::from_bits(slot as u64)),
+        Some(b'B') => Value::Bool(slot != 0),
+        Some(b'C') => Value::Char(char::from_u32(slot as u32).unwrap_or(char::REPLACEMENT_CHARACTER)),
+        Some(b'S') => Value::String(string(slot as *const c_char)),
+        Some(b'A') => array_value(slot as *const RemyArray, &desc[1..]),
+        _ => fail(RuntimeError::Custom("Invalid array element type".to_string())),
+    }
+}
+
+unsafe fn array_value(array: *const RemyArray, desc: &[u8]) -> Value {
+    Value::Array(items(array).iter().map(|&slot| slot_value(slot, desc)).collect())
+}
+
+unsafe fn slots_equal(left: i64, right: i64, desc: &[u8]) -> bool {
+    match desc.first() {
+        Some(b'F') => f64::from_bits(left as u64) == f64::from_bits(right as u64),
+        Some(b'S') => string(left as *const c_char) == string(right as *const c_char),
+        Some(b'A') => remy_array_eq(left as *const RemyArray, right as *const RemyArray, desc[1..].as_ptr() as *const c_char),
+        _ => left == right,
+    }
+}
+
+// ============================================================================
+// RUNTIME FUNCTIONS
+// ============================================================================
+
+unsafe extern "C" fn remy_runtime_error(message: *const c_char) {
+    fail(RuntimeError::Custom(string(message)));
+}
+
+extern "C" fn remy_print_int(value: i64) {
+    write(&builtin::format_value(&Value::Number(value)));
+}
+
+extern "C" fn remy_print_float(value: f64) {
+    write(&builtin::format_value(&Value::Float(value)));
+}
+
+// LLVM passes an i1, only its lowest bit is meaningful
+extern "C" fn remy_print_bool(value: u8) {
+    write(&builtin::format_value(&Value::Bool(value & 1 != 0)));
+}
+
+extern "C" fn remy_print_char(c: u32) {
+    let c = char::from_u32(c).unwrap_or(char::REPLACEMENT_CHARACTER);
+    write(&builtin::format_value(&Value::Char(c)));
+}
+
+unsafe extern "C" fn remy_print_str(s: *const c_char) {
+    write(&builtin::format_value(&Value::String(string(s))));
+}
+
+unsafe extern "C" fn remy_print_array(array: *const RemyArray, desc: *const c_char) {
+    let value = array_value(array, CStr::from_ptr(desc).to_bytes());
+    write(&builtin::format_value(&value));
+}
+
+extern "C" fn remy_print_space() {
+    write(" ");
+}
+
+extern "C" fn remy_print_newline() {
+    write("\n");
+}
+
+/// The result is never freed, as in the native runtime
+unsafe extern "C" fn remy_str_concat(left: *const c_char, right: *const c_char) -> *mut c_char {
+    let mut bytes = CStr::from_ptr(left).to_bytes().to_vec();
+    bytes.extend_from_slice(CStr::from_ptr(right).to_bytes());
+    CString::new(bytes).expect("C strings have no interior nul").into_raw()
+}
+
+unsafe extern "C" fn remy_str_eq(left: *const c_char, right: *const c_char) -> bool {
+    CStr::from_ptr(left) == CStr::from_ptr(right)
+}
+
+extern "C" fn remy_array_new(length: i64) -> *mut RemyArray {
+    let items = vec![0i64; length.max(0) as usize].leak();
+    Box::into_raw(Box::new(RemyArray {
+        length,
+        items: items.as_mut_ptr(),
+    }))
+}
+
+unsafe extern "C" fn remy_array_len(array: *const RemyArray) -> i64 {
+    (*array).length
+}
+
+unsafe fn check_index(array: *const RemyArray, index: i64) {
+    let length = (*array).length;
+    if index < 0 || index >= length {
+        fail(RuntimeError::IndexOutOfBounds { index, length: length as usize });
+    }
+}
+
+unsafe extern "C" fn remy_array_get(array: *const RemyArray, index: i64) -> i64 {
+    check_index(array, index);
+    *(*array).items.add(index as usize)
+}
+
+unsafe extern "C" fn remy_array_set(array: *mut RemyArray, index: i64, value: i64) {
+    check_index(array, index);
+    *(*array).items.add(index as usize) = value;
+}
+
+unsafe extern "C" fn remy_array_eq(left: *const RemyArray, right: *const RemyArray, desc: *const c_char) -> bool {
+    let desc = CStr::from_ptr(desc).to_bytes();
+    let (left, right) = (items(left), items(right));
+    left.len() == right.len() && left.iter().zip(right).all(|(&l, &r)| slots_equal(l, r, desc))
+}
+
+/// Strings are immutable, so only nested arrays are copied
+unsafe extern "C" fn remy_array_copy(array: *const RemyArray, desc: *const c_char) -> *mut RemyArray {
+    let nested = CStr::from_ptr(desc).to_bytes().first() == Some(&b'A');
+    let source = items(array);
+    let copy = remy_array_new(source.len() as i64);
+    for (i, &slot) in source.iter().enumerate() {
+        *(*copy).items.add(i) = if nested {
+            remy_array_copy(slot as *const RemyArray, desc.add(1)) as i64
+        } else {
+            slot
+        };
+    }
+    copy
+}
+
+extern "C" fn remy_pow(base: i64, exponent: i64) -> i64 {
+    if exponent < 0 {
+        fail(RuntimeError::Custom("Negative exponents not supported for integers".to_string()));
+    }
+    ops::checked_pow(base, exponent).unwrap_or_else(|| {
+        fail(RuntimeError::IntegerOverflow { operation: "exponentiation".to_string() })
+    })
+}
@@ -0,0 +1,60 @@
+/*
+ * -------------------------------------------------------------------------
+ * RemyLang — JIT execution
+ * File : src/llvm_backend/jit.rs
+ *
+ * Description :
+ *   Runs generated modules in-process through LLVM's execution engine,
+ *   with the runtime provided by the host (see host.rs).
+ *
+ * Author  : Samuel 'Meeast' Bleau
+ * Created : 2026-10-16
+ *
+ * -------------------------------------------------------------------------
+*/
+
+use inkwell::context::Context;
+use inkwell::execution_engine::ExecutionEngine;
+use inkwell::OptimizationLevel;
+use crate::ast::Stmt;
+use crate::llvm_backend::codegen::CodeGen;
+use crate::llvm_backend::error::{CodegenError, CodegenResult};
+use crate::llvm_backend::host;
+
+/// Signature of the generated entry point
+type MainFunction = unsafe extern "C" fn() -> i32;
+
+/// Compile a type-checked program and run it in-process
+///
+/// A runtime error exits the process with status 1, as native executables do.
+pub fn run(program: &[Stmt], module_name: &str) -> CodegenResult<()> {
+    ExecutionEngine::link_in_mc_jit();
+
+    let context = Context::create();
+    let module = CodeGen::new(&context, module_name).compile_program(program)?;
+    let engine = module
+        .create_jit_execution_engine(OptimizationLevel::Default)
+        .map_err(|e| CodegenError::Llvm(e.to_string()))?;
+
+    // Runtime calls go to the host instead of runtime.c
+    for (name, address) in host::symbols() {
+        if let Some(function) = module.get_function(name) {
+            engine.add_global_mapping(&function, address);
+        }
+    }
+
+    let main = unsafe { engine.get_function::<MainFunction>("main") }
+        .map_err(|e| CodegenError::Llvm(e.to_string()))?;
+    unsafe {
+        main.call();
+    }
+    Ok(())
+}
+
+/// Run a program like `run`, returning what it printed instead of writing it to stdout
+pub fn run_captured(program: &[Stmt], module_name: &str) -> CodegenResult<String> {
+    host::start_capture();
+    let result = run(program, module_name);
+    let output = host::finish_capture();
+    result.map(|()| output)
+}
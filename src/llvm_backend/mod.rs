@@ -0,0 +1,37 @@
+/*
+ * -------------------------------------------------------------------------
+ * RemyLang — LLVM backend module
+ * File : src/llvm_backend/mod.rs
+ *
+ * Description :
+ *   Native code generation through LLVM.
+ *
+ * Author  : Samuel 'Meeast' Bleau
+ * Created : 2026-10-16
+ *
+ * -------------------------------------------------------------------------
+*/
+
+pub mod error;
+pub mod types;
+pub mod context;
+pub mod codegen;
+pub mod module;
+pub mod host;
+pub mod jit;
+
+// Re-exports for convenience
+pub use error::{CodegenError, CodegenResult};
+pub use codegen::CodeGen;
+pub use module::{emit, OutputKind};
+
+use std::path::Path;
+use inkwell::context::Context;
+use crate::ast::Stmt;
+
+/// Compile a type-checked program to `output`
+pub fn build(program: &[Stmt], module_name: &str, kind: OutputKind, output: &Path) -> CodegenResult<()> {
+    let context = Context::create();
+    let module = CodeGen::new(&context, module_name).compile_program(program)?;
+    emit(&module, kind, output)
+}
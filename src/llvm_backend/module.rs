@@ -0,0 +1,127 @@
+/*
+ * -------------------------------------------------------------------------
+ * RemyLang — native output
+ * File : src/llvm_backend/module.rs
+ *
+ * Description :
+ *   Writes generated modules as LLVM IR, object files or executables
+ *   linked with the native runtime.
+ *
+ * Author  : Samuel 'Meeast' Bleau
+ * Created : 2026-10-16
+ *
+ * -------------------------------------------------------------------------
+*/
+
+use std::env;
+use std::fs;
+use std::path::{Path, PathBuf};
+use std::process::Command;
+use inkwell::module::Module;
+use inkwell::targets::{CodeModel, FileType, InitializationConfig, RelocMode, Target, TargetMachine};
+use inkwell::OptimizationLevel;
+use crate::llvm_backend::error::{CodegenError, CodegenResult};
+
+/// Source of the runtime linked into every executable
+pub const RUNTIME_SOURCE: &str = include_str!("runtime.c");
+
+/// Kind of file produced by the backend
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub enum OutputKind {
+    /// Textual LLVM IR (`.ll`)
+    LlvmIr,
+    /// Object file for the host target (`.o`)
+    Object,
+    /// Executable linked with the runtime
+    Executable,
+}
+
+impl OutputKind {
+    /// Conventional extension for the output, empty for executables
+    pub fn extension(&self) -> &'static str {
+        match self {
+            OutputKind::LlvmIr => "ll",
+            OutputKind::Object => "o",
+            OutputKind::Executable => "",
+        }
+    }
+}
+
+/// Write a module to `output`
+pub fn emit(module: &Module, kind: OutputKind, output: &Path) -> CodegenResult<()> {
+    match kind {
+        OutputKind::LlvmIr => {
+            module
+                .print_to_file(output)
+                .map_err(|e| CodegenError::Output(e.to_string()))
+        }
+        OutputKind::Object => write_object(module, output),
+        OutputKind::Executable => {
+            let object = temp_path(output, "o");
+            write_object(module, &object)?;
+            let result = link(&object, output);
+            let _ = fs::remove_file(&object);
+            result
+        }
+    }
+}
+
+/// Machine for the host, used for object files
+pub fn host_target_machine() -> CodegenResult<TargetMachine> {
+    Target::initialize_native(&InitializationConfig::default()).map_err(CodegenError::Llvm)?;
+
+    let triple = TargetMachine::get_default_triple();
+    let target = Target::from_triple(&triple).map_err(|e| CodegenError::Llvm(e.to_string()))?;
+    target
+        .create_target_machine(
+            &triple,
+            &TargetMachine::get_host_cpu_name().to_string(),
+            &TargetMachine::get_host_cpu_features().to_string(),
+            OptimizationLevel::Default,
+            RelocMode::PIC,
+            CodeModel::Default,
+        )
+        .ok_or_else(|| CodegenError::Llvm(format!("No target machine for {}", triple)))
+}
+
+fn write_object(module: &Module, output: &Path) -> CodegenResult<()> {
+    let machine = host_target_machine()?;
+    module.set_triple(&machine.get_triple());
+    module.set_data_layout(&machine.get_target_data().get_data_layout());
+    machine
+        .write_to_file(module, FileType::Object, output)
+        .map_err(|e| CodegenError::Output(e.to_string()))
+}
+
+/// Link an object file with the runtime, using the C compiler from `$CC`
+fn link(object: &Path, output: &Path) -> CodegenResult<()> {
+    let runtime = temp_path(output, "runtime.c");
+    fs::write(&runtime, RUNTIME_SOURCE)
+        .map_err(|e| CodegenError::Output(format!("Cannot write runtime: {}", e)))?;
+
+    let compiler = env::var("CC").unwrap_or_else(|_| "cc".to_string());
+    let status = Command::new(&compiler)
+        .arg(object)
+        .arg(&runtime)
+        // Float exponentiation calls `pow` from the C math library
+        .arg("-lm")
+        .arg("-o")
+        .arg(output)
+        .status();
+    let _ = fs::remove_file(&runtime);
+
+    match status {
+        Ok(status) if status.success() => Ok(()),
+        Ok(status) => Err(CodegenError::Output(format!("Linker '{}' failed with {}", compiler, status))),
+        Err(e) => Err(CodegenError::Output(format!("Cannot run linker '{}': {}", compiler, e))),
+    }
+}
+
+/// Intermediate file next to the system temporary directory
+fn temp_path(output: &Path, extension: &str) -> PathBuf {
+    let stem = output
+        .file_stem()
+        .map(|s| s.to_string_lossy().into_owned())
+        .unwrap_or_else(|| "remylang".to_string());
+    env::temp_dir().join(format!("{}-{}.{}", stem, std::process::id(), extension))
+}
@@ -0,0 +1,266 @@
+/*
+ * -------------------------------------------------------------------------
+ * RemyLang — native runtime
+ * File : src/llvm_backend/runtime.c
+ *
+ * Description :
+ *   Support functions called by natively compiled RemyLang programs:
+ *   printing, strings, arrays and runtime errors. Linked into every
+ *   executable built by the LLVM backend.
+ *
+ * Author  : Samuel 'Meeast' Bleau
+ * Created : 2026-10-16
+ *
+ * -------------------------------------------------------------------------
+*/
+
+#include <math.h>
+#include <stdbool.h>
+#include <stdint.h>
+#include <stdio.h>
+#include <stdlib.h>
+#include <string.h>
+
+/* Arrays store every element in a 64-bit slot */
+typedef struct {
+    int64_t length;
+    int64_t *items;
+} RemyArray;
+
+void remy_runtime_error(const char *message) {
+    fflush(stdout);
+    fprintf(stderr, "Runtime error: %s\n", message);
+    exit(1);
+}
+
+/* ========================================================================
+ * PRINTING
+ * ======================================================================== */
+
+void remy_print_int(int64_t value) {
+    printf("%lld", (long long)value);
+}
+
+/* Same text as the interpreter: the shortest decimal that reads back as the
+   same double, never in exponent form, with ".0" added to whole numbers */
+void remy_print_float(double value) {
+    if (isnan(value)) {
+        fputs("NaN", stdout);
+        return;
+    }
+    if (isinf(value)) {
+        fputs(value < 0 ? "-inf" : "inf", stdout);
+        return;
+    }
+
+    char buffer[32];
+    int digits = 1;
+    for (; digits < 17; digits++) {
+        snprintf(buffer, sizeof(buffer), "%.*e", digits - 1, value);
+        if (strtod(buffer, NULL) == value) {
+            break;
+        }
+    }
+    snprintf(buffer, sizeof(buffer), "%.*e", digits - 1, value);
+    int exponent = atoi(strchr(buffer, 'e') + 1);
+    int decimals = digits - 1 - exponent;
+    printf("%.*f", decimals > 0 ? decimals : 1, value);
+}
+
+void remy_print_bool(bool value) {
+    fputs(value ? "True" : "False", stdout);
+}
+
+void remy_print_char(uint32_t c) {
+    char buffer[4];
+    int length;
+
+    if (c < 0x80) {
+        buffer[0] = (char)c;
+        length = 1;
+    } else if (c < 0x800) {
+        buffer[0] = (char)(0xC0 | (c >> 6));
+        buffer[1] = (char)(0x80 | (c & 0x3F));
+        length = 2;
+    } else if (c < 0x10000) {
+        buffer[0] = (char)(0xE0 | (c >> 12));
+        buffer[1] = (char)(0x80 | ((c >> 6) & 0x3F));
+        buffer[2] = (char)(0x80 | (c & 0x3F));
+        length = 3;
+    } else {
+        buffer[0] = (char)(0xF0 | (c >> 18));
+        buffer[1] = (char)(0x80 | ((c >> 12) & 0x3F));
+        buffer[2] = (char)(0x80 | ((c >> 6) & 0x3F));
+        buffer[3] = (char)(0x80 | (c & 0x3F));
+        length = 4;
+    }
+    fwrite(buffer, 1, (size_t)length, stdout);
+}
+
+/* Escape sequences are decoded by the lexer, so strings print as they are.
+   Strings are nul-terminated, a "\0" escape ends them early. */
+void remy_print_str(const char *s) {
+    fputs(s, stdout);
+}
+
+void remy_print_space(void) {
+    putchar(' ');
+}
+
+void remy_print_newline(void) {
+    putchar('\n');
+}
+
+/* Element types are described by a string: I(nt), F(loat), B(ool), C(har),
+   S(tring) or A(rray) followed by the description of its elements.
+   Floats are stored by their bits. */
+static void print_slot(int64_t slot, const char *desc);
+
+static double slot_float(int64_t slot) {
+    double value;
+    memcpy(&value, &slot, sizeof(value));
+    return value;
+}
+
+void remy_print_array(const RemyArray *array, const char *desc) {
+    putchar('[');
+    for (int64_t i = 0; i < array->length; i++) {
+        if (i > 0) {
+            fputs(", ", stdout);
+        }
+        print_slot(array->items[i], desc);
+    }
+    putchar(']');
+}
+
+static void print_slot(int64_t slot, const char *desc) {
+    switch (desc[0]) {
+        case 'I': remy_print_int(slot); break;
+        case 'F': remy_print_float(slot_float(slot)); break;
+        case 'B': remy_print_bool(slot != 0); break;
+        case 'C': remy_print_char((uint32_t)slot); break;
+        case 'S': remy_print_str((const char *)(intptr_t)slot); break;
+        case 'A': remy_print_array((const RemyArray *)(intptr_t)slot, desc + 1); break;
+        default: remy_runtime_error("Invalid array element type");
+    }
+}
+
+/* ========================================================================
+ * STRINGS
+ * ======================================================================== */
+
+char *remy_str_concat(const char *left, const char *right) {
+    size_t left_length = strlen(left);
+    size_t right_length = strlen(right);
+    char *result = malloc(left_length + right_length + 1);
+    if (!result) {
+        remy_runtime_error("Out of memory");
+    }
+    memcpy(result, left, left_length);
+    memcpy(result + left_length, right, right_length + 1);
+    return result;
+}
+
+bool remy_str_eq(const char *left, const char *right) {
+    return strcmp(left, right) == 0;
+}
+
+/* ========================================================================
+ * ARRAYS
+ * ======================================================================== */
+
+RemyArray *remy_array_new(int64_t length) {
+    RemyArray *array = malloc(sizeof(RemyArray));
+    int64_t *items = calloc(length > 0 ? (size_t)length : 1, sizeof(int64_t));
+    if (!array || !items) {
+        remy_runtime_error("Out of memory");
+    }
+    array->length = length;
+    array->items = items;
+    return array;
+}
+
+int64_t remy_array_len(const RemyArray *array) {
+    return array->length;
+}
+
+static void check_index(const RemyArray *array, int64_t index) {
+    if (index < 0 || index >= array->length) {
+        char message[128];
+        snprintf(message, sizeof(message),
+                 "Index %lld out of bounds for array of length %lld",
+                 (long long)index, (long long)array->length);
+        remy_runtime_error(message);
+    }
+}
+
+int64_t remy_array_get(const RemyArray *array, int64_t index) {
+    check_index(array, index);
+    return array->items[index];
+}
+
+void remy_array_set(RemyArray *array, int64_t index, int64_t value) {
+    check_index(array, index);
+    array->items[index] = value;
+}
+
+static bool slots_equal(int64_t left, int64_t right, const char *desc);
+
+bool remy_array_eq(const RemyArray *left, const RemyArray *right, const char *desc) {
+    if (left->length != right->length) {
+        return false;
+    }
+    for (int64_t i = 0; i < left->length; i++) {
+        if (!slots_equal(left->items[i], right->items[i], desc)) {
+            return false;
+        }
+    }
+    return true;
+}
+
+static bool slots_equal(int64_t left, int64_t right, const char *desc) {
+    switch (desc[0]) {
+        case 'F': return slot_float(left) == slot_float(right);
+        case 'S': return remy_str_eq((const char *)(intptr_t)left, (const char *)(intptr_t)right);
+        case 'A': return remy_array_eq((const RemyArray *)(intptr_t)left,
+                                       (const RemyArray *)(intptr_t)right, desc + 1);
+        default: return left == right;
+    }
+}
+
+/* Arrays are values: storing one copies it, nested arrays included.
+   Strings are immutable, so they are shared. */
+RemyArray *remy_array_copy(const RemyArray *array, const char *desc) {
+    RemyArray *copy = remy_array_new(array->length);
+    for (int64_t i = 0; i < array->length; i++) {
+        int64_t slot = array->items[i];
+        if (desc[0] == 'A') {
+            slot = (int64_t)(intptr_t)remy_array_copy((const RemyArray *)(intptr_t)slot, desc + 1);
+        }
+        copy->items[i] = slot;
+    }
+    return copy;
+}
+
+/* ========================================================================
+ * ARITHMETIC
+ * ======================================================================== */
+
+int64_t remy_pow(int64_t base, int64_t exponent) {
+    if (exponent < 0) {
+        remy_runtime_error("Negative exponents not supported for integers");
+    }
+    /* Square-and-multiply; the last squaring is skipped as it is never used */
+    int64_t result = 1;
+    int64_t factor = base;
+    while (exponent > 0) {
+        if ((exponent & 1) && __builtin_mul_overflow(result, factor, &result)) {
+            remy_runtime_error("Integer overflow in exponentiation");
+        }
+        exponent >>= 1;
+        if (exponent > 0 && __builtin_mul_overflow(factor, factor, &factor)) {
+            remy_runtime_error("Integer overflow in exponentiation");
+        }
+    }
+    return result;
+}
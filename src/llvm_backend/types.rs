@@ -0,0 +1,92 @@
+/*
+ * -------------------------------------------------------------------------
+ * RemyLang — LLVM type mapping
+ * File : src/llvm_backend/types.rs
+ *
+ * Description :
+ *   Maps RemyLang types to their LLVM representation.
+ *
+ * Author  : Samuel 'Meeast' Bleau
+ * Created : 2026-10-16
+ *
+ * -------------------------------------------------------------------------
+*/
+
+use inkwell::context::Context;
+use inkwell::types::{BasicMetadataTypeEnum, BasicType, BasicTypeEnum, FloatType, FunctionType, IntType, PointerType};
+use inkwell::AddressSpace;
+use crate::ast::Type;
+
+/// LLVM types used by the generated code
+///
+/// Int is an i64, Float a double, Bool an i1 and Char an i32 holding a
+/// Unicode scalar value.
+/// Strings are pointers to null-terminated UTF-8 data, Arrays pointers to a
+/// runtime `RemyArray` whose elements are stored in 64-bit slots.
+#[derive(Debug, Clone, Copy)]
+pub struct LlvmTypes<'ctx> {
+    pub context: &'ctx Context,
+    pub int: IntType<'ctx>,
+    pub float: FloatType<'ctx>,
+    pub bool: IntType<'ctx>,
+    pub char: IntType<'ctx>,
+    pub i32: IntType<'ctx>,
+    pub ptr: PointerType<'ctx>,
+}
+
+impl<'ctx> LlvmTypes<'ctx> {
+    pub fn new(context: &'ctx Context) -> Self {
+        LlvmTypes {
+            context,
+            int: context.i64_type(),
+            float: context.f64_type(),
+            bool: context.bool_type(),
+            char: context.i32_type(),
+            i32: context.i32_type(),
+            ptr: context.ptr_type(AddressSpace::default()),
+        }
+    }
+
+    /// LLVM type of a value, None for Void
+    pub fn basic(&self, ty: &Type) -> Option<BasicTypeEnum<'ctx>> {
+        match ty {
+            Type::Int => Some(self.int.into()),
+            Type::Float => Some(self.float.into()),
+            Type::Bool => Some(self.bool.into()),
+            Type::Char => Some(self.char.into()),
+            Type::String | Type::Array(_) | Type::Named(_) | Type::Function { .. } => Some(self.ptr.into()),
+            Type::Void => None,
+        }
+    }
+
+    /// LLVM signature of a function
+    pub fn function(&self, params: &[Type], return_type: &Type) -> FunctionType<'ctx> {
+        let params: Vec<BasicMetadataTypeEnum> = params
+            .iter()
+            .filter_map(|p| self.basic(p))
+            .map(Into::into)
+            .collect();
+
+        match self.basic(return_type) {
+            Some(ret) => ret.fn_type(&params, false),
+            None => self.context.void_type().fn_type(&params, false),
+        }
+    }
+}
+
+/// Description of a type for the runtime, used to print and compare arrays
+/// `Array<Array<Int>>` is described as "AAI".
+pub fn descriptor(ty: &Type) -> String {
+    match ty {
+        Type::Int => "I".to_string(),
+        Type::Float => "F".to_string(),
+        Type::Bool => "B".to_string(),
+        Type::Char => "C".to_string(),
+        Type::String => "S".to_string(),
+        Type::Array(elem) => format!("A{}", descriptor(elem)),
+        Type::Void => "V".to_string(),
+        // Function values are rejected, so arrays of functions stay empty
+        Type::Function { .. } => "P".to_string(),
+        Type::Named(_) => unreachable!("programs with structs or enums are rejected before code generation"),
+    }
+}
@@ -0,0 +1,238 @@
+/*
+ * -------------------------------------------------------------------------
+ * RemyLang — LSP analysis
+ * File : src/lsp/analysis.rs
+ *
+ * Description :
+ *   Front end results for an open document, answering the requests of the
+ *   editor: diagnostics, hover, definition, completion, symbols and
+ *   formatting.
+ *
+ * Author  : Samuel 'Meeast' Bleau
+ * Created : 2026-10-16
+ *
+ * -------------------------------------------------------------------------
+*/
+
+use super::document::Document;
+use crate::ast::{Stmt, StmtKind, Type};
+use crate::diagnostics::{self, Severity};
+use crate::formatter::format_source;
+use crate::lexer::{Lexer, Span, SpannedToken, Token};
+use crate::parser::Parser;
+use crate::semantic::{Declaration, Reference, Symbol, TypeChecker};
+use crate::stdlib;
+use lsp_types::{
+    CompletionItem, CompletionItemKind, Diagnostic, DiagnosticSeverity, DocumentSymbol, Hover, HoverContents,
+    MarkupContent, MarkupKind, NumberOrString, Position, Range, SymbolKind, TextEdit,
+};
+
+/// Tokens, syntax tree and checked names of a document
+/// Parse errors are recovered from, so a document being edited still
+/// gets hover, definition and completion.
+#[derive(Debug)]
+pub struct Analysis {
+    name: String,
+    text: String,
+    document: Document,
+    tokens: Vec<SpannedToken>,
+    ast: Vec<Stmt>,
+    diagnostics: Vec<diagnostics::Diagnostic>,
+    declarations: Vec<Declaration>,
+    references: Vec<Reference>,
+}
+
+impl Analysis {
+    /// Analyzes the text of the document called `name`
+    pub fn new(text: &str, name: &str) -> Self {
+        let tokens = Lexer::with_file(text.to_string(), name).tokenize();
+        let (ast, parse_errors) = Parser::new(tokens.clone()).parse_recovering();
+
+        let mut checker = TypeChecker::new();
+        let type_errors = checker.check_program(&ast).err().unwrap_or_default();
+        // Type errors in a program that does not parse are mostly noise
+        let diagnostics = if parse_errors.is_empty() {
+            type_errors.iter().chain(checker.warnings()).map(diagnostics::Diagnostic::from).collect()
+        } else {
+            parse_errors.iter().map(diagnostics::Diagnostic::from).collect()
+        };
+
+        Analysis {
+            name: name.to_string(),
+            text: text.to_string(),
+            document: Document::new(text),
+            declarations: checker.declarations().to_vec(),
+            references: checker.references().to_vec(),
+            tokens,
+            ast,
+            diagnostics,
+        }
+    }
+
+    /// Errors of the lexer, parser and type checker, and warnings of the type checker
+    pub fn diagnostics(&self) -> Vec<Diagnostic> {
+        self.diagnostics
+            .iter()
+            .map(|diagnostic| {
+                let mut message = diagnostic.message.clone();
+                for help in &diagnostic.help {
+                    message.push_str(&format!("\nhelp: {}", help));
+                }
+                for note in &diagnostic.notes {
+                    message.push_str(&format!("\nnote: {}", note));
+                }
+                Diagnostic {
+                    range: diagnostic.span.as_ref().map(|span| self.document.range(span)).unwrap_or_default(),
+                    severity: Some(match diagnostic.severity {
+                        Severity::Error => DiagnosticSeverity::ERROR,
+                        Severity::Warning => DiagnosticSeverity::WARNING,
+                    }),
+                    code: Some(NumberOrString::String(diagnostic.category.to_string())),
+                    source: Some("remylang".to_string()),
+                    message,
+                    ..Diagnostic::default()
+                }
+            })
+            .collect()
+    }
+
+    /// Type of the variable or function at `position`
+    pub fn hover(&self, position: Position) -> Option<Hover> {
+        let offset = self.document.offset(position);
+        let (name, ty, span) = match self.reference_at(offset) {
+            Some(reference) => (&reference.name, &reference.ty, reference.span.clone()),
+            None => {
+                let (declaration, span) = self
+                    .declarations
+                    .iter()
+                    .map(|declaration| (declaration, self.name_span(&declaration.name, &declaration.span)))
+                    .find(|(_, span)| span.start <= offset && offset <= span.end)?;
+                (&declaration.name, &declaration.ty, span)
+            }
+        };
+        Some(Hover {
+            contents: HoverContents::Markup(MarkupContent {
+                kind: MarkupKind::Markdown,
+                value: format!("```remylang\n{} {}\n```", ty, name),
+            }),
+            range: Some(self.document.range(&span)),
+        })
+    }
+
+    /// Range of the name declaring the variable or function used at `position`
+    pub fn definition(&self, position: Position) -> Option<Range> {
+        let reference = self.reference_at(self.document.offset(position))?;
+        Some(self.document.range(&self.name_span(&reference.name, &reference.declaration)))
+    }
+
+    /// Variables, functions and builtins visible at `position`
+    pub fn completion(&self, position: Position) -> Vec<CompletionItem> {
+        let mut checker = TypeChecker::new();
+        checker.probe_scope(self.document.offset(position));
+        let _ = checker.check_program(&self.ast);
+
+        let mut items: Vec<CompletionItem> = checker
+            .probed_scope()
+            .iter()
+            .map(|(name, symbol)| CompletionItem {
+                label: name.clone(),
+                kind: Some(match symbol {
+                    Symbol::Variable(_) => CompletionItemKind::VARIABLE,
+                    Symbol::Function { .. } => CompletionItemKind::FUNCTION,
+                }),
+                detail: Some(symbol.value_type().to_string()),
+                ..CompletionItem::default()
+            })
+            .collect();
+        // Builtins are hidden by the variables and functions named like them
+        for native in stdlib::natives() {
+            if !items.iter().any(|item| item.label == native.name) {
+                items.push(CompletionItem {
+                    label: native.name.to_string(),
+                    kind: Some(CompletionItemKind::FUNCTION),
+                    detail: Some(native.signature.to_string()),
+                    ..CompletionItem::default()
+                });
+            }
+        }
+        items.sort_by(|a, b| a.label.cmp(&b.label));
+        items
+    }
+
+    /// Functions declared at the top level, and the methods of `impl` blocks
+    pub fn document_symbols(&self) -> Vec<DocumentSymbol> {
+        let mut symbols = Vec::new();
+        for stmt in &self.ast {
+            match &stmt.kind {
+                StmtKind::FunctionDecl { name, .. } => {
+                    symbols.push(self.function_symbol(name.clone(), stmt, SymbolKind::FUNCTION));
+                }
+                StmtKind::Impl { name: type_name, methods } => {
+                    for method in methods {
+                        if let StmtKind::FunctionDecl { name, .. } = &method.kind {
+                            let label = format!("{}.{}", type_name, name);
+                            symbols.push(self.function_symbol(label, method, SymbolKind::METHOD));
+                        }
+                    }
+                }
+                _ => {}
+            }
+        }
+        symbols
+    }
+
+    /// Edit replacing the whole document by its formatted text
+    /// Returns None if the document does not parse.
+    pub fn formatting(&self) -> Option<Vec<TextEdit>> {
+        let formatted = format_source(&self.text, &self.name).ok()?;
+        if formatted == self.text {
+            return Some(Vec::new());
+        }
+        Some(vec![TextEdit::new(self.document.full_range(), formatted)])
+    }
+
+    /// Innermost use of a name covering `offset`
+    fn reference_at(&self, offset: usize) -> Option<&Reference> {
+        self.references
+            .iter()
+            .filter(|reference| reference.span.start <= offset && offset <= reference.span.end)
+            .min_by_key(|reference| reference.span.len())
+    }
+
+    /// Span of `name` where it is declared by the statement or expression at `span`
+    /// The name comes before the body or value of its declaration; `self`,
+    /// which is not written, points at the start of its method.
+    fn name_span(&self, name: &str, span: &Span) -> Span {
+        self.tokens
+            .iter()
+            .skip_while(|token| token.span.start < span.start)
+            .take_while(|token| {
+                token.span.end <= span.end && !matches!(token.token, Token::LeftBrace | Token::Equal | Token::FatArrow)
+            })
+            .find(|token| matches!(&token.token, Token::Identifier(identifier) if identifier == name))
+            .map(|token| token.span.clone())
+            .unwrap_or_else(|| Span { end: span.start, ..span.clone() })
+    }
+
+    /// Symbol of a function declaration, labelled `label`
+    #[allow(deprecated)]
+    fn function_symbol(&self, label: String, stmt: &Stmt, kind: SymbolKind) -> DocumentSymbol {
+        let StmtKind::FunctionDecl { name, params, return_type, .. } = &stmt.kind else {
+            unreachable!("only function declarations have a symbol");
+        };
+        let ty = Type::Function {
+            params: params.iter().map(|param| param.type_annotation.clone()).collect(),
+            return_type: Box::new(return_type.clone().unwrap_or(Type::Void)),
+        };
+        DocumentSymbol {
+            name: label,
+            detail: Some(ty.to_string()),
+            kind,
+            tags: None,
+            deprecated: None,
+            range: self.document.range(&stmt.span),
+            selection_range: self.document.range(&self.name_span(name, &stmt.span)),
+            children: None,
+        }
+    }
+}
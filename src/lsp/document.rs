@@ -0,0 +1,74 @@
+/*
+ * -------------------------------------------------------------------------
+ * RemyLang — LSP documents
+ * File : src/lsp/document.rs
+ *
+ * Description :
+ *   Text of an open document, converting the character offsets of spans
+ *   to the line and UTF-16 column positions of the protocol.
+ *
+ * Author  : Samuel 'Meeast' Bleau
+ * Created : 2026-10-16
+ *
+ * -------------------------------------------------------------------------
+*/
+
+use crate::lexer::Span;
+use lsp_types::{Position, Range};
+
+/// Text of a document, indexed by line
+#[derive(Debug, Clone)]
+pub struct Document {
+    chars: Vec<char>,
+    /// Character offset of the start of each line
+    line_starts: Vec<usize>,
+}
+
+impl Document {
+    /// Creates a document holding `text`
+    pub fn new(text: &str) -> Self {
+        let chars: Vec<char> = text.chars().collect();
+        let line_starts = std::iter::once(0)
+            .chain(chars.iter().enumerate().filter(|(_, c)| **c == '\n').map(|(i, _)| i + 1))
+            .collect();
+        Document { chars, line_starts }
+    }
+
+    /// Protocol position of a character offset
+    /// Offsets past the end of the text are clamped to it.
+    pub fn position(&self, offset: usize) -> Position {
+        let offset = offset.min(self.chars.len());
+        let line = self.line_starts.partition_point(|&start| start <= offset) - 1;
+        let start = self.line_starts[line];
+        let column: usize = self.chars[start..offset].iter().map(|c| c.len_utf16()).sum();
+        Position::new(line as u32, column as u32)
+    }
+
+    /// Character offset of a protocol position
+    /// Positions past the end of their line are clamped to it.
+    pub fn offset(&self, position: Position) -> usize {
+        let Some(&start) = self.line_starts.get(position.line as usize) else {
+            return self.chars.len();
+        };
+        let mut offset = start;
+        let mut column = 0;
+        while offset < self.chars.len() && self.chars[offset] != '\n' {
+            column += self.chars[offset].len_utf16();
+            if column > position.character as usize {
+                break;
+            }
+            offset += 1;
+        }
+        offset
+    }
+
+    /// Protocol range covered by a span
+    pub fn range(&self, span: &Span) -> Range {
+        Range::new(self.position(span.start), self.position(span.end))
+    }
+
+    /// Range covering the whole text
+    pub fn full_range(&self) -> Range {
+        Range::new(Position::new(0, 0), self.position(self.chars.len()))
+    }
+}
@@ -0,0 +1,23 @@
+/*
+ * -------------------------------------------------------------------------
+ * RemyLang — LSP module
+ * File : src/lsp/mod.rs
+ *
+ * Description :
+ *   Language server for editors: diagnostics, hover, go-to-definition,
+ *   completion, document symbols and formatting over stdio.
+ *
+ * Author  : Samuel 'Meeast' Bleau
+ * Created : 2026-10-16
+ *
+ * -------------------------------------------------------------------------
+*/
+
+mod analysis;
+mod document;
+mod server;
+
+// Re-exports
+pub use analysis::Analysis;
+pub use document::Document;
+pub use server::{run, ServerError};
@@ -0,0 +1,225 @@
+/*
+ * -------------------------------------------------------------------------
+ * RemyLang — LSP server
+ * File : src/lsp/server.rs
+ *
+ * Description :
+ *   Language server speaking JSON-RPC over stdio: keeps the open documents
+ *   analyzed and answers the requests of the editor.
+ *
+ * Author  : Samuel 'Meeast' Bleau
+ * Created : 2026-10-16
+ *
+ * -------------------------------------------------------------------------
+*/
+
+use super::analysis::Analysis;
+use lsp_server::{Connection, ErrorCode, ExtractError, Message, Notification, Request, RequestId, Response};
+use lsp_types::notification::{
+    DidChangeTextDocument, DidCloseTextDocument, DidOpenTextDocument, Notification as _, PublishDiagnostics,
+};
+use lsp_types::request::{Completion, DocumentSymbolRequest, Formatting, GotoDefinition, HoverRequest};
+use lsp_types::{
+    CompletionOptions, CompletionResponse, DocumentSymbolResponse, GotoDefinitionResponse, HoverProviderCapability,
+    Location, OneOf, PublishDiagnosticsParams, ServerCapabilities, TextDocumentSyncCapability, TextDocumentSyncKind,
+    Url,
+};
+use std::collections::HashMap;
+use std::fmt;
+use std::io;
+
+/// Why the server stopped before being asked to exit
+#[derive(Debug)]
+pub enum ServerError {
+    /// The client broke the protocol, or went away
+    Protocol(String),
+    Io(io::Error),
+}
+
+impl fmt::Display for ServerError {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        match self {
+            ServerError::Protocol(message) => write!(f, "LSP protocol error: {}", message),
+            ServerError::Io(e) => write!(f, "LSP I/O error: {}", e),
+        }
+    }
+}
+
+impl std::error::Error for ServerError {}
+
+/// Runs the server on stdin and stdout until the client asks it to exit
+pub fn run() -> Result<(), ServerError> {
+    let (connection, io_threads) = Connection::stdio();
+
+    let capabilities = ServerCapabilities {
+        text_document_sync: Some(TextDocumentSyncCapability::Kind(TextDocumentSyncKind::FULL)),
+        hover_provider: Some(HoverProviderCapability::Simple(true)),
+        definition_provider: Some(OneOf::Left(true)),
+        completion_provider: Some(CompletionOptions::default()),
+        document_symbol_provider: Some(OneOf::Left(true)),
+        document_formatting_provider: Some(OneOf::Left(true)),
+        ..ServerCapabilities::default()
+    };
+    let capabilities = serde_json::to_value(capabilities).map_err(|e| ServerError::Protocol(e.to_string()))?;
+    connection
+        .initialize(capabilities)
+        .map_err(|e| ServerError::Protocol(e.to_string()))?;
+
+    Server { connection: &connection, documents: HashMap::new() }.serve()?;
+    // The writer thread stops once the connection is gone
+    drop(connection);
+    io_threads.join().map_err(ServerError::Io)
+}
+
+/// Open documents, and the connection to the client
+struct Server<'a> {
+    connection: &'a Connection,
+    documents: HashMap<Url, Analysis>,
+}
+
+impl Server<'_> {
+    /// Handles messages until the client shuts the server down
+    fn serve(&mut self) -> Result<(), ServerError> {
+        let connection = self.connection;
+        for message in &connection.receiver {
+            match message {
+                Message::Request(request) => {
+                    let shutdown = connection
+                        .handle_shutdown(&request)
+                        .map_err(|e| ServerError::Protocol(e.to_string()))?;
+                    if shutdown {
+                        return Ok(());
+                    }
+                    self.request(request)?;
+                }
+                Message::Notification(notification) => self.notification(notification)?,
+                Message::Response(_) => {}
+            }
+        }
+        Ok(())
+    }
+
+    fn request(&self, request: Request) -> Result<(), ServerError> {
+        let Some(request) = self.respond::<HoverRequest>(request, |server, params| {
+            let position = params.text_document_position_params;
+            server.document(&position.text_document.uri)?.hover(position.position)
+        })? else {
+            return Ok(());
+        };
+        let Some(request) = self.respond::<GotoDefinition>(request, |server, params| {
+            let position = params.text_document_position_params;
+            let uri = position.text_document.uri;
+            let range = server.document(&uri)?.definition(position.position)?;
+            Some(GotoDefinitionResponse::Scalar(Location::new(uri, range)))
+        })? else {
+            return Ok(());
+        };
+        let Some(request) = self.respond::<Completion>(request, |server, params| {
+            let position = params.text_document_position;
+            let items = server.document(&position.text_document.uri)?.completion(position.position);
+            Some(CompletionResponse::Array(items))
+        })? else {
+            return Ok(());
+        };
+        let Some(request) = self.respond::<DocumentSymbolRequest>(request, |server, params| {
+            let symbols = server.document(&params.text_document.uri)?.document_symbols();
+            Some(DocumentSymbolResponse::Nested(symbols))
+        })? else {
+            return Ok(());
+        };
+        let Some(request) = self.respond::<Formatting>(request, |server, params| {
+            server.document(&params.text_document.uri)?.formatting()
+        })? else {
+            return Ok(());
+        };
+
+        let message = format!("Unsupported request: {}", request.method);
+        self.send(Response::new_err(request.id, ErrorCode::MethodNotFound as i32, message).into())
+    }
+
+    /// Answers `request` with `handler` if it is an `R` request
+    /// Returns the request back if it is not. Invalid parameters are
+    /// answered with an error, and the server keeps going.
+    fn respond<R>(
+        &self,
+        request: Request,
+        handler: impl FnOnce(&Self, R::Params) -> R::Result,
+    ) -> Result<Option<Request>, ServerError>
+    where
+        R: lsp_types::request::Request,
+    {
+        let id = request.id.clone();
+        let (id, params): (RequestId, R::Params) = match request.extract(R::METHOD) {
+            Ok(extracted) => extracted,
+            Err(ExtractError::MethodMismatch(request)) => return Ok(Some(request)),
+            Err(ExtractError::JsonError { method, error }) => {
+                let message = format!("Invalid parameters for {}: {}", method, error);
+                return self.send(Response::new_err(id, ErrorCode::InvalidParams as i32, message).into()).map(|_| None);
+            }
+        };
+        // Requests about unknown documents, or places without an answer, get null
+        let result = handler(self, params);
+        self.send(Response::new_ok(id, result).into()).map(|_| None)
+    }
+
+    fn notification(&mut self, notification: Notification) -> Result<(), ServerError> {
+        let (uri, text) = match notification.method.clone().as_str() {
+            DidOpenTextDocument::METHOD => {
+                let Some(params) = Self::params::<DidOpenTextDocument>(notification) else {
+                    return Ok(());
+                };
+                (params.text_document.uri, Some(params.text_document.text))
+            }
+            DidChangeTextDocument::METHOD => {
+                let Some(params) = Self::params::<DidChangeTextDocument>(notification) else {
+                    return Ok(());
+                };
+                // Documents are synchronized whole, the last change holds the text
+                let text = params.content_changes.into_iter().last().map(|change| change.text);
+                (params.text_document.uri, text)
+            }
+            DidCloseTextDocument::METHOD => {
+                let Some(params) = Self::params::<DidCloseTextDocument>(notification) else {
+                    return Ok(());
+                };
+                self.documents.remove(&params.text_document.uri);
+                // Diagnostics of a closed document are cleared
+                return self.publish(params.text_document.uri, Vec::new());
+            }
+            _ => return Ok(()),
+        };
+
+        let Some(text) = text else {
+            return Ok(());
+        };
+        let analysis = Analysis::new(&text, uri.as_str());
+        let diagnostics = analysis.diagnostics();
+        self.documents.insert(uri.clone(), analysis);
+        self.publish(uri, diagnostics)
+    }
+
+    /// Parameters of an `N` notification
+    /// Notifications cannot be answered, so invalid ones are only logged.
+    fn params<N: lsp_types::notification::Notification>(notification: Notification) -> Option<N::Params> {
+        notification
+            .extract(N::METHOD)
+            .map_err(|e| eprintln!("❌ Ignoring {} with invalid parameters: {}", N::METHOD, e))
+            .ok()
+    }
+
+    fn publish(&self, uri: Url, diagnostics: Vec<lsp_types::Diagnostic>) -> Result<(), ServerError> {
+        let params = PublishDiagnosticsParams::new(uri, diagnostics, None);
+        self.send(Notification::new(PublishDiagnostics::METHOD.to_string(), params).into())
+    }
+
+    fn document(&self, uri: &Url) -> Option<&Analysis> {
+        self.documents.get(uri)
+    }
+
+    fn send(&self, message: Message) -> Result<(), ServerError> {
+        self.connection
+            .sender
+            .send(message)
+            .map_err(|e| ServerError::Protocol(e.to_string()))
+    }
+}
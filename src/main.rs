@@ -1,117 +1,348 @@
-use remylang::lexer::Lexer;
+use clap::{Parser as ClapParser, Subcommand};
+use remylang::ast::Stmt;
+use remylang::diagnostics::{self, Diagnostic};
+use remylang::formatter;
+use remylang::lexer::{Lexer, Token};
+use remylang::llvm_backend::{self, OutputKind};
+use remylang::lsp;
 use remylang::parser::Parser;
-use remylang::vm::Interpreter;
-use std::env;
+use remylang::repl;
+use remylang::semantic::TypeChecker;
+use remylang::stdlib;
+use remylang::vm::{Compiler, Machine};
 use std::fs;
+use std::io::{self, Read};
+use std::path::{Path, PathBuf};
+use std::process::ExitCode;
 
-fn main() {
-    let args: Vec<String> = env::args().collect();
-    
-    if args.len() > 1 {
-        // File mode
-        let filename = &args[1];
-        run_file(filename);
-    } else {
-        // Demo mode
-        run_demo();
+/// Name given to the source read from stdin
+const STDIN_NAME: &str = "<stdin>";
+
+#[derive(ClapParser)]
+#[command(name = "remylang", version, about = "RemyLang compiler and VM")]
+#[command(after_help = "Without a command, the interactive REPL starts.
+
+Exit codes: 0 success, 1 runtime error, 2 invalid usage, 3 syntax error,
+4 type error, 5 unreadable input, 6 native code generation error,
+7 unformatted file found by `fmt --check`, 8 language server failure.")]
+struct Cli {
+    /// Report the stages of the pipeline on stderr
+    #[arg(short, long, global = true)]
+    verbose: bool,
+
+    #[command(subcommand)]
+    command: Option<Command>,
+}
+
+#[derive(Subcommand)]
+enum Command {
+    /// Run a program
+    Run {
+        /// Compile the program with LLVM and run it in-process
+        #[arg(long)]
+        jit: bool,
+        /// Source file, or '-' to read stdin
+        file: String,
+        /// Arguments given to the program, returned by args()
+        #[arg(trailing_var_arg = true, allow_hyphen_values = true)]
+        args: Vec<String>,
+    },
+    /// Check a program for errors without running it
+    Check {
+        /// Source file, or '-' to read stdin
+        file: String,
+    },
+    /// Print the tokens of a program
+    Tokens {
+        /// Source file, or '-' to read stdin
+        file: String,
+    },
+    /// Print the syntax tree of a program
+    Ast {
+        /// Source file, or '-' to read stdin
+        file: String,
+    },
+    /// Format programs in place, or print stdin formatted
+    Fmt {
+        /// Only report the files that are not formatted, changing nothing
+        #[arg(long)]
+        check: bool,
+        /// Source files, or '-' to read stdin
+        #[arg(required = true)]
+        files: Vec<String>,
+    },
+    /// Compile a program to a native executable
+    Build {
+        /// Source file, or '-' to read stdin
+        file: String,
+        /// Output file, named after the source file by default
+        #[arg(short, long)]
+        output: Option<PathBuf>,
+        /// Write LLVM IR instead of an executable
+        #[arg(long, conflicts_with = "emit_obj")]
+        emit_llvm: bool,
+        /// Write an object file instead of an executable
+        #[arg(long)]
+        emit_obj: bool,
+    },
+    /// Start the interactive REPL
+    Repl,
+    /// Start the language server, speaking LSP over stdio
+    Lsp,
+}
+
+/// Why a command failed, each reason having its own exit code
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+enum Failure {
+    Runtime,
+    // Invalid usage, exit code 2, is reported by clap
+    Syntax,
+    Type,
+    Input,
+    Codegen,
+    Unformatted,
+    Server,
+}
+
+impl Failure {
+    fn exit_code(self) -> ExitCode {
+        ExitCode::from(match self {
+            Failure::Runtime => 1,
+            Failure::Syntax => 3,
+            Failure::Type => 4,
+            Failure::Input => 5,
+            Failure::Codegen => 6,
+            Failure::Unformatted => 7,
+            Failure::Server => 8,
+        })
+    }
+}
+
+/// A program to process, and how much to report about it
+struct Source {
+    name: String,
+    code: String,
+    verbose: bool,
+}
+
+impl Source {
+    /// Reads a source file, or stdin for '-'
+    fn read(file: &str, verbose: bool) -> Result<Source, Failure> {
+        let (name, code) = if file == "-" {
+            let mut code = String::new();
+            io::stdin().read_to_string(&mut code).map(|_| (STDIN_NAME.to_string(), code))
+        } else {
+            fs::read_to_string(file).map(|code| (file.to_string(), code))
+        }
+        .map_err(|e| {
+            eprintln!("❌ Error reading {}: {}", file, e);
+            Failure::Input
+        })?;
+        Ok(Source { name, code, verbose })
+    }
+
+    /// Reports a stage of the pipeline, in verbose mode
+    fn stage(&self, message: &str) {
+        if self.verbose {
+            eprintln!("{}", message);
+        }
+    }
+
+    /// Reports errors, returning the failure they cause
+    fn fail<'a, E: 'a>(&self, errors: impl IntoIterator<Item = &'a E>, failure: Failure) -> Failure
+    where
+        Diagnostic: From<&'a E>,
+    {
+        for e in errors {
+            diagnostics::report(&Diagnostic::from(e), &self.code);
+        }
+        failure
+    }
+
+    /// Reports warnings, which don't stop the pipeline
+    fn warn<'a, E: 'a>(&self, warnings: impl IntoIterator<Item = &'a E>)
+    where
+        Diagnostic: From<&'a E>,
+    {
+        for w in warnings {
+            diagnostics::report(&Diagnostic::from(w), &self.code);
+        }
+    }
+
+    /// LLVM module name of the source
+    fn module_name(&self) -> String {
+        Path::new(&self.name)
+            .file_stem()
+            .filter(|_| self.name != STDIN_NAME)
+            .map(|s| s.to_string_lossy().into_owned())
+            .unwrap_or_else(|| "main".to_string())
     }
 }
 
-fn run_file(filename: &str) {
-    println!("=== RemyLang v1.0.0 - Running {} ===\n", filename);
-    
-    let code = match fs::read_to_string(filename) {
-        Ok(content) => content,
-        Err(e) => {
-            eprintln!("❌ Error reading file: {}", e);
-            return;
+fn main() -> ExitCode {
+    let cli = Cli::parse();
+    let verbose = cli.verbose;
+    let result = match cli.command {
+        None | Some(Command::Repl) => {
+            repl::run();
+            Ok(())
+        }
+        Some(Command::Lsp) => lsp::run().map_err(|e| {
+            eprintln!("❌ {}", e);
+            Failure::Server
+        }),
+        Some(Command::Run { jit, file, args }) => {
+            Source::read(&file, verbose).and_then(|source| run(&source, jit, args))
+        }
+        Some(Command::Check { file }) => Source::read(&file, verbose).and_then(|source| analyze(&source).map(|_| ())),
+        Some(Command::Tokens { file }) => Source::read(&file, verbose).and_then(|source| tokens(&source)),
+        Some(Command::Ast { file }) => Source::read(&file, verbose).and_then(|source| ast(&source)),
+        Some(Command::Fmt { check, files }) => {
+            // Every file is handled, the first failure giving the exit code
+            let mut result = Ok(());
+            for file in &files {
+                let formatted = Source::read(file, verbose).and_then(|source| fmt(&source, check));
+                result = result.and(formatted);
+            }
+            result
+        }
+        Some(Command::Build { file, output, emit_llvm, emit_obj }) => {
+            let kind = if emit_llvm {
+                OutputKind::LlvmIr
+            } else if emit_obj {
+                OutputKind::Object
+            } else {
+                OutputKind::Executable
+            };
+            Source::read(&file, verbose).and_then(|source| build(&source, kind, output))
         }
     };
-    
-    execute_code(&code);
+    match result {
+        Ok(()) => ExitCode::SUCCESS,
+        Err(failure) => failure.exit_code(),
+    }
 }
 
-fn run_demo() {
-    println!("=== RemyLang v0.1.0 - Interpreter Demo ===\n");
-    
-    // Example code to execute
-    let code = r#"
-Int x = 10;
-Int y = 5;
+/// `run [--jit] <file> [args...]`
+fn run(source: &Source, jit: bool, args: Vec<String>) -> Result<(), Failure> {
+    let ast = analyze(source)?;
+    stdlib::set_program_args(args);
 
-print("x =", x);
-print("y =", y);
+    if jit {
+        source.stage("🚀 Running with the JIT...");
+        return llvm_backend::jit::run(&ast, &source.module_name())
+            .map_err(|e| source.fail([&e], Failure::Codegen));
+    }
 
-Int sum = x + y;
-print("x + y =", sum);
+    source.stage("⚙️  Compiling...");
+    let program = Compiler::new()
+        .compile(&ast)
+        .map_err(|e| source.fail([&e], Failure::Runtime))?;
+    source.stage(&format!("✓ {} instructions generated\n", program.script.chunk.code.len()));
 
-Int product = x * y;
-print("x * y =", product);
+    source.stage("🚀 Execution:");
+    Machine::new()
+        .run(&program)
+        .map_err(|e| source.fail([&e], Failure::Runtime))
+}
 
-if (sum > 10) {
-    print("Sum is greater than 10");
-} else {
-    print("Sum is 10 or less");
+/// `tokens <file>`: one token per line, after its position
+fn tokens(source: &Source) -> Result<(), Failure> {
+    let tokens = Lexer::with_file(source.code.clone(), &source.name).tokenize();
+    let mut failed = false;
+    for token in &tokens {
+        println!("{}:{}\t{:?}", token.span.line, token.span.column, token.token);
+        failed |= matches!(token.token, Token::Error(_) | Token::Invalid(_));
+    }
+    if failed {
+        Err(Failure::Syntax)
+    } else {
+        Ok(())
+    }
 }
 
-Array<Int> numbers = [1, 2, 3, 4, 5];
-print("Array:", numbers);
-print("First element:", numbers[0]);
-print("Third element:", numbers[2]);
-"#;
-
-    println!("📝 Source Code:");
-    println!("{}", "=".repeat(60));
-    println!("{}", code);
-    println!("{}\n", "=".repeat(60));
-    
-    execute_code(code);
+/// `ast <file>`: the statements of the program, parsed but not checked
+fn ast(source: &Source) -> Result<(), Failure> {
+    for stmt in parse(source)? {
+        println!("{:#?}", stmt);
+    }
+    Ok(())
 }
 
-fn execute_code(code: &str) {
-    // Step 1: Tokenization
-    println!("🔤 Tokenization...");
-    let mut lexer = Lexer::new(code.to_string());
-    let mut tokens = Vec::new();
-    
-    loop {
-        let token = lexer.next_token();
-        if token == remylang::lexer::Token::EOF {
-            tokens.push(token);
-            break;
+/// `fmt [--check] <files...>`
+fn fmt(source: &Source, check: bool) -> Result<(), Failure> {
+    let formatted = formatter::format_source(&source.code, &source.name)
+        .map_err(|errors| source.fail(&errors, Failure::Syntax))?;
+
+    if check {
+        if formatted == source.code {
+            return Ok(());
         }
-        tokens.push(token);
+        let line = formatted
+            .lines()
+            .zip(source.code.lines())
+            .position(|(formatted, original)| formatted != original)
+            .unwrap_or_else(|| formatted.lines().count().min(source.code.lines().count()));
+        eprintln!("❌ {} is not formatted (first difference on line {})", source.name, line + 1);
+        return Err(Failure::Unformatted);
     }
-    println!("✓ {} tokens generated\n", tokens.len() - 1);
-    
-    // Step 2: Parsing
-    println!("🌳 Parsing...");
-    let mut parser = Parser::new(tokens);
-    
-    let ast = match parser.parse() {
-        Ok(ast) => {
-            println!("✓ {} statements parsed\n", ast.len());
-            ast
-        }
-        Err(e) => {
-            eprintln!("❌ Parse Error: {}", e);
-            return;
-        }
-    };
-    
-    // Step 3: Execution
-    println!("🚀 Execution:");
-    println!("{}", "=".repeat(60));
-    let mut interpreter = Interpreter::new();
-    
-    match interpreter.execute(ast) {
-        Ok(_) => {
-            println!("{}", "=".repeat(60));
-            println!("✓ Program executed successfully");
-        }
-        Err(e) => {
-            println!("{}", "=".repeat(60));
-            eprintln!("❌ Runtime Error: {}", e);
-        }
+
+    if source.name == STDIN_NAME {
+        print!("{}", formatted);
+    } else if formatted != source.code {
+        fs::write(&source.name, &formatted).map_err(|e| {
+            eprintln!("❌ Error writing {}: {}", source.name, e);
+            Failure::Input
+        })?;
+        source.stage(&format!("✓ Formatted {}", source.name));
+    }
+    Ok(())
+}
+
+/// `build <file> [-o <output>] [--emit-llvm | --emit-obj]`
+fn build(source: &Source, kind: OutputKind, output: Option<PathBuf>) -> Result<(), Failure> {
+    let output = output.unwrap_or_else(|| match source.name.as_str() {
+        STDIN_NAME => PathBuf::from("main").with_extension(kind.extension()),
+        name => Path::new(name).with_extension(kind.extension()),
+    });
+    let ast = analyze(source)?;
+
+    source.stage("⚙️  Generating native code...");
+    llvm_backend::build(&ast, &source.module_name(), kind, &output).map_err(|e| source.fail([&e], Failure::Codegen))?;
+    source.stage(&format!("✓ Wrote {}", output.display()));
+    Ok(())
+}
+
+/// Tokenization and parsing, reporting every syntax error
+fn parse(source: &Source) -> Result<Vec<Stmt>, Failure> {
+    source.stage("🔤 Tokenization...");
+    let tokens = Lexer::with_file(source.code.clone(), &source.name).tokenize();
+    source.stage(&format!("✓ {} tokens generated\n", tokens.len() - 1));
+
+    source.stage("🌳 Parsing...");
+    let (ast, errors) = Parser::new(tokens).parse_recovering();
+    if !errors.is_empty() {
+        let failure = source.fail(&errors, Failure::Syntax);
+        eprintln!("❌ {} parse error(s) found, aborting", errors.len());
+        return Err(failure);
+    }
+    source.stage(&format!("✓ {} statements parsed\n", ast.len()));
+    Ok(ast)
+}
+
+/// Front end shared by every mode: tokenization, parsing and type checking
+fn analyze(source: &Source) -> Result<Vec<Stmt>, Failure> {
+    let ast = parse(source)?;
+
+    source.stage("🔍 Type checking...");
+    let mut checker = TypeChecker::new();
+    let result = checker.check_program(&ast);
+    source.warn(checker.warnings());
+    if let Err(errors) = result {
+        let failure = source.fail(&errors, Failure::Type);
+        eprintln!("❌ {} type error(s) found, aborting", errors.len());
+        return Err(failure);
     }
+    source.stage("✓ No type errors\n");
+    Ok(ast)
 }
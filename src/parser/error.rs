@@ -13,39 +13,66 @@
 */
 
 use std::fmt;
-use crate::lexer::Token;
+use crate::lexer::{LexError, Span, Token};
 
 pub type ParseResult<T> = Result<T, ParseError>;
 
 #[derive(Debug, Clone, PartialEq)]
 pub enum ParseError {
-    UnexpectedToken { expected: String, found: Token },
-    UnexpectedEOF { expected: String },
-    InvalidSyntax { message: String, token: Option<Token> },
-    ExpectedExpression { found: Token },
+    UnexpectedToken { expected: String, found: Token, span: Span },
+    UnexpectedEOF { expected: String, span: Span },
+    InvalidSyntax { message: String, token: Option<Token>, span: Span },
+    ExpectedExpression { found: Token, span: Span },
+    /// Malformed token found by the lexer
+    Lex { error: LexError, span: Span },
 }
 
-impl fmt::Display for ParseError {
-    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+impl ParseError {
+    /// Location of the token that caused the error
+    pub fn span(&self) -> &Span {
         match self {
-            ParseError::UnexpectedToken { expected, found } => {
-                write!(f, "Parse error: Expected {}, found {:?}", expected, found)
-            }
-            ParseError::UnexpectedEOF { expected } => {
-                write!(f, "Parse error: Unexpected end of file, expected {}", expected)
+            ParseError::UnexpectedToken { span, .. }
+            | ParseError::UnexpectedEOF { span, .. }
+            | ParseError::InvalidSyntax { span, .. }
+            | ParseError::ExpectedExpression { span, .. }
+            | ParseError::Lex { span, .. } => span,
+        }
+    }
+
+    /// Description of the error, without its location
+    pub fn message(&self) -> String {
+        match self {
+            ParseError::UnexpectedToken { expected, found, .. } => {
+                format!("Expected {}, found {:?}", expected, found)
             }
-            ParseError::InvalidSyntax { message, token } => {
-                if let Some(tok) = token {
-                    write!(f, "Parse error: {} at {:?}", message, tok)
-                } else {
-                    write!(f, "Parse error: {}", message)
-                }
+            ParseError::UnexpectedEOF { expected, .. } => {
+                format!("Unexpected end of file, expected {}", expected)
             }
-            ParseError::ExpectedExpression { found } => {
-                write!(f, "Parse error: Expected expression, found {:?}", found)
+            ParseError::InvalidSyntax { message, token, .. } => match token {
+                Some(tok) => format!("{} at {:?}", message, tok),
+                None => message.clone(),
+            },
+            ParseError::ExpectedExpression { found, .. } => {
+                format!("Expected expression, found {:?}", found)
             }
+            ParseError::Lex { error, .. } => error.to_string(),
+        }
+    }
+
+    /// The token found where the error was raised, if any
+    pub fn found(&self) -> Option<&Token> {
+        match self {
+            ParseError::UnexpectedToken { found, .. } | ParseError::ExpectedExpression { found, .. } => Some(found),
+            ParseError::InvalidSyntax { token, .. } => token.as_ref(),
+            ParseError::UnexpectedEOF { .. } | ParseError::Lex { .. } => None,
         }
     }
 }
 
-impl std::error::Error for ParseError {}
\ No newline at end of file
+impl fmt::Display for ParseError {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        write!(f, "{}: Parse error: {}", self.span(), self.message())
+    }
+}
+
+impl std::error::Error for ParseError {}
@@ -15,15 +15,25 @@
 
 use super::parser::Parser;
 use super::error::{ParseError, ParseResult};
-use crate::ast::{Expr, Literal};
+use crate::ast::{Expr, ExprKind, InterpolatedPart, Literal};
 use crate::ast::operator::{BinaryOp, UnaryOp};
-use crate::lexer::Token;
+use crate::lexer::{StringPart, Token};
 
 impl Parser {
     pub(super) fn parse_expression(&mut self) -> ParseResult<Expr> {
         self.parse_expression_with_precedence(0)
     }
 
+    /// Parses an expression, where `Name {` starts a struct literal only if `allowed`
+    /// Struct literals are not allowed before the body of a for-in loop,
+    /// unless they are nested in delimiters.
+    pub(super) fn parse_expression_with_struct_literals(&mut self, allowed: bool) -> ParseResult<Expr> {
+        let enclosing = std::mem::replace(&mut self.struct_literals, allowed);
+        let result = self.parse_expression();
+        self.struct_literals = enclosing;
+        result
+    }
+
     /// Parses an expression considering operator precedence using Pratt parsing.
     fn parse_expression_with_precedence(&mut self, min_precedence: u8) -> ParseResult<Expr> {
         let mut left = self.parse_prefix()?;
@@ -44,106 +54,191 @@ impl Parser {
             };
             
             let right = self.parse_expression_with_precedence(next_min_precedence)?;
-            left = Expr::Binary {
-                left: Box::new(left),
-                op,
-                right: Box::new(right),
-            };
+            let span = left.span.to(&right.span);
+            left = Expr::new(
+                ExprKind::Binary {
+                    left: Box::new(left),
+                    op,
+                    right: Box::new(right),
+                },
+                span,
+            );
         }
         Ok(left)
     }
 
     /// Parses prefix expressions: unary operators, literals, identifiers, parenthesized expressions
     fn parse_prefix(&mut self) -> ParseResult<Expr> {
-        match self.peek() {
-            Some(Token::Minus) => {
-                self.advance();
-                let right = self.parse_prefix()?;
-                Ok(Expr::Unary {
-                    op: UnaryOp::Minus,
-                    right: Box::new(right),
-                })
-            }
-            Some(Token::Bang) => {
+        let start = self.current_span();
+        let op = match self.peek() {
+            Some(Token::Minus) => UnaryOp::Minus,
+            Some(Token::Bang) => UnaryOp::Not,
+            _ => return self.parse_primary(),
+        };
+        self.advance();
+        // The smallest Int is only written as a negated literal, which is
+        // folded unless a call, index or field access applies to the literal
+        if op == UnaryOp::Minus && !matches!(self.peek_next(), Some(Token::LeftParen | Token::LeftBracket | Token::Dot)) {
+            if let Some(n) = self.negated_integer() {
                 self.advance();
-                let right = self.parse_prefix()?;
-                Ok(Expr::Unary {
-                    op: UnaryOp::Not,
-                    right: Box::new(right),
-                })
+                return Ok(Expr::new(ExprKind::Literal(Literal::Number(n)), self.span_from(&start)));
             }
-            _ => self.parse_primary(),
         }
+        let right = self.parse_prefix()?;
+        Ok(Expr::new(
+            ExprKind::Unary {
+                op,
+                right: Box::new(right),
+            },
+            self.span_from(&start),
+        ))
     }
 
     /// Parses primary expressions: literals, identifiers, parenthesized expressions, arrays
     fn parse_primary(&mut self) -> ParseResult<Expr> {
-        let expr = match self.peek().cloned() {
+        let start = self.current_span();
+        let kind = match self.peek().cloned() {
             Some(Token::Number(n)) => {
                 self.advance();
-                Expr::Literal(Literal::Number(n))
+                ExprKind::Literal(Literal::Number(n))
+            }
+            Some(Token::FloatLit(x)) => {
+                self.advance();
+                ExprKind::Literal(Literal::Float(x))
             }
             Some(Token::StringLit(s)) => {
                 self.advance();
-                Expr::Literal(Literal::String(s))
+                ExprKind::Literal(Literal::String(s))
+            }
+            Some(Token::InterpolatedString(parts)) => {
+                self.advance();
+                ExprKind::Interpolated(
+                    parts.into_iter().map(Self::parse_string_part).collect::<ParseResult<_>>()?,
+                )
             }
             Some(Token::CharLit(c)) => {
                 self.advance();
-                Expr::Literal(Literal::Char(c))
+                ExprKind::Literal(Literal::Char(c))
             }
             Some(Token::True) => {
                 self.advance();
-                Expr::Literal(Literal::Bool(true))
+                ExprKind::Literal(Literal::Bool(true))
             }
             Some(Token::False) => {
                 self.advance();
-                Expr::Literal(Literal::Bool(false))
+                ExprKind::Literal(Literal::Bool(false))
             }
             Some(Token::Identifier(name)) => {
                 self.advance();
-                Expr::Variable(name)
+                if self.match_token(&[Token::DoubleColon]) {
+                    self.parse_variant(name)?
+                } else if self.struct_literals && self.check(&Token::LeftBrace) {
+                    self.parse_struct_literal(name)?
+                } else {
+                    ExprKind::Variable(name)
+                }
             }
             Some(Token::LeftBracket) => {
                 return self.parse_array_literal();
             }
+            Some(Token::Match) => {
+                let expr = self.parse_match_expression()?;
+                return self.parse_suffix(expr);
+            }
+            Some(Token::Func) => {
+                self.advance();
+                self.parse_lambda()?
+            }
             Some(Token::LeftParen) => {
                 self.advance();
-                let expr = self.parse_expression()?;
+                let mut expr = self.parse_expression_with_struct_literals(true)?;
                 self.expect(&Token::RightParen, "')' after expression")?;
-                expr
+                // The parenthesized expression covers its parentheses
+                expr.span = self.span_from(&start);
+                return self.parse_suffix(expr);
+            }
+            Some(Token::Error(error)) => {
+                return Err(ParseError::Lex { error, span: start });
             }
             _ => {
                 return Err(ParseError::ExpectedExpression {
                     found: self.peek().cloned().unwrap_or(Token::EOF),
+                    span: start,
                 });
             }
         };
+        let expr = Expr::new(kind, self.span_from(&start));
         self.parse_suffix(expr)
     }
 
+    /// Parses a piece of an interpolated string, its code being a complete expression
+    fn parse_string_part(part: StringPart) -> ParseResult<InterpolatedPart> {
+        let tokens = match part {
+            StringPart::Text(text) => return Ok(InterpolatedPart::Text(text)),
+            StringPart::Code(tokens) => tokens,
+        };
+        let mut parser = Parser::new(tokens);
+        let expr = parser.parse_expression().map_err(|mut error| {
+            // The EOF ending the tokens stands for the closing '}'
+            if let ParseError::UnexpectedToken { found, .. } | ParseError::ExpectedExpression { found, .. } = &mut error {
+                if *found == Token::EOF {
+                    *found = Token::RightBrace;
+                }
+            }
+            error
+        })?;
+        if !parser.is_at_end() {
+            return Err(ParseError::UnexpectedToken {
+                expected: "'}' after interpolated expression".to_string(),
+                found: parser.peek().cloned().unwrap_or(Token::EOF),
+                span: parser.current_span(),
+            });
+        }
+        Ok(InterpolatedPart::Expr(expr))
+    }
+
     /// Parses suffixes for expressions (like function calls, array indexing, etc.)
     fn parse_suffix(&mut self, mut expr: Expr) -> ParseResult<Expr> {
         loop {
+            let start = expr.span.clone();
             match self.peek() {
                 Some(Token::LeftParen) => {
                     self.advance();
                     let args = self.parse_argument_list()?;
                     self.expect(&Token::RightParen, "')' after arguments")?;
 
-                    expr = Expr::Call {
-                        callee: Box::new(expr),
-                        args,
-                    };
+                    expr = Expr::new(
+                        ExprKind::Call {
+                            callee: Box::new(expr),
+                            args,
+                        },
+                        self.span_from(&start),
+                    );
                 }
                 Some(Token::LeftBracket) => {
                     self.advance();
-                    let index = self.parse_expression()?;
+                    let index = self.parse_expression_with_struct_literals(true)?;
                     self.expect(&Token::RightBracket, "']' after array index")?;
 
-                    expr = Expr::ArrayAccess {
-                        array: Box::new(expr),
-                        index: Box::new(index),
-                    };
+                    expr = Expr::new(
+                        ExprKind::ArrayAccess {
+                            array: Box::new(expr),
+                            index: Box::new(index),
+                        },
+                        self.span_from(&start),
+                    );
+                }
+                Some(Token::Dot) => {
+                    self.advance();
+                    let field = self.parse_identifier("field or method name after '.'")?;
+
+                    expr = Expr::new(
+                        ExprKind::FieldAccess {
+                            object: Box::new(expr),
+                            field,
+                        },
+                        self.span_from(&start),
+                    );
                 }
                 _ => break,
             }
@@ -151,21 +246,75 @@ impl Parser {
         Ok(expr)
     }
 
+    /// Parses the fields of a struct literal : Name { field: value, ... }
+    /// The name has already been consumed.
+    fn parse_struct_literal(&mut self, name: String) -> ParseResult<ExprKind> {
+        self.expect(&Token::LeftBrace, "'{'")?;
+
+        let mut fields = Vec::new();
+        while !self.check(&Token::RightBrace) {
+            let field = self.parse_identifier("field name")?;
+            self.expect(&Token::Colon, "':' after field name")?;
+            fields.push((field, self.parse_expression_with_struct_literals(true)?));
+            if !self.match_token(&[Token::Comma]) {
+                break;
+            }
+        }
+        self.expect(&Token::RightBrace, "'}' after struct fields")?;
+        Ok(ExprKind::StructLiteral { name, fields })
+    }
+
+    /// Parses an anonymous function : func(Int x) -> Int { ... }
+    /// The 'func' keyword has already been consumed.
+    fn parse_lambda(&mut self) -> ParseResult<ExprKind> {
+        self.expect(&Token::LeftParen, "'(' after 'func'")?;
+        let params = self.parse_parameter_list()?;
+        self.expect(&Token::RightParen, "')' after parameters")?;
+
+        let return_type = if self.match_token(&[Token::Arrow]) {
+            Some(self.parse_type()?)
+        } else {
+            None
+        };
+
+        let body = self.parse_block_statement()?;
+        Ok(ExprKind::Lambda {
+            params,
+            return_type,
+            body: Box::new(body),
+        })
+    }
+
+    /// Parses an enum variant : Enum::Variant or Enum::Variant(args)
+    /// The enum name and '::' have already been consumed.
+    fn parse_variant(&mut self, enum_name: String) -> ParseResult<ExprKind> {
+        let variant = self.parse_identifier("variant name after '::'")?;
+        let args = if self.match_token(&[Token::LeftParen]) {
+            let args = self.parse_argument_list()?;
+            self.expect(&Token::RightParen, "')' after variant fields")?;
+            args
+        } else {
+            Vec::new()
+        };
+        Ok(ExprKind::Variant { enum_name, variant, args })
+    }
+
     /// Parses an array literal : [elem1, elem2, ...]
     fn parse_array_literal(&mut self) -> ParseResult<Expr> {
+        let start = self.current_span();
         self.expect(&Token::LeftBracket, "'['")?;
 
         let mut elements = Vec::new();
         if !self.check(&Token::RightBracket) {
             loop {
-                elements.push(self.parse_expression()?);
+                elements.push(self.parse_expression_with_struct_literals(true)?);
                 if !self.match_token(&[Token::Comma]) {
                     break;
                 }
             }
         }
         self.expect(&Token::RightBracket, "']' after array elements")?;
-        Ok(Expr::ArrayLiteral(elements))
+        Ok(Expr::new(ExprKind::ArrayLiteral(elements), self.span_from(&start)))
     }
 
     /// Parses a list of arguments : (arg1, arg2, ...)
@@ -173,7 +322,7 @@ impl Parser {
         let mut args = Vec::new();
         if !self.check(&Token::RightParen) {
             loop {
-                args.push(self.parse_expression()?);
+                args.push(self.parse_expression_with_struct_literals(true)?);
                 if !self.match_token(&[Token::Comma]) {
                     break;
                 }
@@ -12,9 +12,11 @@
  * -------------------------------------------------------------------------
 */
 
+#[allow(clippy::module_inception)]
 mod parser;
 mod expr_parser;
 mod stmt_parser;
+mod pattern_parser;
 pub mod error;
 
 // Re-exports
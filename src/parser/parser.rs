@@ -12,45 +12,81 @@
  * -------------------------------------------------------------------------
 */
 
-use crate::lexer::Token;
+use crate::lexer::{LexError, Span, SpannedToken, Token};
 use crate::ast::Stmt;
 use super::error::{ParseError, ParseResult};
 
 pub struct Parser {
-    tokens: Vec<Token>,
+    tokens: Vec<SpannedToken>,
     current: usize,
+    errors: Vec<ParseError>, // Errors recovered from so far
+    pub(super) block_depth: usize, // Blocks being parsed, 0 at the top level
+    pub(super) struct_literals: bool, // False where a '{' starts a block, not a struct literal
 }
 
 impl Parser {
     /// Creates a new Parser instance with the given tokens.
-    pub fn new(tokens: Vec<Token>) -> Self {
-        Parser { tokens, current: 0 }
+    pub fn new(tokens: Vec<SpannedToken>) -> Self {
+        Parser {
+            tokens,
+            current: 0,
+            errors: Vec::new(),
+            block_depth: 0,
+            struct_literals: true,
+        }
     }
 
     /// Looks at the current token without consuming it.
     pub(super) fn peek(&self) -> Option<&Token> {
-        if self.current < self.tokens.len() {
-            Some(&self.tokens[self.current])
-        } else {
-            None
-        }
+        self.tokens.get(self.current).map(|t| &t.token)
     }
 
     /// Looks at the next token without consuming it.
     pub(super) fn peek_next(&self) -> Option<&Token> {
-        if self.current + 1 < self.tokens.len() {
-            Some(&self.tokens[self.current + 1])
-        } else {
-            None
+        self.tokens.get(self.current + 1).map(|t| &t.token)
+    }
+
+    /// Value of the current token once negated, if it is an integer literal
+    /// only out of range because it lacks its sign, like `9223372036854775808`.
+    pub(super) fn negated_integer(&self) -> Option<i64> {
+        match self.peek() {
+            Some(Token::Error(LexError::IntegerTooLarge(digits))) => format!("-{}", digits).parse().ok(),
+            _ => None,
+        }
+    }
+
+    /// Returns the span of the current token.
+    /// Past the end of the stream, this is the span of the last token.
+    pub(super) fn current_span(&self) -> Span {
+        self.tokens
+            .get(self.current)
+            .or_else(|| self.tokens.last())
+            .map(|t| t.span.clone())
+            .unwrap_or_default()
+    }
+
+    /// Returns the span of the most recently consumed token.
+    pub(super) fn previous_span(&self) -> Span {
+        if self.current == 0 {
+            return self.current_span();
         }
+        self.tokens[self.current - 1].span.clone()
+    }
+
+    /// Returns a span from `start` up to the end of the last consumed token.
+    pub(super) fn span_from(&self, start: &Span) -> Span {
+        start.to(&self.previous_span())
     }
 
     /// Advances to the next token and returns the current one.
+    /// Does not move past EOF.
     pub(super) fn advance(&mut self) -> &Token {
         if !self.is_at_end() {
             self.current += 1;
+            &self.tokens[self.current - 1].token
+        } else {
+            &self.tokens[self.current].token
         }
-        &self.tokens[self.current - 1]
     }
 
     /// Checks if the actual token is of the given type.
@@ -74,10 +110,16 @@ impl Parser {
     pub(super) fn expect(&mut self, expected: &Token, message: &str) -> ParseResult<Token> {
         if self.check(expected) {
             Ok(self.advance().clone())
+        } else if let Some(Token::Error(error)) = self.peek() {
+            Err(ParseError::Lex {
+                error: error.clone(),
+                span: self.current_span(),
+            })
         } else {
             Err(ParseError::UnexpectedToken {
                 expected: message.to_string(),
                 found: self.peek().cloned().unwrap_or(Token::EOF),
+                span: self.current_span(),
             })
         }
     }
@@ -94,11 +136,143 @@ impl Parser {
     }
 
     /// Entry point for parsing
+    /// Stops at the first error, see `parse_recovering` to get all of them.
     pub fn parse(&mut self) -> ParseResult<Vec<Stmt>> {
+        let (statements, mut errors) = self.parse_recovering();
+        if errors.is_empty() {
+            Ok(statements)
+        } else {
+            Err(errors.remove(0))
+        }
+    }
+
+    /// Parses the whole program, recovering after each error
+    /// Returns the statements that could be parsed, along with every error found.
+    pub fn parse_recovering(&mut self) -> (Vec<Stmt>, Vec<ParseError>) {
         let mut statements = Vec::new();
         while !self.is_at_end() {
-            statements.push(self.parse_statement()?);
+            if let Some(stmt) = self.parse_statement_recovering() {
+                statements.push(stmt);
+            }
+        }
+        (statements, std::mem::take(&mut self.errors))
+    }
+
+    /// Parses a statement, recording the error and synchronizing on failure
+    pub(super) fn parse_statement_recovering(&mut self) -> Option<Stmt> {
+        let start = self.current;
+        match self.parse_statement() {
+            Ok(stmt) => Some(stmt),
+            Err(e) => {
+                self.errors.push(e);
+                self.synchronize(start);
+                None
+            }
+        }
+    }
+
+    /// Skips tokens until the start of the next statement (panic mode)
+    /// Stops after a ';', before a '}' closing the enclosing block, or before a
+    /// statement keyword. Nested blocks and lambdas are skipped as a whole, so
+    /// that the parameters, return type and body of a lambda are not taken
+    /// for statements.
+    fn synchronize(&mut self, statement_start: usize) {
+        // Always make progress, or a token no statement can start with would loop forever
+        if self.current == statement_start && !self.is_at_end() {
+            self.advance();
+            if matches!(self.tokens[self.current - 1].token, Token::Semicolon) {
+                return;
+            }
+        }
+
+        let mut depth = 0;
+        while !self.is_at_end() {
+            match self.peek() {
+                Some(Token::Semicolon) if depth == 0 => {
+                    self.advance();
+                    return;
+                }
+                Some(Token::LeftBrace) => depth += 1,
+                Some(Token::RightBrace) if depth == 0 => return,
+                Some(Token::RightBrace) => {
+                    depth -= 1;
+                    self.advance();
+                    // A block ends the statement, unless an else branch follows
+                    if depth == 0 && !self.check(&Token::Else) {
+                        return;
+                    }
+                    continue;
+                }
+                // `func` not followed by a name starts a lambda, not a declaration
+                Some(Token::Func) if !matches!(self.peek_next(), Some(Token::Identifier(_))) => {
+                    self.skip_lambda();
+                    continue;
+                }
+                Some(Token::Func) if depth == 0 => return,
+                Some(token) if depth == 0 && Self::starts_statement(token) => return,
+                _ => {}
+            }
+            self.advance();
+        }
+    }
+
+    /// Skips a lambda, from its `func` keyword to the end of its body
+    fn skip_lambda(&mut self) {
+        self.advance();
+        // Parameters and return type, which may have parentheses of their own
+        let mut parentheses = 0;
+        while !self.is_at_end() {
+            match self.peek() {
+                Some(Token::LeftBrace | Token::Semicolon | Token::RightBrace | Token::RightParen)
+                    if parentheses == 0 =>
+                {
+                    break
+                }
+                Some(Token::LeftParen) => parentheses += 1,
+                Some(Token::RightParen) => parentheses -= 1,
+                _ => {}
+            }
+            self.advance();
+        }
+        if !self.check(&Token::LeftBrace) {
+            return;
+        }
+        let mut depth = 0;
+        while !self.is_at_end() {
+            match self.advance() {
+                Token::LeftBrace => depth += 1,
+                Token::RightBrace => {
+                    depth -= 1;
+                    if depth == 0 {
+                        return;
+                    }
+                }
+                _ => {}
+            }
         }
-        Ok(statements)
     }
-}
\ No newline at end of file
+
+    /// Returns true for keywords that begin a statement
+    fn starts_statement(token: &Token) -> bool {
+        matches!(
+            token,
+            Token::Struct
+                | Token::Enum
+                | Token::Impl
+                | Token::Match
+                | Token::If
+                | Token::While
+                | Token::For
+                | Token::Return
+                | Token::Break
+                | Token::Continue
+                | Token::Int
+                | Token::Float
+                | Token::String
+                | Token::Char
+                | Token::Bool
+                | Token::Array
+                | Token::FuncType
+        )
+    }
+}
@@ -0,0 +1,145 @@
+/*
+ * -------------------------------------------------------------------------
+ * RemyLang — pattern_parser implementation
+ * File : src/parser/pattern_parser.rs
+ *
+ * Description :
+ *   Parsing of match expressions and statements, and of the patterns
+ *   of their arms.
+ *
+ * Author  : Samuel 'Meeast' Bleau
+ * Created : 2026-10-16
+ *
+ * -------------------------------------------------------------------------
+*/
+
+use super::parser::Parser;
+use super::error::{ParseError, ParseResult};
+use crate::ast::{Expr, ExprKind, Literal, MatchArm, Pattern, Stmt, StmtKind};
+use crate::lexer::{Span, Token};
+
+impl Parser {
+    /// Parse: match (subject) { pattern => expr, ... }
+    pub(super) fn parse_match_expression(&mut self) -> ParseResult<Expr> {
+        let start = self.current_span();
+        let subject = self.parse_match_subject()?;
+
+        let mut arms = Vec::new();
+        while !self.check(&Token::RightBrace) && !self.is_at_end() {
+            let (pattern, span) = self.parse_match_pattern()?;
+            let body = self.parse_expression_with_struct_literals(true)?;
+            arms.push(MatchArm { pattern, body, span });
+            if !self.match_token(&[Token::Comma]) {
+                break;
+            }
+        }
+        self.expect(&Token::RightBrace, "'}' after match arms")?;
+        Ok(Expr::new(
+            ExprKind::Match {
+                subject: Box::new(subject),
+                arms,
+            },
+            self.span_from(&start),
+        ))
+    }
+
+    /// Parse: match (subject) { pattern => { ... } pattern => x += 1, ... }
+    /// Arms whose body is an expression or an assignment end with ',' or ';',
+    /// except the last one.
+    pub(super) fn parse_match_statement(&mut self) -> ParseResult<Stmt> {
+        let start = self.current_span();
+        let subject = self.parse_match_subject()?;
+
+        let mut arms = Vec::new();
+        while !self.check(&Token::RightBrace) && !self.is_at_end() {
+            let (pattern, span) = self.parse_match_pattern()?;
+            let body = if self.check(&Token::LeftBrace) {
+                self.parse_block_statement()?
+            } else {
+                let body = self.parse_simple_statement()?;
+                if !self.match_token(&[Token::Comma, Token::Semicolon]) && !self.check(&Token::RightBrace) {
+                    return Err(ParseError::UnexpectedToken {
+                        expected: "',' after match arm".to_string(),
+                        found: self.peek().cloned().unwrap_or(Token::EOF),
+                        span: self.current_span(),
+                    });
+                }
+                body
+            };
+            arms.push(MatchArm { pattern, body, span });
+        }
+        self.expect(&Token::RightBrace, "'}' after match arms")?;
+        Ok(Stmt::new(StmtKind::Match { subject, arms }, self.span_from(&start)))
+    }
+
+    /// Parses `match (subject) {`, returning the subject
+    fn parse_match_subject(&mut self) -> ParseResult<Expr> {
+        self.expect(&Token::Match, "'match'")?;
+        self.expect(&Token::LeftParen, "'(' after 'match'")?;
+        let subject = self.parse_expression_with_struct_literals(true)?;
+        self.expect(&Token::RightParen, "')' after match subject")?;
+        self.expect(&Token::LeftBrace, "'{' after match subject")?;
+        Ok(subject)
+    }
+
+    /// Parses the `pattern =>` start of an arm, with the span of the pattern
+    fn parse_match_pattern(&mut self) -> ParseResult<(Pattern, Span)> {
+        let start = self.current_span();
+        let pattern = self.parse_pattern()?;
+        let span = self.span_from(&start);
+        self.expect(&Token::FatArrow, "'=>' after pattern")?;
+        Ok((pattern, span))
+    }
+
+    /// Parse: _ / name / 42 / -1 / 2.5 / "text" / 'c' / True / Shape::Rect(w, _)
+    fn parse_pattern(&mut self) -> ParseResult<Pattern> {
+        let span = self.current_span();
+        match self.advance().clone() {
+            Token::Identifier(name) if name == "_" => Ok(Pattern::Wildcard),
+            Token::Identifier(enum_name) if self.match_token(&[Token::DoubleColon]) => {
+                let variant = self.parse_identifier("variant name after '::'")?;
+                let mut fields = Vec::new();
+                if self.match_token(&[Token::LeftParen]) {
+                    if !self.check(&Token::RightParen) {
+                        loop {
+                            fields.push(self.parse_pattern()?);
+                            if !self.match_token(&[Token::Comma]) {
+                                break;
+                            }
+                        }
+                    }
+                    self.expect(&Token::RightParen, "')' after variant patterns")?;
+                }
+                Ok(Pattern::Variant { enum_name, variant, fields })
+            }
+            Token::Identifier(name) => Ok(Pattern::Binding(name)),
+            Token::Number(n) => Ok(Pattern::Literal(Literal::Number(n))),
+            Token::FloatLit(x) => Ok(Pattern::Literal(Literal::Float(x))),
+            Token::Minus => match self.negated_integer() {
+                Some(n) => {
+                    self.advance();
+                    Ok(Pattern::Literal(Literal::Number(n)))
+                }
+                None => match self.advance().clone() {
+                    Token::Number(n) => Ok(Pattern::Literal(Literal::Number(n.wrapping_neg()))),
+                    Token::FloatLit(x) => Ok(Pattern::Literal(Literal::Float(-x))),
+                    token => Err(ParseError::UnexpectedToken {
+                        expected: "number after '-' in pattern".to_string(),
+                        found: token,
+                        span: self.previous_span(),
+                    }),
+                },
+            },
+            Token::StringLit(s) => Ok(Pattern::Literal(Literal::String(s))),
+            Token::CharLit(c) => Ok(Pattern::Literal(Literal::Char(c))),
+            Token::True => Ok(Pattern::Literal(Literal::Bool(true))),
+            Token::False => Ok(Pattern::Literal(Literal::Bool(false))),
+            Token::Error(error) => Err(ParseError::Lex { error, span }),
+            token => Err(ParseError::UnexpectedToken {
+                expected: "pattern".to_string(),
+                found: token,
+                span,
+            }),
+        }
+    }
+}
@@ -14,29 +14,41 @@
 
 use super::parser::Parser;
 use super::error::{ParseError, ParseResult};
-use crate::ast::{Stmt, Param, Type};
+use crate::ast::{AssignTarget, BinaryOp, Expr, ExprKind, Stmt, StmtKind, Param, Type, Variant};
 use crate::lexer::Token;
 
 impl Parser {
     /// Parses a statement.
     pub(super) fn parse_statement(&mut self) -> ParseResult<Stmt> {
         match self.peek() {
-            Some(Token::Int) | Some(Token::String) | Some(Token::Bool) 
-            | Some(Token::Char) | Some(Token::Array) => {
+            Some(Token::Int) | Some(Token::Float) | Some(Token::String) | Some(Token::Bool) 
+            | Some(Token::Char) | Some(Token::Array) | Some(Token::FuncType) => {
                 self.parse_let_statement()
             }
             Some(Token::If) => self.parse_if_statement(),
             Some(Token::Return) => self.parse_return_statement(),
+            Some(Token::While) => self.parse_while_statement(),
+            Some(Token::For) => self.parse_for_statement(),
+            Some(Token::Break) | Some(Token::Continue) => self.parse_loop_control(),
             Some(Token::LeftBrace) => self.parse_block_statement(),
-            Some(Token::Func) => self.parse_function_declaration(),
-            Some(Token::Identifier(_)) => {
-                // Could be assignment or expression statement
-                // Peek ahead to check for '='
-                if matches!(self.peek_next(), Some(Token::Equal)) {
-                    self.parse_assignment()
-                } else {
-                    self.parse_expression_statement()
-                }
+            // `func(` starts an anonymous function, used as an expression
+            Some(Token::Func) if !matches!(self.peek_next(), Some(Token::LeftParen)) => {
+                self.parse_function_declaration()
+            }
+            Some(Token::Struct) | Some(Token::Enum) | Some(Token::Impl) if self.block_depth > 0 => {
+                Err(ParseError::InvalidSyntax {
+                    message: "Structs, enums and impl blocks can only be declared at the top level".to_string(),
+                    token: None,
+                    span: self.current_span(),
+                })
+            }
+            Some(Token::Struct) => self.parse_struct_declaration(),
+            Some(Token::Enum) => self.parse_enum_declaration(),
+            Some(Token::Match) => self.parse_match_statement(),
+            Some(Token::Impl) => self.parse_impl_block(),
+            // A struct name followed by a variable name
+            Some(Token::Identifier(_)) if matches!(self.peek_next(), Some(Token::Identifier(_))) => {
+                self.parse_let_statement()
             }
             _ => self.parse_expression_statement(),
         }
@@ -44,54 +56,35 @@ impl Parser {
 
     /// Parse: Int x = 42;
     fn parse_let_statement(&mut self) -> ParseResult<Stmt> {
+        let start = self.current_span();
         let type_annotation = self.parse_type()?;
-        
-        let name = match self.advance() {
-            Token::Identifier(n) => n.clone(),
-            token => {
-                return Err(ParseError::UnexpectedToken {
-                    expected: "variable name".to_string(),
-                    found: token.clone(),
-                });
-            }
-        };
+        let name = self.parse_identifier("variable name")?;
 
         self.expect(&Token::Equal, "'=' after variable name")?;
         let value = self.parse_expression()?;
         self.expect(&Token::Semicolon, "';' after expression")?;
 
-        Ok(Stmt::Let {
-            name,
-            type_annotation: Some(type_annotation),
-            value,
-        })
-    }
-    /// Parse: x = 10;
-    fn parse_assignment(&mut self) -> ParseResult<Stmt> {
-        let name = match self.advance() {
-            Token::Identifier(n) => n.clone(),
-            token => {
-                return Err(ParseError::UnexpectedToken {
-                    expected: "identifier".to_string(),
-                    found: token.clone(),
-                });
-            }
-        };
-        self.expect(&Token::Equal, "'='")?;
-        let value = self.parse_expression()?;
-        self.expect(&Token::Semicolon, "';' after expression")?;
-        Ok(Stmt::Assignment { name, value })
+        Ok(Stmt::new(
+            StmtKind::Let {
+                name,
+                type_annotation: Some(type_annotation),
+                value,
+            },
+            self.span_from(&start),
+        ))
     }
 
-    /// Parse: expression;
+    /// Parse: expression; / x = 10; / a[i] += 1;
     fn parse_expression_statement(&mut self) -> ParseResult<Stmt> {
-        let expr = self.parse_expression()?;
+        let start = self.current_span();
+        let kind = self.parse_simple_statement()?.kind;
         self.expect(&Token::Semicolon, "';' after expression")?;
-        Ok(Stmt::Expression(expr))
+        Ok(Stmt::new(kind, self.span_from(&start)))
     }
 
     /// Parse: if (condition) { ... } else { ... }
     fn parse_if_statement(&mut self) -> ParseResult<Stmt> {
+        let start = self.current_span();
         self.expect(&Token::If, "'if'")?;
         self.expect(&Token::LeftParen, "'(' after 'if'")?;
         let condition = self.parse_expression()?;
@@ -111,14 +104,18 @@ impl Parser {
             None
         };
 
-        Ok(Stmt::If {
-            condition,
-            then_branch,
-            else_branch,
-        })
+        Ok(Stmt::new(
+            StmtKind::If {
+                condition,
+                then_branch,
+                else_branch,
+            },
+            self.span_from(&start),
+        ))
     }
     /// Parse: return <expr>;
     fn parse_return_statement(&mut self) -> ParseResult<Stmt> {
+        let start = self.current_span();
         self.expect(&Token::Return, "'return'")?;
         let value = if self.check(&Token::Semicolon) {
             None
@@ -126,33 +123,168 @@ impl Parser {
             Some(self.parse_expression()?)
         };
         self.expect(&Token::Semicolon, "';' after return")?;
-        Ok(Stmt::Return(value))
+        Ok(Stmt::new(StmtKind::Return(value), self.span_from(&start)))
     }
+    /// Parse: while (condition) { ... }
+    fn parse_while_statement(&mut self) -> ParseResult<Stmt> {
+        let start = self.current_span();
+        self.expect(&Token::While, "'while'")?;
+        self.expect(&Token::LeftParen, "'(' after 'while'")?;
+        let condition = self.parse_expression()?;
+        self.expect(&Token::RightParen, "')' after condition")?;
+
+        let body = Box::new(self.parse_block_statement()?);
+        Ok(Stmt::new(StmtKind::While { condition, body }, self.span_from(&start)))
+    }
+
+    /// Parse: for (init; condition; increment) { ... }
+    ///    or: for item in array { ... } / for (item in array) { ... }
+    fn parse_for_statement(&mut self) -> ParseResult<Stmt> {
+        let start = self.current_span();
+        self.expect(&Token::For, "'for'")?;
+
+        let parenthesized = self.match_token(&[Token::LeftParen]);
+        let is_for_in = matches!(self.peek(), Some(Token::Identifier(_)))
+            && matches!(self.peek_next(), Some(Token::In));
+
+        if is_for_in || !parenthesized {
+            let variable = self.parse_identifier("loop variable")?;
+            self.expect(&Token::In, "'in' after loop variable")?;
+            // Without parentheses, the '{' after the iterable starts the body
+            let iterable = self.parse_expression_with_struct_literals(parenthesized)?;
+            if parenthesized {
+                self.expect(&Token::RightParen, "')' after iterable")?;
+            }
+            let body = Box::new(self.parse_block_statement()?);
+            return Ok(Stmt::new(
+                StmtKind::ForIn { variable, iterable, body },
+                self.span_from(&start),
+            ));
+        }
+
+        // Initializer (a declaration consumes its own ';')
+        let initializer = match self.peek() {
+            Some(Token::Semicolon) => {
+                self.advance();
+                None
+            }
+            Some(Token::Int) | Some(Token::Float) | Some(Token::String) | Some(Token::Bool)
+            | Some(Token::Char) | Some(Token::Array) | Some(Token::FuncType) => {
+                Some(Box::new(self.parse_let_statement()?))
+            }
+            Some(Token::Identifier(_)) if matches!(self.peek_next(), Some(Token::Identifier(_))) => {
+                Some(Box::new(self.parse_let_statement()?))
+            }
+            _ => {
+                let init = self.parse_simple_statement()?;
+                self.expect(&Token::Semicolon, "';' after loop initializer")?;
+                Some(Box::new(init))
+            }
+        };
+
+        let condition = if self.check(&Token::Semicolon) {
+            None
+        } else {
+            Some(self.parse_expression()?)
+        };
+        self.expect(&Token::Semicolon, "';' after loop condition")?;
+
+        let increment = if self.check(&Token::RightParen) {
+            None
+        } else {
+            Some(Box::new(self.parse_simple_statement()?))
+        };
+        self.expect(&Token::RightParen, "')' after for clauses")?;
+
+        let body = Box::new(self.parse_block_statement()?);
+        Ok(Stmt::new(
+            StmtKind::For {
+                initializer,
+                condition,
+                increment,
+                body,
+            },
+            self.span_from(&start),
+        ))
+    }
+
+    /// Parses an assignment or an expression without its trailing ';'
+    /// Also used for the clauses of a for loop.
+    pub(super) fn parse_simple_statement(&mut self) -> ParseResult<Stmt> {
+        let start = self.current_span();
+        let expr = self.parse_expression()?;
+
+        let op = match self.peek() {
+            Some(Token::Equal) => None,
+            Some(Token::PlusEqual) => Some(BinaryOp::Add),
+            Some(Token::MinusEqual) => Some(BinaryOp::Sub),
+            Some(Token::StarEqual) => Some(BinaryOp::Mul),
+            Some(Token::SlashEqual) => Some(BinaryOp::Div),
+            Some(Token::PercentEqual) => Some(BinaryOp::Mod),
+            _ => return Ok(Stmt::new(StmtKind::Expression(expr), self.span_from(&start))),
+        };
+        self.advance();
+        let target_span = expr.span.clone();
+        let target = Self::assignment_target(expr).ok_or(ParseError::InvalidSyntax {
+            message: "Invalid assignment target".to_string(),
+            token: None,
+            span: target_span,
+        })?;
+        let value = self.parse_expression()?;
+        Ok(Stmt::new(StmtKind::Assignment { target, op, value }, self.span_from(&start)))
+    }
+
+    /// The place an expression designates, if it can be assigned to
+    fn assignment_target(expr: Expr) -> Option<AssignTarget> {
+        match expr.kind {
+            ExprKind::Variable(name) => Some(AssignTarget::Variable(name)),
+            ExprKind::ArrayAccess { array, index } => Some(AssignTarget::Index {
+                array: Box::new(Self::assignment_target(*array)?),
+                index,
+            }),
+            ExprKind::FieldAccess { object, field } => Some(AssignTarget::Field {
+                object: Box::new(Self::assignment_target(*object)?),
+                field,
+            }),
+            _ => None,
+        }
+    }
+
+    /// Parse: break; / continue;
+    fn parse_loop_control(&mut self) -> ParseResult<Stmt> {
+        let start = self.current_span();
+        let kind = match self.advance() {
+            Token::Break => StmtKind::Break,
+            _ => StmtKind::Continue,
+        };
+        self.expect(&Token::Semicolon, "';' after loop control")?;
+        Ok(Stmt::new(kind, self.span_from(&start)))
+    }
+
     /// Parse: { stmt1; stmt2; ... }
-    fn parse_block_statement(&mut self) -> ParseResult<Stmt> {
+    pub(super) fn parse_block_statement(&mut self) -> ParseResult<Stmt> {
+        let start = self.current_span();
         self.expect(&Token::LeftBrace, "'{'")?;
 
+        // Errors inside the block are recorded so the rest of it still parses
         let mut statements = Vec::new();
+        self.block_depth += 1;
         while !self.check(&Token::RightBrace) && !self.is_at_end() {
-            statements.push(self.parse_statement()?);
+            if let Some(stmt) = self.parse_statement_recovering() {
+                statements.push(stmt);
+            }
         }
+        self.block_depth -= 1;
         self.expect(&Token::RightBrace, "'}' after block")?;
-        Ok(Stmt::Block(statements))
+        Ok(Stmt::new(StmtKind::Block(statements), self.span_from(&start)))
     }
 
     /// Parse: func name(params) -> return_type { body }
     fn parse_function_declaration(&mut self) -> ParseResult<Stmt> {
+        let start = self.current_span();
         self.expect(&Token::Func, "'func'")?;
         
-        let name = match self.advance() {
-            Token::Identifier(n) => n.clone(),
-            token => {
-                return Err(ParseError::UnexpectedToken {
-                    expected: "function name".to_string(),
-                    found: token.clone(),
-                })
-            }
-        };
+        let name = self.parse_identifier("function name")?;
         self.expect(&Token::LeftParen, "'(' after function name")?;
         let params = self.parse_parameter_list()?;
         self.expect(&Token::RightParen, "')' after parameters")?;
@@ -164,30 +296,96 @@ impl Parser {
         };
         
         let body = self.parse_block_statement()?;
-        Ok(Stmt::FunctionDecl {
-            name,
-            params,
-            return_type,
-            body: Box::new(body),
-        })
+        Ok(Stmt::new(
+            StmtKind::FunctionDecl {
+                name,
+                params,
+                return_type,
+                body: Box::new(body),
+            },
+            self.span_from(&start),
+        ))
+    }
+
+    /// Parse: struct Point { Int x; Int y; }
+    fn parse_struct_declaration(&mut self) -> ParseResult<Stmt> {
+        let start = self.current_span();
+        self.expect(&Token::Struct, "'struct'")?;
+        let name = self.parse_identifier("struct name")?;
+        self.expect(&Token::LeftBrace, "'{' after struct name")?;
+
+        let mut fields = Vec::new();
+        while !self.check(&Token::RightBrace) && !self.is_at_end() {
+            let type_annotation = self.parse_type()?;
+            let name = self.parse_identifier("field name")?;
+            self.expect(&Token::Semicolon, "';' after field")?;
+            fields.push(Param { name, type_annotation });
+        }
+        self.expect(&Token::RightBrace, "'}' after struct fields")?;
+        Ok(Stmt::new(StmtKind::StructDecl { name, fields }, self.span_from(&start)))
+    }
+
+    /// Parse: enum Shape { Circle(Int), Rect(Int, Int), Empty }
+    fn parse_enum_declaration(&mut self) -> ParseResult<Stmt> {
+        let start = self.current_span();
+        self.expect(&Token::Enum, "'enum'")?;
+        let name = self.parse_identifier("enum name")?;
+        self.expect(&Token::LeftBrace, "'{' after enum name")?;
+
+        let mut variants = Vec::new();
+        while !self.check(&Token::RightBrace) && !self.is_at_end() {
+            let name = self.parse_identifier("variant name")?;
+            let mut fields = Vec::new();
+            if self.match_token(&[Token::LeftParen]) {
+                if !self.check(&Token::RightParen) {
+                    loop {
+                        fields.push(self.parse_type()?);
+                        if !self.match_token(&[Token::Comma]) {
+                            break;
+                        }
+                    }
+                }
+                self.expect(&Token::RightParen, "')' after variant fields")?;
+            }
+            variants.push(Variant { name, fields });
+            if !self.match_token(&[Token::Comma]) {
+                break;
+            }
+        }
+        self.expect(&Token::RightBrace, "'}' after enum variants")?;
+        Ok(Stmt::new(StmtKind::EnumDecl { name, variants }, self.span_from(&start)))
+    }
+
+    /// Parse: impl Point { func Method(params) -> return_type { body } ... }
+    fn parse_impl_block(&mut self) -> ParseResult<Stmt> {
+        let start = self.current_span();
+        self.expect(&Token::Impl, "'impl'")?;
+        let name = self.parse_identifier("type name")?;
+        self.expect(&Token::LeftBrace, "'{' after type name")?;
+
+        let mut methods = Vec::new();
+        while !self.check(&Token::RightBrace) && !self.is_at_end() {
+            if !self.check(&Token::Func) {
+                return Err(ParseError::UnexpectedToken {
+                    expected: "method declaration".to_string(),
+                    found: self.peek().cloned().unwrap_or(Token::EOF),
+                    span: self.current_span(),
+                });
+            }
+            methods.push(self.parse_function_declaration()?);
+        }
+        self.expect(&Token::RightBrace, "'}' after methods")?;
+        Ok(Stmt::new(StmtKind::Impl { name, methods }, self.span_from(&start)))
     }
 
     /// Parses a list of function parameters.
-    fn parse_parameter_list(&mut self) -> ParseResult<Vec<Param>> {
+    pub(super) fn parse_parameter_list(&mut self) -> ParseResult<Vec<Param>> {
         let mut params = Vec::new();
         if !self.check(&Token::RightParen) {
             loop {
                 let type_annotation = self.parse_type()?;
-                let name = match self.advance() {
-                    Token::Identifier(n) => n.clone(),
-                    token => {
-                        return Err(ParseError::UnexpectedToken {
-                            expected: "parameter name".to_string(),
-                            found: token.clone(),
-                        });
-                    }
-                };
-                
+                let name = self.parse_identifier("parameter name")?;
+
                 params.push(Param {
                     name,
                     type_annotation,
@@ -201,10 +399,25 @@ impl Parser {
         Ok(params)
     }
 
+    /// Parses an identifier, reporting `expected` if something else is found.
+    pub(super) fn parse_identifier(&mut self, expected: &str) -> ParseResult<String> {
+        let span = self.current_span();
+        match self.advance() {
+            Token::Identifier(n) => Ok(n.clone()),
+            token => Err(ParseError::UnexpectedToken {
+                expected: expected.to_string(),
+                found: token.clone(),
+                span,
+            }),
+        }
+    }
+
     /// Parses a type annotation.
-    fn parse_type(&mut self) -> ParseResult<Type> {
+    pub(super) fn parse_type(&mut self) -> ParseResult<Type> {
+        let span = self.current_span();
         match self.advance() {
             Token::Int => Ok(Type::Int),
+            Token::Float => Ok(Type::Float),
             Token::String => Ok(Type::String),
             Token::Bool => Ok(Type::Bool),
             Token::Char => Ok(Type::Char),
@@ -214,9 +427,32 @@ impl Parser {
                 self.expect(&Token::Greater, "'>' after array element type")?;
                 Ok(Type::Array(Box::new(element_type)))
             }
+            Token::FuncType => {
+                self.expect(&Token::Less, "'<' after 'Func'")?;
+                self.expect(&Token::LeftParen, "'(' before parameter types")?;
+                let mut params = Vec::new();
+                if !self.check(&Token::RightParen) {
+                    loop {
+                        params.push(self.parse_type()?);
+                        if !self.match_token(&[Token::Comma]) {
+                            break;
+                        }
+                    }
+                }
+                self.expect(&Token::RightParen, "')' after parameter types")?;
+                let return_type = if self.match_token(&[Token::Comma]) {
+                    self.parse_type()?
+                } else {
+                    Type::Void
+                };
+                self.expect(&Token::Greater, "'>' after function type")?;
+                Ok(Type::Function { params, return_type: Box::new(return_type) })
+            }
+            Token::Identifier(name) => Ok(Type::Named(name.clone())),
             token => Err(ParseError::UnexpectedToken {
-                expected: "type (Int, String, Bool, Char, Array)".to_string(),
+                expected: "type (Int, Float, String, Bool, Char, Array, Func, or a struct or enum name)".to_string(),
                 found: token.clone(),
+                span,
             }),
         }
     }
@@ -0,0 +1,87 @@
+/*
+ * -------------------------------------------------------------------------
+ * RemyLang — REPL commands
+ * File : src/repl/command.rs
+ *
+ * Description :
+ *   Commands of the REPL, lines starting with ':' instead of code.
+ *
+ * Author  : Samuel 'Meeast' Bleau
+ * Created : 2026-10-16
+ *
+ * -------------------------------------------------------------------------
+*/
+
+use std::fmt;
+
+#[derive(Debug, Clone, PartialEq)]
+pub enum Command {
+    /// `:help`, listing the commands
+    Help,
+    /// `:quit`, leaving the REPL
+    Quit,
+    /// `:env`, listing the global variables and functions
+    Env,
+    /// `:type <expr>`, showing the type of an expression without evaluating it
+    Type(String),
+    /// `:ast <code>`, showing the syntax tree of code without running it
+    Ast(String),
+    /// `:load <file>`, running a file in the session
+    Load(String),
+}
+
+#[derive(Debug, Clone, PartialEq)]
+pub enum CommandError {
+    Unknown(String),
+    MissingArgument { command: &'static str, argument: &'static str },
+}
+
+impl fmt::Display for CommandError {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        match self {
+            CommandError::Unknown(name) => write!(f, "Unknown command ':{}', :help lists the commands", name),
+            CommandError::MissingArgument { command, argument } => {
+                write!(f, "':{}' expects {}", command, argument)
+            }
+        }
+    }
+}
+
+impl std::error::Error for CommandError {}
+
+impl Command {
+    /// Parses a command line, starting with ':'
+    ///
+    /// # Example
+    /// ```
+    /// use remylang::repl::Command;
+    ///
+    /// assert_eq!(Command::parse(":type 1 + 2"), Ok(Command::Type("1 + 2".to_string())));
+    /// assert_eq!(Command::parse(":q"), Ok(Command::Quit));
+    /// ```
+    pub fn parse(line: &str) -> Result<Command, CommandError> {
+        let line = line.trim();
+        let line = line.strip_prefix(':').unwrap_or(line);
+        let (name, argument) = match line.split_once(char::is_whitespace) {
+            Some((name, argument)) => (name, argument.trim()),
+            None => (line, ""),
+        };
+        let required = |command: &'static str, argument_name: &'static str| {
+            if argument.is_empty() {
+                Err(CommandError::MissingArgument { command, argument: argument_name })
+            } else {
+                Ok(argument.to_string())
+            }
+        };
+
+        match name {
+            "help" | "h" => Ok(Command::Help),
+            "quit" | "q" | "exit" => Ok(Command::Quit),
+            "env" => Ok(Command::Env),
+            "type" | "t" => required("type", "an expression").map(Command::Type),
+            "ast" => required("ast", "some code").map(Command::Ast),
+            "load" | "l" => required("load", "a file name").map(Command::Load),
+            other => Err(CommandError::Unknown(other.to_string())),
+        }
+    }
+}
@@ -0,0 +1,23 @@
+/*
+ * -------------------------------------------------------------------------
+ * RemyLang — REPL module
+ * File : src/repl/mod.rs
+ *
+ * Description :
+ *   Interactive mode, running code as it is typed on a single interpreter.
+ *
+ * Author  : Samuel 'Meeast' Bleau
+ * Created : 2026-10-16
+ *
+ * -------------------------------------------------------------------------
+*/
+
+mod command;
+mod session;
+#[allow(clippy::module_inception)]
+mod repl;
+
+// Re-exports
+pub use command::{Command, CommandError};
+pub use session::{is_complete, parse, Global, Session};
+pub use repl::run;
@@ -0,0 +1,149 @@
+/*
+ * -------------------------------------------------------------------------
+ * RemyLang — REPL loop
+ * File : src/repl/repl.rs
+ *
+ * Description :
+ *   Interactive prompt reading code and commands, with line editing and
+ *   a history kept between runs.
+ *
+ * Author  : Samuel 'Meeast' Bleau
+ * Created : 2026-10-16
+ *
+ * -------------------------------------------------------------------------
+*/
+
+use std::env;
+use std::fs;
+use std::path::PathBuf;
+use rustyline::error::ReadlineError;
+use rustyline::DefaultEditor;
+use super::command::Command;
+use super::session::{self, is_complete, Session};
+use crate::diagnostics;
+use crate::vm::Value;
+
+/// File holding the history, in the home directory
+const HISTORY_FILE: &str = ".remylang_history";
+
+const HELP: &str = "\
+Enter code to run it. Expressions have their value printed, and input
+continues on the next line while a brace is left open.
+
+Commands:
+  :help           Show this help
+  :quit           Leave the REPL (Ctrl-D works too)
+  :env            List the global variables and functions
+  :type <expr>    Show the type of an expression without evaluating it
+  :ast <code>     Show the syntax tree of some code without running it
+  :load <file>    Run a file, keeping its declarations";
+
+/// Runs the REPL until the user leaves it
+pub fn run() {
+    println!("RemyLang v1.0.0 REPL, :help for help, :quit to leave");
+
+    let mut editor = match DefaultEditor::new() {
+        Ok(editor) => editor,
+        Err(e) => {
+            eprintln!("❌ Cannot start the line editor: {}", e);
+            return;
+        }
+    };
+    let history = history_path();
+    if let Some(path) = &history {
+        // There is no history before the first run
+        let _ = editor.load_history(path);
+    }
+
+    let mut session = Session::new();
+    let mut input = String::new();
+    loop {
+        let prompt = if input.is_empty() { ">>> " } else { "... " };
+        match editor.readline(prompt) {
+            Ok(line) => {
+                if input.is_empty() && line.trim_start().starts_with(':') {
+                    let _ = editor.add_history_entry(line.trim());
+                    match Command::parse(&line) {
+                        Ok(Command::Quit) => break,
+                        Ok(command) => run_command(&mut session, command),
+                        Err(e) => eprintln!("❌ {}", e),
+                    }
+                    continue;
+                }
+
+                input.push_str(&line);
+                input.push('\n');
+                if !is_complete(&input) {
+                    continue;
+                }
+                let code = std::mem::take(&mut input);
+                if code.trim().is_empty() {
+                    continue;
+                }
+                let _ = editor.add_history_entry(code.trim_end());
+                eval(&mut session, &code, "<repl>");
+            }
+            // Ctrl-C drops the input being typed
+            Err(ReadlineError::Interrupted) => input.clear(),
+            Err(ReadlineError::Eof) => break,
+            Err(e) => {
+                eprintln!("❌ {}", e);
+                break;
+            }
+        }
+    }
+
+    if let Some(path) = &history {
+        if let Err(e) = editor.save_history(path) {
+            eprintln!("❌ Cannot save the history: {}", e);
+        }
+    }
+}
+
+fn history_path() -> Option<PathBuf> {
+    env::var_os("HOME").map(|home| PathBuf::from(home).join(HISTORY_FILE))
+}
+
+/// Runs code in the session, printing the values of its expressions and its errors
+fn eval(session: &mut Session, code: &str, filename: &str) {
+    if let Err(errors) = session.eval(code, filename, print_value) {
+        for e in &errors {
+            diagnostics::report(e, code);
+        }
+    }
+}
+
+fn print_value(value: &Value) {
+    match value {
+        Value::String(s) => println!("{:?}", s),
+        Value::Char(c) => println!("{:?}", c),
+        _ => println!("{}", value),
+    }
+}
+
+fn run_command(session: &mut Session, command: Command) {
+    match command {
+        Command::Help => println!("{}", HELP),
+        Command::Quit => {}
+        Command::Env => {
+            for global in session.globals() {
+                match global.value {
+                    Some(Value::Function { .. }) | None => println!("{} : {}", global.name, global.ty),
+                    Some(value) => println!("{} : {} = {}", global.name, global.ty, value),
+                }
+            }
+        }
+        Command::Type(code) => match session.type_of(&code) {
+            Ok(ty) => println!("{}", ty),
+            Err(errors) => errors.iter().for_each(|e| diagnostics::report(e, &code)),
+        },
+        Command::Ast(code) => match session::parse(&code, "<repl>") {
+            Ok(ast) => ast.iter().for_each(|stmt| println!("{:#?}", stmt)),
+            Err(errors) => errors.iter().for_each(|e| diagnostics::report(&e.into(), &code)),
+        },
+        Command::Load(filename) => match fs::read_to_string(&filename) {
+            Ok(code) => eval(session, &code, &filename),
+            Err(e) => eprintln!("❌ Error reading {}: {}", filename, e),
+        },
+    }
+}
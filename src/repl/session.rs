@@ -0,0 +1,146 @@
+/*
+ * -------------------------------------------------------------------------
+ * RemyLang — REPL session
+ * File : src/repl/session.rs
+ *
+ * Description :
+ *   State kept between the inputs of the REPL: the declarations known to
+ *   the type checker and the values held by the interpreter.
+ *
+ * Author  : Samuel 'Meeast' Bleau
+ * Created : 2026-10-16
+ *
+ * -------------------------------------------------------------------------
+*/
+
+use crate::ast::{Stmt, StmtKind, Type};
+use crate::diagnostics::Diagnostic;
+use crate::lexer::{LexError, Lexer, Token};
+use crate::parser::{ParseError, Parser};
+use crate::semantic::TypeChecker;
+use crate::vm::{Interpreter, Value};
+
+/// A global variable or function of the session
+#[derive(Debug, Clone, PartialEq)]
+pub struct Global {
+    pub name: String,
+    pub ty: Type,
+    /// None if its declaration failed at runtime
+    pub value: Option<Value>,
+}
+
+pub struct Session {
+    checker: TypeChecker,
+    interpreter: Interpreter,
+}
+
+impl Session {
+    pub fn new() -> Self {
+        Session {
+            checker: TypeChecker::new(),
+            interpreter: Interpreter::new(),
+        }
+    }
+
+    /// Checks and runs code on top of the previous inputs
+    /// Code with a parse or type error is not run and declares nothing.
+    /// `echo` receives the value of each expression statement that has one,
+    /// on the calling thread.
+    ///
+    /// # Example
+    /// ```
+    /// use remylang::repl::Session;
+    ///
+    /// let mut session = Session::new();
+    /// session.eval("Int x = 40;", "<repl>", |_| {}).unwrap();
+    /// let mut echoed = Vec::new();
+    /// session.eval("x + 2", "<repl>", |value| echoed.push(value.to_string())).unwrap();
+    /// assert_eq!(echoed, vec!["42"]);
+    /// ```
+    pub fn eval(&mut self, code: &str, filename: &str, echo: impl FnMut(&Value)) -> Result<(), Vec<Diagnostic>> {
+        let ast = parse(code, filename).map_err(|errors| errors.iter().map(Diagnostic::from).collect::<Vec<_>>())?;
+
+        // Only keep the declarations of code that type checks
+        let mut checker = self.checker.clone();
+        checker
+            .check_program(&ast)
+            .map_err(|errors| errors.iter().map(Diagnostic::from).collect::<Vec<_>>())?;
+        self.checker = checker;
+
+        self.interpreter
+            .execute_interactive(ast, echo)
+            .map_err(|error| vec![Diagnostic::from(&error)])
+    }
+
+    /// Type of an expression, without evaluating it
+    pub fn type_of(&mut self, code: &str) -> Result<Type, Vec<Diagnostic>> {
+        let mut ast = parse(code, "<repl>").map_err(|errors| errors.iter().map(Diagnostic::from).collect::<Vec<_>>())?;
+        match ast.pop() {
+            Some(Stmt { kind: StmtKind::Expression(expr), .. }) if ast.is_empty() => {
+                self.checker.type_of(&expr).map_err(|error| vec![Diagnostic::from(&error)])
+            }
+            _ => Err(vec![Diagnostic::error("repl", "':type' expects a single expression")]),
+        }
+    }
+
+    /// Global variables and functions declared so far, sorted by name
+    pub fn globals(&self) -> Vec<Global> {
+        let mut globals: Vec<Global> = self
+            .checker
+            .globals()
+            .iter()
+            .map(|(name, symbol)| Global {
+                name: name.clone(),
+                ty: symbol.value_type(),
+                value: self.interpreter.environment().get(name).ok(),
+            })
+            .collect();
+        globals.sort_by(|a, b| a.name.cmp(&b.name));
+        globals
+    }
+}
+
+impl Default for Session {
+    fn default() -> Self {
+        Self::new()
+    }
+}
+
+/// Parses code entered at the prompt
+/// The semicolon ending the last statement can be left out, as in `1 + 2`.
+pub fn parse(code: &str, filename: &str) -> Result<Vec<Stmt>, Vec<ParseError>> {
+    let parse_code = |code: String| {
+        let tokens = Lexer::with_file(code, filename).tokenize();
+        match Parser::new(tokens).parse_recovering() {
+            (ast, errors) if errors.is_empty() => Ok(ast),
+            (_, errors) => Err(errors),
+        }
+    };
+    // On a line of its own, so that a trailing comment doesn't swallow it
+    parse_code(code.to_string()).or_else(|errors| parse_code(format!("{}\n;", code)).map_err(|_| errors))
+}
+
+/// True if code can be run, false if more lines are needed
+/// Code is incomplete while a brace, parenthesis or bracket is left open,
+/// or a block comment is not closed.
+///
+/// # Example
+/// ```
+/// use remylang::repl::is_complete;
+///
+/// assert!(!is_complete("func Double(Int x) -> Int {"));
+/// assert!(is_complete("func Double(Int x) -> Int {\n    return x * 2;\n}"));
+/// ```
+pub fn is_complete(code: &str) -> bool {
+    let mut depth: i64 = 0;
+    for token in Lexer::new(code.to_string()).tokenize() {
+        match token.token {
+            Token::LeftBrace | Token::LeftParen | Token::LeftBracket => depth += 1,
+            Token::RightBrace | Token::RightParen | Token::RightBracket => depth -= 1,
+            Token::Error(LexError::UnterminatedComment) => return false,
+            _ => {}
+        }
+    }
+    // Extra closing delimiters are reported by the parser
+    depth <= 0
+}
@@ -0,0 +1,179 @@
+/*
+ * -------------------------------------------------------------------------
+ * RemyLang — exhaustiveness checking
+ * File : src/semantic/exhaustiveness.rs
+ *
+ * Description :
+ *   Finds the values a match does not cover, by looking for a pattern
+ *   that none of its arms matches, and the arms that no value reaches.
+ *
+ * Author  : Samuel 'Meeast' Bleau
+ * Created : 2026-10-16
+ *
+ * -------------------------------------------------------------------------
+*/
+
+use crate::ast::{Literal, Pattern, Type};
+use crate::semantic::symbol_table::SymbolTable;
+
+/// A way of building a value of a type with finitely many shapes
+struct Constructor {
+    pattern: Pattern,
+    fields: Vec<Type>,
+}
+
+impl Constructor {
+    /// True if `pattern` starts with this constructor
+    fn matches(&self, pattern: &Pattern) -> bool {
+        match (&self.pattern, pattern) {
+            (Pattern::Variant { variant, .. }, Pattern::Variant { variant: other, .. }) => variant == other,
+            (Pattern::Literal(literal), Pattern::Literal(other)) => literal == other,
+            _ => false,
+        }
+    }
+
+    /// The constructor applied to `fields`
+    fn apply(&self, fields: Vec<Pattern>) -> Pattern {
+        match &self.pattern {
+            Pattern::Variant { enum_name, variant, .. } => Pattern::Variant {
+                enum_name: enum_name.clone(),
+                variant: variant.clone(),
+                fields,
+            },
+            pattern => pattern.clone(),
+        }
+    }
+}
+
+/// Patterns of values of type `ty` that none of `patterns` matches
+/// For enums and Bool, one pattern is given per uncovered constructor.
+/// Other types have too many values to list, so `_` stands for them.
+pub fn missing_patterns(symbols: &SymbolTable, ty: &Type, patterns: &[&Pattern]) -> Vec<Pattern> {
+    let rows: Vec<Vec<Pattern>> = patterns.iter().map(|&p| vec![p.clone()]).collect();
+    match constructors(symbols, ty) {
+        Some(constructors) => constructors
+            .iter()
+            .filter_map(|constructor| {
+                let rows = specialize(&rows, constructor);
+                let witness = uncovered(symbols, rows, &constructor.fields)?;
+                Some(constructor.apply(witness))
+            })
+            .collect(),
+        None => uncovered(symbols, rows, std::slice::from_ref(ty))
+            .map(|_| vec![Pattern::Wildcard])
+            .unwrap_or_default(),
+    }
+}
+
+/// Indices of the patterns that only match values the patterns before them already match
+pub fn unreachable_patterns(symbols: &SymbolTable, ty: &Type, patterns: &[&Pattern]) -> Vec<usize> {
+    (0..patterns.len())
+        .filter(|&i| {
+            let rows: Vec<Vec<Pattern>> = patterns[..i].iter().map(|&p| vec![p.clone()]).collect();
+            !useful(symbols, &rows, vec![patterns[i].clone()], std::slice::from_ref(ty))
+        })
+        .collect()
+}
+
+/// Constructors of `ty`, if it has finitely many
+fn constructors(symbols: &SymbolTable, ty: &Type) -> Option<Vec<Constructor>> {
+    match ty {
+        Type::Bool => Some(
+            [true, false]
+                .into_iter()
+                .map(|b| Constructor { pattern: Pattern::Literal(Literal::Bool(b)), fields: Vec::new() })
+                .collect(),
+        ),
+        Type::Named(name) => {
+            let info = symbols.get_enum(name)?;
+            Some(
+                info.variants
+                    .iter()
+                    .map(|(variant, fields)| Constructor {
+                        pattern: Pattern::Variant {
+                            enum_name: name.clone(),
+                            variant: variant.clone(),
+                            fields: Vec::new(),
+                        },
+                        fields: fields.clone(),
+                    })
+                    .collect(),
+            )
+        }
+        _ => None,
+    }
+}
+
+/// Rows that match values built with `constructor`, with its fields in
+/// place of their first pattern
+fn specialize(rows: &[Vec<Pattern>], constructor: &Constructor) -> Vec<Vec<Pattern>> {
+    rows.iter()
+        .filter_map(|row| {
+            let fields = match &row[0] {
+                pattern if pattern.is_irrefutable() => vec![Pattern::Wildcard; constructor.fields.len()],
+                pattern if constructor.matches(pattern) => match pattern {
+                    Pattern::Variant { fields, .. } => fields.clone(),
+                    _ => Vec::new(),
+                },
+                _ => return None,
+            };
+            Some(fields.into_iter().chain(row[1..].iter().cloned()).collect())
+        })
+        .collect()
+}
+
+/// A row of patterns, of the given types, that no row of `rows` matches
+fn uncovered(symbols: &SymbolTable, rows: Vec<Vec<Pattern>>, types: &[Type]) -> Option<Vec<Pattern>> {
+    let Some((ty, rest)) = types.split_first() else {
+        // Without columns left, a row matches everything
+        return rows.is_empty().then(Vec::new);
+    };
+
+    // When no row looks inside the column, `_` describes the missing values best
+    let constructors = constructors(symbols, ty).filter(|_| rows.iter().any(|row| !row[0].is_irrefutable()));
+    match constructors {
+        Some(constructors) => constructors.iter().find_map(|constructor| {
+            let types: Vec<Type> = constructor.fields.iter().chain(rest).cloned().collect();
+            let mut witness = uncovered(symbols, specialize(&rows, constructor), &types)?;
+            let tail = witness.split_off(constructor.fields.len());
+            Some(std::iter::once(constructor.apply(witness)).chain(tail).collect())
+        }),
+        // Otherwise literals can't cover every value, only rows starting with a catch-all help
+        None => {
+            let rows = rows
+                .into_iter()
+                .filter(|row| row[0].is_irrefutable())
+                .map(|row| row[1..].to_vec())
+                .collect();
+            let witness = uncovered(symbols, rows, rest)?;
+            Some(std::iter::once(Pattern::Wildcard).chain(witness).collect())
+        }
+    }
+}
+
+/// True if `row` matches a row of values, of the given types, that no row of `rows` matches
+fn useful(symbols: &SymbolTable, rows: &[Vec<Pattern>], row: Vec<Pattern>, types: &[Type]) -> bool {
+    let Some((ty, rest)) = types.split_first() else {
+        return rows.is_empty();
+    };
+
+    match constructors(symbols, ty) {
+        Some(constructors) => constructors
+            .iter()
+            .filter(|constructor| row[0].is_irrefutable() || constructor.matches(&row[0]))
+            .any(|constructor| {
+                let types: Vec<Type> = constructor.fields.iter().chain(rest).cloned().collect();
+                let row = specialize(std::slice::from_ref(&row), constructor).remove(0);
+                useful(symbols, &specialize(rows, constructor), row, &types)
+            }),
+        // Literals can't cover every value, so a catch-all is only hidden by another one
+        None => {
+            let rows: Vec<Vec<Pattern>> = rows
+                .iter()
+                .filter(|other| other[0].is_irrefutable() || other[0] == row[0])
+                .map(|other| other[1..].to_vec())
+                .collect();
+            useful(symbols, &rows, row[1..].to_vec(), rest)
+        }
+    }
+}
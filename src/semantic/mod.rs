@@ -0,0 +1,22 @@
+/*
+ * -------------------------------------------------------------------------
+ * RemyLang — semantic module
+ * File : src/semantic/mod.rs
+ *
+ * Description :
+ *   Semantic analysis (symbol resolution and type checking) run between
+ *   parsing and execution.
+ *
+ * Author  : Samuel 'Meeast' Bleau
+ * Created : 2026-10-16
+ *
+ * -------------------------------------------------------------------------
+*/
+
+pub mod symbol_table;
+pub mod type_checker;
+pub mod exhaustiveness;
+
+// Re-exports
+pub use symbol_table::{Declaration, Reference, Symbol, SymbolTable, StructInfo, EnumInfo};
+pub use type_checker::{TypeChecker, TypeError};
@@ -1,4 +1,19 @@
+/*
+ * -------------------------------------------------------------------------
+ * RemyLang — symbol table
+ * File : src/semantic/symbol_table.rs
+ *
+ * Description :
+ *   Scoped symbol storage used by the type checker.
+ *
+ * Author  : Samuel 'Meeast' Bleau
+ * Created : 2026-10-16
+ *
+ * -------------------------------------------------------------------------
+*/
+
 use crate::ast::Type;
+use crate::lexer::Span;
 use std::collections::HashMap;
 
 
@@ -12,33 +27,113 @@ pub enum  Symbol {
     },
 }
 
+impl Symbol {
+    /// Type of the symbol used as a value, functions having a function type
+    pub fn value_type(&self) -> Type {
+        match self {
+            Symbol::Variable(ty) => ty.clone(),
+            Symbol::Function { params, return_type } => Type::Function {
+                params: params.clone(),
+                return_type: Box::new(return_type.clone()),
+            },
+        }
+    }
+}
+
+/// A variable or function declared in the checked code
+#[derive(Debug, Clone, PartialEq)]
+pub struct Declaration {
+    pub name: String,
+    pub ty: Type,
+    /// The statement or expression declaring it, whose name is somewhere inside
+    pub span: Span,
+}
+
+/// A use of a variable or function in the checked code
+#[derive(Debug, Clone, PartialEq)]
+pub struct Reference {
+    pub name: String,
+    pub ty: Type,
+    /// Where the name is used
+    pub span: Span,
+    /// Span of the declaration it refers to
+    pub declaration: Span,
+}
+
+/// A struct declaration
+#[derive(Debug, Clone, PartialEq, Default)]
+pub struct StructInfo {
+    pub fields: Vec<(String, Type)>,
+}
+
+impl StructInfo {
+    /// Type of a field, if the struct has it
+    pub fn field(&self, name: &str) -> Option<&Type> {
+        self.fields.iter().find(|(field, _)| field == name).map(|(_, ty)| ty)
+    }
+}
+
+/// An enum declaration, with the field types of each variant in order
+#[derive(Debug, Clone, PartialEq, Default)]
+pub struct EnumInfo {
+    pub variants: Vec<(String, Vec<Type>)>,
+}
+
+impl EnumInfo {
+    /// Field types of a variant, if the enum has it
+    pub fn variant(&self, name: &str) -> Option<&[Type]> {
+        self.variants.iter().find(|(variant, _)| variant == name).map(|(_, fields)| fields.as_slice())
+    }
+}
+
+#[derive(Clone)]
 pub struct SymbolTable {
     scopes: Vec<HashMap<String, Symbol>>,
+    /// Where the symbols of each scope are declared
+    locations: Vec<HashMap<String, Span>>,
+    /// Types are declared at the top level, so they are not scoped
+    structs: HashMap<String, StructInfo>,
+    enums: HashMap<String, EnumInfo>,
+    /// Method signatures by type name, without the implicit `self` parameter
+    methods: HashMap<String, HashMap<String, Symbol>>,
 }
 
 impl SymbolTable {
     pub fn new() -> Self {
         Self {
             scopes: vec![HashMap::new()],
+            locations: vec![HashMap::new()],
+            structs: HashMap::new(),
+            enums: HashMap::new(),
+            methods: HashMap::new(),
         }
     }
 
     pub fn enter_scope(&mut self) {
         self.scopes.push(HashMap::new());
+        self.locations.push(HashMap::new());
     }
 
     pub fn exit_scope(&mut self) {
         if self.scopes.len() > 1 {
             self.scopes.pop();
+            self.locations.pop();
         }
     }
 
-    pub fn define(&mut self, name: String, symbol: Symbol) {
-        if let Some(scope) = self.scopes.last_mut() {
+    /// Declares a symbol in the innermost scope, `span` being its declaration
+    pub fn define(&mut self, name: String, symbol: Symbol, span: &Span) {
+        if let (Some(scope), Some(locations)) = (self.scopes.last_mut(), self.locations.last_mut()) {
+            locations.insert(name.clone(), span.clone());
             scope.insert(name, symbol);
         }
     }
 
+    /// Symbols of the outermost scope, by name
+    pub fn globals(&self) -> &HashMap<String, Symbol> {
+        &self.scopes[0]
+    }
+
     pub fn get(&self, name: &str) -> Option<&Symbol> {
         for scope in self.scopes.iter().rev() {
             if let Some(symbol) = scope.get(name) {
@@ -47,4 +142,55 @@ impl SymbolTable {
         }
         None
     }
-}
\ No newline at end of file
+
+    /// Declaration of the symbol `get` finds
+    pub fn location(&self, name: &str) -> Option<&Span> {
+        let depth = self.scopes.iter().rposition(|scope| scope.contains_key(name))?;
+        self.locations[depth].get(name)
+    }
+
+    /// Every symbol that can be named here, inner declarations hiding outer ones
+    pub fn visible(&self) -> Vec<(String, Symbol)> {
+        let mut visible: HashMap<&String, &Symbol> = HashMap::new();
+        for scope in &self.scopes {
+            visible.extend(scope);
+        }
+        visible.into_iter().map(|(name, symbol)| (name.clone(), symbol.clone())).collect()
+    }
+
+    pub fn define_struct(&mut self, name: String, info: StructInfo) {
+        self.structs.insert(name, info);
+    }
+
+    pub fn get_struct(&self, name: &str) -> Option<&StructInfo> {
+        self.structs.get(name)
+    }
+
+    pub fn define_enum(&mut self, name: String, info: EnumInfo) {
+        self.enums.insert(name, info);
+    }
+
+    pub fn get_enum(&self, name: &str) -> Option<&EnumInfo> {
+        self.enums.get(name)
+    }
+
+    /// True if `name` is a declared struct or enum
+    pub fn has_type(&self, name: &str) -> bool {
+        self.structs.contains_key(name) || self.enums.contains_key(name)
+    }
+
+    /// Registers a method, returning false if the type already had one with that name
+    pub fn define_method(&mut self, type_name: &str, name: String, signature: Symbol) -> bool {
+        self.methods.entry(type_name.to_string()).or_default().insert(name, signature).is_none()
+    }
+
+    pub fn get_method(&self, type_name: &str, name: &str) -> Option<&Symbol> {
+        self.methods.get(type_name)?.get(name)
+    }
+}
+
+impl Default for SymbolTable {
+    fn default() -> Self {
+        Self::new()
+    }
+}
@@ -1,13 +1,36 @@
-use crate::ast::*;
-use crate::semantic::symbol_table::{Symbol, SymbolTable};
+/*
+ * -------------------------------------------------------------------------
+ * RemyLang — type checker
+ * File : src/semantic/type_checker.rs
+ *
+ * Description :
+ *   Static type checking pass run on the AST before execution.
+ *
+ * Author  : Samuel 'Meeast' Bleau
+ * Created : 2026-10-16
+ *
+ * -------------------------------------------------------------------------
+*/
+
 use std::collections::HashMap;
+use std::fmt;
+use crate::ast::*;
+use crate::lexer::Span;
+use crate::semantic::exhaustiveness::{missing_patterns, unreachable_patterns};
+use crate::semantic::symbol_table::{Declaration, EnumInfo, Reference, StructInfo, Symbol, SymbolTable};
+use crate::stdlib::{self, Returns};
+use crate::vm::builtin;
 
 #[derive(Debug, Clone, PartialEq)]
 pub enum TypeError {
     UndefinedVariable(String),
     TypeMismatch { expected: Type, found: Type },
     InvalidOperand(String),
-    ReturnTypeMismatch,
+    ReturnTypeMismatch { expected: Type, found: Type },
+    ReturnOutsideFunction,
+    BreakOutsideLoop,
+    ContinueOutsideLoop,
+    NotIterable { found: Type },
     CannotAssignToFunction(String),
     ArgumentCountMismatch { expected: usize, found: usize },
     ArgumentTypeMismatch { position: usize, expected: Type, found: Type },
@@ -21,12 +44,198 @@ pub enum TypeError {
     NotIndexable { found: Type },
     MissingReturn(String),
     VoidValueNotAllowed,
+    VoidTypeNotAllowed,
+    UndefinedType(String),
+    /// Struct containing itself through `field`, without an array in between
+    RecursiveStruct { name: String, field: String },
+    UndefinedField { found: Type, field: String },
+    UndefinedMethod { found: Type, method: String },
+    MissingField { type_name: String, field: String },
+    DuplicateField(String),
+    DuplicateDefinition(String),
+    UndefinedVariant { enum_name: String, variant: String },
+    VariantFieldCount { variant: String, expected: usize, found: usize },
+    /// Argument of a native function of a kind it does not take, like `len(1)`
+    InvalidArgument { function: String, position: usize, expected: String, found: Type },
+    /// Patterns of the values no arm matches
+    NonExhaustiveMatch { missing: Vec<String> },
+    /// Arm of a match whose values are all matched by the arms before it
+    UnreachableArm { pattern: String },
+    /// Binding pattern named like a variant of the matched enum, a warning
+    BindingNamedLikeVariant { name: String, enum_name: String },
+
+    /// Error annotated with the source location that raised it
+    Located {
+        span: Span,
+        error: Box<TypeError>,
+    },
 }
 
+impl TypeError {
+    /// Attaches a location to the error, unless it already has one
+    pub fn with_span(self, span: &Span) -> Self {
+        match self {
+            TypeError::Located { .. } => self,
+            error => TypeError::Located {
+                span: span.clone(),
+                error: Box::new(error),
+            },
+        }
+    }
+
+    /// Location of the error, if known
+    pub fn span(&self) -> Option<&Span> {
+        match self {
+            TypeError::Located { span, .. } => Some(span),
+            _ => None,
+        }
+    }
+
+    /// The underlying error, without its location
+    pub fn kind(&self) -> &TypeError {
+        match self {
+            TypeError::Located { error, .. } => error.kind(),
+            error => error,
+        }
+    }
+
+    /// Description of the error, without its location
+    pub fn message(&self) -> String {
+        match self.kind() {
+            TypeError::UndefinedVariable(name) => {
+                format!("Undefined variable '{}'", name)
+            }
+            TypeError::TypeMismatch { expected, found } => {
+                format!("Expected {}, found {}", expected, found)
+            }
+            TypeError::InvalidOperand(msg) => {
+                msg.clone()
+            }
+            TypeError::ReturnTypeMismatch { expected, found } => {
+                format!("Function should return {}, but returns {}", expected, found)
+            }
+            TypeError::ReturnOutsideFunction => {
+                "Return statement outside of function".to_string()
+            }
+            TypeError::BreakOutsideLoop => {
+                "Break statement outside of loop".to_string()
+            }
+            TypeError::ContinueOutsideLoop => {
+                "Continue statement outside of loop".to_string()
+            }
+            TypeError::NotIterable { found } => {
+                format!("Cannot iterate over value of type {}", found)
+            }
+            TypeError::CannotAssignToFunction(name) => {
+                format!("Cannot assign to function '{}'", name)
+            }
+            TypeError::ArgumentCountMismatch { expected, found } => {
+                format!("Expected {} arguments, but {} were provided", expected, found)
+            }
+            TypeError::ArgumentTypeMismatch { position, expected, found } => {
+                format!("Argument {} should be {}, found {}", position + 1, expected, found)
+            }
+            TypeError::NotCallable => {
+                "Value is not callable".to_string()
+            }
+            TypeError::InvalidCallTarget => {
+                "Can only call functions by name".to_string()
+            }
+            TypeError::InvalidUnaryOperand { op, operand_type } => {
+                format!("Invalid operand of type {} for unary {:?}", operand_type, op)
+            }
+            TypeError::InvalidBinaryOperands { op, left, right } => {
+                format!("Invalid operands for {:?}: {} and {}", op, left, right)
+            }
+            TypeError::EmptyArrayLiteral => {
+                "Cannot infer the type of an empty array".to_string()
+            }
+            TypeError::HeterogeneousArray { expected, found } => {
+                format!("Array elements should be {}, found {}", expected, found)
+            }
+            TypeError::InvalidIndexType { found } => {
+                format!("Array index should be Int, found {}", found)
+            }
+            TypeError::NotIndexable { found } => {
+                format!("Cannot index into value of type {}", found)
+            }
+            TypeError::MissingReturn(name) => {
+                format!("Function '{}' does not return a value on every path", name)
+            }
+            TypeError::VoidValueNotAllowed => {
+                "Cannot use a Void value".to_string()
+            }
+            TypeError::VoidTypeNotAllowed => {
+                "Variables cannot have type Void".to_string()
+            }
+            TypeError::UndefinedType(name) => {
+                format!("Undefined type '{}'", name)
+            }
+            TypeError::RecursiveStruct { name, field } => {
+                format!("Struct '{}' contains itself through its field '{}'", name, field)
+            }
+            TypeError::UndefinedField { found, field } => {
+                format!("Type {} has no field '{}'", found, field)
+            }
+            TypeError::UndefinedMethod { found, method } => {
+                format!("Type {} has no method '{}'", found, method)
+            }
+            TypeError::MissingField { type_name, field } => {
+                format!("Missing field '{}' in {} literal", field, type_name)
+            }
+            TypeError::DuplicateField(field) => {
+                format!("Field '{}' is given more than once", field)
+            }
+            TypeError::DuplicateDefinition(name) => {
+                format!("'{}' is already defined", name)
+            }
+            TypeError::UndefinedVariant { enum_name, variant } => {
+                format!("Enum {} has no variant '{}'", enum_name, variant)
+            }
+            TypeError::VariantFieldCount { variant, expected, found } => {
+                format!("Variant {} has {} fields, but {} were provided", variant, expected, found)
+            }
+            TypeError::InvalidArgument { function, position, expected, found } => {
+                format!("Argument {} of {} should be {}, found {}", position + 1, function, expected, found)
+            }
+            TypeError::NonExhaustiveMatch { missing } => {
+                let verb = if missing.len() == 1 { "is" } else { "are" };
+                format!("Match is not exhaustive: {} {} not covered", missing.join(", "), verb)
+            }
+            TypeError::UnreachableArm { pattern } => {
+                format!("Unreachable arm: the arms before it already match every value of '{}'", pattern)
+            }
+            TypeError::BindingNamedLikeVariant { name, enum_name } => {
+                format!("'{}' binds any value, it does not match the variant {}::{}", name, enum_name, name)
+            }
+            TypeError::Located { .. } => unreachable!("kind() strips locations"),
+        }
+    }
+}
+
+impl fmt::Display for TypeError {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        match self {
+            TypeError::Located { span, error } => write!(f, "{}: {}", span, error),
+            _ => write!(f, "Type error: {}", self.message()),
+        }
+    }
+}
+
+impl std::error::Error for TypeError {}
+
+#[derive(Clone)]
 pub struct TypeChecker {
     symbol_table: SymbolTable,
     current_function_return_type: Option<Type>,
+    loop_depth: usize,
     errors: Vec<TypeError>,
+    warnings: Vec<TypeError>,
+    declarations: Vec<Declaration>,
+    references: Vec<Reference>,
+    /// Offset whose visible symbols are recorded, see `probe_scope`
+    scope_probe: Option<usize>,
+    probed_scope: Vec<(String, Symbol)>,
 }
 
 impl TypeChecker {
@@ -34,150 +243,366 @@ impl TypeChecker {
         Self {
             symbol_table: SymbolTable::new(),
             current_function_return_type: None,
+            loop_depth: 0,
             errors: Vec::new(),
+            warnings: Vec::new(),
+            declarations: Vec::new(),
+            references: Vec::new(),
+            scope_probe: None,
+            probed_scope: Vec::new(),
         }
     }
 
+    /// Checks a whole program, collecting every error instead of stopping at the first one
     pub fn check_program(&mut self, stmts: &[Stmt]) -> Result<(), Vec<TypeError>> {
+        self.warnings.clear();
+        // Declare types and top-level functions first so they can refer to each other
+        self.declare_types(stmts);
+        self.declare_functions(stmts);
+        self.record_scope(0);
+        self.check_stmts(stmts);
+
+        if self.errors.is_empty() {
+            Ok(())
+        } else {
+            Err(std::mem::take(&mut self.errors))
+        }
+    }
+
+    /// Warnings found by the last `check_program`, which don't stop the program from running
+    pub fn warnings(&self) -> &[TypeError] {
+        &self.warnings
+    }
+
+    /// Type of an expression, using what the statements checked so far declared
+    pub fn type_of(&mut self, expr: &Expr) -> Result<Type, TypeError> {
+        self.infer_expr(expr)
+    }
+
+    /// Variables and functions declared at the top level of the checked statements
+    pub fn globals(&self) -> &HashMap<String, Symbol> {
+        self.symbol_table.globals()
+    }
+
+    /// Every variable and function declared in the checked statements, in order
+    pub fn declarations(&self) -> &[Declaration] {
+        &self.declarations
+    }
+
+    /// Every use of a declared variable or function in the checked statements
+    pub fn references(&self) -> &[Reference] {
+        &self.references
+    }
+
+    /// Asks the next `check_program` to record the symbols visible at `offset`
+    /// They are found with `probed_scope`, for completion in an editor.
+    pub fn probe_scope(&mut self, offset: usize) {
+        self.scope_probe = Some(offset);
+    }
+
+    /// Symbols visible at the offset given to `probe_scope`, in no particular order
+    pub fn probed_scope(&self) -> &[(String, Symbol)] {
+        &self.probed_scope
+    }
+
+    /// Declares a variable or function in the current scope
+    fn declare(&mut self, name: &str, symbol: Symbol, span: &Span) {
+        self.declarations.push(Declaration {
+            name: name.to_string(),
+            ty: symbol.value_type(),
+            span: span.clone(),
+        });
+        self.symbol_table.define(name.to_string(), symbol, span);
+    }
+
+    /// Records the visible symbols if checking has not gone past the probed offset
+    /// Called before and after each statement, the last call before the offset wins.
+    fn record_scope(&mut self, reached: usize) {
+        if self.scope_probe.is_some_and(|offset| reached <= offset) {
+            self.probed_scope = self.symbol_table.visible();
+        }
+    }
+
+    /// Registers the signature of every function declared in `stmts`
+    fn declare_functions(&mut self, stmts: &[Stmt]) {
         for stmt in stmts {
-            if let Err(e) = self.check_stmt(stmt) {
-                self.errors.push(e);
+            if let StmtKind::FunctionDecl { name, params, return_type, .. } = &stmt.kind {
+                let param_types: Vec<Type> = params.iter().map(|p| p.type_annotation.clone()).collect();
+                let symbol = Symbol::Function {
+                    params: param_types,
+                    return_type: return_type.clone().unwrap_or(Type::Void),
+                };
+                self.declare(name, symbol, &stmt.span);
             }
         }
+    }
 
-        if self.errors.is_empty() {
-            Ok(());
-        } else {
-            Err(self.errors.clone())
+    /// Registers every struct and enum declared in `stmts`, then the methods of their impl blocks
+    fn declare_types(&mut self, stmts: &[Stmt]) {
+        for stmt in stmts {
+            let name = match &stmt.kind {
+                StmtKind::StructDecl { name, .. } | StmtKind::EnumDecl { name, .. } => name,
+                _ => continue,
+            };
+            if self.symbol_table.has_type(name) {
+                self.errors.push(TypeError::DuplicateDefinition(name.clone()).with_span(&stmt.span));
+                continue;
+            }
+            match &stmt.kind {
+                StmtKind::StructDecl { fields, .. } => {
+                    let fields = fields.iter().map(|f| (f.name.clone(), f.type_annotation.clone())).collect();
+                    self.symbol_table.define_struct(name.clone(), StructInfo { fields });
+                }
+                StmtKind::EnumDecl { variants, .. } => {
+                    let variants = variants.iter().map(|v| (v.name.clone(), v.fields.clone())).collect();
+                    self.symbol_table.define_enum(name.clone(), EnumInfo { variants });
+                }
+                _ => {}
+            }
+        }
+
+        // A struct holding itself directly would be infinite, arrays can be empty
+        let mut checked = Vec::new();
+        for stmt in stmts {
+            let StmtKind::StructDecl { name, .. } = &stmt.kind else { continue };
+            if checked.contains(name) {
+                continue;
+            }
+            checked.push(name.clone());
+            let fields = self.symbol_table.get_struct(name).map(|info| info.fields.clone()).unwrap_or_default();
+            let recursive = fields.iter().find(|(_, ty)| self.struct_contains(ty, name, &mut Vec::new()));
+            if let Some((field, _)) = recursive {
+                let error = TypeError::RecursiveStruct { name: name.clone(), field: field.clone() };
+                self.errors.push(error.with_span(&stmt.span));
+            }
+        }
+
+        for stmt in stmts {
+            let StmtKind::Impl { name, methods } = &stmt.kind else { continue };
+            // Impl blocks of unknown types are reported when they are checked
+            if !self.symbol_table.has_type(name) {
+                continue;
+            }
+            for method in methods {
+                if let StmtKind::FunctionDecl { name: method_name, params, return_type, .. } = &method.kind {
+                    let signature = Symbol::Function {
+                        params: params.iter().map(|p| p.type_annotation.clone()).collect(),
+                        return_type: return_type.clone().unwrap_or(Type::Void),
+                    };
+                    if !self.symbol_table.define_method(name, method_name.clone(), signature) {
+                        let duplicate = format!("{}.{}", name, method_name);
+                        self.errors.push(TypeError::DuplicateDefinition(duplicate).with_span(&method.span));
+                    }
+                }
+            }
+        }
+    }
+
+    /// True if a value of type `ty` holds a `target` struct, directly or through struct fields
+    /// `visited` holds the structs already looked into.
+    fn struct_contains(&self, ty: &Type, target: &str, visited: &mut Vec<String>) -> bool {
+        let Type::Named(name) = ty else { return false };
+        if name == target {
+            return true;
+        }
+        if visited.contains(name) {
+            return false;
+        }
+        visited.push(name.clone());
+        let Some(info) = self.symbol_table.get_struct(name) else { return false };
+        info.fields.iter().any(|(_, field_type)| self.struct_contains(field_type, target, visited))
+    }
+
+    /// Checks that every struct or enum named in a type is declared
+    fn check_type(&self, ty: &Type) -> Result<(), TypeError> {
+        match ty {
+            Type::Named(name) if !self.symbol_table.has_type(name) => {
+                Err(TypeError::UndefinedType(name.clone()))
+            }
+            Type::Array(inner) => self.check_type(inner),
+            Type::Function { params, return_type } => {
+                params.iter().try_for_each(|param| self.check_type(param))?;
+                self.check_type(return_type)
+            }
+            _ => Ok(()),
+        }
+    }
+
+    /// Checks a list of statements, recording errors and carrying on after each one
+    fn check_stmts(&mut self, stmts: &[Stmt]) {
+        for stmt in stmts {
+            if let Err(e) = self.check_stmt(stmt) {
+                self.errors.push(e);
+            }
         }
     }
 
     fn has_return(&self, stmt: &Stmt) -> bool {
-        match stmt {
-            Stmt::Return(_) => true,
-            Stmt::Block(stmts) => stmts.iter().any(|s| self.has_return(s)),
-            Stmt::If { then_branch, else_branch, .. } => {
-                self.has_return(then_branch) && else_branch.as_ref().map_or(false, |e| self.has_return(e))
+        match &stmt.kind {
+            StmtKind::Return(_) => true,
+            StmtKind::Block(stmts) => stmts.iter().any(|s| self.has_return(s)),
+            StmtKind::If { then_branch, else_branch, .. } => {
+                self.has_return(then_branch) && else_branch.as_ref().is_some_and(|e| self.has_return(e))
+            }
+            // Matches are exhaustive, so one arm always runs
+            StmtKind::Match { arms, .. } => {
+                !arms.is_empty() && arms.iter().all(|arm| self.has_return(&arm.body))
             }
             _ => false,
         }
     }
 
     fn check_stmt(&mut self, stmt: &Stmt) -> Result<(), TypeError> {
-        match stmt {
-            Stmt::Let { name, type_annotation, value } => {
-                let value_type = self.infer_expr(value)?;
+        self.record_scope(stmt.span.start);
+        let result = self.check_stmt_kind(stmt).map_err(|e| e.with_span(&stmt.span));
+        self.record_scope(stmt.span.end);
+        result
+    }
 
-                if value_type == Type::Void {
-                    return Err(TypeError::VoidValueNotAllowed);
+    fn check_stmt_kind(&mut self, stmt: &Stmt) -> Result<(), TypeError> {
+        match &stmt.kind {
+            StmtKind::Let { name, type_annotation, value } => {
+                if type_annotation == &Some(Type::Void) {
+                    return Err(TypeError::VoidTypeNotAllowed);
+                }
+                if let Some(ty) = type_annotation {
+                    self.check_type(ty)?;
                 }
 
-                if let Some(expected_type) = type_annotation {
-                    if expected_type == &Type::Void {
-                        return Err(TypeError::VoidTypeNotAllowed);
-                    }
-                    if expected_type != &value_type {
-                        return Err(TypeError::TypeMismatch { expected: expected_type.clone(), found: value_type.clone() });
-                    }
+                let result = self.check_let_value(type_annotation.as_ref(), value);
+
+                // Declare the variable even on error, so later uses don't cascade
+                let declared_type = match (&result, type_annotation) {
+                    (Ok(value_type), _) => Some(value_type.clone()),
+                    (Err(_), annotation) => annotation.clone(),
+                };
+                if let Some(ty) = declared_type {
+                    self.declare(name, Symbol::Variable(ty), &stmt.span);
                 }
-                self.symbol_table.define(name.clone(), Symbol::Variable(value_type));
-                Ok(())
+                result.map(|_| ())
             }
 
-            Stmt::Return(expr_opt) => {
+            StmtKind::Return(expr_opt) => {
+                let expected = self
+                    .current_function_return_type
+                    .clone()
+                    .ok_or(TypeError::ReturnOutsideFunction)?;
+
                 let return_type = match expr_opt {
-                    Some(expr) => self.infer_expr(expr)?,
+                    Some(expr) => self.check_expr(expr, &expected)?,
                     None => Type::Void,
                 };
 
-                if let Some(expected) = &self.current_function_return_type {
-                    if expected != &return_type {
-                        return Err(TypeError::ReturnTypeMismatch);
-                    }
+                if expected != return_type {
+                    return Err(TypeError::ReturnTypeMismatch { expected, found: return_type });
                 }
                 Ok(())
             }
 
-            Stmt::FunctionDecl { name, params, return_type, body } => {
+            StmtKind::FunctionDecl { name, params, return_type, body } => {
                 // Register the function in the current scope first
+                // Its declaration was recorded with the functions of the block.
                 let param_types: Vec<Type> = params.iter().map(|p| p.type_annotation.clone()).collect();
                 self.symbol_table.define(
                     name.clone(),
                     Symbol::Function {
                         params: param_types,
                         return_type: return_type.clone().unwrap_or(Type::Void),
-                    }
+                    },
+                    &stmt.span,
                 );
 
-                self.symbol_table.enter_scope();
-                self.current_function_return_type = return_type.clone();
+                self.check_function(name, params, return_type.as_ref(), body, None, &stmt.span)
+            }
 
-                for param in params {
-                    self.symbol_table.define(
-                        param.name.clone(),
-                        Symbol::Variable(param.type_annotation.clone()),
-                    )
+            StmtKind::StructDecl { name: _, fields } => {
+                for (i, field) in fields.iter().enumerate() {
+                    if fields[..i].iter().any(|other| other.name == field.name) {
+                        return Err(TypeError::DuplicateField(field.name.clone()));
+                    }
+                    if field.type_annotation == Type::Void {
+                        return Err(TypeError::VoidTypeNotAllowed);
+                    }
+                    self.check_type(&field.type_annotation)?;
                 }
+                Ok(())
+            }
 
-                self.check_stmt(body)?;
-
-                if let Some(ret_type) = return_type {
-                    if ret_type != &Type::Void && !self.has_return(body) {
-                        return Err(TypeError::MissingReturn(name.clone()));
+            StmtKind::EnumDecl { name, variants } => {
+                for (i, variant) in variants.iter().enumerate() {
+                    if variants[..i].iter().any(|other| other.name == variant.name) {
+                        return Err(TypeError::DuplicateDefinition(format!("{}::{}", name, variant.name)));
+                    }
+                    for ty in &variant.fields {
+                        if ty == &Type::Void {
+                            return Err(TypeError::VoidTypeNotAllowed);
+                        }
+                        self.check_type(ty)?;
                     }
                 }
+                Ok(())
+            }
 
-                self.symbol_table.exit_scope();
-                self.current_function_return_type = None;
+            StmtKind::Impl { name, methods } => {
+                if !self.symbol_table.has_type(name) {
+                    return Err(TypeError::UndefinedType(name.clone()));
+                }
+
+                let receiver = Type::Named(name.clone());
+                for method in methods {
+                    if let StmtKind::FunctionDecl { name: method_name, params, return_type, body } = &method.kind {
+                        let result = self.check_function(
+                            method_name,
+                            params,
+                            return_type.as_ref(),
+                            body,
+                            Some(&receiver),
+                            &method.span,
+                        );
+                        if let Err(e) = result {
+                            self.errors.push(e.with_span(&method.span));
+                        }
+                    }
+                }
                 Ok(())
             }
 
-            Stmt::Block(stmts) => {
+            StmtKind::Block(stmts) => {
                 self.symbol_table.enter_scope();
-                for stmt in stmts {
-                    self.check_stmt(stmt)?;
-                }
+                self.declare_functions(stmts);
+                self.check_stmts(stmts);
                 self.symbol_table.exit_scope();
                 Ok(())
             }
 
-            Stmt::Expression(expr) => {
+            StmtKind::Expression(expr) => {
                 self.infer_expr(expr)?;
                 Ok(())
             }
 
-            Stmt::Assignment { name, value } => {
-                let symbol = self.symbol_table.get(name)
-                    .ok_or_else(|| TypeError::UndefinedVariable(name.clone()))?;
-
-                let var_type = match symbol {
-                    Symbol::Variable(ty) => ty.clone(),
-                    Symbol::Function { .. } => {
-                        return Err(TypeError::CannotAssignToFunction(name.clone()));
-                    }
-                };
-
-                let value_type = self.infer_expr(value)?;
+            StmtKind::Assignment { target, op, value } => {
+                let target_type = self.infer_target(target)?;
+                // The statement starts with the name of the assigned variable
+                let name = target.name();
+                let name_span = Span { end: stmt.span.start + name.chars().count(), ..stmt.span.clone() };
+                self.record_reference(name, &name_span);
+                let mut value_type = self.check_expr(value, &target_type)?;
+                if let Some(op) = op {
+                    value_type = Self::binary_result(*op, target_type.clone(), value_type)?;
+                }
 
-                if var_type != value_type {
+                if target_type != value_type {
                     return Err(TypeError::TypeMismatch {
-                        expected: var_type,
+                        expected: target_type,
                         found: value_type,
-                    });
+                    }.with_span(&value.span));
                 }
 
                 Ok(())
             }
 
-            Stmt::If { condition, then_branch, else_branch } => {
-                let cond_type = self.infer_expr(condition)?;
-                if cond_type != Type::Bool {
-                    return Err(TypeError::TypeMismatch {
-                        expected: Type::Bool,
-                        found: cond_type,
-                    });
-                }
-
+            StmtKind::If { condition, then_branch, else_branch } => {
+                self.check_condition(condition)?;
                 self.check_stmt(then_branch)?;
 
                 if let Some(else_stmt) = else_branch {
@@ -187,131 +612,557 @@ impl TypeChecker {
                 Ok(())
             }
 
-            _ => Ok(()),
+            StmtKind::While { condition, body } => {
+                self.check_condition(condition)?;
+                self.check_loop_body(body)
+            }
+
+            StmtKind::For { initializer, condition, increment, body } => {
+                self.symbol_table.enter_scope();
+                let result = self.check_for_clauses(initializer.as_deref(), condition.as_ref(), increment.as_deref());
+                let body_result = self.check_loop_body(body);
+                self.symbol_table.exit_scope();
+                result.and(body_result)
+            }
+
+            StmtKind::ForIn { variable, iterable, body } => {
+                let element_type = match self.infer_expr(iterable)? {
+                    Type::Array(elem_type) => *elem_type,
+                    other => {
+                        return Err(TypeError::NotIterable { found: other }.with_span(&iterable.span));
+                    }
+                };
+
+                self.symbol_table.enter_scope();
+                self.declare(variable, Symbol::Variable(element_type), &stmt.span);
+                let result = self.check_loop_body(body);
+                self.symbol_table.exit_scope();
+                result
+            }
+
+            StmtKind::Match { subject, arms } => {
+                let subject_type = self.check_match_subject(subject)?;
+                for arm in arms {
+                    self.symbol_table.enter_scope();
+                    let result = self.check_pattern(&arm.pattern, &subject_type, &arm.span, &mut Vec::new())
+                        .map_err(|e| e.with_span(&arm.span))
+                        .and_then(|_| self.check_stmt(&arm.body));
+                    self.symbol_table.exit_scope();
+                    result?;
+                }
+                self.check_arms(&subject_type, arms)
+            }
+
+            StmtKind::Break => {
+                if self.loop_depth == 0 {
+                    return Err(TypeError::BreakOutsideLoop);
+                }
+                Ok(())
+            }
+
+            StmtKind::Continue => {
+                if self.loop_depth == 0 {
+                    return Err(TypeError::ContinueOutsideLoop);
+                }
+                Ok(())
+            }
         }
     }
 
-    fn infer_expr(&mut self, expr: &Expr) -> Result<Type, TypeError> {
-        match expr {
-            Expr::Literal(Literal::Number(_)) => Ok(Type::Int),
-            Expr::Literal(Literal::Bool(_)) => Ok(Type::Bool),
-            Expr::Literal(Literal::String(_)) => Ok(Type::String),
-            Expr::Variable(name) => {
+    /// Checks the body of a function or method, declared at `span`
+    /// Methods see the value they are called on as `self`, of type `receiver`.
+    fn check_function(
+        &mut self,
+        name: &str,
+        params: &[Param],
+        return_type: Option<&Type>,
+        body: &Stmt,
+        receiver: Option<&Type>,
+        span: &Span,
+    ) -> Result<(), TypeError> {
+        for param in params {
+            self.check_type(&param.type_annotation)?;
+        }
+        if let Some(ty) = return_type {
+            self.check_type(ty)?;
+        }
+
+        self.symbol_table.enter_scope();
+        let enclosing_return_type = self
+            .current_function_return_type
+            .replace(return_type.cloned().unwrap_or(Type::Void));
+        // Loops around the declaration don't extend into its body
+        let enclosing_loop_depth = std::mem::take(&mut self.loop_depth);
+
+        if let Some(receiver) = receiver {
+            self.declare("self", Symbol::Variable(receiver.clone()), span);
+        }
+        for param in params {
+            self.declare(&param.name, Symbol::Variable(param.type_annotation.clone()), span);
+        }
+
+        let result = self.check_stmt(body);
+
+        self.symbol_table.exit_scope();
+        self.current_function_return_type = enclosing_return_type;
+        self.loop_depth = enclosing_loop_depth;
+        result?;
+
+        if let Some(ret_type) = return_type {
+            if ret_type != &Type::Void && !self.has_return(body) {
+                return Err(TypeError::MissingReturn(name.to_string()));
+            }
+        }
+        Ok(())
+    }
+
+    /// Type of the value a match is on
+    fn check_match_subject(&mut self, subject: &Expr) -> Result<Type, TypeError> {
+        let subject_type = self.infer_expr(subject)?;
+        if subject_type == Type::Void {
+            return Err(TypeError::VoidValueNotAllowed.with_span(&subject.span));
+        }
+        Ok(subject_type)
+    }
+
+    /// Checks that `pattern` can match a value of type `ty`, and declares the names it binds
+    /// `span` is the whole pattern of the arm, and `bound` holds the names already
+    /// bound by its enclosing patterns.
+    fn check_pattern(
+        &mut self,
+        pattern: &Pattern,
+        ty: &Type,
+        span: &Span,
+        bound: &mut Vec<String>,
+    ) -> Result<(), TypeError> {
+        match pattern {
+            Pattern::Wildcard => Ok(()),
+            Pattern::Binding(name) => {
+                if bound.contains(name) {
+                    return Err(TypeError::DuplicateDefinition(name.clone()));
+                }
+                bound.push(name.clone());
+                if let Type::Named(enum_name) = ty {
+                    if self.symbol_table.get_enum(enum_name).is_some_and(|info| info.variant(name).is_some()) {
+                        self.warnings.push(TypeError::BindingNamedLikeVariant {
+                            name: name.clone(),
+                            enum_name: enum_name.clone(),
+                        }.with_span(span));
+                    }
+                }
+                self.declare(name, Symbol::Variable(ty.clone()), span);
+                Ok(())
+            }
+            Pattern::Literal(literal) => {
+                let literal_type = match literal {
+                    Literal::Number(_) => Type::Int,
+                    Literal::Float(_) => Type::Float,
+                    Literal::Bool(_) => Type::Bool,
+                    Literal::String(_) => Type::String,
+                    Literal::Char(_) => Type::Char,
+                };
+                if &literal_type != ty {
+                    return Err(TypeError::TypeMismatch { expected: ty.clone(), found: literal_type });
+                }
+                Ok(())
+            }
+            Pattern::Variant { enum_name, variant, fields } => {
+                let info = self.symbol_table.get_enum(enum_name)
+                    .ok_or_else(|| TypeError::UndefinedType(enum_name.clone()))?;
+                let enum_type = Type::Named(enum_name.clone());
+                if &enum_type != ty {
+                    return Err(TypeError::TypeMismatch { expected: ty.clone(), found: enum_type });
+                }
+                let field_types = info.variant(variant)
+                    .ok_or_else(|| TypeError::UndefinedVariant {
+                        enum_name: enum_name.clone(),
+                        variant: variant.clone(),
+                    })?
+                    .to_vec();
+                if field_types.len() != fields.len() {
+                    return Err(TypeError::VariantFieldCount {
+                        variant: format!("{}::{}", enum_name, variant),
+                        expected: field_types.len(),
+                        found: fields.len(),
+                    });
+                }
+                for (field, field_type) in fields.iter().zip(&field_types) {
+                    self.check_pattern(field, field_type, span, bound)?;
+                }
+                Ok(())
+            }
+        }
+    }
+
+    /// Checks that the arms of a match cover every value of type `ty`, each one matching some
+    fn check_arms<T>(&self, ty: &Type, arms: &[MatchArm<T>]) -> Result<(), TypeError> {
+        let patterns: Vec<&Pattern> = arms.iter().map(|arm| &arm.pattern).collect();
+        if let Some(&i) = unreachable_patterns(&self.symbol_table, ty, &patterns).first() {
+            return Err(TypeError::UnreachableArm { pattern: patterns[i].to_string() }.with_span(&arms[i].span));
+        }
+        let missing = missing_patterns(&self.symbol_table, ty, &patterns);
+        if !missing.is_empty() {
+            return Err(TypeError::NonExhaustiveMatch {
+                missing: missing.iter().map(Pattern::to_string).collect(),
+            });
+        }
+        Ok(())
+    }
+
+    /// Checks that a condition is a Bool
+    fn check_condition(&mut self, condition: &Expr) -> Result<(), TypeError> {
+        let cond_type = self.infer_expr(condition)?;
+        if cond_type != Type::Bool {
+            return Err(TypeError::TypeMismatch {
+                expected: Type::Bool,
+                found: cond_type,
+            }.with_span(&condition.span));
+        }
+        Ok(())
+    }
+
+    /// Checks the initializer, condition and increment of a C-style for loop
+    fn check_for_clauses(
+        &mut self,
+        initializer: Option<&Stmt>,
+        condition: Option<&Expr>,
+        increment: Option<&Stmt>,
+    ) -> Result<(), TypeError> {
+        if let Some(init) = initializer {
+            self.check_stmt(init)?;
+        }
+        if let Some(cond) = condition {
+            self.check_condition(cond)?;
+        }
+        if let Some(inc) = increment {
+            self.check_stmt(inc)?;
+        }
+        Ok(())
+    }
+
+    /// Checks a loop body, where break and continue are allowed
+    fn check_loop_body(&mut self, body: &Stmt) -> Result<(), TypeError> {
+        self.loop_depth += 1;
+        let result = self.check_stmt(body);
+        self.loop_depth -= 1;
+        result
+    }
+
+    /// Checks the initializer of a variable declaration against its annotation
+    fn check_let_value(&mut self, annotation: Option<&Type>, value: &Expr) -> Result<Type, TypeError> {
+        let value_type = match annotation {
+            Some(expected) => self.check_expr(value, expected)?,
+            None => self.infer_expr(value)?,
+        };
+
+        if value_type == Type::Void {
+            return Err(TypeError::VoidValueNotAllowed);
+        }
+
+        if let Some(expected) = annotation {
+            if expected != &value_type {
+                return Err(TypeError::TypeMismatch {
+                    expected: expected.clone(),
+                    found: value_type,
+                }.with_span(&value.span));
+            }
+        }
+        Ok(value_type)
+    }
+
+    /// Infers the type of an expression that is expected to have type `expected`
+    /// This lets empty array literals take their element type from the context.
+    fn check_expr(&mut self, expr: &Expr, expected: &Type) -> Result<Type, TypeError> {
+        match (&expr.kind, expected) {
+            (ExprKind::ArrayLiteral(elements), Type::Array(_)) if elements.is_empty() => {
+                Ok(expected.clone())
+            }
+            _ => self.infer_expr(expr),
+        }
+    }
+
+    /// Type of the variable or array element written by an assignment
+    fn infer_target(&mut self, target: &AssignTarget) -> Result<Type, TypeError> {
+        match target {
+            AssignTarget::Variable(name) => {
                 let symbol = self.symbol_table.get(name)
                     .ok_or_else(|| TypeError::UndefinedVariable(name.clone()))?;
 
                 match symbol {
                     Symbol::Variable(ty) => Ok(ty.clone()),
-                    Symbol::Function { .. } => {
-                        Err(TypeError::InvalidOperand(format!("Cannot use function '{}' as a value", name)))
-                    }
+                    Symbol::Function { .. } => Err(TypeError::CannotAssignToFunction(name.clone())),
                 }
             }
-            Expr::Binary { left, op, right } => {
-                let left_type = self.infer_expr(left)?;
-                let right_type = self.infer_expr(right)?;
+            AssignTarget::Index { array, index } => {
+                let array_type = self.infer_target(array)?;
+                let index_type = self.infer_expr(index)?;
 
-                use BinaryOp::*;
-                match op {
-                    // Arithmetic operators : Int -> Int -> Int
-                    Add | Sub | Mul | Div | Mod | Pow => {
-                        if left_type != Type::Int || right_type != Type::Int {
-                            return Err(TypeError::InvalidBinaryOperands {
-                                op: op.clone(),
-                                left: left_type,
-                                right: right_type,
-                            });
-                        }
-                        Ok(Type::Int);
-                    }
+                if index_type != Type::Int {
+                    return Err(TypeError::InvalidIndexType { found: index_type }.with_span(&index.span));
+                }
 
-                    // Comparison operators : Int -> Int -> Bool
-                    Less | Greater | LessEqual | GreaterEqual => {
-                        if left_type != Type::Int || right_type != Type::Int {
-                            return Err(TypeError::InvalidBinaryOperands {
-                                op: op.clone(),
-                                left: left_type,
-                                right: right_type,
-                            });
-                        }
-                        Ok(Type::Bool);
-                    }
+                match array_type {
+                    Type::Array(elem_type) => Ok(*elem_type),
+                    _ => Err(TypeError::NotIndexable { found: array_type }),
+                }
+            }
+            AssignTarget::Field { object, field } => {
+                let object_type = self.infer_target(object)?;
+                self.field_type(object_type, field)
+            }
+        }
+    }
 
-                    // Equality operators : T -> T -> Bool
-                    Equal | NotEqual => {
-                        if left_type != right_type {
-                            return Err(TypeError::InvalidBinaryOperands {
-                                op: op.clone(),
-                                left: left_type,
-                                right: right_type,
-                            });
-                        }
-                        Ok(Type::Bool);
+    /// Type of the field `field` of a value of type `object_type`
+    fn field_type(&self, object_type: Type, field: &str) -> Result<Type, TypeError> {
+        let info = match &object_type {
+            Type::Named(name) => self.symbol_table.get_struct(name),
+            _ => None,
+        };
+        info.and_then(|info| info.field(field).cloned())
+            .ok_or_else(|| TypeError::UndefinedField { found: object_type, field: field.to_string() })
+    }
+
+    /// Checks the arguments of a call against the parameter types of the callee
+    fn check_arguments(&mut self, params: &[Type], args: &[Expr]) -> Result<(), TypeError> {
+        if params.len() != args.len() {
+            return Err(TypeError::ArgumentCountMismatch {
+                expected: params.len(),
+                found: args.len(),
+            });
+        }
+
+        for (i, (arg, expected_type)) in args.iter().zip(params.iter()).enumerate() {
+            let arg_type = self.check_expr(arg, expected_type)?;
+            if &arg_type != expected_type {
+                return Err(TypeError::ArgumentTypeMismatch {
+                    position: i,
+                    expected: expected_type.clone(),
+                    found: arg_type,
+                }.with_span(&arg.span));
+            }
+        }
+        Ok(())
+    }
+
+    /// Type returned by the call of a built-in function
+    /// Built-ins are the native functions of the standard library, whose
+    /// numeric parameters all take the same type in a call.
+    fn check_builtin_call(&mut self, name: &str, args: &[Expr]) -> Result<Type, TypeError> {
+        let signature = &stdlib::lookup(name).expect("built-ins are in the standard library").signature;
+        if !signature.accepts_count(args.len()) {
+            return Err(TypeError::ArgumentCountMismatch { expected: signature.params.len(), found: args.len() });
+        }
+
+        let mut numeric: Option<Type> = None;
+        for (position, arg) in args.iter().enumerate() {
+            let arg_type = self.infer_expr(arg)?;
+            if arg_type == Type::Void {
+                return Err(TypeError::VoidValueNotAllowed.with_span(&arg.span));
+            }
+            let error = match signature.param(position) {
+                Some(stdlib::Param::Type(expected)) if arg_type != *expected => TypeError::ArgumentTypeMismatch {
+                    position,
+                    expected: expected.clone(),
+                    found: arg_type,
+                },
+                Some(stdlib::Param::Numeric) if arg_type.is_numeric() => match &numeric {
+                    Some(expected) if arg_type != *expected => TypeError::ArgumentTypeMismatch {
+                        position,
+                        expected: expected.clone(),
+                        found: arg_type,
+                    },
+                    _ => {
+                        numeric = Some(arg_type);
+                        continue;
                     }
+                },
+                Some(param) if !param.accepts(&arg_type) => TypeError::InvalidArgument {
+                    function: name.to_string(),
+                    position,
+                    expected: param.to_string(),
+                    found: arg_type,
+                },
+                _ => continue,
+            };
+            return Err(error.with_span(&arg.span));
+        }
 
-                    // Logical operators : Bool -> Bool -> Bool
-                    And | Or => {
-                        if left_type != Type::Bool || right_type != Type::Bool {
-                            return Err(TypeError::InvalidBinaryOperands {
-                                op: op.clone(),
-                                left: left_type,
-                                right: right_type,
-                            });
+        Ok(match &signature.returns {
+            Returns::Type(ty) => ty.clone(),
+            Returns::Numeric => numeric.expect("functions returning a number take one"),
+        })
+    }
+
+    /// Type returned by the call of method `method` on `object`
+    fn check_method_call(&mut self, object: &Expr, method: &str, args: &[Expr]) -> Result<Type, TypeError> {
+        let object_type = self.infer_expr(object)?;
+        let signature = match &object_type {
+            Type::Named(name) => self.symbol_table.get_method(name, method).cloned(),
+            _ => None,
+        };
+
+        match signature {
+            Some(Symbol::Function { params, return_type }) => {
+                self.check_arguments(&params, args)?;
+                Ok(return_type)
+            }
+            _ => Err(TypeError::UndefinedMethod { found: object_type, method: method.to_string() }),
+        }
+    }
+
+    /// Type of `left op right`, for operands of the given types
+    fn binary_result(op: BinaryOp, left_type: Type, right_type: Type) -> Result<Type, TypeError> {
+        use BinaryOp::*;
+        match op {
+            // String concatenation : String -> String -> String
+            Add if left_type == Type::String && right_type == Type::String => {
+                Ok(Type::String)
+            }
+
+            // Arithmetic operators : Int -> Int -> Int, and Float as soon as one operand is a Float
+            Add | Sub | Mul | Div | Mod | Pow => {
+                if !left_type.is_numeric() || !right_type.is_numeric() {
+                    return Err(TypeError::InvalidBinaryOperands {
+                        op,
+                        left: left_type,
+                        right: right_type,
+                    });
+                }
+                if left_type == Type::Float || right_type == Type::Float {
+                    Ok(Type::Float)
+                } else {
+                    Ok(Type::Int)
+                }
+            }
+
+            // Comparison operators : number -> number -> Bool
+            Less | Greater | LessEqual | GreaterEqual => {
+                if !left_type.is_numeric() || !right_type.is_numeric() {
+                    return Err(TypeError::InvalidBinaryOperands {
+                        op,
+                        left: left_type,
+                        right: right_type,
+                    });
+                }
+                Ok(Type::Bool)
+            }
+
+            // Equality operators : T -> T -> Bool, functions excepted, or number -> number -> Bool
+            Equal | NotEqual if left_type.is_numeric() && right_type.is_numeric() => Ok(Type::Bool),
+            Equal | NotEqual => {
+                if left_type != right_type || matches!(left_type, Type::Function { .. }) {
+                    return Err(TypeError::InvalidBinaryOperands {
+                        op,
+                        left: left_type,
+                        right: right_type,
+                    });
+                }
+                Ok(Type::Bool)
+            }
+
+            // Logical operators : Bool -> Bool -> Bool
+            And | Or => {
+                if left_type != Type::Bool || right_type != Type::Bool {
+                    return Err(TypeError::InvalidBinaryOperands {
+                        op,
+                        left: left_type,
+                        right: right_type,
+                    });
+                }
+                Ok(Type::Bool)
+            }
+        }
+    }
+
+    fn infer_expr(&mut self, expr: &Expr) -> Result<Type, TypeError> {
+        if let ExprKind::Variable(name) = &expr.kind {
+            self.record_reference(name, &expr.span);
+        }
+        self.infer_expr_kind(expr).map_err(|e| e.with_span(&expr.span))
+    }
+
+    /// Records a use of `name`, if it is declared
+    fn record_reference(&mut self, name: &str, span: &Span) {
+        let (Some(symbol), Some(declaration)) = (self.symbol_table.get(name), self.symbol_table.location(name)) else {
+            return;
+        };
+        self.references.push(Reference {
+            name: name.to_string(),
+            ty: symbol.value_type(),
+            span: span.clone(),
+            declaration: declaration.clone(),
+        });
+    }
+
+    fn infer_expr_kind(&mut self, expr: &Expr) -> Result<Type, TypeError> {
+        match &expr.kind {
+            ExprKind::Literal(Literal::Number(_)) => Ok(Type::Int),
+            ExprKind::Literal(Literal::Float(_)) => Ok(Type::Float),
+            ExprKind::Literal(Literal::Bool(_)) => Ok(Type::Bool),
+            ExprKind::Literal(Literal::String(_)) => Ok(Type::String),
+            ExprKind::Literal(Literal::Char(_)) => Ok(Type::Char),
+            ExprKind::Interpolated(parts) => {
+                // Every value can be printed, so only Void is rejected
+                for part in parts {
+                    if let InterpolatedPart::Expr(expr) = part {
+                        if self.infer_expr(expr)? == Type::Void {
+                            return Err(TypeError::VoidValueNotAllowed.with_span(&expr.span));
                         }
-                        Ok(Type::Bool);
                     }
                 }
+                Ok(Type::String)
             }
-            Expr::Literal(Literal::Char(_)) => Ok(Type::Char),
-            Expr::Call { callee, args } => {
-                let func_symbol = if let Expr::Variable(name) = callee.as_ref() {
-                self.symbol_table.get(name)
-                    .ok_or_else(|| TypeError::UndefinedVariable(name.clone()))?
-                } else {
-                    return Err(TypeError::InvalidCallTarget);
-                };
-                match func_symbol {
-                    Symbol::Function { params, return_type } => {
-                        if params.len() != args.len() {
-                            return Err(TypeError::ArgumentCountMismatch {
-                                expected: params.len(),
-                                found: args.len(),
-                            });
-                        }
+            ExprKind::Variable(name) => {
+                let symbol = self.symbol_table.get(name)
+                    .ok_or_else(|| TypeError::UndefinedVariable(name.clone()))?;
 
-                        for (i, (arg, expected_type)) in args.iter().zip(params.iter()).enumerate() {
-                            let arg_type = self.infer_expr(arg)?;
-                            if &arg_type != expected_type {
-                                return Err(TypeError::ArgumentTypeMismatch {
-                                    index: i,
-                                    expected: expected_type.clone(),
-                                    found: arg_type,
-                                });
-                            }
-                        }
-                        Ok(return_type.clone())
+                Ok(symbol.value_type())
+            }
+            ExprKind::Binary { left, op, right } => {
+                let left_type = self.infer_expr(left)?;
+                let right_type = self.infer_expr(right)?;
+
+                Self::binary_result(*op, left_type, right_type)
+            }
+            ExprKind::Call { callee, args } => {
+                match &callee.kind {
+                    ExprKind::Variable(name) if builtin::is_builtin(name) && self.symbol_table.get(name).is_none() => {
+                        return self.check_builtin_call(name, args);
+                    }
+                    ExprKind::FieldAccess { object, field } => {
+                        return self.check_method_call(object, field, args);
                     }
-                    Symbol::Variable(_) => Err(TypeError::NotCallable)
+                    _ => {}
+                }
+
+                // Declared functions and function values are called alike
+                match self.infer_expr(callee)? {
+                    Type::Function { params, return_type } => {
+                        self.check_arguments(&params, args)?;
+                        Ok(*return_type)
+                    }
+                    _ => Err(TypeError::NotCallable.with_span(&callee.span)),
                 }
             }
-            Expr::Unary { op, right } => {
+            ExprKind::Unary { op, right } => {
                 let right_type = self.infer_expr(right)?;
 
                 use UnaryOp::*;
                 match op {
                     Minus => {
-                        if right_type != Type::Int {
+                        if !right_type.is_numeric() {
                             return Err(TypeError::InvalidUnaryOperand {
-                                op: op.clone(),
+                                op: *op,
                                 operand_type: right_type,
                             });
                         }
-                        Ok(Type::Int)
+                        Ok(right_type)
                     }
                     Not => {
                         if right_type != Type::Bool {
                             return Err(TypeError::InvalidUnaryOperand {
-                                op: op.clone(),
+                                op: *op,
                                 operand_type: right_type,
                             });
                         }
@@ -319,24 +1170,24 @@ impl TypeChecker {
                     }
                 }
             }
-            Expr::ArrayLiteral(elements) => {
+            ExprKind::ArrayLiteral(elements) => {
                 if elements.is_empty() {
                     return Err(TypeError::EmptyArrayLiteral);
                 }
 
                 let first_type = self.infer_expr(&elements[0])?;
                 for elem in &elements[1..] {
-                    let elem_type = self.infer_expr(elem)?;
+                    let elem_type = self.check_expr(elem, &first_type)?;
                     if elem_type != first_type {
                         return Err(TypeError::HeterogeneousArray {
                             expected: first_type.clone(),
                             found: elem_type,
-                        });
+                        }.with_span(&elem.span));
                     }
                 }
                 Ok(Type::Array(Box::new(first_type)))
             }
-            Expr::ArrayAccess { array, index } => {
+            ExprKind::ArrayAccess { array, index } => {
                 let array_type = self.infer_expr(array)?;
                 let index_type = self.infer_expr(index)?;
 
@@ -349,6 +1200,105 @@ impl TypeChecker {
                     _ => Err(TypeError::NotIndexable { found: array_type }),
                 }
             }
+            ExprKind::FieldAccess { object, field } => {
+                let object_type = self.infer_expr(object)?;
+                self.field_type(object_type, field)
+            }
+            ExprKind::StructLiteral { name, fields } => {
+                let declared = self.symbol_table.get_struct(name)
+                    .ok_or_else(|| TypeError::UndefinedType(name.clone()))?
+                    .fields
+                    .clone();
+                let struct_type = Type::Named(name.clone());
+
+                for (i, (field, value)) in fields.iter().enumerate() {
+                    if fields[..i].iter().any(|(other, _)| other == field) {
+                        return Err(TypeError::DuplicateField(field.clone()).with_span(&value.span));
+                    }
+                    let expected = declared.iter()
+                        .find(|(name, _)| name == field)
+                        .map(|(_, ty)| ty)
+                        .ok_or_else(|| TypeError::UndefinedField {
+                            found: struct_type.clone(),
+                            field: field.clone(),
+                        }.with_span(&value.span))?;
+                    let value_type = self.check_expr(value, expected)?;
+                    if &value_type != expected {
+                        return Err(TypeError::TypeMismatch {
+                            expected: expected.clone(),
+                            found: value_type,
+                        }.with_span(&value.span));
+                    }
+                }
+
+                if let Some((missing, _)) = declared.iter().find(|(name, _)| !fields.iter().any(|(f, _)| f == name)) {
+                    return Err(TypeError::MissingField { type_name: name.clone(), field: missing.clone() });
+                }
+                Ok(struct_type)
+            }
+            ExprKind::Variant { enum_name, variant, args } => {
+                let field_types = self.symbol_table.get_enum(enum_name)
+                    .ok_or_else(|| TypeError::UndefinedType(enum_name.clone()))?
+                    .variant(variant)
+                    .ok_or_else(|| TypeError::UndefinedVariant {
+                        enum_name: enum_name.clone(),
+                        variant: variant.clone(),
+                    })?
+                    .to_vec();
+                if field_types.len() != args.len() {
+                    return Err(TypeError::VariantFieldCount {
+                        variant: format!("{}::{}", enum_name, variant),
+                        expected: field_types.len(),
+                        found: args.len(),
+                    });
+                }
+                self.check_arguments(&field_types, args)?;
+                Ok(Type::Named(enum_name.clone()))
+            }
+            ExprKind::Match { subject, arms } => {
+                let subject_type = self.check_match_subject(subject)?;
+
+                // Every arm gives a value of the type of the first one
+                let mut result_type: Option<Type> = None;
+                for arm in arms {
+                    self.symbol_table.enter_scope();
+                    let arm_type = self.check_pattern(&arm.pattern, &subject_type, &arm.span, &mut Vec::new())
+                        .map_err(|e| e.with_span(&arm.span))
+                        .and_then(|_| match &result_type {
+                            Some(expected) => self.check_expr(&arm.body, expected),
+                            None => self.infer_expr(&arm.body),
+                        });
+                    self.symbol_table.exit_scope();
+                    let arm_type = arm_type?;
+
+                    match &result_type {
+                        Some(expected) if expected != &arm_type => {
+                            return Err(TypeError::TypeMismatch {
+                                expected: expected.clone(),
+                                found: arm_type,
+                            }.with_span(&arm.body.span));
+                        }
+                        Some(_) => {}
+                        None => result_type = Some(arm_type),
+                    }
+                }
+
+                self.check_arms(&subject_type, arms)?;
+                Ok(result_type.unwrap_or(Type::Void))
+            }
+            ExprKind::Lambda { params, return_type, body } => {
+                self.check_function("<lambda>", params, return_type.as_ref(), body, None, &expr.span)?;
+                Ok(Type::Function {
+                    params: params.iter().map(|p| p.type_annotation.clone()).collect(),
+                    return_type: Box::new(return_type.clone().unwrap_or(Type::Void)),
+                })
+            }
         }
     }
 }
+
+impl Default for TypeChecker {
+    fn default() -> Self {
+        Self::new()
+    }
+}
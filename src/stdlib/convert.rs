@@ -0,0 +1,81 @@
+/*
+ * -------------------------------------------------------------------------
+ * RemyLang — conversion functions
+ * File : src/stdlib/convert.rs
+ *
+ * Description :
+ *   int and float between numbers, to_string for any value, to_int and
+ *   to_float parsing Strings.
+ *
+ * Author  : Samuel 'Meeast' Bleau
+ * Created : 2026-10-16
+ *
+ * -------------------------------------------------------------------------
+*/
+
+use super::{invalid_arguments, Native, Param, Returns, Signature};
+use crate::ast::Type;
+use crate::vm::builtin::format_value;
+use crate::vm::error::{RuntimeError, RuntimeResult};
+use crate::vm::value::Value;
+
+pub(super) fn natives() -> Vec<Native> {
+    let string = || Param::Type(Type::String);
+    vec![
+        Native::new("int", Signature::new(vec![Param::Numeric], Returns::Type(Type::Int)), int),
+        Native::new("float", Signature::new(vec![Param::Numeric], Returns::Type(Type::Float)), float),
+        Native::new("to_string", Signature::new(vec![Param::Any], Returns::Type(Type::String)), to_string),
+        Native::new("to_int", Signature::new(vec![string()], Returns::Type(Type::Int)), to_int),
+        Native::new("to_float", Signature::new(vec![string()], Returns::Type(Type::Float)), to_float),
+    ]
+}
+
+/// int(x) - converts a number to an Int, truncating Floats toward zero
+fn int(args: Vec<Value>) -> RuntimeResult<Value> {
+    match args.as_slice() {
+        // Every Float in this range truncates to an Int, and NaN is in no range
+        [Value::Float(x)] if (i64::MIN as f64..-(i64::MIN as f64)).contains(x) => Ok(Value::Number(*x as i64)),
+        [Value::Float(_)] => Err(RuntimeError::FloatToIntConversion),
+        [Value::Number(n)] => Ok(Value::Number(*n)),
+        _ => Err(invalid_arguments("int", "Int or Float", &args)),
+    }
+}
+
+/// float(x) - converts a number to a Float
+fn float(args: Vec<Value>) -> RuntimeResult<Value> {
+    match args.as_slice() {
+        [Value::Number(n)] => Ok(Value::Float(*n as f64)),
+        [Value::Float(x)] => Ok(Value::Float(*x)),
+        _ => Err(invalid_arguments("float", "Int or Float", &args)),
+    }
+}
+
+/// to_string(x) - `x` as `print` shows it
+fn to_string(args: Vec<Value>) -> RuntimeResult<Value> {
+    match args.as_slice() {
+        [value] => Ok(Value::String(format_value(value))),
+        _ => Err(invalid_arguments("to_string", "a value", &args)),
+    }
+}
+
+/// to_int(s) - the Int written in `s`, surrounding whitespace aside
+fn to_int(args: Vec<Value>) -> RuntimeResult<Value> {
+    match args.as_slice() {
+        [Value::String(s)] => s.trim().parse().map(Value::Number).map_err(|_| RuntimeError::InvalidConversion {
+            text: s.clone(),
+            target: "Int".to_string(),
+        }),
+        _ => Err(invalid_arguments("to_int", "String", &args)),
+    }
+}
+
+/// to_float(s) - the Float written in `s`, surrounding whitespace aside
+fn to_float(args: Vec<Value>) -> RuntimeResult<Value> {
+    match args.as_slice() {
+        [Value::String(s)] => s.trim().parse().map(Value::Float).map_err(|_| RuntimeError::InvalidConversion {
+            text: s.clone(),
+            target: "Float".to_string(),
+        }),
+        _ => Err(invalid_arguments("to_float", "String", &args)),
+    }
+}
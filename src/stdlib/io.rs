@@ -0,0 +1,77 @@
+/*
+ * -------------------------------------------------------------------------
+ * RemyLang — I/O functions
+ * File : src/stdlib/io.rs
+ *
+ * Description :
+ *   print, println, input and args.
+ *
+ * Author  : Samuel 'Meeast' Bleau
+ * Created : 2026-10-16
+ *
+ * -------------------------------------------------------------------------
+*/
+
+use super::{Native, Param, Returns, Signature};
+use crate::ast::Type;
+use crate::vm::builtin::format_value;
+use crate::vm::error::{RuntimeError, RuntimeResult};
+use crate::vm::value::Value;
+use std::io::{self, BufRead, Write};
+use std::sync::OnceLock;
+
+/// Command line arguments given to the program, returned by `args()`
+static PROGRAM_ARGS: OnceLock<Vec<String>> = OnceLock::new();
+
+/// Set the arguments returned by `args()`, before running the program
+/// Only the first call has an effect.
+pub fn set_program_args(args: Vec<String>) {
+    let _ = PROGRAM_ARGS.set(args);
+}
+
+pub(super) fn natives() -> Vec<Native> {
+    let void = || Returns::Type(Type::Void);
+    vec![
+        Native::new("print", Signature::variadic(Param::Any, void()), print),
+        Native::new("println", Signature::variadic(Param::Any, void()), println),
+        Native::new("input", Signature::new(vec![], Returns::Type(Type::String)), input),
+        Native::new(
+            "args",
+            Signature::new(vec![], Returns::Type(Type::Array(Box::new(Type::String)))),
+            args,
+        ),
+    ]
+}
+
+/// print(...) - prints values to stdout, separated by spaces
+fn print(args: Vec<Value>) -> RuntimeResult<Value> {
+    let text: Vec<String> = args.iter().map(format_value).collect();
+    print!("{}", text.join(" "));
+    Ok(Value::Void)
+}
+
+/// println(...) - prints values to stdout with newline
+fn println(args: Vec<Value>) -> RuntimeResult<Value> {
+    print(args)?;
+    println!();
+    Ok(Value::Void)
+}
+
+/// input() - a line read from stdin, without its line break
+/// Returns an empty String at the end of the input.
+fn input(_: Vec<Value>) -> RuntimeResult<Value> {
+    // A prompt printed without a line break must show before reading
+    let mut line = String::new();
+    io::stdout()
+        .flush()
+        .and_then(|_| io::stdin().lock().read_line(&mut line))
+        .map_err(|e| RuntimeError::Custom(format!("Cannot read input: {}", e)))?;
+    let line = line.strip_suffix('\n').unwrap_or(&line);
+    Ok(Value::String(line.strip_suffix('\r').unwrap_or(line).to_string()))
+}
+
+/// args() - the command line arguments given to the program
+fn args(_: Vec<Value>) -> RuntimeResult<Value> {
+    let program_args = PROGRAM_ARGS.get().map(Vec::as_slice).unwrap_or_default();
+    Ok(Value::Array(program_args.iter().cloned().map(Value::String).collect()))
+}
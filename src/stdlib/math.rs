@@ -0,0 +1,103 @@
+/*
+ * -------------------------------------------------------------------------
+ * RemyLang — math functions
+ * File : src/stdlib/math.rs
+ *
+ * Description :
+ *   abs, min, max, clamp and gcd. Apart from gcd they take Ints or Floats,
+ *   returning a number of the same type.
+ *
+ * Author  : Samuel 'Meeast' Bleau
+ * Created : 2026-10-16
+ *
+ * -------------------------------------------------------------------------
+*/
+
+use super::{invalid_arguments, Native, Param, Returns, Signature};
+use crate::ast::Type;
+use crate::vm::error::{RuntimeError, RuntimeResult};
+use crate::vm::value::Value;
+
+pub(super) fn natives() -> Vec<Native> {
+    let int = || Param::Type(Type::Int);
+    vec![
+        Native::new("abs", Signature::new(vec![Param::Numeric], Returns::Numeric), abs),
+        Native::new("min", Signature::new(vec![Param::Numeric, Param::Numeric], Returns::Numeric), min),
+        Native::new("max", Signature::new(vec![Param::Numeric, Param::Numeric], Returns::Numeric), max),
+        Native::new(
+            "clamp",
+            Signature::new(vec![Param::Numeric, Param::Numeric, Param::Numeric], Returns::Numeric),
+            clamp,
+        ),
+        Native::new("gcd", Signature::new(vec![int(), int()], Returns::Type(Type::Int)), gcd),
+    ]
+}
+
+/// abs(x) - the absolute value of `x`
+fn abs(args: Vec<Value>) -> RuntimeResult<Value> {
+    match args.as_slice() {
+        [Value::Number(n)] => n
+            .checked_abs()
+            .map(Value::Number)
+            .ok_or(RuntimeError::IntegerOverflow { operation: "abs".to_string() }),
+        [Value::Float(x)] => Ok(Value::Float(x.abs())),
+        _ => Err(invalid_arguments("abs", "Int or Float", &args)),
+    }
+}
+
+/// min(a, b) - the smaller of `a` and `b`
+fn min(args: Vec<Value>) -> RuntimeResult<Value> {
+    match args.as_slice() {
+        [Value::Number(a), Value::Number(b)] => Ok(Value::Number(*a.min(b))),
+        [Value::Float(a), Value::Float(b)] => Ok(Value::Float(a.min(*b))),
+        _ => Err(invalid_arguments("min", "two Ints or two Floats", &args)),
+    }
+}
+
+/// max(a, b) - the larger of `a` and `b`
+fn max(args: Vec<Value>) -> RuntimeResult<Value> {
+    match args.as_slice() {
+        [Value::Number(a), Value::Number(b)] => Ok(Value::Number(*a.max(b))),
+        [Value::Float(a), Value::Float(b)] => Ok(Value::Float(a.max(*b))),
+        _ => Err(invalid_arguments("max", "two Ints or two Floats", &args)),
+    }
+}
+
+/// clamp(x, low, high) - `x` brought back between `low` and `high`
+fn clamp(args: Vec<Value>) -> RuntimeResult<Value> {
+    let reversed = |low: &dyn std::fmt::Display, high: &dyn std::fmt::Display| RuntimeError::InvalidArgument {
+        function: "clamp".to_string(),
+        reason: format!("the lower bound {} is greater than the upper bound {}", low, high),
+    };
+    match args.as_slice() {
+        [Value::Number(x), Value::Number(low), Value::Number(high)] => {
+            if low > high {
+                return Err(reversed(low, high));
+            }
+            Ok(Value::Number(*x.clamp(low, high)))
+        }
+        [Value::Float(x), Value::Float(low), Value::Float(high)] => {
+            // NaN bounds are not ordered either
+            if low > high || low.is_nan() || high.is_nan() {
+                return Err(reversed(low, high));
+            }
+            Ok(Value::Float(x.clamp(*low, *high)))
+        }
+        _ => Err(invalid_arguments("clamp", "three Ints or three Floats", &args)),
+    }
+}
+
+/// gcd(a, b) - the greatest common divisor of `a` and `b`, never negative
+/// `gcd(0, 0)` is 0.
+fn gcd(args: Vec<Value>) -> RuntimeResult<Value> {
+    let [Value::Number(a), Value::Number(b)] = args.as_slice() else {
+        return Err(invalid_arguments("gcd", "Int, Int", &args));
+    };
+    let (mut a, mut b) = (a.unsigned_abs(), b.unsigned_abs());
+    while b != 0 {
+        (a, b) = (b, a % b);
+    }
+    i64::try_from(a)
+        .map(Value::Number)
+        .map_err(|_| RuntimeError::IntegerOverflow { operation: "gcd".to_string() })
+}
@@ -0,0 +1,79 @@
+/*
+ * -------------------------------------------------------------------------
+ * RemyLang — standard library
+ * File : src/stdlib/mod.rs
+ *
+ * Description :
+ *   Registry of the native functions: their signatures, checked by the
+ *   type checker, and their implementations, called by the interpreter
+ *   and the VM.
+ *
+ * Author  : Samuel 'Meeast' Bleau
+ * Created : 2026-10-16
+ *
+ * -------------------------------------------------------------------------
+*/
+
+mod signature;
+mod io;
+mod strings;
+mod math;
+mod convert;
+
+use crate::vm::error::{RuntimeError, RuntimeResult};
+use crate::vm::value::Value;
+use std::sync::OnceLock;
+
+// Re-exports
+pub use io::set_program_args;
+pub use signature::{Param, Returns, Signature};
+
+/// A function of the standard library, implemented in Rust
+#[derive(Debug, Clone)]
+pub struct Native {
+    pub name: &'static str,
+    pub signature: Signature,
+    /// Implementation, given as many arguments as the signature takes
+    function: fn(Vec<Value>) -> RuntimeResult<Value>,
+}
+
+impl Native {
+    fn new(name: &'static str, signature: Signature, function: fn(Vec<Value>) -> RuntimeResult<Value>) -> Self {
+        Native { name, signature, function }
+    }
+
+    /// Calls the function, checking the number of arguments
+    /// The types of the arguments are checked by the function itself.
+    pub fn call(&self, args: Vec<Value>) -> RuntimeResult<Value> {
+        if !self.signature.accepts_count(args.len()) {
+            return Err(RuntimeError::ArgumentCountMismatch {
+                expected: self.signature.params.len(),
+                found: args.len(),
+                function_name: self.name.to_string(),
+            });
+        }
+        (self.function)(args)
+    }
+}
+
+/// Every native function: I/O, strings, math, then conversions
+pub fn natives() -> &'static [Native] {
+    static REGISTRY: OnceLock<Vec<Native>> = OnceLock::new();
+    REGISTRY.get_or_init(|| [io::natives(), strings::natives(), math::natives(), convert::natives()].concat())
+}
+
+/// The native function called `name`, if there is one
+pub fn lookup(name: &str) -> Option<&'static Native> {
+    natives().iter().find(|native| native.name == name)
+}
+
+/// Error for arguments a native function has no meaning for
+/// Calls are type checked, so this only happens in unchecked code.
+fn invalid_arguments(function: &str, expected: &str, args: &[Value]) -> RuntimeError {
+    let found: Vec<&str> = args.iter().map(Value::type_name).collect();
+    RuntimeError::TypeMismatch {
+        operation: format!("call to {}", function),
+        expected: expected.to_string(),
+        found: found.join(", "),
+    }
+}
@@ -0,0 +1,110 @@
+/*
+ * -------------------------------------------------------------------------
+ * RemyLang — native function signatures
+ * File : src/stdlib/signature.rs
+ *
+ * Description :
+ *   Parameter and return types of the native functions, as the type
+ *   checker sees them.
+ *
+ * Author  : Samuel 'Meeast' Bleau
+ * Created : 2026-10-16
+ *
+ * -------------------------------------------------------------------------
+*/
+
+use crate::ast::Type;
+use std::fmt;
+
+/// Values a parameter of a native function accepts
+#[derive(Debug, Clone, PartialEq)]
+pub enum Param {
+    /// Values of exactly this type
+    Type(Type),
+    /// An Int or a Float, the same for every numeric parameter of a call
+    Numeric,
+    /// A String or any array
+    Sized,
+    /// Any value
+    Any,
+}
+
+impl Param {
+    /// Returns true if the parameter accepts values of type `ty`
+    pub fn accepts(&self, ty: &Type) -> bool {
+        match self {
+            Param::Type(expected) => ty == expected,
+            Param::Numeric => ty.is_numeric(),
+            Param::Sized => matches!(ty, Type::String | Type::Array(_)),
+            Param::Any => *ty != Type::Void,
+        }
+    }
+}
+
+impl fmt::Display for Param {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        match self {
+            Param::Type(ty) => write!(f, "{}", ty),
+            Param::Numeric => write!(f, "Int or Float"),
+            Param::Sized => write!(f, "String or Array"),
+            Param::Any => write!(f, "any value"),
+        }
+    }
+}
+
+/// Type returned by a native function
+#[derive(Debug, Clone, PartialEq)]
+pub enum Returns {
+    Type(Type),
+    /// The type of the numeric arguments
+    Numeric,
+}
+
+/// Parameters and return type of a native function
+#[derive(Debug, Clone, PartialEq)]
+pub struct Signature {
+    pub params: Vec<Param>,
+    /// Kind of the arguments that can follow `params`, as many as wanted
+    pub rest: Option<Param>,
+    pub returns: Returns,
+}
+
+impl Signature {
+    /// Signature taking exactly `params`
+    pub fn new(params: Vec<Param>, returns: Returns) -> Self {
+        Signature { params, rest: None, returns }
+    }
+
+    /// Signature taking any number of `rest` arguments
+    pub fn variadic(rest: Param, returns: Returns) -> Self {
+        Signature { params: Vec::new(), rest: Some(rest), returns }
+    }
+
+    /// Returns true if the function can be called with `count` arguments
+    pub fn accepts_count(&self, count: usize) -> bool {
+        match self.rest {
+            Some(_) => count >= self.params.len(),
+            None => count == self.params.len(),
+        }
+    }
+
+    /// The parameter the argument at `position` is given to
+    pub fn param(&self, position: usize) -> Option<&Param> {
+        self.params.get(position).or(self.rest.as_ref())
+    }
+}
+
+/// `(String, String) -> Array<String>`, `(any value...) -> Void`
+impl fmt::Display for Signature {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        let mut params: Vec<String> = self.params.iter().map(Param::to_string).collect();
+        if let Some(rest) = &self.rest {
+            params.push(format!("{}...", rest));
+        }
+        write!(f, "({}) -> ", params.join(", "))?;
+        match &self.returns {
+            Returns::Type(ty) => write!(f, "{}", ty),
+            Returns::Numeric => write!(f, "Int or Float"),
+        }
+    }
+}
@@ -0,0 +1,165 @@
+/*
+ * -------------------------------------------------------------------------
+ * RemyLang — string functions
+ * File : src/stdlib/strings.rs
+ *
+ * Description :
+ *   len, split, join, trim, contains, replace, upper, lower, char_at and
+ *   substring. Strings are indexed by character.
+ *
+ * Author  : Samuel 'Meeast' Bleau
+ * Created : 2026-10-16
+ *
+ * -------------------------------------------------------------------------
+*/
+
+use super::{invalid_arguments, Native, Param, Returns, Signature};
+use crate::ast::Type;
+use crate::vm::error::{RuntimeError, RuntimeResult};
+use crate::vm::value::Value;
+
+pub(super) fn natives() -> Vec<Native> {
+    let string = || Param::Type(Type::String);
+    let strings = || Type::Array(Box::new(Type::String));
+    vec![
+        Native::new("len", Signature::new(vec![Param::Sized], Returns::Type(Type::Int)), len),
+        Native::new("split", Signature::new(vec![string(), string()], Returns::Type(strings())), split),
+        Native::new("join", Signature::new(vec![Param::Type(strings()), string()], Returns::Type(Type::String)), join),
+        Native::new("trim", Signature::new(vec![string()], Returns::Type(Type::String)), trim),
+        Native::new("contains", Signature::new(vec![string(), string()], Returns::Type(Type::Bool)), contains),
+        Native::new(
+            "replace",
+            Signature::new(vec![string(), string(), string()], Returns::Type(Type::String)),
+            replace,
+        ),
+        Native::new("upper", Signature::new(vec![string()], Returns::Type(Type::String)), upper),
+        Native::new("lower", Signature::new(vec![string()], Returns::Type(Type::String)), lower),
+        Native::new(
+            "char_at",
+            Signature::new(vec![string(), Param::Type(Type::Int)], Returns::Type(Type::Char)),
+            char_at,
+        ),
+        Native::new(
+            "substring",
+            Signature::new(vec![string(), Param::Type(Type::Int), Param::Type(Type::Int)], Returns::Type(Type::String)),
+            substring,
+        ),
+    ]
+}
+
+/// len(s) - number of characters of a String, or of elements of an array
+fn len(args: Vec<Value>) -> RuntimeResult<Value> {
+    match args.as_slice() {
+        [Value::String(s)] => Ok(Value::Number(s.chars().count() as i64)),
+        [Value::Array(elements)] => Ok(Value::Number(elements.len() as i64)),
+        _ => Err(invalid_arguments("len", "String or Array", &args)),
+    }
+}
+
+/// split(s, separator) - the parts of `s` between separators
+/// An empty separator splits `s` into its characters.
+fn split(args: Vec<Value>) -> RuntimeResult<Value> {
+    let parts: Vec<String> = match args.as_slice() {
+        [Value::String(s), Value::String(separator)] if separator.is_empty() => {
+            s.chars().map(String::from).collect()
+        }
+        [Value::String(s), Value::String(separator)] => s.split(separator.as_str()).map(String::from).collect(),
+        _ => return Err(invalid_arguments("split", "String, String", &args)),
+    };
+    Ok(Value::Array(parts.into_iter().map(Value::String).collect()))
+}
+
+/// join(parts, separator) - the Strings of `parts`, separated by `separator`
+fn join(args: Vec<Value>) -> RuntimeResult<Value> {
+    if let [Value::Array(parts), Value::String(separator)] = args.as_slice() {
+        let parts: Option<Vec<&str>> = parts
+            .iter()
+            .map(|part| match part {
+                Value::String(s) => Some(s.as_str()),
+                _ => None,
+            })
+            .collect();
+        if let Some(parts) = parts {
+            return Ok(Value::String(parts.join(separator)));
+        }
+    }
+    Err(invalid_arguments("join", "Array<String>, String", &args))
+}
+
+/// trim(s) - `s` without its leading and trailing whitespace
+fn trim(args: Vec<Value>) -> RuntimeResult<Value> {
+    map_string("trim", args, |s| s.trim().to_string())
+}
+
+/// contains(s, part) - whether `part` appears in `s`
+fn contains(args: Vec<Value>) -> RuntimeResult<Value> {
+    match args.as_slice() {
+        [Value::String(s), Value::String(part)] => Ok(Value::Bool(s.contains(part.as_str()))),
+        _ => Err(invalid_arguments("contains", "String, String", &args)),
+    }
+}
+
+/// replace(s, from, to) - `s` with every `from` replaced by `to`
+fn replace(args: Vec<Value>) -> RuntimeResult<Value> {
+    match args.as_slice() {
+        [Value::String(s), Value::String(from), Value::String(to)] => {
+            Ok(Value::String(s.replace(from.as_str(), to)))
+        }
+        _ => Err(invalid_arguments("replace", "String, String, String", &args)),
+    }
+}
+
+/// upper(s) - `s` in upper case
+fn upper(args: Vec<Value>) -> RuntimeResult<Value> {
+    map_string("upper", args, |s| s.to_uppercase())
+}
+
+/// lower(s) - `s` in lower case
+fn lower(args: Vec<Value>) -> RuntimeResult<Value> {
+    map_string("lower", args, |s| s.to_lowercase())
+}
+
+/// char_at(s, index) - the character of `s` at `index`
+fn char_at(args: Vec<Value>) -> RuntimeResult<Value> {
+    match args.as_slice() {
+        [Value::String(s), Value::Number(index)] => {
+            let length = s.chars().count();
+            usize::try_from(*index)
+                .ok()
+                .and_then(|i| s.chars().nth(i))
+                .map(Value::Char)
+                .ok_or(RuntimeError::IndexOutOfBounds { index: *index, length })
+        }
+        _ => Err(invalid_arguments("char_at", "String, Int", &args)),
+    }
+}
+
+/// substring(s, start, end) - the characters of `s` from `start` up to `end`, excluded
+fn substring(args: Vec<Value>) -> RuntimeResult<Value> {
+    match args.as_slice() {
+        [Value::String(s), Value::Number(start), Value::Number(end)] => {
+            let length = s.chars().count();
+            let in_bounds = |index: i64| usize::try_from(index).ok().filter(|&i| i <= length);
+            let (Some(from), Some(to)) = (in_bounds(*start), in_bounds(*end)) else {
+                let index = if in_bounds(*start).is_none() { *start } else { *end };
+                return Err(RuntimeError::IndexOutOfBounds { index, length });
+            };
+            if from > to {
+                return Err(RuntimeError::InvalidArgument {
+                    function: "substring".to_string(),
+                    reason: format!("the start {} is after the end {}", from, to),
+                });
+            }
+            Ok(Value::String(s.chars().skip(from).take(to - from).collect()))
+        }
+        _ => Err(invalid_arguments("substring", "String, Int, Int", &args)),
+    }
+}
+
+/// Applies `f` to the only argument of `function`, a String
+fn map_string(function: &str, args: Vec<Value>, f: impl FnOnce(&str) -> String) -> RuntimeResult<Value> {
+    match args.as_slice() {
+        [Value::String(s)] => Ok(Value::String(f(s))),
+        _ => Err(invalid_arguments(function, "String", &args)),
+    }
+}
@@ -4,7 +4,7 @@
  * File : src/vm/builtin.rs
  *
  * Description :
- *   Built-in functions for the RemyLang VM.
+ *   Built-in functions for the RemyLang VM, found in the standard library.
  *
  * Author  : Samuel 'Meeast' Bleau
  * Created : 2025-12-20
@@ -12,48 +12,25 @@
  * -------------------------------------------------------------------------
 */
 
+use crate::stdlib;
 use crate::vm::value::Value;
 use crate::vm::error::{RuntimeError, RuntimeResult};
 
-/// Execute a built-in function
+/// Execute a built-in function, one of the native functions of the standard library
 pub fn call_builtin(name: &str, args: Vec<Value>) -> RuntimeResult<Value> {
-    match name {
-        "print" => builtin_print(args),
-        "println" => builtin_println(args),
-        _ => Err(RuntimeError::UndefinedFunction { name: name.to_string() }),
+    match stdlib::lookup(name) {
+        Some(native) => native.call(args),
+        None => Err(RuntimeError::UndefinedFunction { name: name.to_string() }),
     }
 }
 
-/// Built-in: print(...) - prints values to stdout
-fn builtin_print(args: Vec<Value>) -> RuntimeResult<Value> {
-    for (i, arg) in args.iter().enumerate() {
-        if i > 0 {
-            print!(" ");
-        }
-        // Interpréter les séquences d'échappement dans les chaînes
-        match arg {
-            Value::String(s) => {
-                let processed = s
-                    .replace("\\n", "\n")
-                    .replace("\\t", "\t")
-                    .replace("\\r", "\r")
-                    .replace("\\\\", "\\");
-                print!("{}", processed);
-            }
-            _ => print!("{}", arg),
-        }
-    }
-    Ok(Value::Void)
-}
-
-/// Built-in: println(...) - prints values to stdout with newline
-fn builtin_println(args: Vec<Value>) -> RuntimeResult<Value> {
-    builtin_print(args)?;
-    println!();
-    Ok(Value::Void)
+/// Text printed for a value by `print`
+/// Escape sequences are decoded by the lexer, so strings print as they are.
+pub fn format_value(value: &Value) -> String {
+    value.to_string()
 }
 
 /// Check if a function name is a built-in
 pub fn is_builtin(name: &str) -> bool {
-    matches!(name, "print" | "println")
+    stdlib::lookup(name).is_some()
 }
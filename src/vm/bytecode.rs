@@ -0,0 +1,255 @@
+/*
+ * -------------------------------------------------------------------------
+ * RemyLang — bytecode definitions
+ * File : src/vm/bytecode.rs
+ *
+ * Description :
+ *   Instruction set, chunks and compiled functions produced by the
+ *   compiler and executed by the stack machine.
+ *
+ * Author  : Samuel 'Meeast' Bleau
+ * Created : 2026-10-16
+ *
+ * -------------------------------------------------------------------------
+*/
+
+use std::cell::RefCell;
+use std::fmt;
+use std::rc::Rc;
+use crate::ast::{BinaryOp, Pattern, UnaryOp};
+use crate::lexer::Span;
+use crate::vm::value::Value;
+
+/// A single machine instruction
+/// Operands are indices into the constant pool, the globals table, the
+/// current frame's local slots or the code itself (absolute jump targets).
+#[derive(Debug, Clone, Copy, PartialEq)]
+pub enum OpCode {
+    /// Push a value from the constant pool
+    Constant(u32),
+    /// Push Void
+    Void,
+    /// Discard the top of the stack
+    Pop,
+
+    /// Pop the top of the stack into a global, declaring it
+    DefineGlobal(u32),
+    GetGlobal(u32),
+    /// Pop the top of the stack into an existing global
+    SetGlobal(u32),
+    GetLocal(u32),
+    SetLocal(u32),
+    GetUpvalue(u32),
+    SetUpvalue(u32),
+    /// Move the top of the stack to the heap for the closures that captured it, then pop it
+    CloseUpvalue,
+
+    Binary(BinaryOp),
+    Unary(UnaryOp),
+    /// Build an array from the top `n` values
+    Array(u32),
+    /// Build a String from the text of the top `n` values
+    Interpolate(u32),
+    /// Pop an index and an array, push the element
+    Index,
+    /// Push the element of `variable` at the `depth` indices on top of the
+    /// stack, outermost index first, leaving the indices there. Field names
+    /// stand for the fields of structs, as in `ops::element_mut`.
+    GetElement { variable: Variable, depth: u32 },
+    /// Pop a value and `depth` indices, and store the value in place at
+    /// these indices of `variable`
+    SetElement { variable: Variable, depth: u32 },
+
+    /// Declare a struct, whose name and Array of field names are constants
+    DefineStruct { name: u32, fields: u32 },
+    /// Pop a closure and make it a method of a struct, both named by constants
+    DefineMethod { type_name: u32, method: u32 },
+    /// Build a struct from the values on top of the stack, the constant
+    /// Array `fields` naming them in the same order
+    MakeStruct { name: u32, fields: u32 },
+    /// Pop a struct, push its field named by a constant
+    GetField(u32),
+    /// Declare an enum, named by a constant, whose variants are a constant
+    /// Array of `[name, number of fields]` Arrays
+    DefineEnum { name: u32, variants: u32 },
+    /// Build a variant of an enum, both named by constants, from the `argc`
+    /// values on top of the stack
+    MakeVariant { enum_name: u32, variant: u32, argc: u32 },
+    /// Test the value in local `slot` against a pattern of the chunk. On a
+    /// match, push the values it binds, else jump to `exit`.
+    MatchPattern { slot: u32, pattern: u32, exit: u32 },
+    /// Report that no arm matched the value in local `slot`
+    NoMatch(u32),
+
+    Jump(u32),
+    /// Pop the condition and jump if it is falsy
+    JumpIfFalse(u32),
+    /// Jump to `exit` if the Bool on top of the stack alone decides the
+    /// result of `op`, `&&` or `||`. The Bool is left on the stack.
+    ShortCircuit { op: BinaryOp, exit: u32 },
+    /// Ensure the top of the stack can be iterated over
+    IterCheck,
+    /// Push the next element of the array in local `slot`, whose cursor is
+    /// in `slot + 1`, or jump to `exit` once the array is exhausted
+    IterNext { slot: u32, exit: u32 },
+
+    /// Call the function on top of the stack with the `n` arguments below it
+    Call(u32),
+    /// Call the method named by a constant on the value below the `argc`
+    /// arguments on top of the stack, which it gets as `self`
+    Invoke { method: u32, argc: u32 },
+    /// Call a built-in function, whose name is in the constant pool
+    CallBuiltin { name: u32, argc: u32 },
+    /// Create a closure from a function of the current chunk
+    Closure(u32),
+    Return,
+}
+
+/// Where a variable lives, as resolved by the compiler
+#[derive(Debug, Clone, Copy, PartialEq)]
+pub enum Variable {
+    /// Slot in the current frame
+    Local(u32),
+    /// Upvalue of the current closure
+    Upvalue(u32),
+    /// Slot in the globals table
+    Global(u32),
+}
+
+/// A sequence of instructions with its constant pool
+#[derive(Debug, Clone, Default)]
+pub struct Chunk {
+    pub code: Vec<OpCode>,
+    /// Source location of each instruction, for error reporting
+    pub spans: Vec<Span>,
+    pub constants: Vec<Value>,
+    /// Functions declared in this chunk
+    pub functions: Vec<Rc<Function>>,
+    /// Patterns of the match arms in this chunk
+    pub patterns: Vec<Pattern>,
+}
+
+impl Chunk {
+    /// Append an instruction, returning its position
+    pub fn emit(&mut self, op: OpCode, span: &Span) -> usize {
+        self.code.push(op);
+        self.spans.push(span.clone());
+        self.code.len() - 1
+    }
+
+    /// Add a constant to the pool, reusing an identical one if present
+    pub fn add_constant(&mut self, value: Value) -> u32 {
+        if let Some(index) = self.constants.iter().position(|c| *c == value) {
+            return index as u32;
+        }
+        self.constants.push(value);
+        (self.constants.len() - 1) as u32
+    }
+
+    /// Add a pattern, returning its index
+    pub fn add_pattern(&mut self, pattern: Pattern) -> u32 {
+        self.patterns.push(pattern);
+        (self.patterns.len() - 1) as u32
+    }
+
+    /// Change in the height of the stack when `op` runs without jumping
+    pub fn stack_effect(&self, op: OpCode) -> isize {
+        let count = |n: u32| n as isize;
+        match op {
+            OpCode::Constant(_) | OpCode::Void | OpCode::GetGlobal(_) | OpCode::GetLocal(_)
+            | OpCode::GetUpvalue(_) | OpCode::GetElement { .. } | OpCode::IterNext { .. }
+            | OpCode::Closure(_) => 1,
+            OpCode::Pop | OpCode::DefineGlobal(_) | OpCode::SetGlobal(_) | OpCode::SetLocal(_)
+            | OpCode::SetUpvalue(_) | OpCode::CloseUpvalue | OpCode::Binary(_) | OpCode::Index
+            | OpCode::DefineMethod { .. } | OpCode::JumpIfFalse(_) | OpCode::Return => -1,
+            OpCode::Unary(_) | OpCode::DefineStruct { .. } | OpCode::GetField(_) | OpCode::DefineEnum { .. }
+            | OpCode::NoMatch(_) | OpCode::Jump(_) | OpCode::ShortCircuit { .. } | OpCode::IterCheck => 0,
+            OpCode::Array(n) | OpCode::Interpolate(n) => 1 - count(n),
+            OpCode::SetElement { depth, .. } => -1 - count(depth),
+            OpCode::MakeStruct { fields, .. } => match &self.constants[fields as usize] {
+                Value::Array(fields) => 1 - fields.len() as isize,
+                other => unreachable!("struct fields must be an array, found {:?}", other),
+            },
+            OpCode::MakeVariant { argc, .. } | OpCode::CallBuiltin { argc, .. } => 1 - count(argc),
+            OpCode::MatchPattern { pattern, .. } => self.patterns[pattern as usize].bindings().len() as isize,
+            // The callee, or the receiver, is replaced by the result
+            OpCode::Call(argc) | OpCode::Invoke { argc, .. } => -count(argc),
+        }
+    }
+}
+
+/// How a closure captures one of its upvalues when it is created
+#[derive(Debug, Clone, Copy, PartialEq)]
+pub struct UpvalueRef {
+    /// True if the variable is a local of the enclosing function,
+    /// false if it is one of the enclosing function's own upvalues
+    pub is_local: bool,
+    pub index: u32,
+}
+
+/// A compiled function
+#[derive(Debug, Clone, Default)]
+pub struct Function {
+    pub name: String,
+    pub arity: usize,
+    pub chunk: Chunk,
+    pub upvalues: Vec<UpvalueRef>,
+}
+
+/// A compiled program, ready to be run by the machine
+#[derive(Debug, Clone)]
+pub struct Program {
+    /// Top-level code, run as a function without parameters
+    pub script: Rc<Function>,
+    /// Names of the global variables, indexed by slot
+    pub globals: Vec<String>,
+}
+
+/// A captured variable
+/// It stays on the stack while its scope is alive, then moves to the heap.
+#[derive(Debug, Clone, PartialEq)]
+pub enum UpvalueState {
+    Open(usize),
+    Closed(Value),
+}
+
+pub type Upvalue = Rc<RefCell<UpvalueState>>;
+
+/// A function together with the variables it captured
+pub struct Closure {
+    pub function: Rc<Function>,
+    pub upvalues: Vec<Upvalue>,
+}
+
+/// Closures are compared by identity
+impl PartialEq for Closure {
+    fn eq(&self, other: &Self) -> bool {
+        std::ptr::eq(self, other)
+    }
+}
+
+/// Only the name is shown, as a closure can capture itself
+impl fmt::Debug for Closure {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        f.debug_struct("Closure").field("name", &self.function.name).finish()
+    }
+}
+
+impl fmt::Display for Function {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        writeln!(f, "== {} ({} params) ==", self.name, self.arity)?;
+        for (i, op) in self.chunk.code.iter().enumerate() {
+            write!(f, "{:04} {:<5} {:?}", i, self.chunk.spans[i].line, op)?;
+            match op {
+                OpCode::Constant(index) => write!(f, "    ; {}", self.chunk.constants[*index as usize])?,
+                OpCode::MatchPattern { pattern, .. } => write!(f, "    ; {}", self.chunk.patterns[*pattern as usize])?,
+                _ => {}
+            }
+            writeln!(f)?;
+        }
+        for function in &self.chunk.functions {
+            write!(f, "\n{}", function)?;
+        }
+        Ok(())
+    }
+}
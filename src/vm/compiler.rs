@@ -0,0 +1,900 @@
+/*
+ * -------------------------------------------------------------------------
+ * RemyLang — bytecode compiler
+ * File : src/vm/compiler.rs
+ *
+ * Description :
+ *   Lowers the AST to bytecode for the stack machine. Variables are
+ *   resolved at compile time to local slots, upvalues or global slots.
+ *
+ * Author  : Samuel 'Meeast' Bleau
+ * Created : 2026-10-16
+ *
+ * -------------------------------------------------------------------------
+*/
+
+use std::collections::HashMap;
+use std::rc::Rc;
+use crate::ast::*;
+use crate::lexer::Span;
+use crate::vm::builtin;
+use crate::vm::bytecode::{Chunk, Function, OpCode, Program, UpvalueRef, Variable};
+use crate::vm::error::{RuntimeError, RuntimeResult};
+use crate::vm::value::Value;
+
+/// Compiles programs to bytecode
+/// Global slots are kept between calls to `compile`, so that successive
+/// programs can be run on the same machine.
+pub struct Compiler {
+    states: Vec<FunctionState>, // Functions being compiled, innermost last
+    globals: Vec<String>,
+    global_slots: HashMap<String, u32>,
+}
+
+/// Compilation state of a single function
+struct FunctionState {
+    function: Function,
+    locals: Vec<Local>,
+    scope_depth: usize,
+    loops: Vec<LoopState>,
+    /// Values on the stack at this point of the code, locals included
+    stack_height: usize,
+}
+
+struct Local {
+    name: String,
+    depth: usize,
+    captured: bool, // Captured by a closure, must be closed when it goes out of scope
+    declared: bool, // False while the code before its declaration is compiled
+}
+
+/// Jumps of the innermost loop that still need a target
+struct LoopState {
+    local_count: usize, // Locals alive when the loop started
+    continue_target: Option<usize>,
+    continue_jumps: Vec<usize>,
+    break_jumps: Vec<usize>,
+}
+
+impl Compiler {
+    /// Create a new compiler
+    pub fn new() -> Self {
+        Compiler {
+            states: Vec::new(),
+            globals: Vec::new(),
+            global_slots: HashMap::new(),
+        }
+    }
+
+    /// Compile a program
+    /// `return`, `break` and `continue` in invalid positions are reported here.
+    pub fn compile(&mut self, statements: &[Stmt]) -> RuntimeResult<Program> {
+        self.states.clear();
+        self.states.push(FunctionState::new("<script>", 0));
+        // Top-level functions hide the builtins named like them, even before their declaration
+        for stmt in statements {
+            if let StmtKind::FunctionDecl { name, .. } = &stmt.kind {
+                self.global_slot(name);
+            }
+        }
+
+        let result = self
+            .declare_types(statements)
+            .and_then(|_| self.compile_statements(statements));
+        let end = statements.last().map(|s| s.span.clone()).unwrap_or_default();
+        self.emit(OpCode::Void, &end);
+        self.emit(OpCode::Return, &end);
+
+        let state = self.states.pop().expect("script state");
+        result?;
+
+        Ok(Program {
+            script: Rc::new(state.function),
+            globals: self.globals.clone(),
+        })
+    }
+
+    // ========================================================================
+    // STATEMENTS
+    // ========================================================================
+
+    fn compile_stmt(&mut self, stmt: &Stmt) -> RuntimeResult<()> {
+        let span = &stmt.span;
+        self.start_statement();
+
+        match &stmt.kind {
+            StmtKind::Expression(expr) => {
+                self.compile_expr(expr)?;
+                self.emit(OpCode::Pop, span);
+            }
+
+            StmtKind::Let { name, type_annotation: _, value } => {
+                self.compile_expr(value)?;
+                self.define_variable(name, span);
+            }
+
+            StmtKind::Assignment { target, op, value } => {
+                let variable = self.resolve(target.name());
+                let path = target.path();
+                for accessor in &path {
+                    match accessor {
+                        Accessor::Index(index) => self.compile_expr(index)?,
+                        Accessor::Field(field) => self.emit_constant(Value::String(field.to_string()), span),
+                    }
+                }
+                let depth = path.len() as u32;
+
+                if let Some(op) = op {
+                    let load = match variable {
+                        Variable::Local(slot) if depth == 0 => OpCode::GetLocal(slot),
+                        Variable::Upvalue(index) if depth == 0 => OpCode::GetUpvalue(index),
+                        Variable::Global(slot) if depth == 0 => OpCode::GetGlobal(slot),
+                        _ => OpCode::GetElement { variable, depth },
+                    };
+                    self.emit(load, span);
+                    self.compile_expr(value)?;
+                    self.emit(OpCode::Binary(*op), span);
+                } else {
+                    self.compile_expr(value)?;
+                }
+
+                let store = match variable {
+                    Variable::Local(slot) if depth == 0 => OpCode::SetLocal(slot),
+                    Variable::Upvalue(index) if depth == 0 => OpCode::SetUpvalue(index),
+                    Variable::Global(slot) if depth == 0 => OpCode::SetGlobal(slot),
+                    _ => OpCode::SetElement { variable, depth },
+                };
+                self.emit(store, span);
+            }
+
+            StmtKind::Block(statements) => {
+                self.begin_scope();
+                let result = self.compile_statements(statements);
+                self.end_scope(span);
+                result?;
+            }
+
+            StmtKind::If { condition, then_branch, else_branch } => {
+                self.compile_expr(condition)?;
+                let else_jump = self.emit(OpCode::JumpIfFalse(0), span);
+                self.compile_stmt(then_branch)?;
+
+                if let Some(else_branch) = else_branch {
+                    let end_jump = self.emit(OpCode::Jump(0), span);
+                    self.patch_jump(else_jump);
+                    self.compile_stmt(else_branch)?;
+                    self.patch_jump(end_jump);
+                } else {
+                    self.patch_jump(else_jump);
+                }
+            }
+
+            StmtKind::Return(value) => {
+                if self.states.len() == 1 {
+                    return Err(RuntimeError::ReturnOutsideFunction.with_span(span));
+                }
+                match value {
+                    Some(expr) => self.compile_expr(expr)?,
+                    None => {
+                        self.emit(OpCode::Void, span);
+                    }
+                }
+                self.emit(OpCode::Return, span);
+            }
+
+            StmtKind::While { condition, body } => {
+                let start = self.code_len();
+                self.compile_expr(condition)?;
+                let exit_jump = self.emit(OpCode::JumpIfFalse(0), span);
+
+                self.begin_loop(Some(start));
+                self.compile_stmt(body)?;
+                self.emit(OpCode::Jump(start as u32), span);
+                self.patch_jump(exit_jump);
+                self.end_loop();
+            }
+
+            StmtKind::For { initializer, condition, increment, body } => {
+                // The initializer's variables are scoped to the loop
+                self.begin_scope();
+                let result = self.compile_for(
+                    initializer.as_deref(),
+                    condition.as_ref(),
+                    increment.as_deref(),
+                    body,
+                    span,
+                );
+                self.end_scope(span);
+                result?;
+            }
+
+            StmtKind::ForIn { variable, iterable, body } => {
+                self.begin_scope();
+                let result = self.compile_for_in(variable, iterable, body, span);
+                self.end_scope(span);
+                result?;
+            }
+
+            StmtKind::Break => {
+                let jump = self.emit_loop_exit(RuntimeError::BreakOutsideLoop, span)?;
+                self.current_loop().break_jumps.push(jump);
+            }
+
+            StmtKind::Continue => {
+                match self.current_state().loops.last().and_then(|l| l.continue_target) {
+                    Some(target) => {
+                        let jump = self.emit_loop_exit(RuntimeError::ContinueOutsideLoop, span)?;
+                        self.patch_to(jump, target);
+                    }
+                    None => {
+                        let jump = self.emit_loop_exit(RuntimeError::ContinueOutsideLoop, span)?;
+                        self.current_loop().continue_jumps.push(jump);
+                    }
+                }
+            }
+
+            StmtKind::FunctionDecl { name, params, return_type: _, body } => {
+                let is_global = self.is_global_scope();
+                if !is_global {
+                    // Declared before its body is compiled, so it can call itself
+                    self.add_local(name);
+                }
+                self.compile_function(name, params, body, span)?;
+                if is_global {
+                    let slot = self.global_slot(name);
+                    self.emit(OpCode::DefineGlobal(slot), span);
+                }
+            }
+
+            StmtKind::Match { subject, arms } => {
+                // The subject lives in a hidden local, for the arms to test
+                self.begin_scope();
+                let result = self.compile_expr(subject).and_then(|_| {
+                    let slot = self.add_local("(subject)");
+                    self.compile_arms(slot, arms, span, |compiler, body| compiler.compile_stmt(body))
+                });
+                self.end_scope(span);
+                result?;
+            }
+
+            // Declared before the program runs
+            StmtKind::StructDecl { .. } | StmtKind::EnumDecl { .. } | StmtKind::Impl { .. } => {}
+        }
+        Ok(())
+    }
+
+    /// Compile the statements of the script or of a block
+    /// Their functions are defined before the first statement runs, as the
+    /// type checker lets code call them before their declaration.
+    fn compile_statements(&mut self, statements: &[Stmt]) -> RuntimeResult<()> {
+        let is_function = |stmt: &Stmt| matches!(stmt.kind, StmtKind::FunctionDecl { .. });
+        if !statements.iter().any(is_function) {
+            return statements.iter().try_for_each(|stmt| self.compile_stmt(stmt));
+        }
+        if self.is_global_scope() {
+            for stmt in statements.iter().filter(|stmt| is_function(stmt)) {
+                self.compile_stmt(stmt)?;
+            }
+            return statements.iter().filter(|stmt| !is_function(stmt)).try_for_each(|stmt| self.compile_stmt(stmt));
+        }
+
+        // Every variable of the block gets its slot first, for the functions
+        // to capture it, but is only visible from its declaration on
+        let first = self.current_state().locals.len() as u32;
+        let declarations: Vec<(u32, &Stmt)> = statements
+            .iter()
+            .filter(|stmt| matches!(stmt.kind, StmtKind::Let { .. } | StmtKind::FunctionDecl { .. }))
+            .zip(first..)
+            .map(|(stmt, slot)| (slot, stmt))
+            .collect();
+        for &(_, stmt) in &declarations {
+            let (StmtKind::Let { name, .. } | StmtKind::FunctionDecl { name, .. }) = &stmt.kind else { continue };
+            self.emit(OpCode::Void, &stmt.span);
+            let slot = self.add_local(name);
+            self.set_declared(slot, is_function(stmt));
+        }
+
+        // Each function sees the variables declared before it
+        for &(slot, stmt) in &declarations {
+            match &stmt.kind {
+                StmtKind::FunctionDecl { name, params, body, .. } => {
+                    self.start_statement();
+                    self.compile_function(name, params, body, &stmt.span)?;
+                    self.emit(OpCode::SetLocal(slot), &stmt.span);
+                }
+                _ => self.set_declared(slot, true),
+            }
+        }
+        for &(slot, stmt) in &declarations {
+            self.set_declared(slot, is_function(stmt));
+        }
+
+        let mut slots = declarations.iter().map(|&(slot, _)| slot);
+        for stmt in statements {
+            match &stmt.kind {
+                StmtKind::FunctionDecl { .. } => {
+                    slots.next();
+                }
+                StmtKind::Let { value, .. } => {
+                    let slot = slots.next().expect("every variable has a slot");
+                    self.start_statement();
+                    self.compile_expr(value)?;
+                    self.emit(OpCode::SetLocal(slot), &stmt.span);
+                    self.set_declared(slot, true);
+                }
+                _ => self.compile_stmt(stmt)?,
+            }
+        }
+        Ok(())
+    }
+
+    /// Compile the arms of a match on the value in local `slot`
+    /// Each arm tests its pattern, binding its names in a scope of their
+    /// own, and runs its body with `compile_body`. The code after the arms
+    /// reports that none of them matched.
+    fn compile_arms<T>(
+        &mut self,
+        slot: u32,
+        arms: &[MatchArm<T>],
+        span: &Span,
+        mut compile_body: impl FnMut(&mut Self, &T) -> RuntimeResult<()>,
+    ) -> RuntimeResult<()> {
+        let mut end_jumps = Vec::new();
+        for arm in arms {
+            let pattern = self.current_chunk().add_pattern(arm.pattern.clone());
+            let test = self.emit(OpCode::MatchPattern { slot, pattern, exit: 0 }, &arm.span);
+
+            self.begin_scope();
+            for name in arm.pattern.bindings() {
+                self.add_local(name);
+            }
+            let result = compile_body(self, &arm.body);
+            self.end_scope(span);
+            result?;
+
+            end_jumps.push(self.emit(OpCode::Jump(0), span));
+            self.patch_jump(test);
+        }
+        self.emit(OpCode::NoMatch(slot), span);
+        for jump in end_jumps {
+            self.patch_jump(jump);
+        }
+        Ok(())
+    }
+
+    /// Emit the declarations of the structs, enums and methods of a program
+    /// They are hoisted, as the type checker lets code use them before their
+    /// declaration. Methods take the value they are called on as `self`.
+    fn declare_types(&mut self, statements: &[Stmt]) -> RuntimeResult<()> {
+        for stmt in statements {
+            let span = &stmt.span;
+            match &stmt.kind {
+                StmtKind::StructDecl { name, fields } => {
+                    let fields = fields.iter().map(|field| Value::String(field.name.clone())).collect();
+                    let name = self.current_chunk().add_constant(Value::String(name.clone()));
+                    let fields = self.current_chunk().add_constant(Value::Array(fields));
+                    self.emit(OpCode::DefineStruct { name, fields }, span);
+                }
+                StmtKind::EnumDecl { name, variants } => {
+                    let variants = variants
+                        .iter()
+                        .map(|v| Value::Array(vec![Value::String(v.name.clone()), Value::Number(v.fields.len() as i64)]))
+                        .collect();
+                    let name = self.current_chunk().add_constant(Value::String(name.clone()));
+                    let variants = self.current_chunk().add_constant(Value::Array(variants));
+                    self.emit(OpCode::DefineEnum { name, variants }, span);
+                }
+                StmtKind::Impl { name: type_name, methods } => {
+                    for method in methods {
+                        let StmtKind::FunctionDecl { name, params, body, .. } = &method.kind else { continue };
+                        let receiver = Param {
+                            name: "self".to_string(),
+                            type_annotation: Type::Named(type_name.clone()),
+                        };
+                        let params: Vec<Param> = std::iter::once(receiver).chain(params.iter().cloned()).collect();
+                        self.compile_function(&format!("{}.{}", type_name, name), &params, body, &method.span)?;
+
+                        let owner = self.current_chunk().add_constant(Value::String(type_name.clone()));
+                        let name = self.current_chunk().add_constant(Value::String(name.clone()));
+                        self.emit(OpCode::DefineMethod { type_name: owner, method: name }, &method.span);
+                    }
+                }
+                _ => {}
+            }
+        }
+        Ok(())
+    }
+
+    /// Compile a C-style for loop, once its scope has been opened
+    fn compile_for(
+        &mut self,
+        initializer: Option<&Stmt>,
+        condition: Option<&Expr>,
+        increment: Option<&Stmt>,
+        body: &Stmt,
+        span: &Span,
+    ) -> RuntimeResult<()> {
+        if let Some(init) = initializer {
+            self.compile_stmt(init)?;
+        }
+
+        let start = self.code_len();
+        let exit_jump = match condition {
+            Some(cond) => {
+                self.compile_expr(cond)?;
+                Some(self.emit(OpCode::JumpIfFalse(0), span))
+            }
+            None => None,
+        };
+
+        // `continue` jumps forward to the increment
+        self.begin_loop(None);
+        self.compile_stmt(body)?;
+        let increment_start = self.code_len();
+        for jump in std::mem::take(&mut self.current_loop().continue_jumps) {
+            self.patch_to(jump, increment_start);
+        }
+        if let Some(inc) = increment {
+            self.compile_stmt(inc)?;
+        }
+        self.emit(OpCode::Jump(start as u32), span);
+
+        if let Some(jump) = exit_jump {
+            self.patch_jump(jump);
+        }
+        self.end_loop();
+        Ok(())
+    }
+
+    /// Compile a for-in loop, once its scope has been opened
+    /// The array and its cursor live in two hidden locals.
+    fn compile_for_in(
+        &mut self,
+        variable: &str,
+        iterable: &Expr,
+        body: &Stmt,
+        span: &Span,
+    ) -> RuntimeResult<()> {
+        self.compile_expr(iterable)?;
+        self.emit(OpCode::IterCheck, span);
+        let slot = self.add_local("(array)");
+        self.emit_constant(Value::Number(0), span);
+        self.add_local("(cursor)");
+
+        let start = self.code_len();
+        let next = self.emit(OpCode::IterNext { slot, exit: 0 }, span);
+
+        // Each iteration gets a fresh variable
+        self.begin_loop(Some(start));
+        self.begin_scope();
+        self.add_local(variable);
+        self.compile_stmt(body)?;
+        self.end_scope(span);
+        self.emit(OpCode::Jump(start as u32), span);
+
+        self.patch_jump(next);
+        self.end_loop();
+        Ok(())
+    }
+
+    /// Compile a function body and emit the instruction creating its closure
+    fn compile_function(
+        &mut self,
+        name: &str,
+        params: &[Param],
+        body: &Stmt,
+        span: &Span,
+    ) -> RuntimeResult<()> {
+        self.states.push(FunctionState::new(name, params.len()));
+        for param in params {
+            self.add_local(&param.name);
+        }
+
+        let result = self.compile_stmt(body);
+        self.emit(OpCode::Void, span);
+        self.emit(OpCode::Return, span);
+
+        let state = self.states.pop().expect("function state");
+        result?;
+
+        let chunk = self.current_chunk();
+        chunk.functions.push(Rc::new(state.function));
+        let index = (chunk.functions.len() - 1) as u32;
+        self.emit(OpCode::Closure(index), span);
+        Ok(())
+    }
+
+    // ========================================================================
+    // EXPRESSIONS
+    // ========================================================================
+
+    fn compile_expr(&mut self, expr: &Expr) -> RuntimeResult<()> {
+        let span = &expr.span;
+        match &expr.kind {
+            ExprKind::Literal(lit) => {
+                let value = match lit {
+                    Literal::Number(n) => Value::Number(*n),
+                    Literal::Float(x) => Value::Float(*x),
+                    Literal::String(s) => Value::String(s.clone()),
+                    Literal::Char(c) => Value::Char(*c),
+                    Literal::Bool(b) => Value::Bool(*b),
+                };
+                self.emit_constant(value, span);
+            }
+
+            ExprKind::Interpolated(parts) => {
+                for part in parts {
+                    match part {
+                        InterpolatedPart::Text(text) => self.emit_constant(Value::String(text.clone()), span),
+                        InterpolatedPart::Expr(expr) => self.compile_expr(expr)?,
+                    }
+                }
+                self.emit(OpCode::Interpolate(parts.len() as u32), span);
+            }
+
+            ExprKind::Variable(name) => {
+                let op = match self.resolve(name) {
+                    Variable::Local(slot) => OpCode::GetLocal(slot),
+                    Variable::Upvalue(index) => OpCode::GetUpvalue(index),
+                    Variable::Global(slot) => OpCode::GetGlobal(slot),
+                };
+                self.emit(op, span);
+            }
+
+            ExprKind::Binary { left, op: op @ (BinaryOp::And | BinaryOp::Or), right } => {
+                // The left operand stays on the stack, as the result or as
+                // the left operand of the Binary instruction
+                self.compile_expr(left)?;
+                let exit_jump = self.emit(OpCode::ShortCircuit { op: *op, exit: 0 }, span);
+                self.compile_expr(right)?;
+                self.emit(OpCode::Binary(*op), span);
+                self.patch_jump(exit_jump);
+            }
+
+            ExprKind::Binary { left, op, right } => {
+                self.compile_expr(left)?;
+                self.compile_expr(right)?;
+                self.emit(OpCode::Binary(*op), span);
+            }
+
+            ExprKind::Unary { op, right } => {
+                self.compile_expr(right)?;
+                self.emit(OpCode::Unary(*op), span);
+            }
+
+            ExprKind::Call { callee, args } => {
+                if let ExprKind::FieldAccess { object, field } = &callee.kind {
+                    // The value the method is called on comes before its arguments
+                    self.compile_expr(object)?;
+                    for arg in args {
+                        self.compile_expr(arg)?;
+                    }
+                    let method = self.current_chunk().add_constant(Value::String(field.clone()));
+                    self.emit(OpCode::Invoke { method, argc: args.len() as u32 }, span);
+                    return Ok(());
+                }
+
+                // Arguments are evaluated before the callee
+                for arg in args {
+                    self.compile_expr(arg)?;
+                }
+                let argc = args.len() as u32;
+
+                match &callee.kind {
+                    ExprKind::Variable(name) if builtin::is_builtin(name) && !self.is_declared(name) => {
+                        let name = self.current_chunk().add_constant(Value::String(name.clone()));
+                        self.emit(OpCode::CallBuiltin { name, argc }, span);
+                    }
+                    _ => {
+                        self.compile_expr(callee)?;
+                        self.emit(OpCode::Call(argc), span);
+                    }
+                }
+            }
+
+            ExprKind::ArrayLiteral(elements) => {
+                for element in elements {
+                    self.compile_expr(element)?;
+                }
+                self.emit(OpCode::Array(elements.len() as u32), span);
+            }
+
+            ExprKind::ArrayAccess { array, index } => {
+                self.compile_expr(array)?;
+                self.compile_expr(index)?;
+                self.emit(OpCode::Index, span);
+            }
+
+            ExprKind::FieldAccess { object, field } => {
+                self.compile_expr(object)?;
+                let field = self.current_chunk().add_constant(Value::String(field.clone()));
+                self.emit(OpCode::GetField(field), span);
+            }
+
+            ExprKind::StructLiteral { name, fields } => {
+                let mut names = Vec::new();
+                for (field, value) in fields {
+                    self.compile_expr(value)?;
+                    names.push(Value::String(field.clone()));
+                }
+                let name = self.current_chunk().add_constant(Value::String(name.clone()));
+                let fields = self.current_chunk().add_constant(Value::Array(names));
+                self.emit(OpCode::MakeStruct { name, fields }, span);
+            }
+
+            ExprKind::Variant { enum_name, variant, args } => {
+                for arg in args {
+                    self.compile_expr(arg)?;
+                }
+                let enum_name = self.current_chunk().add_constant(Value::String(enum_name.clone()));
+                let variant = self.current_chunk().add_constant(Value::String(variant.clone()));
+                self.emit(OpCode::MakeVariant { enum_name, variant, argc: args.len() as u32 }, span);
+            }
+
+            ExprKind::Match { subject, arms } => {
+                // Locals are addressed by stack slot, so the values the
+                // enclosing expression already pushed are registered as
+                // hidden locals before the subject and the bindings
+                let state = self.current_state();
+                let temporaries = state.stack_height - state.locals.len();
+                self.begin_scope();
+                for _ in 0..temporaries {
+                    self.add_local("(temporary)");
+                }
+                self.compile_expr(subject)?;
+                let slot = self.add_local("(subject)");
+
+                // Each arm moves its result to the subject's slot, which
+                // is left on the stack as the value of the match
+                self.compile_arms(slot, arms, span, |compiler, body| {
+                    compiler.compile_expr(body)?;
+                    compiler.emit(OpCode::SetLocal(slot), span);
+                    Ok(())
+                })?;
+                let state = self.current_state();
+                state.scope_depth -= 1;
+                state.locals.truncate(slot as usize - temporaries);
+            }
+
+            ExprKind::Lambda { params, return_type: _, body } => {
+                self.compile_function("<lambda>", params, body, span)?;
+            }
+        }
+        Ok(())
+    }
+
+    // ========================================================================
+    // SCOPES AND VARIABLES
+    // ========================================================================
+
+    fn current_state(&mut self) -> &mut FunctionState {
+        self.states.last_mut().expect("no function being compiled")
+    }
+
+    fn current_chunk(&mut self) -> &mut Chunk {
+        &mut self.current_state().function.chunk
+    }
+
+    fn current_loop(&mut self) -> &mut LoopState {
+        self.current_state().loops.last_mut().expect("not in a loop")
+    }
+
+    /// Top-level code outside of any block declares globals
+    fn is_global_scope(&self) -> bool {
+        self.states.len() == 1 && self.states[0].scope_depth == 0
+    }
+
+    /// Between statements, only locals are on the stack
+    fn start_statement(&mut self) {
+        let state = self.current_state();
+        state.stack_height = state.locals.len();
+    }
+
+    fn begin_scope(&mut self) {
+        self.current_state().scope_depth += 1;
+    }
+
+    /// Close a scope, dropping its locals from the stack
+    fn end_scope(&mut self, span: &Span) {
+        let state = self.current_state();
+        state.scope_depth -= 1;
+        let depth = state.scope_depth;
+
+        while let Some(local) = self.current_state().locals.last() {
+            if local.depth <= depth {
+                break;
+            }
+            let op = if local.captured { OpCode::CloseUpvalue } else { OpCode::Pop };
+            self.current_state().locals.pop();
+            self.emit(op, span);
+        }
+    }
+
+    /// Declare a local in the current scope, returning its slot
+    /// Its value is whatever the preceding code left on top of the stack.
+    fn add_local(&mut self, name: &str) -> u32 {
+        let state = self.current_state();
+        state.locals.push(Local {
+            name: name.to_string(),
+            depth: state.scope_depth,
+            captured: false,
+            declared: true,
+        });
+        (state.locals.len() - 1) as u32
+    }
+
+    fn set_declared(&mut self, slot: u32, declared: bool) {
+        self.current_state().locals[slot as usize].declared = declared;
+    }
+
+    /// Bind the value on top of the stack to a new variable
+    fn define_variable(&mut self, name: &str, span: &Span) {
+        if self.is_global_scope() {
+            let slot = self.global_slot(name);
+            self.emit(OpCode::DefineGlobal(slot), span);
+        } else {
+            self.add_local(name);
+        }
+    }
+
+    fn global_slot(&mut self, name: &str) -> u32 {
+        if let Some(&slot) = self.global_slots.get(name) {
+            return slot;
+        }
+        let slot = self.globals.len() as u32;
+        self.globals.push(name.to_string());
+        self.global_slots.insert(name.to_string(), slot);
+        slot
+    }
+
+    /// Returns true if `name` is a variable of the program, hiding the builtin of that name
+    fn is_declared(&self, name: &str) -> bool {
+        self.global_slots.contains_key(name)
+            || self.states.iter().any(|state| state.locals.iter().any(|local| local.declared && local.name == name))
+    }
+
+    /// Resolve a name from the innermost function outwards
+    fn resolve(&mut self, name: &str) -> Variable {
+        let innermost = self.states.len() - 1;
+        if let Some(slot) = self.resolve_local(innermost, name) {
+            return Variable::Local(slot);
+        }
+        if let Some(index) = self.resolve_upvalue(innermost, name) {
+            return Variable::Upvalue(index);
+        }
+        Variable::Global(self.global_slot(name))
+    }
+
+    fn resolve_local(&self, state: usize, name: &str) -> Option<u32> {
+        self.states[state]
+            .locals
+            .iter()
+            .rposition(|local| local.declared && local.name == name)
+            .map(|slot| slot as u32)
+    }
+
+    /// Find a variable of an enclosing function, capturing it along the way
+    fn resolve_upvalue(&mut self, state: usize, name: &str) -> Option<u32> {
+        if state == 0 {
+            return None;
+        }
+        let enclosing = state - 1;
+
+        if let Some(slot) = self.resolve_local(enclosing, name) {
+            self.states[enclosing].locals[slot as usize].captured = true;
+            return Some(self.add_upvalue(state, true, slot));
+        }
+        let index = self.resolve_upvalue(enclosing, name)?;
+        Some(self.add_upvalue(state, false, index))
+    }
+
+    fn add_upvalue(&mut self, state: usize, is_local: bool, index: u32) -> u32 {
+        let upvalue = UpvalueRef { is_local, index };
+        let upvalues = &mut self.states[state].function.upvalues;
+        if let Some(existing) = upvalues.iter().position(|u| *u == upvalue) {
+            return existing as u32;
+        }
+        upvalues.push(upvalue);
+        (upvalues.len() - 1) as u32
+    }
+
+    // ========================================================================
+    // JUMPS AND LOOPS
+    // ========================================================================
+
+    fn emit(&mut self, op: OpCode, span: &Span) -> usize {
+        let state = self.current_state();
+        let effect = state.function.chunk.stack_effect(op);
+        state.stack_height = state.stack_height.saturating_add_signed(effect);
+        state.function.chunk.emit(op, span)
+    }
+
+    /// Emit the instruction pushing `value`
+    fn emit_constant(&mut self, value: Value, span: &Span) {
+        let index = self.current_chunk().add_constant(value);
+        self.emit(OpCode::Constant(index), span);
+    }
+
+    fn code_len(&mut self) -> usize {
+        self.current_chunk().code.len()
+    }
+
+    /// Point a forward jump at the next instruction
+    fn patch_jump(&mut self, jump: usize) {
+        let target = self.code_len();
+        self.patch_to(jump, target);
+    }
+
+    fn patch_to(&mut self, jump: usize, target: usize) {
+        let target = target as u32;
+        let code = &mut self.current_chunk().code;
+        code[jump] = match code[jump] {
+            OpCode::Jump(_) => OpCode::Jump(target),
+            OpCode::JumpIfFalse(_) => OpCode::JumpIfFalse(target),
+            OpCode::ShortCircuit { op, .. } => OpCode::ShortCircuit { op, exit: target },
+            OpCode::IterNext { slot, .. } => OpCode::IterNext { slot, exit: target },
+            OpCode::MatchPattern { slot, pattern, .. } => OpCode::MatchPattern { slot, pattern, exit: target },
+            other => unreachable!("cannot patch {:?}", other),
+        };
+    }
+
+    fn begin_loop(&mut self, continue_target: Option<usize>) {
+        let state = self.current_state();
+        let local_count = state.locals.len();
+        state.loops.push(LoopState {
+            local_count,
+            continue_target,
+            continue_jumps: Vec::new(),
+            break_jumps: Vec::new(),
+        });
+    }
+
+    /// Pop the innermost loop, pointing its breaks after the loop
+    fn end_loop(&mut self) {
+        let state = self.current_state().loops.pop().expect("not in a loop");
+        for jump in state.break_jumps {
+            self.patch_jump(jump);
+        }
+    }
+
+    /// Drop the locals of the loop body and emit a jump to be patched
+    /// `error` is reported if there is no enclosing loop.
+    fn emit_loop_exit(&mut self, error: RuntimeError, span: &Span) -> RuntimeResult<usize> {
+        let local_count = match self.current_state().loops.last() {
+            Some(state) => state.local_count,
+            None => return Err(error.with_span(span)),
+        };
+
+        let ops: Vec<OpCode> = self.current_state().locals[local_count..]
+            .iter()
+            .rev()
+            .map(|local| if local.captured { OpCode::CloseUpvalue } else { OpCode::Pop })
+            .collect();
+        for op in ops {
+            self.emit(op, span);
+        }
+        Ok(self.emit(OpCode::Jump(0), span))
+    }
+}
+
+impl FunctionState {
+    fn new(name: &str, arity: usize) -> Self {
+        FunctionState {
+            function: Function {
+                name: name.to_string(),
+                arity,
+                ..Function::default()
+            },
+            locals: Vec::new(),
+            scope_depth: 0,
+            loops: Vec::new(),
+            stack_height: 0,
+        }
+    }
+}
+
+impl Default for Compiler {
+    fn default() -> Self {
+        Self::new()
+    }
+}
@@ -12,67 +12,122 @@
  * -------------------------------------------------------------------------
 */
 
+use std::cell::RefCell;
 use std::collections::HashMap;
+use std::fmt;
+use std::rc::Rc;
 use crate::vm::value::Value;
 use crate::vm::error::{RuntimeError, RuntimeResult};
 
+/// A single scope, linked to the scope it is nested in
+#[derive(Default)]
+struct Scope {
+    values: RefCell<HashMap<String, Value>>,
+    parent: Option<Rc<Scope>>,
+}
+
 /// Environment for managing variable scopes
-#[derive(Debug, Clone)]
+///
+/// Scopes are reference-counted and linked to their parent, so a function
+/// value can keep the scope it was defined in alive (closures).
+/// Cloning an environment is cheap and shares the same scopes.
+#[derive(Clone)]
 pub struct Environment {
-    scopes: Vec<HashMap<String, Value>>,
+    scope: Rc<Scope>,
 }
 
 impl Environment {
     /// Create a new environment with a global scope
     pub fn new() -> Self {
         Environment {
-            scopes: vec![HashMap::new()],
+            scope: Rc::new(Scope::default()),
+        }
+    }
+
+    /// Create a new environment whose innermost scope is nested in this one
+    pub fn child(&self) -> Self {
+        Environment {
+            scope: Rc::new(Scope {
+                values: RefCell::new(HashMap::new()),
+                parent: Some(self.scope.clone()),
+            }),
         }
     }
 
     /// Push a new scope (for blocks, functions, etc.)
     pub fn push_scope(&mut self) {
-        self.scopes.push(HashMap::new());
+        *self = self.child();
     }
 
     /// Pop the current scope
     pub fn pop_scope(&mut self) {
-        if self.scopes.len() > 1 {
-            self.scopes.pop();
+        if let Some(parent) = &self.scope.parent {
+            self.scope = parent.clone();
         }
     }
 
     /// Define a new variable in the current scope
     pub fn define(&mut self, name: String, value: Value) {
-        if let Some(scope) = self.scopes.last_mut() {
-            scope.insert(name, value);
-        }
+        self.scope.values.borrow_mut().insert(name, value);
     }
 
     /// Get a variable value (searches from innermost to outermost scope)
     pub fn get(&self, name: &str) -> RuntimeResult<Value> {
-        for scope in self.scopes.iter().rev() {
-            if let Some(value) = scope.get(name) {
+        let mut scope = Some(&self.scope);
+        while let Some(current) = scope {
+            if let Some(value) = current.values.borrow().get(name) {
                 return Ok(value.clone());
             }
+            scope = current.parent.as_ref();
         }
         Err(RuntimeError::UndefinedVariable { name: name.to_string() })
     }
 
     /// Update an existing variable (searches from innermost to outermost scope)
     pub fn set(&mut self, name: &str, value: Value) -> RuntimeResult<()> {
-        for scope in self.scopes.iter_mut().rev() {
-            if scope.contains_key(name) {
-                scope.insert(name.to_string(), value);
+        let mut scope = Some(&self.scope);
+        while let Some(current) = scope {
+            if let Some(slot) = current.values.borrow_mut().get_mut(name) {
+                *slot = value;
                 return Ok(());
             }
+            scope = current.parent.as_ref();
+        }
+        Err(RuntimeError::AssignmentToUndefined { name: name.to_string() })
+    }
+
+    /// Modify an existing variable in place (searches from innermost to outermost scope)
+    /// Used to write array elements without copying the whole array.
+    pub fn update<T>(
+        &mut self,
+        name: &str,
+        f: impl FnOnce(&mut Value) -> RuntimeResult<T>,
+    ) -> RuntimeResult<T> {
+        let mut scope = Some(&self.scope);
+        while let Some(current) = scope {
+            if let Some(slot) = current.values.borrow_mut().get_mut(name) {
+                return f(slot);
+            }
+            scope = current.parent.as_ref();
         }
         Err(RuntimeError::AssignmentToUndefined { name: name.to_string() })
     }
 
     /// Check if a variable exists in any scope
     pub fn exists(&self, name: &str) -> bool {
-        self.scopes.iter().rev().any(|scope| scope.contains_key(name))
+        let mut scope = Some(&self.scope);
+        while let Some(current) = scope {
+            if current.values.borrow().contains_key(name) {
+                return true;
+            }
+            scope = current.parent.as_ref();
+        }
+        false
+    }
+
+    /// Returns true if both environments share the same innermost scope
+    pub fn same_scope(&self, other: &Environment) -> bool {
+        Rc::ptr_eq(&self.scope, &other.scope)
     }
 }
 
@@ -81,3 +136,23 @@ impl Default for Environment {
         Self::new()
     }
 }
+
+/// Environments are compared by identity, as closures can make them cyclic
+impl PartialEq for Environment {
+    fn eq(&self, other: &Self) -> bool {
+        self.same_scope(other)
+    }
+}
+
+/// Only the variable names are shown, as closures can make scopes cyclic
+impl fmt::Debug for Environment {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        let mut names: Vec<String> = Vec::new();
+        let mut scope = Some(&self.scope);
+        while let Some(current) = scope {
+            names.extend(current.values.borrow().keys().cloned());
+            scope = current.parent.as_ref();
+        }
+        f.debug_struct("Environment").field("names", &names).finish()
+    }
+}
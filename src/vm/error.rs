@@ -13,6 +13,8 @@
 */
 
 use std::fmt;
+use crate::lexer::Span;
+use crate::vm::trace::{format_traceback, StackFrame};
 
 pub type RuntimeResult<T> = Result<T, RuntimeError>;
 
@@ -28,6 +30,40 @@ pub enum RuntimeError {
         name: String,
     },
 
+    /// Struct or enum type not declared
+    UndefinedType {
+        name: String,
+    },
+
+    /// Field not found in a value
+    UndefinedField {
+        type_name: String,
+        field: String,
+    },
+
+    /// Method not found for a value
+    UndefinedMethod {
+        type_name: String,
+        method: String,
+    },
+
+    /// Struct literal that doesn't give every field
+    MissingField {
+        type_name: String,
+        field: String,
+    },
+
+    /// Variant not found in an enum
+    UndefinedVariant {
+        enum_name: String,
+        variant: String,
+    },
+
+    /// Match without an arm for the value it is on
+    NoMatch {
+        value: String,
+    },
+
     /// Type mismatch error
     TypeMismatch {
         operation: String,
@@ -39,6 +75,26 @@ pub enum RuntimeError {
     DivisionByZero,
     ModuloByZero,
 
+    /// Integer arithmetic result that does not fit in an Int
+    IntegerOverflow {
+        operation: String,
+    },
+
+    /// Float with no Int equivalent, being NaN, infinite or out of range
+    FloatToIntConversion,
+
+    /// String that does not hold a number of the `target` type
+    InvalidConversion {
+        text: String,
+        target: String,
+    },
+
+    /// Argument of a native function outside of the values it accepts
+    InvalidArgument {
+        function: String,
+        reason: String,
+    },
+
     /// Index out of bounds error for arrays
     IndexOutOfBounds {
         index: i64,
@@ -62,14 +118,24 @@ pub enum RuntimeError {
         function_name: String,
     },
 
-    /// Stack overflow
+    /// Too many nested calls, `function` being the call that went over `depth`
     StackOverflow {
-        max_depth: usize,
+        depth: usize,
+        function: String,
     },
 
     /// Return statement outside of function
     ReturnOutsideFunction,
 
+    /// Break or continue statement outside of a loop
+    BreakOutsideLoop,
+    ContinueOutsideLoop,
+
+    /// Trying to iterate over a non-array value
+    NotIterable {
+        value_type: String,
+    },
+
     /// Invalid operation
     InvalidOperation {
         operation: String,
@@ -85,85 +151,176 @@ pub enum RuntimeError {
     /// Custom error message
     Custom(String),
 
+    /// Error annotated with the source location that raised it
+    Located {
+        span: Span,
+        error: Box<RuntimeError>,
+    },
+
+    /// Error annotated with the RemyLang calls active when it was raised
+    Traced {
+        error: Box<RuntimeError>,
+        frames: Vec<StackFrame>,
+    },
 }
 
-impl fmt::Display for RuntimeError {
-    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+impl RuntimeError {
+    /// Attaches a location to the error, unless it already has one
+    /// The innermost location is kept, as it is the most precise.
+    pub fn with_span(self, span: &Span) -> Self {
+        if self.span().is_some() {
+            return self;
+        }
         match self {
+            RuntimeError::Traced { error, frames } => RuntimeError::Traced {
+                error: Box::new(error.with_span(span)),
+                frames,
+            },
+            error => RuntimeError::Located {
+                span: span.clone(),
+                error: Box::new(error),
+            },
+        }
+    }
+
+    /// Attaches the active calls to the error, unless it already has them
+    pub fn with_trace(self, frames: Vec<StackFrame>) -> Self {
+        match self {
+            RuntimeError::Traced { .. } => self,
+            error => RuntimeError::Traced {
+                error: Box::new(error),
+                frames,
+            },
+        }
+    }
+
+    /// Location of the error, if known
+    pub fn span(&self) -> Option<&Span> {
+        match self {
+            RuntimeError::Located { span, .. } => Some(span),
+            RuntimeError::Traced { error, .. } => error.span(),
+            _ => None,
+        }
+    }
+
+    /// Calls active when the error was raised, outermost first, if known
+    pub fn trace(&self) -> Option<&[StackFrame]> {
+        match self {
+            RuntimeError::Traced { frames, .. } => Some(frames),
+            RuntimeError::Located { error, .. } => error.trace(),
+            _ => None,
+        }
+    }
+
+    /// The underlying error, without its location or trace
+    pub fn kind(&self) -> &RuntimeError {
+        match self {
+            RuntimeError::Located { error, .. } | RuntimeError::Traced { error, .. } => error.kind(),
+            error => error,
+        }
+    }
+
+    /// Description of the error, without its location
+    pub fn message(&self) -> String {
+        match self.kind() {
             RuntimeError::UndefinedVariable { name } => {
-                write!(f, "Runtime error: Undefined variable '{}'", name)
+                format!("Undefined variable '{}'", name)
             }
             RuntimeError::UndefinedFunction { name } => {
-                write!(f, "Runtime error: Undefined function '{}'", name)
+                format!("Undefined function '{}'", name)
+            }
+            RuntimeError::UndefinedType { name } => {
+                format!("Undefined type '{}'", name)
+            }
+            RuntimeError::UndefinedField { type_name, field } => {
+                format!("Type {} has no field '{}'", type_name, field)
+            }
+            RuntimeError::UndefinedMethod { type_name, method } => {
+                format!("Type {} has no method '{}'", type_name, method)
+            }
+            RuntimeError::MissingField { type_name, field } => {
+                format!("Missing field '{}' in {} literal", field, type_name)
+            }
+            RuntimeError::UndefinedVariant { enum_name, variant } => {
+                format!("Enum {} has no variant '{}'", enum_name, variant)
+            }
+            RuntimeError::NoMatch { value } => {
+                format!("No match arm matches the value {}", value)
             }
             RuntimeError::TypeMismatch { operation, expected, found } => {
-                write!(
-                    f,
-                    "Runtime error: Type error in {}: expected {}, found {}",
-                    operation, expected, found
-                )
+                format!("Type error in {}: expected {}, found {}", operation, expected, found)
             }
             RuntimeError::DivisionByZero => {
-                write!(f, "Runtime error: Division by zero")
+                "Division by zero".to_string()
             }
             RuntimeError::ModuloByZero => {
-                write!(f, "Runtime error: Modulo by zero")
+                "Modulo by zero".to_string()
+            }
+            RuntimeError::IntegerOverflow { operation } => {
+                format!("Integer overflow in {}", operation)
+            }
+            RuntimeError::FloatToIntConversion => {
+                "Cannot convert a NaN, infinite or out of range Float to Int".to_string()
+            }
+            RuntimeError::InvalidConversion { text, target } => {
+                format!("Cannot convert {:?} to {}", text, target)
+            }
+            RuntimeError::InvalidArgument { function, reason } => {
+                format!("Invalid argument to {}: {}", function, reason)
             }
             RuntimeError::IndexOutOfBounds { index, length } => {
-                write!(
-                    f,
-                    "Runtime error: Index {} out of bounds for array of length {}",
-                    index, length
-                )
+                format!("Index {} out of bounds for array of length {}", index, length)
             }
             RuntimeError::NotIndexable { value_type } => {
-                write!(
-                    f,
-                    "Runtime error: Cannot index into value of type {}",
-                    value_type
-                )
+                format!("Cannot index into value of type {}", value_type)
             }
             RuntimeError::NotCallable { value_type } => {
-                write!(
-                    f,
-                    "Runtime error: Cannot call value of type {}",
-                    value_type
-                )
+                format!("Cannot call value of type {}", value_type)
             }
             RuntimeError::ArgumentCountMismatch { expected, found, function_name } => {
-                write!(
-                    f,
-                    "Runtime error: Function '{}' expects {} arguments, but {} were provided",
-                    function_name, expected, found
-                )
-            }
-            RuntimeError::StackOverflow { max_depth } => {
-                write!(
-                    f,
-                    "Runtime error: Stack overflow (maximum call depth of {} exceeded)",
-                    max_depth
-                )
+                format!("Function '{}' expects {} arguments, but {} were provided", function_name, expected, found)
+            }
+            RuntimeError::StackOverflow { depth, function } => {
+                format!("Stack overflow calling '{}' (maximum call depth of {} exceeded)", function, depth)
             }
             RuntimeError::ReturnOutsideFunction => {
-                write!(f, "Runtime error: Return statement outside of function")
+                "Return statement outside of function".to_string()
+            }
+            RuntimeError::BreakOutsideLoop => {
+                "Break statement outside of loop".to_string()
+            }
+            RuntimeError::ContinueOutsideLoop => {
+                "Continue statement outside of loop".to_string()
+            }
+            RuntimeError::NotIterable { value_type } => {
+                format!("Cannot iterate over value of type {}", value_type)
             }
             RuntimeError::InvalidOperation { operation, left_type, right_type } => {
-                write!(
-                    f,
-                    "Runtime error: Invalid operation '{}' between {} and {}",
-                    operation, left_type, right_type
-                )
+                format!("Invalid operation '{}' between {} and {}", operation, left_type, right_type)
             }
             RuntimeError::AssignmentToUndefined { name } => {
-                write!(
-                    f,
-                    "Runtime error: Assignment to undefined variable '{}'. Use 'Type {} = ...' to declare it first",
-                    name, name
-                )
+                format!("Assignment to undefined variable '{}'. Use 'Type {} = ...' to declare it first", name, name)
             }
             RuntimeError::Custom(msg) => {
-                write!(f, "Runtime error: {}", msg)
+                msg.clone()
+            }
+            RuntimeError::Located { .. } | RuntimeError::Traced { .. } => {
+                unreachable!("kind() strips locations and traces")
+            }
+        }
+    }
+}
+
+impl fmt::Display for RuntimeError {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        match self {
+            RuntimeError::Located { span, error } => write!(f, "{}: {}", span, error),
+            // Errors of the top-level code need no traceback
+            RuntimeError::Traced { error, frames } if frames.is_empty() => write!(f, "{}", error),
+            RuntimeError::Traced { error, frames } => {
+                write!(f, "{}{}", format_traceback(frames, error.span(), None), error)
             }
+            _ => write!(f, "Runtime error: {}", self.message()),
         }
     }
 }
@@ -12,27 +12,57 @@
  * -------------------------------------------------------------------------
 */
 
+use std::collections::HashMap;
 use crate::ast::*;
+use crate::lexer::Span;
 use crate::vm::value::Value;
 use crate::vm::environment::Environment;
 use crate::vm::error::{RuntimeError, RuntimeResult};
 use crate::vm::builtin;
+use crate::vm::ops;
+use crate::vm::trace::{StackFrame, MAX_CALL_DEPTH};
+
+/// Host stack left before a call grows the stack
+/// Each statement nested in a function body takes a few recursive Rust
+/// calls, whose frames are large in debug builds, so this is generous.
+const STACK_RED_ZONE: usize = 1024 * 1024;
+/// Size of each stack segment allocated when the host stack runs low
+const STACK_SEGMENT_SIZE: usize = 8 * 1024 * 1024;
 
 /// Main interpreter struct
 pub struct Interpreter {
     env: Environment,
     in_function: bool, // Track if we're inside a function for return statements
+    frames: Vec<StackFrame>, // Calls being executed, outermost first
+    max_call_depth: usize,
+    structs: HashMap<String, Vec<String>>, // Field names of each struct, in declaration order
+    enums: HashMap<String, Vec<(String, usize)>>, // Variants of each enum, with their number of fields
+    methods: HashMap<String, HashMap<String, Value>>, // Methods of each type, by name
 }
 
-/// Control flow result type (for handling returns)
+/// Control flow result type (for handling returns and loop control)
 type ControlFlowResult = Result<Value, ControlFlow>;
 
 #[derive(Debug, Clone)]
 enum ControlFlow {
     Return(Value),
+    Break,
+    Continue,
     Error(RuntimeError),
 }
 
+impl ControlFlow {
+    /// Converts a signal that escaped its construct into the matching error
+    fn into_error(self) -> RuntimeError {
+        match self {
+            ControlFlow::Error(e) => e,
+            ControlFlow::Return(_) => RuntimeError::ReturnOutsideFunction,
+            ControlFlow::Break => RuntimeError::BreakOutsideLoop,
+            ControlFlow::Continue => RuntimeError::ContinueOutsideLoop,
+        }
+    }
+}
+
 impl From<RuntimeError> for ControlFlow {
     fn from(err: RuntimeError) -> Self {
         ControlFlow::Error(err)
@@ -42,45 +72,149 @@ impl From<RuntimeError> for ControlFlow {
 impl Interpreter {
     /// Create a new interpreter
     pub fn new() -> Self {
+        Self::with_max_call_depth(MAX_CALL_DEPTH)
+    }
+
+    /// Create an interpreter allowing at most `max_call_depth` nested calls
+    ///
+    /// # Example
+    /// ```
+    /// use remylang::lexer::Lexer;
+    /// use remylang::parser::Parser;
+    /// use remylang::vm::{Interpreter, RuntimeError};
+    ///
+    /// let code = "func F(Int n) -> Int { return F(n + 1); } F(0);";
+    /// let program = Parser::new(Lexer::new(code.to_string()).tokenize()).parse().unwrap();
+    /// let error = Interpreter::with_max_call_depth(100).execute(program).unwrap_err();
+    /// assert_eq!(error.kind(), &RuntimeError::StackOverflow { depth: 100, function: "F".to_string() });
+    /// ```
+    pub fn with_max_call_depth(max_call_depth: usize) -> Self {
         Interpreter {
             env: Environment::new(),
             in_function: false,
+            frames: Vec::new(),
+            max_call_depth,
+            structs: HashMap::new(),
+            enums: HashMap::new(),
+            methods: HashMap::new(),
         }
     }
 
+    /// Access the interpreter's global environment
+    pub fn environment(&self) -> &Environment {
+        &self.env
+    }
+
     /// Execute a program (list of statements)
     pub fn execute(&mut self, statements: Vec<Stmt>) -> RuntimeResult<()> {
+        self.execute_interactive(statements, |_| {})
+    }
+
+    /// Execute statements entered interactively, on top of the previous ones
+    /// `echo` receives the value of each expression statement that has one,
+    /// as soon as it is evaluated, on the calling thread.
+    pub fn execute_interactive(&mut self, statements: Vec<Stmt>, mut echo: impl FnMut(&Value)) -> RuntimeResult<()> {
+        self.declare_types(&statements);
+        self.declare_functions(&statements);
         for stmt in statements {
-            match self.execute_stmt_cf(stmt) {
-                Ok(_) => {}
-                Err(ControlFlow::Error(e)) => return Err(e),
-                Err(ControlFlow::Return(_)) => {
-                    return Err(RuntimeError::ReturnOutsideFunction);
-                }
+            let is_expression = matches!(stmt.kind, StmtKind::Expression(_));
+            let value = self.execute_stmt_cf(stmt).map_err(|cf| self.traced(cf.into_error()))?;
+            if is_expression && !matches!(value, Value::Void) {
+                echo(&value);
             }
         }
         Ok(())
     }
 
+    /// Register the structs, enums and methods declared in a program
+    /// They are hoisted, as the type checker lets code use them before their
+    /// declaration. Methods take the value they are called on as `self`.
+    fn declare_types(&mut self, statements: &[Stmt]) {
+        for stmt in statements {
+            match &stmt.kind {
+                StmtKind::StructDecl { name, fields } => {
+                    let fields = fields.iter().map(|field| field.name.clone()).collect();
+                    self.structs.insert(name.clone(), fields);
+                }
+                StmtKind::EnumDecl { name, variants } => {
+                    let variants = variants.iter().map(|v| (v.name.clone(), v.fields.len())).collect();
+                    self.enums.insert(name.clone(), variants);
+                }
+                StmtKind::Impl { name: type_name, methods } => {
+                    for method in methods {
+                        let StmtKind::FunctionDecl { name, params, body, .. } = &method.kind else { continue };
+                        let params = std::iter::once("self".to_string())
+                            .chain(params.iter().map(|p| p.name.clone()))
+                            .collect();
+                        let func_value = Value::Function {
+                            name: format!("{}.{}", type_name, name),
+                            params,
+                            body: body.clone(),
+                            closure: self.env.clone(),
+                        };
+                        self.methods.entry(type_name.clone()).or_default().insert(name.clone(), func_value);
+                    }
+                }
+                _ => {}
+            }
+        }
+    }
+
+    /// Define the functions declared by a list of statements in the current scope
+    /// They are hoisted, as the type checker lets code call them before their
+    /// declaration. Their closure is the scope, so they see its variables
+    /// once they are defined.
+    fn declare_functions(&mut self, statements: &[Stmt]) {
+        for stmt in statements {
+            if let StmtKind::FunctionDecl { name, params, body, .. } = &stmt.kind {
+                let func_value = Value::Function {
+                    name: name.clone(),
+                    params: params.iter().map(|p| p.name.clone()).collect(),
+                    body: body.clone(),
+                    closure: self.env.clone(),
+                };
+                self.env.define(name.clone(), func_value);
+            }
+        }
+    }
+
+    /// Attach the calls being executed to an error, unless it already has them
+    /// Errors are traced where they are raised, when the frames are complete.
+    fn traced(&self, error: RuntimeError) -> RuntimeError {
+        match error.trace() {
+            Some(_) => error,
+            None => error.with_trace(self.frames.clone()),
+        }
+    }
+
     /// Execute a single statement (with control flow)
+    /// Errors raised by the statement are annotated with its span.
     fn execute_stmt_cf(&mut self, stmt: Stmt) -> ControlFlowResult {
-        match stmt {
-            Stmt::Expression(expr) => self.eval_expr(expr).map_err(ControlFlow::from),
+        let Stmt { kind, span } = stmt;
+        self.execute_stmt_kind(kind).map_err(|cf| match cf {
+            ControlFlow::Error(e) => ControlFlow::Error(e.with_span(&span)),
+            other => other,
+        })
+    }
+
+    fn execute_stmt_kind(&mut self, kind: StmtKind) -> ControlFlowResult {
+        match kind {
+            StmtKind::Expression(expr) => self.eval_expr(expr).map_err(ControlFlow::from),
             
-            Stmt::Let { name, type_annotation: _, value } => {
+            StmtKind::Let { name, type_annotation: _, value } => {
                 let val = self.eval_expr(value).map_err(ControlFlow::from)?;
                 self.env.define(name, val);
                 Ok(Value::Void)
             }
             
-            Stmt::Assignment { name, value } => {
-                let val = self.eval_expr(value).map_err(ControlFlow::from)?;
-                self.env.set(&name, val).map_err(ControlFlow::from)?;
+            StmtKind::Assignment { target, op, value } => {
+                self.execute_assignment(target, op, value).map_err(ControlFlow::from)?;
                 Ok(Value::Void)
             }
             
-            Stmt::Block(statements) => {
+            StmtKind::Block(statements) => {
                 self.env.push_scope();
+                self.declare_functions(&statements);
                 let mut result = Ok(Value::Void);
                 
                 for stmt in statements {
@@ -97,7 +231,7 @@ impl Interpreter {
                 result
             }
             
-            Stmt::If { condition, then_branch, else_branch } => {
+            StmtKind::If { condition, then_branch, else_branch } => {
                 let cond_val = self.eval_expr(condition).map_err(ControlFlow::from)?;
                 
                 if cond_val.is_truthy() {
@@ -109,7 +243,7 @@ impl Interpreter {
                 }
             }
             
-            Stmt::Return(expr) => {
+            StmtKind::Return(expr) => {
                 if !self.in_function {
                     return Err(ControlFlow::Error(RuntimeError::ReturnOutsideFunction));
                 }
@@ -121,46 +255,211 @@ impl Interpreter {
                 Err(ControlFlow::Return(value))
             }
             
-            Stmt::FunctionDecl { name, params, return_type: _, body } => {
+            StmtKind::While { condition, body } => {
+                while self.eval_expr(condition.clone()).map_err(ControlFlow::from)?.is_truthy() {
+                    if !self.execute_loop_body(&body)? {
+                        break;
+                    }
+                }
+                Ok(Value::Void)
+            }
+
+            StmtKind::For { initializer, condition, increment, body } => {
+                // The initializer's variables are scoped to the loop
+                self.env.push_scope();
+                let result = self.execute_for(initializer, condition, increment, &body);
+                self.env.pop_scope();
+                result
+            }
+
+            StmtKind::ForIn { variable, iterable, body } => {
+                let items = match self.eval_expr(iterable).map_err(ControlFlow::from)? {
+                    Value::Array(items) => items,
+                    other => {
+                        return Err(ControlFlow::Error(RuntimeError::NotIterable {
+                            value_type: other.type_name().to_string(),
+                        }));
+                    }
+                };
+
+                for item in items {
+                    self.env.push_scope();
+                    self.env.define(variable.clone(), item);
+                    let keep_going = self.execute_loop_body(&body);
+                    self.env.pop_scope();
+                    if !keep_going? {
+                        break;
+                    }
+                }
+                Ok(Value::Void)
+            }
+
+            StmtKind::Break => Err(ControlFlow::Break),
+
+            StmtKind::Continue => Err(ControlFlow::Continue),
+
+            StmtKind::FunctionDecl { name, params, return_type: _, body } => {
                 let param_names: Vec<String> = params.into_iter()
                     .map(|p| p.name)
                     .collect();
                 
+                // Capture the declaring scope, which the function is then
+                // defined in, so it can also call itself recursively.
+                // Functions directly in a block were already defined when it
+                // started, this defines them again with the same value.
                 let func_value = Value::Function {
                     name: name.clone(),
                     params: param_names,
                     body,
+                    closure: self.env.clone(),
                 };
                 
                 self.env.define(name, func_value);
                 Ok(Value::Void)
             }
+
+            StmtKind::Match { subject, arms } => {
+                let value = self.eval_expr(subject).map_err(ControlFlow::from)?;
+                let (body, bindings) = Self::select_arm(arms, &value)?;
+                self.env.push_scope();
+                for (name, value) in bindings {
+                    self.env.define(name, value);
+                }
+                let result = self.execute_stmt_cf(body);
+                self.env.pop_scope();
+                result
+            }
+
+            // Declared before the program runs
+            StmtKind::StructDecl { .. } | StmtKind::EnumDecl { .. } | StmtKind::Impl { .. } => Ok(Value::Void),
+        }
+    }
+
+    /// Body of the first arm whose pattern matches `value`, with the names it binds
+    fn select_arm<T>(arms: Vec<MatchArm<T>>, value: &Value) -> RuntimeResult<(T, Vec<(String, Value)>)> {
+        for arm in arms {
+            if let Some(values) = ops::match_pattern(&arm.pattern, value) {
+                let names = arm.pattern.bindings().into_iter().map(str::to_string);
+                return Ok((arm.body, names.zip(values).collect()));
+            }
+        }
+        Err(RuntimeError::NoMatch { value: value.to_string() })
+    }
+
+    /// Execute `target = value` or `target op= value`
+    /// The indices of the target are evaluated first, then the value, and
+    /// the variable, element or field is updated in place.
+    fn execute_assignment(&mut self, target: AssignTarget, op: Option<BinaryOp>, value: Expr) -> RuntimeResult<()> {
+        let name = target.name();
+        let path = target
+            .path()
+            .into_iter()
+            .map(|accessor| match accessor {
+                Accessor::Index(index) => self.eval_expr(index.clone()),
+                Accessor::Field(field) => Ok(Value::String(field.to_string())),
+            })
+            .collect::<RuntimeResult<Vec<Value>>>()?;
+
+        let value = match op {
+            Some(op) => {
+                let current = self.env.update(name, |variable| Ok(ops::element_mut(variable, &path)?.clone()))?;
+                ops::binary_op(current, op, self.eval_expr(value)?)?
+            }
+            None => self.eval_expr(value)?,
+        };
+        self.env.update(name, |variable| {
+            *ops::element_mut(variable, &path)? = value;
+            Ok(())
+        })
+    }
+
+    /// Execute a C-style for loop, once its scope has been pushed
+    fn execute_for(
+        &mut self,
+        initializer: Option<Box<Stmt>>,
+        condition: Option<Expr>,
+        increment: Option<Box<Stmt>>,
+        body: &Stmt,
+    ) -> ControlFlowResult {
+        if let Some(init) = initializer {
+            self.execute_stmt_cf(*init)?;
+        }
+
+        loop {
+            if let Some(cond) = &condition {
+                if !self.eval_expr(cond.clone()).map_err(ControlFlow::from)?.is_truthy() {
+                    break;
+                }
+            }
+            if !self.execute_loop_body(body)? {
+                break;
+            }
+            if let Some(inc) = &increment {
+                self.execute_stmt_cf((**inc).clone())?;
+            }
+        }
+        Ok(Value::Void)
+    }
+
+    /// Execute one iteration of a loop body
+    /// Returns false when the loop should stop because of a break.
+    fn execute_loop_body(&mut self, body: &Stmt) -> Result<bool, ControlFlow> {
+        match self.execute_stmt_cf(body.clone()) {
+            Ok(_) | Err(ControlFlow::Continue) => Ok(true),
+            Err(ControlFlow::Break) => Ok(false),
+            Err(other) => Err(other),
         }
     }
 
     /// Evaluate an expression
+    /// Errors raised by the expression are annotated with its span.
     fn eval_expr(&mut self, expr: Expr) -> RuntimeResult<Value> {
-        match expr {
-            Expr::Literal(lit) => Ok(self.eval_literal(lit)),
+        let Expr { kind, span } = expr;
+        self.eval_expr_kind(kind, &span).map_err(|e| e.with_span(&span))
+    }
+
+    fn eval_expr_kind(&mut self, kind: ExprKind, span: &Span) -> RuntimeResult<Value> {
+        match kind {
+            ExprKind::Literal(lit) => Ok(self.eval_literal(lit)),
+
+            ExprKind::Interpolated(parts) => {
+                let mut text = String::new();
+                for part in parts {
+                    match part {
+                        InterpolatedPart::Text(s) => text.push_str(&s),
+                        InterpolatedPart::Expr(expr) => text.push_str(&self.eval_expr(expr)?.to_string()),
+                    }
+                }
+                Ok(Value::String(text))
+            }
             
-            Expr::Variable(name) => self.env.get(&name),
+            ExprKind::Variable(name) => self.env.get(&name),
             
-            Expr::Binary { left, op, right } => {
+            ExprKind::Binary { left, op: op @ (BinaryOp::And | BinaryOp::Or), right } => {
+                let left_val = self.eval_expr(*left)?;
+                if ops::short_circuits(op, ops::logical_operand(op, &left_val)?) {
+                    return Ok(left_val);
+                }
+                let right_val = self.eval_expr(*right)?;
+                ops::binary_op(left_val, op, right_val)
+            }
+
+            ExprKind::Binary { left, op, right } => {
                 let left_val = self.eval_expr(*left)?;
                 let right_val = self.eval_expr(*right)?;
-                self.eval_binary_op(left_val, op, right_val)
+                ops::binary_op(left_val, op, right_val)
             }
             
-            Expr::Unary { op, right } => {
+            ExprKind::Unary { op, right } => {
                 let right_val = self.eval_expr(*right)?;
-                self.eval_unary_op(op, right_val)
+                ops::unary_op(op, right_val)
             }
             
-            Expr::Call { callee, args } => {
-                self.eval_call(*callee, args)
+            ExprKind::Call { callee, args } => {
+                self.eval_call(*callee, args, span)
             }
             
-            Expr::ArrayLiteral(elements) => {
+            ExprKind::ArrayLiteral(elements) => {
                 let mut values = Vec::new();
                 for elem in elements {
                     values.push(self.eval_expr(elem)?);
@@ -168,34 +467,52 @@ impl Interpreter {
                 Ok(Value::Array(values))
             }
             
-            Expr::ArrayAccess { array, index } => {
+            ExprKind::ArrayAccess { array, index } => {
                 let array_val = self.eval_expr(*array)?;
                 let index_val = self.eval_expr(*index)?;
-                
-                match array_val {
-                    Value::Array(arr) => {
-                        match index_val.as_number() {
-                            Some(idx) => {
-                                if idx < 0 || idx >= arr.len() as i64 {
-                                    return Err(RuntimeError::IndexOutOfBounds {
-                                        index: idx,
-                                        length: arr.len(),
-                                    });
-                                }
-                                Ok(arr[idx as usize].clone())
-                            }
-                            None => Err(RuntimeError::TypeMismatch {
-                                operation: "array indexing".to_string(),
-                                expected: "Int".to_string(),
-                                found: index_val.type_name().to_string(),
-                            }),
-                        }
-                    }
-                    _ => Err(RuntimeError::NotIndexable {
-                        value_type: array_val.type_name().to_string(),
-                    }),
+                ops::index(&array_val, &index_val)
+            }
+
+            ExprKind::FieldAccess { object, field } => {
+                let object_val = self.eval_expr(*object)?;
+                ops::field(&object_val, &field)
+            }
+
+            ExprKind::StructLiteral { name, fields } => {
+                let layout = self.structs.get(&name).cloned()
+                    .ok_or_else(|| RuntimeError::UndefinedType { name: name.clone() })?;
+                let mut values = Vec::new();
+                for (field, value) in fields {
+                    values.push((field, self.eval_expr(value)?));
                 }
+                ops::make_struct(&name, &layout, values)
+            }
+
+            ExprKind::Variant { enum_name, variant, args } => {
+                let variants = self.enums.get(&enum_name).cloned()
+                    .ok_or_else(|| RuntimeError::UndefinedType { name: enum_name.clone() })?;
+                let values = self.eval_args(args)?;
+                ops::make_variant(&enum_name, &variants, &variant, values)
             }
+
+            ExprKind::Match { subject, arms } => {
+                let value = self.eval_expr(*subject)?;
+                let (body, bindings) = Self::select_arm(arms, &value)?;
+                self.env.push_scope();
+                for (name, value) in bindings {
+                    self.env.define(name, value);
+                }
+                let result = self.eval_expr(body);
+                self.env.pop_scope();
+                result
+            }
+
+            ExprKind::Lambda { params, return_type: _, body } => Ok(Value::Function {
+                name: "<lambda>".to_string(),
+                params: params.into_iter().map(|p| p.name).collect(),
+                body,
+                closure: self.env.clone(),
+            }),
         }
     }
 
@@ -203,210 +520,120 @@ impl Interpreter {
     fn eval_literal(&self, lit: Literal) -> Value {
         match lit {
             Literal::Number(n) => Value::Number(n),
+            Literal::Float(x) => Value::Float(x),
             Literal::String(s) => Value::String(s),
             Literal::Char(c) => Value::Char(c),
             Literal::Bool(b) => Value::Bool(b),
         }
     }
 
-    /// Evaluate a binary operation
-    fn eval_binary_op(&self, left: Value, op: BinaryOp, right: Value) -> RuntimeResult<Value> {
-        match op {
-            // Arithmetic operations
-            BinaryOp::Add => match (left, right) {
-                (Value::Number(l), Value::Number(r)) => Ok(Value::Number(l + r)),
-                (Value::String(l), Value::String(r)) => Ok(Value::String(l + &r)),
-                (l, r) => Err(RuntimeError::InvalidOperation {
-                    operation: "addition".to_string(),
-                    left_type: l.type_name().to_string(),
-                    right_type: r.type_name().to_string(),
-                }),
-            },
-            
-            BinaryOp::Sub => match (left, right) {
-                (Value::Number(l), Value::Number(r)) => Ok(Value::Number(l - r)),
-                (l, r) => Err(RuntimeError::InvalidOperation {
-                    operation: "subtraction".to_string(),
-                    left_type: l.type_name().to_string(),
-                    right_type: r.type_name().to_string(),
-                }),
-            },
-            
-            BinaryOp::Mul => match (left, right) {
-                (Value::Number(l), Value::Number(r)) => Ok(Value::Number(l * r)),
-                (l, r) => Err(RuntimeError::InvalidOperation {
-                    operation: "multiplication".to_string(),
-                    left_type: l.type_name().to_string(),
-                    right_type: r.type_name().to_string(),
-                }),
-            },
-            
-            BinaryOp::Div => match (left, right) {
-                (Value::Number(l), Value::Number(r)) => {
-                    if r == 0 {
-                        Err(RuntimeError::DivisionByZero)
-                    } else {
-                        Ok(Value::Number(l / r))
-                    }
-                }
-                (l, r) => Err(RuntimeError::InvalidOperation {
-                    operation: "division".to_string(),
-                    left_type: l.type_name().to_string(),
-                    right_type: r.type_name().to_string(),
-                }),
-            },
-            
-            BinaryOp::Mod => match (left, right) {
-                (Value::Number(l), Value::Number(r)) => {
-                    if r == 0 {
-                        Err(RuntimeError::ModuloByZero)
-                    } else {
-                        Ok(Value::Number(l % r))
-                    }
-                }
-                (l, r) => Err(RuntimeError::InvalidOperation {
-                    operation: "modulo".to_string(),
-                    left_type: l.type_name().to_string(),
-                    right_type: r.type_name().to_string(),
-                }),
-            },
-            
-            BinaryOp::Pow => match (left, right) {
-                (Value::Number(l), Value::Number(r)) => {
-                    if r < 0 {
-                        Err(RuntimeError::Custom("Negative exponents not supported for integers".to_string()))
-                    } else {
-                        Ok(Value::Number(l.pow(r as u32)))
-                    }
+    /// Evaluate a function call, `span` being the call expression
+    fn eval_call(&mut self, callee: Expr, args: Vec<Expr>, span: &Span) -> RuntimeResult<Value> {
+        match callee.kind {
+            ExprKind::Variable(name) => {
+                // Evaluate arguments first
+                let arg_values = self.eval_args(args)?;
+
+                // Check if it's a built-in function, not hidden by a variable
+                if builtin::is_builtin(&name) && self.env.get(&name).is_err() {
+                    return builtin::call_builtin(&name, arg_values);
                 }
-                (l, r) => Err(RuntimeError::InvalidOperation {
-                    operation: "exponentiation".to_string(),
-                    left_type: l.type_name().to_string(),
-                    right_type: r.type_name().to_string(),
-                }),
-            },
-            
-            // Comparison operations
-            BinaryOp::Equal => Ok(Value::Bool(left == right)),
-            BinaryOp::NotEqual => Ok(Value::Bool(left != right)),
-            
-            BinaryOp::Less => match (left, right) {
-                (Value::Number(l), Value::Number(r)) => Ok(Value::Bool(l < r)),
-                (l, r) => Err(RuntimeError::InvalidOperation {
-                    operation: "comparison".to_string(),
-                    left_type: l.type_name().to_string(),
-                    right_type: r.type_name().to_string(),
-                }),
-            },
-            
-            BinaryOp::Greater => match (left, right) {
-                (Value::Number(l), Value::Number(r)) => Ok(Value::Bool(l > r)),
-                (l, r) => Err(RuntimeError::InvalidOperation {
-                    operation: "comparison".to_string(),
-                    left_type: l.type_name().to_string(),
-                    right_type: r.type_name().to_string(),
-                }),
-            },
-            
-            BinaryOp::LessEqual => match (left, right) {
-                (Value::Number(l), Value::Number(r)) => Ok(Value::Bool(l <= r)),
-                (l, r) => Err(RuntimeError::InvalidOperation {
-                    operation: "comparison".to_string(),
-                    left_type: l.type_name().to_string(),
-                    right_type: r.type_name().to_string(),
-                }),
-            },
-            
-            BinaryOp::GreaterEqual => match (left, right) {
-                (Value::Number(l), Value::Number(r)) => Ok(Value::Bool(l >= r)),
-                (l, r) => Err(RuntimeError::InvalidOperation {
-                    operation: "comparison".to_string(),
-                    left_type: l.type_name().to_string(),
-                    right_type: r.type_name().to_string(),
-                }),
-            },
-            
-            // Logical operations
-            BinaryOp::And => Ok(Value::Bool(left.as_bool() && right.as_bool())),
-            BinaryOp::Or => Ok(Value::Bool(left.as_bool() || right.as_bool())),
+
+                // Otherwise, get user-defined function
+                let func = self.env.get(&name)?;
+                self.call_function(func, arg_values, span)
+            }
+            ExprKind::FieldAccess { object, field } => {
+                // The value the method is called on comes before its arguments
+                let receiver = self.eval_expr(*object)?;
+                let mut arg_values = vec![receiver];
+                arg_values.extend(self.eval_args(args)?);
+
+                let type_name = arg_values[0].type_name();
+                let method = self.methods.get(type_name)
+                    .and_then(|methods| methods.get(&field))
+                    .cloned()
+                    .ok_or_else(|| RuntimeError::UndefinedMethod {
+                        type_name: type_name.to_string(),
+                        method: field,
+                    })?;
+                self.call_function(method, arg_values, span)
+            }
+            kind => {
+                // Arguments are evaluated before the callee, as for named functions
+                let arg_values = self.eval_args(args)?;
+                let func = self.eval_expr(Expr::new(kind, callee.span))?;
+                self.call_function(func, arg_values, span)
+            }
         }
     }
 
-    /// Evaluate a unary operation
-    fn eval_unary_op(&self, op: UnaryOp, right: Value) -> RuntimeResult<Value> {
-        match op {
-            UnaryOp::Minus => match right {
-                Value::Number(n) => Ok(Value::Number(-n)),
-                _ => Err(RuntimeError::TypeMismatch {
-                    operation: "unary minus".to_string(),
-                    expected: "Int".to_string(),
-                    found: right.type_name().to_string(),
-                }),
-            },
-            
-            UnaryOp::Not => Ok(Value::Bool(!right.as_bool())),
-        }
+    /// Evaluate call arguments, in order
+    fn eval_args(&mut self, args: Vec<Expr>) -> RuntimeResult<Vec<Value>> {
+        args.into_iter().map(|arg| self.eval_expr(arg)).collect()
     }
 
-    /// Evaluate a function call
-    fn eval_call(&mut self, callee: Expr, args: Vec<Expr>) -> RuntimeResult<Value> {
-        // Evaluate arguments first
-        let mut arg_values = Vec::new();
-        for arg in args {
-            arg_values.push(self.eval_expr(arg)?);
-        }
+    /// Call a function value with evaluated arguments
+    /// The host stack grows on demand, so the call depth is only bounded by
+    /// `max_call_depth`, whatever the stack of the calling thread.
+    fn call_function(&mut self, func: Value, arg_values: Vec<Value>, span: &Span) -> RuntimeResult<Value> {
+        stacker::maybe_grow(STACK_RED_ZONE, STACK_SEGMENT_SIZE, || self.call_function_here(func, arg_values, span))
+    }
 
-        // Get the function
-        match callee {
-            Expr::Variable(name) => {
-                // Check if it's a built-in function
-                if builtin::is_builtin(&name) {
-                    return builtin::call_builtin(&name, arg_values);
+    /// Call a function value on the current host stack
+    fn call_function_here(&mut self, func: Value, arg_values: Vec<Value>, span: &Span) -> RuntimeResult<Value> {
+        match func {
+            Value::Function { name: fn_name, params, body, closure } => {
+                // Check arity
+                if params.len() != arg_values.len() {
+                    return Err(RuntimeError::ArgumentCountMismatch {
+                        expected: params.len(),
+                        found: arg_values.len(),
+                        function_name: fn_name,
+                    });
+                }
+                if self.frames.len() >= self.max_call_depth {
+                    let error = RuntimeError::StackOverflow {
+                        depth: self.max_call_depth,
+                        function: fn_name,
+                    };
+                    return Err(self.traced(error));
                 }
 
-                // Otherwise, get user-defined function
-                let func = self.env.get(&name)?;
-                
-                match func {
-                    Value::Function { name: fn_name, params, body } => {
-                        // Check arity
-                        if params.len() != arg_values.len() {
-                            return Err(RuntimeError::ArgumentCountMismatch {
-                                expected: params.len(),
-                                found: arg_values.len(),
-                                function_name: fn_name,
-                            });
-                        }
+                // The body runs in a new scope nested in the declaring
+                // scope, not the caller's (lexical scoping)
+                let mut call_env = closure.child();
 
-                        // Create new scope for function
-                        self.env.push_scope();
-                        
-                        // Bind parameters
-                        for (param, arg) in params.iter().zip(arg_values.iter()) {
-                            self.env.define(param.clone(), arg.clone());
-                        }
+                // Bind parameters
+                for (param, arg) in params.iter().zip(arg_values.iter()) {
+                    call_env.define(param.clone(), arg.clone());
+                }
+                let caller_env = std::mem::replace(&mut self.env, call_env);
 
-                        // Execute function body
-                        let was_in_function = self.in_function;
-                        self.in_function = true;
-                        
-                        let result = match self.execute_stmt_cf(*body) {
-                            Ok(val) => Ok(val),
-                            Err(ControlFlow::Return(val)) => Ok(val),
-                            Err(ControlFlow::Error(e)) => Err(e),
-                        };
+                // Execute function body
+                let was_in_function = self.in_function;
+                self.in_function = true;
+                self.frames.push(StackFrame {
+                    function: fn_name,
+                    call_site: span.clone(),
+                    args: arg_values,
+                });
 
-                        self.in_function = was_in_function;
-                        self.env.pop_scope();
-                        
-                        result
-                    }
-                    _ => Err(RuntimeError::NotCallable {
-                        value_type: func.type_name().to_string(),
-                    }),
-                }
+                let result = match self.execute_stmt_cf(*body) {
+                    Ok(val) => Ok(val),
+                    Err(ControlFlow::Return(val)) => Ok(val),
+                    Err(other) => Err(self.traced(other.into_error())),
+                };
+
+                self.frames.pop();
+                self.in_function = was_in_function;
+                self.env = caller_env;
+
+                result
             }
-            _ => Err(RuntimeError::Custom("Can only call functions by name".to_string())),
+            _ => Err(RuntimeError::NotCallable {
+                value_type: func.type_name().to_string(),
+            }),
         }
     }
 }
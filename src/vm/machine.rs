@@ -0,0 +1,543 @@
+/*
+ * -------------------------------------------------------------------------
+ * RemyLang — stack machine
+ * File : src/vm/machine.rs
+ *
+ * Description :
+ *   Executes compiled bytecode on a value stack with call frames.
+ *
+ * Author  : Samuel 'Meeast' Bleau
+ * Created : 2026-10-16
+ *
+ * -------------------------------------------------------------------------
+*/
+
+use std::cell::RefCell;
+use std::collections::HashMap;
+use std::rc::Rc;
+use crate::vm::builtin;
+use crate::vm::bytecode::{Chunk, Closure, OpCode, Program, Upvalue, UpvalueState, Variable};
+use crate::vm::error::{RuntimeError, RuntimeResult};
+use crate::vm::ops;
+use crate::vm::trace::{StackFrame, MAX_CALL_DEPTH};
+use crate::vm::value::Value;
+
+/// Bytecode virtual machine
+/// Globals are kept between runs, so that successive programs compiled by
+/// the same compiler share their state.
+pub struct Machine {
+    stack: Vec<Value>,
+    frames: Vec<CallFrame>,
+    globals: Vec<Option<Value>>, // None until the global is defined
+    global_names: Vec<String>,
+    open_upvalues: Vec<Upvalue>, // Captured variables still living on the stack
+    structs: HashMap<String, Vec<String>>, // Field names of each struct, in declaration order
+    enums: HashMap<String, Vec<(String, usize)>>, // Variants of each enum, with their number of fields
+    methods: HashMap<String, HashMap<String, Value>>, // Methods of each type, by name
+}
+
+/// A function being executed
+struct CallFrame {
+    closure: Rc<Closure>,
+    ip: usize,
+    base: usize, // Stack index of the frame's first local slot
+}
+
+impl Machine {
+    /// Create a new machine
+    pub fn new() -> Self {
+        Machine {
+            stack: Vec::new(),
+            frames: Vec::new(),
+            globals: Vec::new(),
+            global_names: Vec::new(),
+            open_upvalues: Vec::new(),
+            structs: HashMap::new(),
+            enums: HashMap::new(),
+            methods: HashMap::new(),
+        }
+    }
+
+    /// Value of a global variable, if it is defined
+    pub fn global(&self, name: &str) -> Option<Value> {
+        let slot = self.global_names.iter().position(|n| n == name)?;
+        self.globals.get(slot).cloned().flatten()
+    }
+
+    /// Run a compiled program
+    pub fn run(&mut self, program: &Program) -> RuntimeResult<()> {
+        self.global_names = program.globals.clone();
+        self.globals.resize(program.globals.len(), None);
+
+        let closure = Rc::new(Closure {
+            function: program.script.clone(),
+            upvalues: Vec::new(),
+        });
+        self.frames.push(CallFrame { closure, ip: 0, base: self.stack.len() });
+
+        let result = self.execute().map_err(|e| e.with_trace(self.trace()));
+        if result.is_err() {
+            self.frames.clear();
+            self.stack.clear();
+            self.open_upvalues.clear();
+        }
+        result
+    }
+
+    /// Main dispatch loop, until the script returns
+    /// Errors are annotated with the span of the failing instruction.
+    fn execute(&mut self) -> RuntimeResult<()> {
+        loop {
+            let frame = self.frames.last_mut().expect("no frame");
+            let ip = frame.ip;
+            let op = frame.closure.function.chunk.code[ip];
+            frame.ip += 1;
+
+            match self.step(op) {
+                Ok(true) => {}
+                Ok(false) => return Ok(()),
+                Err(e) => {
+                    let span = &self.chunk().spans[ip];
+                    return Err(e.with_span(span));
+                }
+            }
+        }
+    }
+
+    /// Execute one instruction
+    /// Returns false once the script has returned.
+    fn step(&mut self, op: OpCode) -> RuntimeResult<bool> {
+        match op {
+            OpCode::Constant(index) => {
+                let value = self.chunk().constants[index as usize].clone();
+                self.stack.push(value);
+            }
+
+            OpCode::Void => self.stack.push(Value::Void),
+
+            OpCode::Pop => {
+                self.pop();
+            }
+
+            OpCode::DefineGlobal(slot) => {
+                let value = self.pop();
+                self.globals[slot as usize] = Some(value);
+            }
+
+            OpCode::GetGlobal(slot) => match &self.globals[slot as usize] {
+                Some(value) => self.stack.push(value.clone()),
+                None => {
+                    return Err(RuntimeError::UndefinedVariable {
+                        name: self.global_names[slot as usize].clone(),
+                    });
+                }
+            },
+
+            OpCode::SetGlobal(slot) => {
+                let value = self.pop();
+                match &mut self.globals[slot as usize] {
+                    Some(global) => *global = value,
+                    None => {
+                        return Err(RuntimeError::AssignmentToUndefined {
+                            name: self.global_names[slot as usize].clone(),
+                        });
+                    }
+                }
+            }
+
+            OpCode::GetLocal(slot) => {
+                let value = self.stack[self.base() + slot as usize].clone();
+                self.stack.push(value);
+            }
+
+            OpCode::SetLocal(slot) => {
+                let value = self.pop();
+                let index = self.base() + slot as usize;
+                self.stack[index] = value;
+            }
+
+            OpCode::GetUpvalue(index) => {
+                let upvalue = self.frame().closure.upvalues[index as usize].clone();
+                let value = match &*upvalue.borrow() {
+                    UpvalueState::Open(slot) => self.stack[*slot].clone(),
+                    UpvalueState::Closed(value) => value.clone(),
+                };
+                self.stack.push(value);
+            }
+
+            OpCode::SetUpvalue(index) => {
+                let value = self.pop();
+                let upvalue = self.frame().closure.upvalues[index as usize].clone();
+                let mut state = upvalue.borrow_mut();
+                match &mut *state {
+                    UpvalueState::Open(slot) => self.stack[*slot] = value,
+                    UpvalueState::Closed(closed) => *closed = value,
+                }
+            }
+
+            OpCode::CloseUpvalue => {
+                self.close_upvalues(self.stack.len() - 1);
+                self.pop();
+            }
+
+            OpCode::Binary(op) => {
+                let right = self.pop();
+                let left = self.pop();
+                self.stack.push(ops::binary_op(left, op, right)?);
+            }
+
+            OpCode::Unary(op) => {
+                let right = self.pop();
+                self.stack.push(ops::unary_op(op, right)?);
+            }
+
+            OpCode::Array(count) => {
+                let start = self.stack.len() - count as usize;
+                let elements = self.stack.split_off(start);
+                self.stack.push(Value::Array(elements));
+            }
+
+            OpCode::Interpolate(count) => {
+                let start = self.stack.len() - count as usize;
+                let text = self.stack.split_off(start).iter().map(Value::to_string).collect();
+                self.stack.push(Value::String(text));
+            }
+
+            OpCode::Index => {
+                let index = self.pop();
+                let array = self.pop();
+                self.stack.push(ops::index(&array, &index)?);
+            }
+
+            OpCode::GetElement { variable, depth } => {
+                let indices = self.stack[self.stack.len() - depth as usize..].to_vec();
+                let element = self.with_variable(variable, |value| Ok(ops::element_mut(value, &indices)?.clone()))?;
+                self.stack.push(element);
+            }
+
+            OpCode::SetElement { variable, depth } => {
+                let value = self.pop();
+                let indices = self.stack.split_off(self.stack.len() - depth as usize);
+                self.with_variable(variable, |target| {
+                    *ops::element_mut(target, &indices)? = value;
+                    Ok(())
+                })?;
+            }
+
+            OpCode::DefineStruct { name, fields } => {
+                let fields = match &self.chunk().constants[fields as usize] {
+                    Value::Array(fields) => fields.iter().map(|field| field.to_string()).collect(),
+                    other => unreachable!("struct fields must be an array, found {:?}", other),
+                };
+                self.structs.insert(self.name_constant(name), fields);
+            }
+
+            OpCode::DefineMethod { type_name, method } => {
+                let closure = self.pop();
+                let type_name = self.name_constant(type_name);
+                let method = self.name_constant(method);
+                self.methods.entry(type_name).or_default().insert(method, closure);
+            }
+
+            OpCode::MakeStruct { name, fields } => {
+                let name = self.name_constant(name);
+                let names: Vec<String> = match &self.chunk().constants[fields as usize] {
+                    Value::Array(names) => names.iter().map(|field| field.to_string()).collect(),
+                    other => unreachable!("struct fields must be an array, found {:?}", other),
+                };
+                let layout = self.structs.get(&name)
+                    .ok_or_else(|| RuntimeError::UndefinedType { name: name.clone() })?;
+                let values = self.stack.split_off(self.stack.len() - names.len());
+                let value = ops::make_struct(&name, layout, names.into_iter().zip(values).collect())?;
+                self.stack.push(value);
+            }
+
+            OpCode::GetField(field) => {
+                let object = self.pop();
+                let field = self.name_constant(field);
+                self.stack.push(ops::field(&object, &field)?);
+            }
+
+            OpCode::DefineEnum { name, variants } => {
+                let variants = match &self.chunk().constants[variants as usize] {
+                    Value::Array(variants) => variants
+                        .iter()
+                        .map(|variant| match variant {
+                            Value::Array(pair) => match pair.as_slice() {
+                                [name, Value::Number(arity)] => (name.to_string(), *arity as usize),
+                                other => unreachable!("enum variants must be [name, arity], found {:?}", other),
+                            },
+                            other => unreachable!("enum variants must be arrays, found {:?}", other),
+                        })
+                        .collect(),
+                    other => unreachable!("enum variants must be an array, found {:?}", other),
+                };
+                self.enums.insert(self.name_constant(name), variants);
+            }
+
+            OpCode::MakeVariant { enum_name, variant, argc } => {
+                let enum_name = self.name_constant(enum_name);
+                let variant = self.name_constant(variant);
+                let variants = self.enums.get(&enum_name)
+                    .ok_or_else(|| RuntimeError::UndefinedType { name: enum_name.clone() })?;
+                let fields = self.stack.split_off(self.stack.len() - argc as usize);
+                let value = ops::make_variant(&enum_name, variants, &variant, fields)?;
+                self.stack.push(value);
+            }
+
+            OpCode::MatchPattern { slot, pattern, exit } => {
+                let subject = &self.stack[self.base() + slot as usize];
+                match ops::match_pattern(&self.chunk().patterns[pattern as usize], subject) {
+                    Some(bound) => self.stack.extend(bound),
+                    None => self.frame_mut().ip = exit as usize,
+                }
+            }
+
+            OpCode::NoMatch(slot) => {
+                let subject = &self.stack[self.base() + slot as usize];
+                return Err(RuntimeError::NoMatch { value: subject.to_string() });
+            }
+
+            OpCode::Jump(target) => self.frame_mut().ip = target as usize,
+
+            OpCode::JumpIfFalse(target) => {
+                if !self.pop().is_truthy() {
+                    self.frame_mut().ip = target as usize;
+                }
+            }
+
+            OpCode::ShortCircuit { op, exit } => {
+                let left = self.stack.last().expect("empty stack");
+                if ops::short_circuits(op, ops::logical_operand(op, left)?) {
+                    self.frame_mut().ip = exit as usize;
+                }
+            }
+
+            OpCode::IterCheck => {
+                let top = self.stack.last().expect("empty stack");
+                if !matches!(top, Value::Array(_)) {
+                    return Err(RuntimeError::NotIterable {
+                        value_type: top.type_name().to_string(),
+                    });
+                }
+            }
+
+            OpCode::IterNext { slot, exit } => {
+                let array_slot = self.base() + slot as usize;
+                let cursor = match self.stack[array_slot + 1] {
+                    Value::Number(n) => n as usize,
+                    _ => unreachable!("for-in cursor is always an Int"),
+                };
+                let next = match &self.stack[array_slot] {
+                    Value::Array(items) => items.get(cursor).cloned(),
+                    _ => unreachable!("for-in target is checked by IterCheck"),
+                };
+                match next {
+                    Some(item) => {
+                        self.stack[array_slot + 1] = Value::Number(cursor as i64 + 1);
+                        self.stack.push(item);
+                    }
+                    None => self.frame_mut().ip = exit as usize,
+                }
+            }
+
+            OpCode::Call(argc) => {
+                let callee = self.pop();
+                self.call(callee, argc as usize)?;
+            }
+
+            OpCode::Invoke { method, argc } => {
+                let method = self.name_constant(method);
+                let receiver = &self.stack[self.stack.len() - argc as usize - 1];
+                let closure = self.methods.get(receiver.type_name())
+                    .and_then(|methods| methods.get(&method))
+                    .cloned()
+                    .ok_or_else(|| RuntimeError::UndefinedMethod {
+                        type_name: receiver.type_name().to_string(),
+                        method,
+                    })?;
+                self.call(closure, argc as usize + 1)?;
+            }
+
+            OpCode::CallBuiltin { name, argc } => {
+                let name = self.name_constant(name);
+                let start = self.stack.len() - argc as usize;
+                let args = self.stack.split_off(start);
+                self.stack.push(builtin::call_builtin(&name, args)?);
+            }
+
+            OpCode::Closure(index) => {
+                let function = self.chunk().functions[index as usize].clone();
+                let base = self.base();
+                let upvalues = function
+                    .upvalues
+                    .iter()
+                    .map(|upvalue| {
+                        if upvalue.is_local {
+                            self.capture_upvalue(base + upvalue.index as usize)
+                        } else {
+                            self.frame().closure.upvalues[upvalue.index as usize].clone()
+                        }
+                    })
+                    .collect();
+                self.stack.push(Value::Closure(Rc::new(Closure { function, upvalues })));
+            }
+
+            OpCode::Return => {
+                let result = self.pop();
+                let frame = self.frames.pop().expect("no frame");
+                self.close_upvalues(frame.base);
+                self.stack.truncate(frame.base);
+
+                if self.frames.is_empty() {
+                    return Ok(false);
+                }
+                self.stack.push(result);
+            }
+        }
+        Ok(true)
+    }
+
+    /// Calls being executed, outermost first
+    /// Arguments are read back from the parameters' slots, so a parameter
+    /// the function assigned to shows its new value.
+    fn trace(&self) -> Vec<StackFrame> {
+        self.frames
+            .windows(2)
+            .map(|pair| {
+                let (caller, frame) = (&pair[0], &pair[1]);
+                let function = &frame.closure.function;
+                StackFrame {
+                    function: function.name.clone(),
+                    call_site: caller.closure.function.chunk.spans[caller.ip - 1].clone(),
+                    args: self.stack[frame.base..frame.base + function.arity].to_vec(),
+                }
+            })
+            .collect()
+    }
+
+    /// Start executing a function, its arguments being on top of the stack
+    fn call(&mut self, callee: Value, argc: usize) -> RuntimeResult<()> {
+        let closure = match callee {
+            Value::Closure(closure) => closure,
+            other => {
+                return Err(RuntimeError::NotCallable {
+                    value_type: other.type_name().to_string(),
+                });
+            }
+        };
+
+        let function = &closure.function;
+        if function.arity != argc {
+            return Err(RuntimeError::ArgumentCountMismatch {
+                expected: function.arity,
+                found: argc,
+                function_name: function.name.clone(),
+            });
+        }
+        // The frame of the top-level code is not a call
+        if self.frames.len() > MAX_CALL_DEPTH {
+            return Err(RuntimeError::StackOverflow {
+                depth: MAX_CALL_DEPTH,
+                function: function.name.clone(),
+            });
+        }
+
+        let base = self.stack.len() - argc;
+        self.frames.push(CallFrame { closure, ip: 0, base });
+        Ok(())
+    }
+
+    /// Upvalue for a stack slot, shared with the closures that already captured it
+    fn capture_upvalue(&mut self, slot: usize) -> Upvalue {
+        let existing = self
+            .open_upvalues
+            .iter()
+            .find(|upvalue| *upvalue.borrow() == UpvalueState::Open(slot));
+        if let Some(upvalue) = existing {
+            return upvalue.clone();
+        }
+
+        let upvalue = Rc::new(RefCell::new(UpvalueState::Open(slot)));
+        self.open_upvalues.push(upvalue.clone());
+        upvalue
+    }
+
+    /// Move the captured slots at or above `from` off the stack
+    fn close_upvalues(&mut self, from: usize) {
+        let stack = &self.stack;
+        self.open_upvalues.retain(|upvalue| {
+            let slot = match *upvalue.borrow() {
+                UpvalueState::Open(slot) => slot,
+                UpvalueState::Closed(_) => return false,
+            };
+            if slot < from {
+                return true;
+            }
+            *upvalue.borrow_mut() = UpvalueState::Closed(stack[slot].clone());
+            false
+        });
+    }
+
+    fn pop(&mut self) -> Value {
+        self.stack.pop().expect("empty stack")
+    }
+
+    fn frame(&self) -> &CallFrame {
+        self.frames.last().expect("no frame")
+    }
+
+    fn frame_mut(&mut self) -> &mut CallFrame {
+        self.frames.last_mut().expect("no frame")
+    }
+
+    fn base(&self) -> usize {
+        self.frame().base
+    }
+
+    /// Run `f` on the value of a variable, which it can modify in place
+    fn with_variable<T>(
+        &mut self,
+        variable: Variable,
+        f: impl FnOnce(&mut Value) -> RuntimeResult<T>,
+    ) -> RuntimeResult<T> {
+        match variable {
+            Variable::Local(slot) => {
+                let index = self.base() + slot as usize;
+                f(&mut self.stack[index])
+            }
+            Variable::Upvalue(index) => {
+                let upvalue = self.frame().closure.upvalues[index as usize].clone();
+                let mut state = upvalue.borrow_mut();
+                match &mut *state {
+                    UpvalueState::Open(slot) => f(&mut self.stack[*slot]),
+                    UpvalueState::Closed(value) => f(value),
+                }
+            }
+            Variable::Global(slot) => match &mut self.globals[slot as usize] {
+                Some(value) => f(value),
+                None => Err(RuntimeError::AssignmentToUndefined {
+                    name: self.global_names[slot as usize].clone(),
+                }),
+            },
+        }
+    }
+
+    fn chunk(&self) -> &Chunk {
+        &self.frame().closure.function.chunk
+    }
+
+    /// A name stored in the constant pool of the current function
+    fn name_constant(&self, index: u32) -> String {
+        match &self.chunk().constants[index as usize] {
+            Value::String(name) => name.clone(),
+            other => unreachable!("names must be strings, found {:?}", other),
+        }
+    }
+}
+
+impl Default for Machine {
+    fn default() -> Self {
+        Self::new()
+    }
+}
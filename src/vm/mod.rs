@@ -14,12 +14,20 @@
 
 pub mod value;
 pub mod error;
+pub mod trace;
 pub mod environment;
 pub mod interpreter;
 pub mod builtin;
+pub mod ops;
+pub mod bytecode;
+pub mod compiler;
+pub mod machine;
 
 // Re-exports for convenience
 pub use value::Value;
 pub use error::{RuntimeError, RuntimeResult};
+pub use trace::{StackFrame, MAX_CALL_DEPTH};
 pub use environment::Environment;
 pub use interpreter::Interpreter;
+pub use compiler::Compiler;
+pub use machine::Machine;
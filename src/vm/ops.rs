@@ -0,0 +1,370 @@
+/*
+ * -------------------------------------------------------------------------
+ * RemyLang — operator semantics
+ * File : src/vm/ops.rs
+ *
+ * Description :
+ *   Operator and indexing semantics shared by the interpreter and the
+ *   bytecode machine, so both backends agree on every result and error.
+ *
+ * Author  : Samuel 'Meeast' Bleau
+ * Created : 2026-10-16
+ *
+ * -------------------------------------------------------------------------
+*/
+
+use crate::ast::{BinaryOp, Literal, Pattern, UnaryOp};
+use crate::vm::value::Value;
+use crate::vm::error::{RuntimeError, RuntimeResult};
+
+/// Apply a binary operator to two evaluated operands
+pub fn binary_op(left: Value, op: BinaryOp, right: Value) -> RuntimeResult<Value> {
+    match op {
+        // Arithmetic operations, on Floats as soon as one operand is a Float
+        BinaryOp::Add => match (left, right) {
+            (Value::Number(l), Value::Number(r)) => checked(l.checked_add(r), "addition"),
+            (Value::String(l), Value::String(r)) => Ok(Value::String(l + &r)),
+            (l, r) => {
+                let (l, r) = float_operands(&l, &r, "addition")?;
+                Ok(Value::Float(l + r))
+            }
+        },
+        
+        BinaryOp::Sub => match (left, right) {
+            (Value::Number(l), Value::Number(r)) => checked(l.checked_sub(r), "subtraction"),
+            (l, r) => {
+                let (l, r) = float_operands(&l, &r, "subtraction")?;
+                Ok(Value::Float(l - r))
+            }
+        },
+        
+        BinaryOp::Mul => match (left, right) {
+            (Value::Number(l), Value::Number(r)) => checked(l.checked_mul(r), "multiplication"),
+            (l, r) => {
+                let (l, r) = float_operands(&l, &r, "multiplication")?;
+                Ok(Value::Float(l * r))
+            }
+        },
+        
+        // Float division follows IEEE 754: dividing by zero gives an infinity or NaN
+        BinaryOp::Div => match (left, right) {
+            (Value::Number(l), Value::Number(r)) => {
+                if r == 0 {
+                    Err(RuntimeError::DivisionByZero)
+                } else {
+                    // Only Int::MIN / -1 overflows
+                    checked(l.checked_div(r), "division")
+                }
+            }
+            (l, r) => {
+                let (l, r) = float_operands(&l, &r, "division")?;
+                Ok(Value::Float(l / r))
+            }
+        },
+        
+        BinaryOp::Mod => match (left, right) {
+            (Value::Number(l), Value::Number(r)) => {
+                if r == 0 {
+                    Err(RuntimeError::ModuloByZero)
+                } else {
+                    // Int::MIN % -1 is 0, even though computing it overflows in Rust
+                    Ok(Value::Number(l.checked_rem(r).unwrap_or(0)))
+                }
+            }
+            (l, r) => {
+                let (l, r) = float_operands(&l, &r, "modulo")?;
+                Ok(Value::Float(l % r))
+            }
+        },
+        
+        BinaryOp::Pow => match (left, right) {
+            (Value::Number(l), Value::Number(r)) => {
+                if r < 0 {
+                    Err(RuntimeError::Custom("Negative exponents not supported for integers".to_string()))
+                } else {
+                    checked(checked_pow(l, r), "exponentiation")
+                }
+            }
+            (l, r) => {
+                let (l, r) = float_operands(&l, &r, "exponentiation")?;
+                Ok(Value::Float(l.powf(r)))
+            }
+        },
+        
+        // Comparison operations
+        BinaryOp::Equal => Ok(Value::Bool(values_equal(&left, &right))),
+        BinaryOp::NotEqual => Ok(Value::Bool(!values_equal(&left, &right))),
+        
+        BinaryOp::Less => match (left, right) {
+            (Value::Number(l), Value::Number(r)) => Ok(Value::Bool(l < r)),
+            (l, r) => {
+                let (l, r) = float_operands(&l, &r, "comparison")?;
+                Ok(Value::Bool(l < r))
+            }
+        },
+        
+        BinaryOp::Greater => match (left, right) {
+            (Value::Number(l), Value::Number(r)) => Ok(Value::Bool(l > r)),
+            (l, r) => {
+                let (l, r) = float_operands(&l, &r, "comparison")?;
+                Ok(Value::Bool(l > r))
+            }
+        },
+        
+        BinaryOp::LessEqual => match (left, right) {
+            (Value::Number(l), Value::Number(r)) => Ok(Value::Bool(l <= r)),
+            (l, r) => {
+                let (l, r) = float_operands(&l, &r, "comparison")?;
+                Ok(Value::Bool(l <= r))
+            }
+        },
+        
+        BinaryOp::GreaterEqual => match (left, right) {
+            (Value::Number(l), Value::Number(r)) => Ok(Value::Bool(l >= r)),
+            (l, r) => {
+                let (l, r) = float_operands(&l, &r, "comparison")?;
+                Ok(Value::Bool(l >= r))
+            }
+        },
+        
+        // Logical operations, once the left operand did not short-circuit
+        BinaryOp::And => Ok(Value::Bool(logical_operand(op, &left)? && logical_operand(op, &right)?)),
+        BinaryOp::Or => Ok(Value::Bool(logical_operand(op, &left)? || logical_operand(op, &right)?)),
+    }
+}
+
+/// Equality of two values, an Int being converted to a Float when compared with one
+fn values_equal(left: &Value, right: &Value) -> bool {
+    match (left, right) {
+        (Value::Number(_), Value::Float(_)) | (Value::Float(_), Value::Number(_)) => left.as_float() == right.as_float(),
+        _ => left == right,
+    }
+}
+
+/// Operands of a Float operation, an Int being converted to a Float
+fn float_operands(left: &Value, right: &Value, operation: &str) -> RuntimeResult<(f64, f64)> {
+    match (left.as_float(), right.as_float()) {
+        (Some(l), Some(r)) => Ok((l, r)),
+        _ => Err(RuntimeError::InvalidOperation {
+            operation: operation.to_string(),
+            left_type: left.type_name().to_string(),
+            right_type: right.type_name().to_string(),
+        }),
+    }
+}
+
+/// Value of an operand of `&&` or `||`, which must be a Bool
+pub fn logical_operand(op: BinaryOp, value: &Value) -> RuntimeResult<bool> {
+    match value {
+        Value::Bool(b) => Ok(*b),
+        _ => Err(RuntimeError::TypeMismatch {
+            operation: if op == BinaryOp::And { "logical and" } else { "logical or" }.to_string(),
+            expected: "Bool".to_string(),
+            found: value.type_name().to_string(),
+        }),
+    }
+}
+
+/// True if the left operand of `&&` or `||` alone decides the result,
+/// in which case the right operand must not be evaluated
+pub fn short_circuits(op: BinaryOp, left: bool) -> bool {
+    matches!((op, left), (BinaryOp::And, false) | (BinaryOp::Or, true))
+}
+
+/// Apply a unary operator to an evaluated operand
+pub fn unary_op(op: UnaryOp, right: Value) -> RuntimeResult<Value> {
+    match op {
+        UnaryOp::Minus => match right {
+            Value::Number(n) => checked(n.checked_neg(), "negation"),
+            Value::Float(x) => Ok(Value::Float(-x)),
+            _ => Err(RuntimeError::TypeMismatch {
+                operation: "unary minus".to_string(),
+                expected: "Int or Float".to_string(),
+                found: right.type_name().to_string(),
+            }),
+        },
+        
+        UnaryOp::Not => Ok(Value::Bool(!right.as_bool())),
+    }
+}
+
+/// Raise `base` to a non-negative `exponent`, or None on overflow
+pub fn checked_pow(base: i64, exponent: i64) -> Option<i64> {
+    // Square-and-multiply, so large exponents of 0, 1 and -1 stay fast
+    let (mut result, mut factor, mut exponent) = (1i64, base, exponent);
+    while exponent > 0 {
+        if exponent & 1 == 1 {
+            result = result.checked_mul(factor)?;
+        }
+        exponent >>= 1;
+        // The last squaring is never used, and could overflow on its own
+        if exponent > 0 {
+            factor = factor.checked_mul(factor)?;
+        }
+    }
+    Some(result)
+}
+
+/// Result of a checked integer operation
+fn checked(result: Option<i64>, operation: &str) -> RuntimeResult<Value> {
+    result.map(Value::Number).ok_or_else(|| RuntimeError::IntegerOverflow {
+        operation: operation.to_string(),
+    })
+}
+
+/// Read `array[index]`
+pub fn index(array: &Value, index: &Value) -> RuntimeResult<Value> {
+    match array {
+        Value::Array(arr) => Ok(arr[position(arr.len(), index)?].clone()),
+        _ => Err(RuntimeError::NotIndexable {
+            value_type: array.type_name().to_string(),
+        }),
+    }
+}
+
+/// Read `value.name`
+pub fn field(value: &Value, name: &str) -> RuntimeResult<Value> {
+    let found = match value {
+        Value::Struct { fields, .. } => fields.iter().find(|(field, _)| field == name),
+        _ => None,
+    };
+    found.map(|(_, value)| value.clone()).ok_or_else(|| RuntimeError::UndefinedField {
+        type_name: value.type_name().to_string(),
+        field: name.to_string(),
+    })
+}
+
+/// Build a struct from fields given in any order
+/// `layout` lists the declared fields, whose order the struct keeps.
+pub fn make_struct(name: &str, layout: &[String], mut fields: Vec<(String, Value)>) -> RuntimeResult<Value> {
+    if let Some((extra, _)) = fields.iter().find(|(field, _)| !layout.contains(field)) {
+        return Err(RuntimeError::UndefinedField {
+            type_name: name.to_string(),
+            field: extra.clone(),
+        });
+    }
+
+    let mut ordered = Vec::with_capacity(layout.len());
+    for declared in layout {
+        let position = fields.iter().position(|(field, _)| field == declared).ok_or_else(|| {
+            RuntimeError::MissingField {
+                type_name: name.to_string(),
+                field: declared.clone(),
+            }
+        })?;
+        ordered.push(fields.swap_remove(position));
+    }
+    Ok(Value::Struct {
+        name: name.to_string(),
+        fields: ordered,
+    })
+}
+
+/// Build the variant `variant` of an enum from its fields
+/// `variants` lists the declared variants with their number of fields.
+pub fn make_variant(enum_name: &str, variants: &[(String, usize)], variant: &str, fields: Vec<Value>) -> RuntimeResult<Value> {
+    let (_, arity) = variants.iter().find(|(name, _)| name == variant).ok_or_else(|| {
+        RuntimeError::UndefinedVariant {
+            enum_name: enum_name.to_string(),
+            variant: variant.to_string(),
+        }
+    })?;
+    if *arity != fields.len() {
+        return Err(RuntimeError::ArgumentCountMismatch {
+            expected: *arity,
+            found: fields.len(),
+            function_name: format!("{}::{}", enum_name, variant),
+        });
+    }
+    Ok(Value::EnumVariant {
+        enum_name: enum_name.to_string(),
+        variant: variant.to_string(),
+        fields,
+    })
+}
+
+/// Values bound by `pattern` when it matches `value`, in the order of `Pattern::bindings`
+pub fn match_pattern(pattern: &Pattern, value: &Value) -> Option<Vec<Value>> {
+    let mut bound = Vec::new();
+    bind(pattern, value, &mut bound).then_some(bound)
+}
+
+fn bind(pattern: &Pattern, value: &Value, bound: &mut Vec<Value>) -> bool {
+    match (pattern, value) {
+        (Pattern::Wildcard, _) => true,
+        (Pattern::Binding(_), _) => {
+            bound.push(value.clone());
+            true
+        }
+        (Pattern::Literal(Literal::Number(n)), Value::Number(v)) => n == v,
+        (Pattern::Literal(Literal::Float(x)), Value::Float(v)) => x == v,
+        (Pattern::Literal(Literal::String(s)), Value::String(v)) => s == v,
+        (Pattern::Literal(Literal::Char(c)), Value::Char(v)) => c == v,
+        (Pattern::Literal(Literal::Bool(b)), Value::Bool(v)) => b == v,
+        (
+            Pattern::Variant { enum_name, variant, fields },
+            Value::EnumVariant { enum_name: value_enum, variant: value_variant, fields: values },
+        ) => {
+            enum_name == value_enum
+                && variant == value_variant
+                && fields.len() == values.len()
+                && fields.iter().zip(values).all(|(field, value)| bind(field, value, bound))
+        }
+        _ => false,
+    }
+}
+
+/// The element reached by following `path` from `value`, for writing
+/// Ints index into arrays and Strings name struct fields, so
+/// `element_mut(a, [i, "x"])` designates `a[i].x`. With an empty path,
+/// this is `value` itself.
+pub fn element_mut<'a>(value: &'a mut Value, path: &[Value]) -> RuntimeResult<&'a mut Value> {
+    let mut element = value;
+    for key in path {
+        element = match (element, key) {
+            (Value::Array(arr), _) => {
+                let position = position(arr.len(), key)?;
+                &mut arr[position]
+            }
+            (Value::Struct { name, fields }, Value::String(field)) => {
+                match fields.iter_mut().find(|(name, _)| name == field) {
+                    Some((_, value)) => value,
+                    None => {
+                        return Err(RuntimeError::UndefinedField {
+                            type_name: name.clone(),
+                            field: field.clone(),
+                        })
+                    }
+                }
+            }
+            (other, Value::String(field)) => {
+                return Err(RuntimeError::UndefinedField {
+                    type_name: other.type_name().to_string(),
+                    field: field.clone(),
+                })
+            }
+            (other, _) => {
+                return Err(RuntimeError::NotIndexable {
+                    value_type: other.type_name().to_string(),
+                })
+            }
+        };
+    }
+    Ok(element)
+}
+
+/// Position of `index` in an array of `length` elements, if it is in bounds
+fn position(length: usize, index: &Value) -> RuntimeResult<usize> {
+    match index.as_number() {
+        Some(idx) if idx < 0 || idx >= length as i64 => Err(RuntimeError::IndexOutOfBounds {
+            index: idx,
+            length,
+        }),
+        Some(idx) => Ok(idx as usize),
+        None => Err(RuntimeError::TypeMismatch {
+            operation: "array indexing".to_string(),
+            expected: "Int".to_string(),
+            found: index.type_name().to_string(),
+        }),
+    }
+}
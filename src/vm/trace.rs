@@ -0,0 +1,116 @@
+/*
+ * -------------------------------------------------------------------------
+ * RemyLang — stack traces
+ * File : src/vm/trace.rs
+ *
+ * Description :
+ *   RemyLang call frames, recorded by the backends and attached to
+ *   runtime errors, and their rendering as Python-like tracebacks.
+ *
+ * Author  : Samuel 'Meeast' Bleau
+ * Created : 2026-10-16
+ *
+ * -------------------------------------------------------------------------
+*/
+
+use crate::lexer::Span;
+use crate::vm::value::Value;
+
+/// Maximum number of nested calls before reporting a stack overflow
+/// Both backends share it, so that they accept the same programs.
+pub const MAX_CALL_DEPTH: usize = 10_000;
+
+/// Longest argument shown in a traceback, in characters
+const MAX_ARGUMENT_WIDTH: usize = 30;
+
+/// A call of a RemyLang function
+#[derive(Debug, Clone, PartialEq)]
+pub struct StackFrame {
+    /// Name of the called function
+    pub function: String,
+    /// Location of the call expression
+    pub call_site: Span,
+    /// Arguments of the call
+    pub args: Vec<Value>,
+}
+
+impl StackFrame {
+    /// The call as written in a traceback, like `Add(1, "two")`
+    pub fn describe(&self) -> String {
+        let args: Vec<String> = self.args.iter().map(describe_value).collect();
+        format!("{}({})", self.function, args.join(", "))
+    }
+}
+
+/// Render the calls that led to an error, outermost first, like Python does
+/// Each call is shown at the line it was executing: the next call, or
+/// `error_span` for the innermost one. With the program's `source`, the
+/// code of each line is shown too.
+///
+/// # Example
+/// ```
+/// use remylang::lexer::Span;
+/// use remylang::vm::{trace::format_traceback, StackFrame, Value};
+///
+/// let frames = vec![StackFrame {
+///     function: "Half".to_string(),
+///     call_site: Span::new("main.remy".into(), 40, 47, 4, 1),
+///     args: vec![Value::Number(0)],
+/// }];
+/// let error_span = Span::new("main.remy".into(), 30, 35, 2, 12);
+/// assert_eq!(
+///     format_traceback(&frames, Some(&error_span), None),
+///     "Traceback (most recent call last):\n  File \"main.remy\", line 4, in <main>\n  File \"main.remy\", line 2, in Half(0)\n"
+/// );
+/// ```
+pub fn format_traceback(frames: &[StackFrame], error_span: Option<&Span>, source: Option<&str>) -> String {
+    // The top-level code, then every call, with the function name used to collapse runs
+    let mut entries = vec![("<main>", "<main>".to_string())];
+    entries.extend(frames.iter().map(|frame| (frame.function.as_str(), frame.describe())));
+    // Where each entry is executing
+    let locations: Vec<Option<&Span>> = frames
+        .iter()
+        .map(|frame| Some(&frame.call_site))
+        .chain([error_span])
+        .collect();
+
+    let mut out = String::from("Traceback (most recent call last):\n");
+    let mut i = 0;
+    while i < entries.len() {
+        let (function, name) = &entries[i];
+        // Runs of calls of the same function at the same line are collapsed
+        let repeats = (i + 1..entries.len())
+            .take_while(|&j| entries[j].0 == *function && locations[j] == locations[i])
+            .count();
+
+        match locations[i] {
+            Some(span) => {
+                out.push_str(&format!("  File \"{}\", line {}, in {}\n", span.file, span.line, name));
+                let line = source.and_then(|source| source.lines().nth(span.line.saturating_sub(1)));
+                if let Some(line) = line {
+                    out.push_str(&format!("    {}\n", line.trim()));
+                }
+            }
+            None => out.push_str(&format!("  in {}\n", name)),
+        }
+        if repeats > 0 {
+            out.push_str(&format!("  [Previous line repeated {} more times]\n", repeats));
+        }
+        i += repeats + 1;
+    }
+    out
+}
+
+/// An argument as shown in a traceback, shortened if too long
+fn describe_value(value: &Value) -> String {
+    let text = match value {
+        Value::String(s) => format!("{:?}", s),
+        Value::Char(c) => format!("{:?}", c),
+        other => other.to_string(),
+    };
+    if text.chars().count() <= MAX_ARGUMENT_WIDTH {
+        return text;
+    }
+    let shortened: String = text.chars().take(MAX_ARGUMENT_WIDTH - 3).collect();
+    format!("{}...", shortened)
+}
@@ -13,21 +13,36 @@
 */
 
 use std::fmt;
+use std::rc::Rc;
 use crate::ast::Stmt;
+use crate::vm::bytecode::Closure;
+use crate::vm::environment::Environment;
 
 /// Runtime value representation
 #[derive(Debug, Clone, PartialEq)]
 pub enum Value {
     Number(i64),
+    Float(f64),
     String(String),
     Char(char),
     Bool(bool),
     Array(Vec<Value>),
+    Struct {
+        name: String,
+        fields: Vec<(String, Value)>, // In declaration order
+    },
+    EnumVariant {
+        enum_name: String,
+        variant: String,
+        fields: Vec<Value>,
+    },
     Function {
         name: String,
         params: Vec<String>,
         body: Box<Stmt>,
+        closure: Environment, // Scope the function was declared in
     },
+    Closure(Rc<Closure>), // Compiled function, as used by the bytecode machine
     Void, // For functions that don't return anything
 }
 
@@ -36,11 +51,14 @@ impl Value {
     pub fn type_name(&self) -> &str {
         match self {
             Value::Number(_) => "Int",
+            Value::Float(_) => "Float",
             Value::String(_) => "String",
             Value::Char(_) => "Char",
             Value::Bool(_) => "Bool",
             Value::Array(_) => "Array",
-            Value::Function { .. } => "Function",
+            Value::Struct { name, .. } => name,
+            Value::EnumVariant { enum_name, .. } => enum_name,
+            Value::Function { .. } | Value::Closure(_) => "Function",
             Value::Void => "Void",
         }
     }
@@ -50,6 +68,7 @@ impl Value {
         match self {
             Value::Bool(b) => *b,
             Value::Number(n) => *n != 0,
+            Value::Float(x) => *x != 0.0,
             Value::String(s) => !s.is_empty(),
             Value::Array(arr) => !arr.is_empty(),
             Value::Void => false,
@@ -72,12 +91,24 @@ impl Value {
             _ => None,
         }
     }
+
+    /// Try to convert to a float, Ints included
+    pub fn as_float(&self) -> Option<f64> {
+        match self {
+            Value::Number(n) => Some(*n as f64),
+            Value::Float(x) => Some(*x),
+            _ => None,
+        }
+    }
 }
 
 impl fmt::Display for Value {
     fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
         match self {
             Value::Number(n) => write!(f, "{}", n),
+            // Whole floats keep a fractional part, so 2.0 doesn't print as an Int
+            Value::Float(x) if x.is_finite() && x.fract() == 0.0 => write!(f, "{:.1}", x),
+            Value::Float(x) => write!(f, "{}", x),
             Value::String(s) => write!(f, "{}", s),
             Value::Char(c) => write!(f, "{}", c),
             Value::Bool(b) => write!(f, "{}", if *b { "True" } else { "False" }),
@@ -91,7 +122,32 @@ impl fmt::Display for Value {
                 }
                 write!(f, "]")
             }
+            Value::Struct { name, fields } => {
+                write!(f, "{} {{ ", name)?;
+                for (i, (field, val)) in fields.iter().enumerate() {
+                    if i > 0 {
+                        write!(f, ", ")?;
+                    }
+                    write!(f, "{}: {}", field, val)?;
+                }
+                write!(f, " }}")
+            }
+            Value::EnumVariant { enum_name, variant, fields } => {
+                write!(f, "{}::{}", enum_name, variant)?;
+                if !fields.is_empty() {
+                    write!(f, "(")?;
+                    for (i, val) in fields.iter().enumerate() {
+                        if i > 0 {
+                            write!(f, ", ")?;
+                        }
+                        write!(f, "{}", val)?;
+                    }
+                    write!(f, ")")?;
+                }
+                Ok(())
+            }
             Value::Function { name, .. } => write!(f, "<function {}>", name),
+            Value::Closure(closure) => write!(f, "<function {}>", closure.function.name),
             Value::Void => write!(f, "void"),
         }
     }
@@ -0,0 +1,170 @@
+/*
+ * -------------------------------------------------------------------------
+ * RemyLang — CLI Tests
+ * File : tests/cli_tests.rs
+ *
+ * Description :
+ *   Integration tests running the remylang binary: subcommands, output
+ *   and exit codes.
+ *
+ * Author  : Samuel 'Meeast' Bleau
+ * Created : 2026-10-16
+ *
+ * -------------------------------------------------------------------------
+*/
+
+use std::io::Write;
+use std::process::{Command, Output, Stdio};
+
+// Helper running the binary with code on stdin
+fn remylang(args: &[&str], stdin: &str) -> Output {
+    let mut child = Command::new(env!("CARGO_BIN_EXE_remylang"))
+        .args(args)
+        .env("NO_COLOR", "1")
+        .stdin(Stdio::piped())
+        .stdout(Stdio::piped())
+        .stderr(Stdio::piped())
+        .spawn()
+        .expect("remylang should start");
+    child
+        .stdin
+        .take()
+        .expect("stdin is piped")
+        .write_all(stdin.as_bytes())
+        .expect("stdin should be writable");
+    child.wait_with_output().expect("remylang should finish")
+}
+
+fn stdout(output: &Output) -> String {
+    String::from_utf8_lossy(&output.stdout).into_owned()
+}
+
+fn stderr(output: &Output) -> String {
+    String::from_utf8_lossy(&output.stderr).into_owned()
+}
+
+// ============================================================================
+// RUN
+// ============================================================================
+
+#[test]
+fn test_run_prints_only_program_output() {
+    let output = remylang(&["run", "-"], "println(\"hello\", 1 + 2);");
+    assert_eq!(output.status.code(), Some(0));
+    assert_eq!(stdout(&output), "hello 3\n");
+    assert_eq!(stderr(&output), "");
+}
+
+#[test]
+fn test_verbose_stages_go_to_stderr() {
+    let output = remylang(&["run", "--verbose", "-"], "println(1);");
+    assert_eq!(stdout(&output), "1\n");
+    assert!(stderr(&output).contains("Type checking"));
+}
+
+#[test]
+fn test_program_arguments_are_passed_through() {
+    let code = "Array<String> a = args(); println(a);";
+    let output = remylang(&["run", "-", "first", "--verbose", "-x"], code);
+    assert_eq!(output.status.code(), Some(0));
+    assert_eq!(stdout(&output), "[first, --verbose, -x]\n");
+}
+
+#[test]
+fn test_run_file() {
+    let path = std::env::temp_dir().join(format!("remylang-cli-{}.remy", std::process::id()));
+    std::fs::write(&path, "Int x = 6 * 7; println(x);").unwrap();
+    let output = remylang(&["run", path.to_str().unwrap()], "");
+    std::fs::remove_file(&path).unwrap();
+    assert_eq!(stdout(&output), "42\n");
+}
+
+#[test]
+fn test_input_reads_stdin_lines() {
+    let path = std::env::temp_dir().join(format!("remylang-input-{}.remy", std::process::id()));
+    let code = "print(\"Name: \"); String a = input(); String b = input(); String c = input(); println(upper(a), b, len(c));";
+    std::fs::write(&path, code).unwrap();
+    let output = remylang(&["run", path.to_str().unwrap()], "Ada\r\nLovelace");
+    std::fs::remove_file(&path).unwrap();
+    // The input ends without a line break, then reads as empty
+    assert_eq!(stdout(&output), "Name: ADA Lovelace 0\n");
+}
+
+// ============================================================================
+// EXIT CODES
+// ============================================================================
+
+#[test]
+fn test_exit_codes() {
+    assert_eq!(remylang(&["run", "-"], "println(1 / 0);").status.code(), Some(1));
+    assert_eq!(remylang(&["frobnicate"], "").status.code(), Some(2));
+    assert_eq!(remylang(&["run", "-"], "Int x = ;").status.code(), Some(3));
+    assert_eq!(remylang(&["run", "-"], "Int x = True;").status.code(), Some(4));
+    assert_eq!(remylang(&["run", "/no/such/file.remy"], "").status.code(), Some(5));
+}
+
+#[test]
+fn test_errors_are_reported_on_stderr() {
+    let output = remylang(&["run", "-"], "Int x = True;");
+    assert_eq!(stdout(&output), "");
+    assert!(stderr(&output).contains("error[type]: Expected Int, found Bool\n --> <stdin>:1:9"));
+}
+
+// ============================================================================
+// FRONT END STAGES
+// ============================================================================
+
+#[test]
+fn test_check_does_not_run() {
+    let output = remylang(&["check", "-"], "println(1 / 0);");
+    assert_eq!(output.status.code(), Some(0));
+    assert_eq!(stdout(&output), "");
+    assert_eq!(remylang(&["check", "-"], "Int x = \"s\";").status.code(), Some(4));
+}
+
+#[test]
+fn test_tokens() {
+    let output = remylang(&["tokens", "-"], "Int x = 1;");
+    assert_eq!(
+        stdout(&output),
+        "1:1\tInt\n1:5\tIdentifier(\"x\")\n1:7\tEqual\n1:9\tNumber(1)\n1:10\tSemicolon\n1:11\tEOF\n"
+    );
+    assert_eq!(remylang(&["tokens", "-"], "\"open").status.code(), Some(3));
+}
+
+#[test]
+fn test_ast() {
+    let output = remylang(&["ast", "-"], "Int x = 1;");
+    assert_eq!(output.status.code(), Some(0));
+    assert!(stdout(&output).starts_with("Stmt {\n    kind: Let {\n        name: \"x\","));
+    assert_eq!(remylang(&["ast", "-"], "Int x = ;").status.code(), Some(3));
+}
+
+// ============================================================================
+// FORMATTER
+// ============================================================================
+
+#[test]
+fn test_fmt_stdin() {
+    let output = remylang(&["fmt", "-"], "Int x=1+2;");
+    assert_eq!(output.status.code(), Some(0));
+    assert_eq!(stdout(&output), "Int x = 1 + 2;\n");
+    assert_eq!(remylang(&["fmt", "-"], "Int x = ;").status.code(), Some(3));
+}
+
+#[test]
+fn test_fmt_check_and_write() {
+    let path = std::env::temp_dir().join(format!("remylang-fmt-{}.remy", std::process::id()));
+    let file = path.to_str().unwrap();
+    std::fs::write(&path, "Int x = 1;\nInt y=2;\n").unwrap();
+
+    let output = remylang(&["fmt", "--check", file], "");
+    assert_eq!(output.status.code(), Some(7));
+    assert!(stderr(&output).contains("is not formatted (first difference on line 2)"));
+    assert_eq!(std::fs::read_to_string(&path).unwrap(), "Int x = 1;\nInt y=2;\n");
+
+    assert_eq!(remylang(&["fmt", file], "").status.code(), Some(0));
+    assert_eq!(std::fs::read_to_string(&path).unwrap(), "Int x = 1;\nInt y = 2;\n");
+    assert_eq!(remylang(&["fmt", "--check", file], "").status.code(), Some(0));
+    std::fs::remove_file(&path).unwrap();
+}
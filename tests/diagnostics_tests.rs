@@ -0,0 +1,209 @@
+/*
+ * -------------------------------------------------------------------------
+ * RemyLang — Diagnostics Tests
+ * File : tests/diagnostics_tests.rs
+ *
+ * Description :
+ *   Tests for the rendering of errors from every stage of the pipeline.
+ *
+ * Author  : Samuel 'Meeast' Bleau
+ * Created : 2026-10-16
+ *
+ * -------------------------------------------------------------------------
+*/
+
+use remylang::diagnostics::{render, Diagnostic, Severity};
+use remylang::lexer::{Lexer, Span};
+use remylang::parser::Parser;
+use remylang::semantic::TypeChecker;
+use remylang::vm::{Compiler, Machine};
+
+// Helper rendering the first parse error of code, without colour
+fn parse_diagnostic(code: &str) -> String {
+    let tokens = Lexer::with_file(code.to_string(), "test.remy").tokenize();
+    let error = Parser::new(tokens).parse().expect_err("code should not parse");
+    render(&Diagnostic::from(&error), code, false)
+}
+
+// Helper rendering the first type error of code, without colour
+fn type_diagnostic(code: &str) -> String {
+    let tokens = Lexer::with_file(code.to_string(), "test.remy").tokenize();
+    let ast = Parser::new(tokens).parse().expect("code should parse");
+    let errors = TypeChecker::new().check_program(&ast).expect_err("code should not type check");
+    render(&Diagnostic::from(&errors[0]), code, false)
+}
+
+// Helper rendering the runtime error raised by code, without colour
+fn runtime_diagnostic(code: &str) -> String {
+    let tokens = Lexer::with_file(code.to_string(), "test.remy").tokenize();
+    let ast = Parser::new(tokens).parse().expect("code should parse");
+    let program = Compiler::new().compile(&ast).expect("code should compile");
+    let error = Machine::new().run(&program).expect_err("code should fail");
+    render(&Diagnostic::from(&error), code, false)
+}
+
+// ============================================================================
+// RENDERING TESTS
+// ============================================================================
+
+#[test]
+fn test_render_points_at_span() {
+    let span = Span::new("main.remy".into(), 14, 18, 2, 9);
+    let diagnostic = Diagnostic::error("type", "Expected Int, found Bool").with_span(Some(&span));
+    let text = render(&diagnostic, "Int y = 1;\nInt x = True;\n", false);
+    assert_eq!(
+        text,
+        "error[type]: Expected Int, found Bool\n --> main.remy:2:9\n  |\n2 | Int x = True;\n  |         ^^^^\n"
+    );
+}
+
+#[test]
+fn test_render_notes_and_help() {
+    let span = Span::new("main.remy".into(), 0, 1, 1, 1);
+    let diagnostic = Diagnostic::error("parse", "Oops")
+        .with_span(Some(&span))
+        .with_note("a note")
+        .with_help("a help");
+    let text = render(&diagnostic, "x", false);
+    assert!(text.ends_with("  = note: a note\n  = help: a help\n"), "{}", text);
+}
+
+#[test]
+fn test_render_without_span() {
+    let diagnostic = Diagnostic::error("runtime", "Stack overflow").with_help("a help");
+    assert_eq!(render(&diagnostic, "", false), "error[runtime]: Stack overflow\nhelp: a help\n");
+}
+
+#[test]
+fn test_render_keeps_tabs_aligned() {
+    let span = Span::new("main.remy".into(), 9, 10, 1, 10);
+    let diagnostic = Diagnostic::error("type", "Oops").with_span(Some(&span));
+    let text = render(&diagnostic, "\tInt x = y;", false);
+    assert!(text.contains("  | \t        ^\n"), "{}", text);
+}
+
+#[test]
+fn test_render_wide_gutter() {
+    let source = "\n".repeat(11) + "Int x = 1";
+    let span = Span::new("main.remy".into(), 11, 14, 12, 1);
+    let diagnostic = Diagnostic::error("parse", "Oops").with_span(Some(&span));
+    let text = render(&diagnostic, &source, false);
+    assert!(text.contains("  --> main.remy:12:1\n   |\n12 | Int x = 1\n   | ^^^\n"), "{}", text);
+}
+
+#[test]
+fn test_render_with_color() {
+    let diagnostic = Diagnostic::error("type", "Oops");
+    let text = render(&diagnostic, "", true);
+    assert!(text.starts_with("\x1b[1;31merror[type]\x1b[0m"), "{:?}", text);
+}
+
+#[test]
+fn test_warning_severity() {
+    let mut diagnostic = Diagnostic::error("type", "Unused");
+    diagnostic.severity = Severity::Warning;
+    assert!(render(&diagnostic, "", false).starts_with("warning[type]: Unused"));
+}
+
+// ============================================================================
+// PIPELINE ERROR TESTS
+// ============================================================================
+
+#[test]
+fn test_parse_error_diagnostic() {
+    let text = parse_diagnostic("Int x = 1\nprint(x);");
+    assert!(text.starts_with("error[parse]: "), "{}", text);
+    assert!(text.contains(" --> test.remy:2:1\n"), "{}", text);
+    assert!(text.contains("2 | print(x);\n  | ^^^^^\n"), "{}", text);
+}
+
+#[test]
+fn test_invalid_character_diagnostic() {
+    let text = parse_diagnostic("Bool b = True & False;");
+    assert!(text.starts_with("error[lex]: Unexpected character '&'\n"), "{}", text);
+    assert!(text.contains("^\n"), "{}", text);
+    assert!(text.contains("= help: use '&&' for a logical and"), "{}", text);
+}
+
+#[test]
+fn test_unterminated_string_diagnostic() {
+    let text = parse_diagnostic("String s = \"hello;\nprint(s);");
+    assert!(text.starts_with("error[lex]: Unterminated string literal\n"), "{}", text);
+    assert!(text.contains(" --> test.remy:1:12\n"), "{}", text);
+    assert!(text.contains("= help: close the string with '\"'"), "{}", text);
+}
+
+#[test]
+fn test_invalid_char_literal_diagnostic() {
+    let text = parse_diagnostic("Char c = 'ab';");
+    assert!(text.starts_with("error[lex]: Character literal 'ab' holds more than one character\n"), "{}", text);
+    assert!(text.contains("1 | Char c = 'ab';\n  |          ^^^^\n"), "{}", text);
+    assert!(text.contains("= note: character literals hold exactly one character, like 'a'"), "{}", text);
+    assert!(parse_diagnostic("Char c = '';").starts_with("error[lex]: Empty character literal\n"));
+}
+
+#[test]
+fn test_invalid_escape_diagnostic() {
+    let text = parse_diagnostic(r#"String s = "a\qb";"#);
+    assert!(text.starts_with("error[lex]: Invalid escape sequence '\\q'\n"), "{}", text);
+    assert!(text.contains("= note: valid escapes are"), "{}", text);
+}
+
+#[test]
+fn test_type_error_diagnostic() {
+    let text = type_diagnostic("Int x = True;");
+    assert!(text.starts_with("error[type]: "), "{}", text);
+    assert!(text.contains("1 | Int x = True;\n  |         ^^^^\n"), "{}", text);
+}
+
+#[test]
+fn test_undefined_variable_has_help() {
+    let text = type_diagnostic("Int x = y;");
+    assert!(text.contains("= help: declare it before using it, like 'Int y = 0;'"), "{}", text);
+}
+
+#[test]
+fn test_non_exhaustive_match_lists_missing_patterns() {
+    let text = type_diagnostic("enum E { A, B(Int), C }\nInt x = match (E::A) { E::A => 1 };");
+    assert!(text.starts_with("error[type]: Match is not exhaustive: E::B(_), E::C are not covered\n"), "{}", text);
+    assert!(text.contains(" --> test.remy:2:9\n"), "{}", text);
+    assert!(text.contains("= help: add an arm for each missing pattern, or a '_' arm matching everything else"), "{}", text);
+}
+
+#[test]
+fn test_binding_named_like_a_variant_is_a_warning() {
+    let code = "enum E { A, B }\nInt x = match (E::B) { A => 1 };";
+    let tokens = Lexer::with_file(code.to_string(), "test.remy").tokenize();
+    let ast = Parser::new(tokens).parse().expect("code should parse");
+    let mut checker = TypeChecker::new();
+    checker.check_program(&ast).expect("code should type check");
+    let text = render(&Diagnostic::from(&checker.warnings()[0]), code, false);
+    assert!(text.starts_with("warning[type]: 'A' binds any value, it does not match the variant E::A\n"), "{}", text);
+    assert!(text.contains("= help: write 'E::A' to match the variant"), "{}", text);
+}
+
+#[test]
+fn test_runtime_error_traceback() {
+    let code = "func Half(Int n) -> Int {\n    return n / 0;\n}\nprintln(Half(4));";
+    let text = runtime_diagnostic(code);
+    assert!(
+        text.starts_with(
+            "Traceback (most recent call last):\n  \
+             File \"test.remy\", line 4, in <main>\n    \
+             println(Half(4));\n  \
+             File \"test.remy\", line 2, in Half(4)\n    \
+             return n / 0;\n\
+             error[runtime]: Division by zero\n"
+        ),
+        "{}",
+        text
+    );
+}
+
+#[test]
+fn test_runtime_error_diagnostic() {
+    let text = runtime_diagnostic("Int zero = 0;\nInt x = 10 / zero;");
+    assert!(text.starts_with("error[runtime]: "), "{}", text);
+    assert!(text.contains(" --> test.remy:2:"), "{}", text);
+    assert!(text.contains("2 | Int x = 10 / zero;\n"), "{}", text);
+}
@@ -0,0 +1,175 @@
+/*
+ * -------------------------------------------------------------------------
+ * RemyLang — Formatter Tests
+ * File : tests/formatter_tests.rs
+ *
+ * Description :
+ *   Integration tests for the formatter: canonical layout, parentheses,
+ *   comments and blank lines, and idempotence.
+ *
+ * Author  : Samuel 'Meeast' Bleau
+ * Created : 2026-10-16
+ *
+ * -------------------------------------------------------------------------
+*/
+
+use remylang::formatter::format_source;
+
+// Helper formatting code, checking that formatting it again changes nothing
+fn format(code: &str) -> String {
+    let formatted = format_source(code, "test.remy").expect("code should parse");
+    assert_eq!(
+        format_source(&formatted, "test.remy").expect("formatted code should parse"),
+        formatted,
+        "formatting is not idempotent"
+    );
+    formatted
+}
+
+// ============================================================================
+// LAYOUT
+// ============================================================================
+
+#[test]
+fn test_statements_are_laid_out() {
+    let code = "Int x=1;if(x>0){println(x);}else if(x<0){x-=1;}else{}while(x<3){x+=1;}";
+    assert_eq!(
+        format(code),
+        "Int x = 1;\nif (x > 0) {\n    println(x);\n} else if (x < 0) {\n    x -= 1;\n} else {}\n\
+         while (x < 3) {\n    x += 1;\n}\n"
+    );
+}
+
+#[test]
+fn test_loops() {
+    let code = "for(Int i=0;i<3;i+=1){} for(;;){break;} for x in [1,2]{continue;} for (p in Point{x:1}.Items()){}";
+    assert_eq!(
+        format(code),
+        "for (Int i = 0; i < 3; i += 1) {}\nfor (;;) {\n    break;\n}\nfor x in [1, 2] {\n    continue;\n}\n\
+         for (p in Point { x: 1 }.Items()) {}\n"
+    );
+}
+
+#[test]
+fn test_declarations() {
+    let code = "struct Point{Int x;Int y;} enum Shape{Circle(Int),Rect(Int,Int),Empty}\
+                impl Point{func Sum()->Int{return self.x+self.y;}func Clear(){}}";
+    assert_eq!(
+        format(code),
+        "struct Point {\n    Int x;\n    Int y;\n}\n\n\
+         enum Shape {\n    Circle(Int),\n    Rect(Int, Int),\n    Empty,\n}\n\n\
+         impl Point {\n    func Sum() -> Int {\n        return self.x + self.y;\n    }\n\n    func Clear() {}\n}\n"
+    );
+}
+
+#[test]
+fn test_match() {
+    let code = "Int y=match(x){1=>10,Shape::Rect(w,_)=>w,_=>0};match(x){\"a\"=>{println(1);}_=>x+=1}";
+    assert_eq!(
+        format(code),
+        "Int y = match (x) {\n    1 => 10,\n    Shape::Rect(w, _) => w,\n    _ => 0,\n};\n\
+         match (x) {\n    \"a\" => {\n        println(1);\n    }\n    _ => x += 1,\n}\n"
+    );
+}
+
+#[test]
+fn test_lambdas_and_literals() {
+    let code = "Func<(Int),Int> f=func(Int n)->Int{return n*2;};Float x=1.0;Char c='\\n';Bool b=False;";
+    assert_eq!(
+        format(code),
+        "Func<(Int), Int> f = func(Int n) -> Int {\n    return n * 2;\n};\nFloat x = 1.0;\nChar c = '\\n';\nBool b = False;\n"
+    );
+}
+
+#[test]
+fn test_floats_are_written_as_in_the_source() {
+    let code = "Float a = 1e15 + 123456789012345680000.0 + 2.50;\nString s = match (a) { -1E3 => \"x\", 0.5e-2 => \"y\", _ => \"z\" };\n";
+    assert_eq!(
+        format(code),
+        "Float a = 1e15 + 123456789012345680000.0 + 2.50;\n\
+         String s = match (a) {\n    -1E3 => \"x\",\n    0.5e-2 => \"y\",\n    _ => \"z\",\n};\n"
+    );
+}
+
+#[test]
+fn test_strings_are_escaped_back() {
+    let code = r#"String s = "tab\t \"q\" \\ \u{1b} \${x} ${a + 1}$";"#;
+    assert_eq!(format(code), "String s = \"tab\\t \\\"q\\\" \\\\ \\u{1b} \\${x} ${a + 1}$\";\n");
+}
+
+// ============================================================================
+// PARENTHESES
+// ============================================================================
+
+#[test]
+fn test_only_needed_parentheses_are_kept() {
+    assert_eq!(format("x = ((1 + 2)) * (3 * 4) + (5 * 6);"), "x = (1 + 2) * (3 * 4) + 5 * 6;\n");
+    assert_eq!(format("x = 1 - (2 - 3) - (4 + 5);"), "x = 1 - (2 - 3) - (4 + 5);\n");
+    assert_eq!(format("x = (1 - 2) - 3;"), "x = 1 - 2 - 3;\n");
+    assert_eq!(format("x = (2 ** 3) ** 2 + 2 ** (3 ** 2);"), "x = (2 ** 3) ** 2 + 2 ** 3 ** 2;\n");
+    assert_eq!(format("b = (a && b) || (c == (d != (e < f)));"), "b = a && b || c == (d != e < f);\n");
+}
+
+#[test]
+fn test_parentheses_around_operands() {
+    assert_eq!(format("x = -(a + b) + -(-c) + !(!d);"), "x = -(a + b) + -(-c) + !!d;\n");
+    assert_eq!(format("x = --9223372036854775808;"), "x = -(-9223372036854775808);\n");
+    assert_eq!(format("x = (a + b).y + (-a)[0];"), "x = (a + b).y + (-a)[0];\n");
+    assert_eq!(format("(match (x) { _ => f }).Run();"), "(match (x) {\n    _ => f,\n}.Run());\n");
+}
+
+// ============================================================================
+// COMMENTS AND BLANK LINES
+// ============================================================================
+
+#[test]
+fn test_comments_are_kept() {
+    let code = "// Header\nInt x = 1; // one\n/* before */ Int y = 2;\nfunc F() {\n    // inside\n    return;\n    // at the end\n}\n// last";
+    assert_eq!(
+        format(code),
+        "// Header\nInt x = 1; // one\n/* before */\nInt y = 2;\n\nfunc F() {\n    // inside\n    return;\n    // at the end\n}\n// last\n"
+    );
+}
+
+#[test]
+fn test_comments_in_declarations() {
+    let code = "struct P {\n    // first\n    Int x; // x\n    Int y;\n}\nenum E { A, // a\n B }";
+    assert_eq!(
+        format(code),
+        "struct P {\n    // first\n    Int x; // x\n    Int y;\n}\n\nenum E {\n    A, // a\n    B,\n}\n"
+    );
+}
+
+#[test]
+fn test_comments_before_else_stay_there() {
+    let code = "if (x) {\n    a();\n} // done\nelse {\n    b();\n}\nif (x) {\n    a();\n}\n// otherwise\nelse if (y) {\n    b();\n} /* none */ else {}\n";
+    assert_eq!(format(code), code);
+    assert_eq!(format("if (x) { a(); } /* c */\nelse { b(); }"), "if (x) {\n    a();\n} /* c */ else {\n    b();\n}\n");
+}
+
+#[test]
+fn test_comments_inside_expressions_follow_the_statement() {
+    assert_eq!(format("Int x = 1 /* one */ + 2;"), "Int x = 1 + 2; /* one */\n");
+    assert_eq!(format("Int x = f(1, // one\n 2); // call"), "Int x = f(1, 2); // one\n// call\n");
+}
+
+#[test]
+fn test_blank_lines() {
+    let code = "Int a = 1;\n\n\n\nInt b = 2;\nInt c = 3;\n// Adds\nfunc Add() {}\nInt d = 4;";
+    assert_eq!(
+        format(code),
+        "Int a = 1;\n\nInt b = 2;\nInt c = 3;\n\n// Adds\nfunc Add() {}\n\nInt d = 4;\n"
+    );
+}
+
+#[test]
+fn test_empty_program() {
+    assert_eq!(format(""), "");
+    assert_eq!(format("\n// only a comment\n"), "// only a comment\n");
+}
+
+#[test]
+fn test_parse_errors_are_returned() {
+    let errors = format_source("Int x = ;", "test.remy").unwrap_err();
+    assert_eq!(errors.len(), 1);
+}
@@ -0,0 +1,895 @@
+/*
+ * -------------------------------------------------------------------------
+ * RemyLang — Interpreter Tests
+ * File : tests/interpreter_tests.rs
+ *
+ * Description :
+ *   Integration tests for the tree-walking interpreter.
+ *
+ * Author  : Samuel 'Meeast' Bleau
+ * Created : 2026-10-16
+ *
+ * -------------------------------------------------------------------------
+*/
+
+use remylang::lexer::Lexer;
+use remylang::parser::Parser;
+use remylang::semantic::TypeChecker;
+use remylang::vm::{Interpreter, RuntimeError, RuntimeResult, Value};
+
+// Helper function to run code and return the interpreter for inspection
+fn run(code: &str) -> (Interpreter, RuntimeResult<()>) {
+    let tokens = Lexer::new(code.to_string()).tokenize();
+    let ast = Parser::new(tokens).parse().expect("code should parse");
+    let mut interpreter = Interpreter::new();
+    let result = interpreter.execute(ast);
+    (interpreter, result)
+}
+
+// Helper returning the value of a global variable after running code
+fn global(code: &str, name: &str) -> Value {
+    let (interpreter, result) = run(code);
+    result.expect("code should run");
+    interpreter.environment().get(name).expect("variable should exist")
+}
+
+// Helper returning the runtime error raised by code, without its location
+fn runtime_error(code: &str) -> RuntimeError {
+    let (_, result) = run(code);
+    result.expect_err("code should fail").kind().clone()
+}
+
+// ============================================================================
+// BASICS
+// ============================================================================
+
+#[test]
+fn test_arithmetic_and_variables() {
+    assert_eq!(global("Int x = 2 + 3 * 4; x = x - 1;", "x"), Value::Number(13));
+}
+
+#[test]
+fn test_function_call() {
+    let code = r#"
+        func Add(Int a, Int b) -> Int {
+            return a + b;
+        }
+        Int r = Add(Add(1, 2), 3);
+    "#;
+    assert_eq!(global(code, "r"), Value::Number(6));
+}
+
+#[test]
+fn test_runtime_error_has_location() {
+    let (_, result) = run("Int a = 1;\nInt b = a / 0;");
+    let err = result.unwrap_err();
+    assert_eq!(err.kind(), &RuntimeError::DivisionByZero);
+    assert_eq!(err.span().map(|s| (s.line, s.column)), Some((2, 9)));
+}
+
+// ============================================================================
+// INTEGER ARITHMETIC
+// ============================================================================
+
+// Helper building an IntegerOverflow error
+fn overflow(operation: &str) -> RuntimeError {
+    RuntimeError::IntegerOverflow { operation: operation.to_string() }
+}
+
+#[test]
+fn test_integer_overflow() {
+    assert_eq!(runtime_error("Int x = 9223372036854775807 + 1;"), overflow("addition"));
+    assert_eq!(runtime_error("Int x = -9223372036854775807 - 2;"), overflow("subtraction"));
+    assert_eq!(runtime_error("Int x = 4611686018427387904 * 2;"), overflow("multiplication"));
+    assert_eq!(runtime_error("Int x = 2 ** 63;"), overflow("exponentiation"));
+    assert_eq!(runtime_error("Int x = 10 ** 100;"), overflow("exponentiation"));
+}
+
+#[test]
+fn test_minimum_integer_edge_cases() {
+    let min = "Int min = -9223372036854775808;";
+    assert_eq!(global(min, "min"), Value::Number(i64::MIN));
+    assert_eq!(runtime_error(&format!("{} Int x = -min;", min)), overflow("negation"));
+    assert_eq!(runtime_error(&format!("{} Int x = min / -1;", min)), overflow("division"));
+    assert_eq!(global(&format!("{} Int x = min % -1;", min), "x"), Value::Number(0));
+}
+
+#[test]
+fn test_arithmetic_near_limits() {
+    assert_eq!(global("Int x = 2 ** 62;", "x"), Value::Number(1 << 62));
+    assert_eq!(global("Int x = -2 ** 63;", "x"), Value::Number(i64::MIN));
+    assert_eq!(global("Int x = 1 ** 1000000000000;", "x"), Value::Number(1));
+    assert_eq!(global("Int x = -1 ** 1000000000001;", "x"), Value::Number(-1));
+    assert_eq!(global("Int x = 9223372036854775807 - 1 + 1;", "x"), Value::Number(i64::MAX));
+}
+
+#[test]
+fn test_logical_operators_short_circuit() {
+    let code = r#"
+        Array<Int> a = [1, 2];
+        Int i = 5;
+        Bool and = i < 2 && a[i] > 0;
+        Bool or = i >= 2 || a[i] > 0;
+    "#;
+    assert_eq!(global(code, "and"), Value::Bool(false));
+    assert_eq!(global(code, "or"), Value::Bool(true));
+}
+
+#[test]
+fn test_logical_operators_evaluate_right_when_needed() {
+    let code = r#"
+        Int calls = 0;
+        func Check(Bool b) -> Bool {
+            calls = calls + 1;
+            return b;
+        }
+        Bool x = Check(True) && Check(False) || Check(True);
+    "#;
+    assert_eq!(global(code, "x"), Value::Bool(true));
+    assert_eq!(global(code, "calls"), Value::Number(3));
+}
+
+#[test]
+fn test_logical_operands_must_be_bool() {
+    assert_eq!(
+        runtime_error("Bool b = 1 && True;"),
+        RuntimeError::TypeMismatch {
+            operation: "logical and".to_string(),
+            expected: "Bool".to_string(),
+            found: "Int".to_string(),
+        }
+    );
+    assert_eq!(
+        runtime_error("Bool b = False || 1;"),
+        RuntimeError::TypeMismatch {
+            operation: "logical or".to_string(),
+            expected: "Bool".to_string(),
+            found: "Int".to_string(),
+        }
+    );
+}
+
+// ============================================================================
+// FLOATS
+// ============================================================================
+
+#[test]
+fn test_float_arithmetic() {
+    assert_eq!(global("Float x = 1.5 * 4 - 0.25;", "x"), Value::Float(5.75));
+    assert_eq!(global("Float x = 7 / 2.0;", "x"), Value::Float(3.5));
+    assert_eq!(global("Int x = 7 / 2;", "x"), Value::Number(3));
+    assert_eq!(global("Float x = 7.5 % 2;", "x"), Value::Float(1.5));
+    assert_eq!(global("Float x = 2 ** 0.5 ** 2;", "x"), Value::Float(2f64.powf(0.25)));
+    assert_eq!(global("Float x = 2.0 ** -1;", "x"), Value::Float(0.5));
+    assert_eq!(global("Float x = -(1.0 / 0);", "x"), Value::Float(f64::NEG_INFINITY));
+    assert_eq!(global("Bool b = 2 < 2.5 && 1e3 >= 1000;", "b"), Value::Bool(true));
+    assert_eq!(global("Bool b = 1.0 == 1 && 2 != 2.5 && !(1 == 1.5);", "b"), Value::Bool(true));
+    assert_eq!(global("Float x = 1.0; x += 1; x *= 3;", "x"), Value::Float(6.0));
+}
+
+#[test]
+fn test_float_conversions() {
+    assert_eq!(global("Int x = int(-3.9);", "x"), Value::Number(-3));
+    assert_eq!(global("Int x = int(7);", "x"), Value::Number(7));
+    assert_eq!(global("Float x = float(7);", "x"), Value::Float(7.0));
+    assert_eq!(runtime_error("Int x = int(1e19);"), RuntimeError::FloatToIntConversion);
+    assert_eq!(runtime_error("Int x = int(0.0 / 0);"), RuntimeError::FloatToIntConversion);
+}
+
+#[test]
+fn test_float_display() {
+    let display = |x: f64| Value::Float(x).to_string();
+    assert_eq!(display(2.0), "2.0");
+    assert_eq!(display(0.1 + 0.2), "0.30000000000000004");
+    assert_eq!(display(-0.0), "-0.0");
+    assert_eq!(display(1e21), "1000000000000000000000.0");
+    assert_eq!(display(1e-7), "0.0000001");
+    assert_eq!(display(f64::INFINITY), "inf");
+    assert_eq!(display(f64::NAN), "NaN");
+}
+
+// ============================================================================
+// STRINGS
+// ============================================================================
+
+#[test]
+fn test_string_escapes() {
+    assert_eq!(global(r#"String s = "a\tb\n";"#, "s"), Value::String("a\tb\n".to_string()));
+    assert_eq!(global(r#"String s = "\\n" + "\u{e9}";"#, "s"), Value::String("\\né".to_string()));
+    assert_eq!(global(r"Char c = '\'';", "c"), Value::Char('\''));
+    assert_eq!(global(r#"Bool b = "\u{41}" == "A";"#, "b"), Value::Bool(true));
+}
+
+#[test]
+fn test_string_interpolation() {
+    let code = r#"
+        String name = "Remy";
+        Int age = 41;
+        String s = "Hello ${name}, you are ${age + 1}";
+    "#;
+    assert_eq!(global(code, "s"), Value::String("Hello Remy, you are 42".to_string()));
+    assert_eq!(
+        global(r#"String s = "${[1.0, 2.5]} ${'c'}${False} ${"in${1}ner"}";"#, "s"),
+        Value::String("[1.0, 2.5] cFalse in1ner".to_string())
+    );
+    assert_eq!(global(r#"String s = "\${x} costs $5";"#, "s"), Value::String("${x} costs $5".to_string()));
+}
+
+// ============================================================================
+// ASSIGNMENT
+// ============================================================================
+
+#[test]
+fn test_compound_assignment() {
+    let code = r#"
+        Int x = 10;
+        x += 5;
+        x -= 3;
+        x *= 4;
+        x /= 6;
+        x %= 5;
+        String s = "Remy";
+        s += "Lang";
+    "#;
+    assert_eq!(global(code, "x"), Value::Number(3));
+    assert_eq!(global(code, "s"), Value::String("RemyLang".to_string()));
+}
+
+#[test]
+fn test_element_assignment() {
+    let code = r#"
+        Array<Int> a = [1, 2, 3];
+        a[1] = 42;
+        a[2] += 10;
+        Array<Array<Int>> grid = [[1, 2], [3, 4]];
+        grid[1][0] = 30;
+        grid[0][1] *= 5;
+        grid[0] = [7];
+    "#;
+    assert_eq!(global(code, "a").to_string(), "[1, 42, 13]");
+    assert_eq!(global(code, "grid").to_string(), "[[7], [30, 4]]");
+}
+
+#[test]
+fn test_element_assignment_does_not_alias() {
+    let code = r#"
+        Array<Int> a = [1, 2];
+        Array<Int> b = a;
+        b[0] = 9;
+        Array<Array<Int>> grid = [a, a];
+        grid[0][1] = 7;
+        func Clear(Array<Int> values) {
+            values[0] = 0;
+        }
+        Clear(a);
+    "#;
+    assert_eq!(global(code, "a").to_string(), "[1, 2]");
+    assert_eq!(global(code, "b").to_string(), "[9, 2]");
+    assert_eq!(global(code, "grid").to_string(), "[[1, 7], [1, 2]]");
+}
+
+#[test]
+fn test_assignment_evaluation_order() {
+    // The index is evaluated before the value
+    let code = r#"
+        Int counter = 0;
+        func Next() -> Int {
+            counter += 1;
+            return counter;
+        }
+        Array<Int> a = [0, 0, 0];
+        a[Next()] = Next() * 10;
+    "#;
+    assert_eq!(global(code, "a").to_string(), "[0, 20, 0]");
+}
+
+#[test]
+fn test_element_assignment_errors() {
+    assert_eq!(
+        runtime_error("Array<Int> a = [1]; a[3] = 0;"),
+        RuntimeError::IndexOutOfBounds { index: 3, length: 1 }
+    );
+    assert_eq!(
+        runtime_error("Array<Array<Int>> a = [[1]]; a[0][-1] += 1;"),
+        RuntimeError::IndexOutOfBounds { index: -1, length: 1 }
+    );
+    assert_eq!(
+        runtime_error("Int x = 9223372036854775807; x += 1;"),
+        RuntimeError::IntegerOverflow { operation: "addition".to_string() }
+    );
+}
+
+// ============================================================================
+// STRUCTS
+// ============================================================================
+
+#[test]
+fn test_struct_fields() {
+    let code = r#"
+        struct Point { Int x; Int y; }
+        struct Line { Point a; Point b; }
+        Point p = Point { y: 2, x: 1 };
+        Line l = Line { a: p, b: Point { x: 5, y: 6 } };
+        l.b.x += 10;
+        p.y = 20;
+        Int sum = l.a.y + l.b.x;
+    "#;
+    assert_eq!(global(code, "p").to_string(), "Point { x: 1, y: 20 }");
+    assert_eq!(global(code, "l").to_string(), "Line { a: Point { x: 1, y: 2 }, b: Point { x: 15, y: 6 } }");
+    assert_eq!(global(code, "sum"), Value::Number(17));
+}
+
+#[test]
+fn test_methods() {
+    let code = r#"
+        struct Point { Int x; Int y; }
+        impl Point {
+            func Add(Point other) -> Point {
+                return Point { x: self.x + other.x, y: self.y + other.y };
+            }
+            func Len2() -> Int {
+                return self.x * self.x + self.y * self.y;
+            }
+        }
+        Point p = Point { x: 1, y: 2 };
+        Int len = p.Add(Point { x: 2, y: 2 }).Len2();
+    "#;
+    assert_eq!(global(code, "len"), Value::Number(25));
+}
+
+#[test]
+fn test_structs_are_copied() {
+    let code = r#"
+        struct Box { Array<Int> items; }
+        impl Box {
+            func Clear() -> Int {
+                self.items[0] = 0;
+                return self.items[0];
+            }
+        }
+        Box a = Box { items: [1, 2] };
+        Box b = a;
+        b.items[1] = 9;
+        Int cleared = a.Clear();
+        Array<Box> boxes = [a, a];
+        boxes[0].items[0] = 7;
+    "#;
+    assert_eq!(global(code, "a").to_string(), "Box { items: [1, 2] }");
+    assert_eq!(global(code, "b").to_string(), "Box { items: [1, 9] }");
+    assert_eq!(global(code, "cleared"), Value::Number(0));
+    assert_eq!(global(code, "boxes").to_string(), "[Box { items: [7, 2] }, Box { items: [1, 2] }]");
+}
+
+#[test]
+fn test_struct_errors() {
+    let point = "struct Point { Int x; Int y; } ";
+    assert_eq!(
+        runtime_error(&format!("{}Point p = Point {{ x: 1 }};", point)),
+        RuntimeError::MissingField { type_name: "Point".to_string(), field: "y".to_string() }
+    );
+    assert_eq!(
+        runtime_error(&format!("{}Point p = Point {{ x: 1, y: 2 }}; Int z = p.z;", point)),
+        RuntimeError::UndefinedField { type_name: "Point".to_string(), field: "z".to_string() }
+    );
+    assert_eq!(
+        runtime_error(&format!("{}Point p = Point {{ x: 1, y: 2 }}; p.Len();", point)),
+        RuntimeError::UndefinedMethod { type_name: "Point".to_string(), method: "Len".to_string() }
+    );
+    assert_eq!(
+        runtime_error("Int x = Circle { r: 1 };"),
+        RuntimeError::UndefinedType { name: "Circle".to_string() }
+    );
+}
+
+// ============================================================================
+// ENUMS AND MATCH
+// ============================================================================
+
+const SHAPES: &str = "enum Shape { Circle(Int), Rect(Int, Int), Empty } ";
+
+#[test]
+fn test_enum_variants() {
+    let code = format!("{}Shape c = Shape::Circle(2); Shape e = Shape::Empty; Bool same = Shape::Rect(1, 2) == Shape::Rect(1, 2);", SHAPES);
+    assert_eq!(global(&code, "c").to_string(), "Shape::Circle(2)");
+    assert_eq!(global(&code, "e").to_string(), "Shape::Empty");
+    assert_eq!(global(&code, "same"), Value::Bool(true));
+}
+
+#[test]
+fn test_match_expression() {
+    let code = format!(r#"{}
+        func Area(Shape s) -> Int {{
+            return match (s) {{
+                Shape::Circle(r) => 3 * r * r,
+                Shape::Rect(w, h) => w * h,
+                Shape::Empty => 0,
+            }};
+        }}
+        Int total = Area(Shape::Circle(2)) + Area(Shape::Rect(3, 4)) + Area(Shape::Empty);
+        Int x = 5;
+        String size = match (x) {{ 0 => "none", 1 => "one", n => "many" }};
+        String sign = match (x > 0) {{ True => "+", False => "-" }};
+    "#, SHAPES);
+    assert_eq!(global(&code, "total"), Value::Number(24));
+    assert_eq!(global(&code, "size"), Value::String("many".to_string()));
+    assert_eq!(global(&code, "sign"), Value::String("+".to_string()));
+}
+
+#[test]
+fn test_match_statement() {
+    let code = format!(r#"{}
+        Array<Shape> shapes = [Shape::Rect(2, 2), Shape::Rect(1, 3), Shape::Empty];
+        Int squares = 0;
+        Int others = 0;
+        for s in shapes {{
+            match (s) {{
+                Shape::Rect(w, h) => {{
+                    if (w == h) {{
+                        squares += 1;
+                        continue;
+                    }}
+                    others += w;
+                }}
+                _ => others += 10,
+            }}
+        }}
+    "#, SHAPES);
+    assert_eq!(global(&code, "squares"), Value::Number(1));
+    assert_eq!(global(&code, "others"), Value::Number(11));
+}
+
+#[test]
+fn test_first_matching_arm_wins() {
+    let code = r#"
+        enum Pair { Of(Int, Int) }
+        Int a = match (Pair::Of(1, 2)) { Pair::Of(1, y) => y, Pair::Of(x, 2) => x * 10, _ => 0 };
+        Int b = match (Pair::Of(3, 2)) { Pair::Of(1, y) => y, Pair::Of(x, 2) => x * 10, _ => 0 };
+    "#;
+    assert_eq!(global(code, "a"), Value::Number(2));
+    assert_eq!(global(code, "b"), Value::Number(30));
+}
+
+#[test]
+fn test_enum_errors() {
+    assert_eq!(
+        runtime_error(&format!("{}Shape s = Shape::Square(1);", SHAPES)),
+        RuntimeError::UndefinedVariant { enum_name: "Shape".to_string(), variant: "Square".to_string() }
+    );
+    assert_eq!(
+        runtime_error(&format!("{}Shape s = Shape::Rect(1);", SHAPES)),
+        RuntimeError::ArgumentCountMismatch { expected: 2, found: 1, function_name: "Shape::Rect".to_string() }
+    );
+    // The type checker rejects matches without an arm for every value
+    assert_eq!(
+        runtime_error(&format!("{}Int n = match (Shape::Empty) {{ Shape::Circle(r) => r }};", SHAPES)),
+        RuntimeError::NoMatch { value: "Shape::Empty".to_string() }
+    );
+}
+
+// ============================================================================
+// LOOPS
+// ============================================================================
+
+#[test]
+fn test_while_loop() {
+    let code = r#"
+        Int i = 0;
+        Int sum = 0;
+        while (i < 5) {
+            sum = sum + i;
+            i = i + 1;
+        }
+    "#;
+    assert_eq!(global(code, "sum"), Value::Number(10));
+}
+
+#[test]
+fn test_c_style_for_loop() {
+    let code = r#"
+        Int sum = 0;
+        for (Int i = 1; i <= 10; i = i + 1) {
+            sum = sum + i;
+        }
+    "#;
+    assert_eq!(global(code, "sum"), Value::Number(55));
+}
+
+#[test]
+fn test_for_loop_variable_is_scoped() {
+    let (interpreter, result) = run("for (Int i = 0; i < 3; i = i + 1) { }");
+    assert!(result.is_ok());
+    assert!(!interpreter.environment().exists("i"));
+}
+
+#[test]
+fn test_for_in_loop() {
+    let code = r#"
+        Int sum = 0;
+        for item in [1, 2, 3, 4] {
+            sum = sum + item;
+        }
+        for (item in [10, 20]) {
+            sum = sum + item;
+        }
+    "#;
+    assert_eq!(global(code, "sum"), Value::Number(40));
+}
+
+#[test]
+fn test_break_and_continue() {
+    let code = r#"
+        Int sum = 0;
+        Int i = 0;
+        while (True) {
+            i = i + 1;
+            if (i > 10) {
+                break;
+            }
+            if (i % 2 == 0) {
+                continue;
+            }
+            sum = sum + i;
+        }
+    "#;
+    assert_eq!(global(code, "sum"), Value::Number(25));
+}
+
+#[test]
+fn test_continue_runs_for_increment() {
+    let code = r#"
+        Int count = 0;
+        for (Int i = 0; i < 5; i = i + 1) {
+            if (i == 2) {
+                continue;
+            }
+            count = count + 1;
+        }
+    "#;
+    assert_eq!(global(code, "count"), Value::Number(4));
+}
+
+#[test]
+fn test_return_from_inside_loop() {
+    let code = r#"
+        func FirstOver(Array<Int> values, Int limit) -> Int {
+            for v in values {
+                if (v > limit) {
+                    return v;
+                }
+            }
+            return -1;
+        }
+        Int r = FirstOver([1, 5, 9, 12], 6);
+    "#;
+    assert_eq!(global(code, "r"), Value::Number(9));
+}
+
+#[test]
+fn test_deep_iteration_does_not_overflow() {
+    let code = r#"
+        Int i = 0;
+        while (i < 100000) {
+            i = i + 1;
+        }
+    "#;
+    assert_eq!(global(code, "i"), Value::Number(100000));
+}
+
+#[test]
+fn test_break_outside_loop() {
+    assert_eq!(runtime_error("break;"), RuntimeError::BreakOutsideLoop);
+}
+
+#[test]
+fn test_break_does_not_escape_function() {
+    let code = r#"
+        func Stop() {
+            break;
+        }
+        while (True) {
+            Stop();
+        }
+    "#;
+    assert_eq!(runtime_error(code), RuntimeError::BreakOutsideLoop);
+}
+
+#[test]
+fn test_for_in_requires_array() {
+    assert_eq!(
+        runtime_error("for x in 5 { }"),
+        RuntimeError::NotIterable { value_type: "Int".to_string() }
+    );
+}
+
+// ============================================================================
+// CALL DEPTH
+// ============================================================================
+
+#[test]
+fn test_unbounded_recursion_overflows() {
+    let code = "func Loop(Int n) -> Int {\n    return Loop(n + 1);\n}\nLoop(0);";
+    let (_, result) = run(code);
+    let error = result.expect_err("code should fail");
+    assert_eq!(
+        error.kind(),
+        &RuntimeError::StackOverflow { depth: 10_000, function: "Loop".to_string() }
+    );
+    let span = error.span().expect("error should have a location");
+    assert_eq!((span.line, span.column), (2, 12));
+}
+
+#[test]
+fn test_stack_overflow_has_trace() {
+    let code = "func Loop(Int n) -> Int {\n    return Loop(n + 1);\n}\nLoop(0);";
+    let (_, result) = run(code);
+    let error = result.expect_err("code should fail");
+    let trace = error.trace().expect("error should have a trace");
+    assert_eq!(trace.len(), 10_000);
+    assert!(trace.iter().all(|frame| frame.function == "Loop"));
+    assert_eq!(trace[0].call_site.line, 4);
+    assert_eq!(trace[1].call_site.line, 2);
+
+    let message = error.to_string();
+    assert!(message.starts_with("Traceback (most recent call last):\n"), "{}", message);
+    assert!(message.contains("  File \"<input>\", line 4, in <main>\n"), "{}", message);
+    assert!(message.contains("  File \"<input>\", line 2, in Loop(0)\n"), "{}", message);
+    assert!(message.contains("  [Previous line repeated 9999 more times]\n"), "{}", message);
+}
+
+#[test]
+fn test_max_call_depth_is_configurable() {
+    let code = r#"
+        func Depth(Int n) -> Int {
+            if (n == 0) {
+                return 0;
+            }
+            return 1 + Depth(n - 1);
+        }
+        Int d = Depth(50);
+    "#;
+    let program = |code: &str| Parser::new(Lexer::new(code.to_string()).tokenize()).parse().unwrap();
+
+    let mut interpreter = Interpreter::with_max_call_depth(51);
+    assert_eq!(interpreter.execute(program(code)), Ok(()));
+
+    let mut interpreter = Interpreter::with_max_call_depth(50);
+    let error = interpreter.execute(program(code)).expect_err("code should fail");
+    assert_eq!(
+        error.kind(),
+        &RuntimeError::StackOverflow { depth: 50, function: "Depth".to_string() }
+    );
+}
+
+#[test]
+fn test_deep_recursion_does_not_depend_on_host_stack() {
+    // Test threads have a small stack, which the interpreter must not rely on
+    let code = r#"
+        func Deep(Int n) -> Int {
+            if (n == 0) {
+                return 0;
+            }
+            while (True) {
+                for (Int i = 0; i < 1; i = i + 1) {
+                    for x in [n] {
+                        return 1 + Deep(x - 1) * 1;
+                    }
+                }
+            }
+            return 0;
+        }
+        Int d = Deep(999);
+    "#;
+    assert_eq!(global(code, "d"), Value::Number(999));
+}
+
+#[test]
+fn test_unlimited_call_depth() {
+    let code = "func Depth(Int n) -> Int { if (n == 0) { return 0; } return 1 + Depth(n - 1); } Int d = Depth(3000);";
+    let program = Parser::new(Lexer::new(code.to_string()).tokenize()).parse().unwrap();
+    let mut interpreter = Interpreter::with_max_call_depth(usize::MAX);
+    assert_eq!(interpreter.execute(program), Ok(()));
+    assert_eq!(interpreter.environment().get("d"), Ok(Value::Number(3000)));
+}
+
+// ============================================================================
+// STACK TRACES
+// ============================================================================
+
+#[test]
+fn test_error_in_nested_calls_has_trace() {
+    let code = "func Divide(Int a, Int b) -> Int {\n    return a / b;\n}\nfunc Add(Int a, Int b) -> Int {\n    return a + Divide(a, b - b);\n}\nInt r = Add(Add(1, 2), 3);";
+    let (_, result) = run(code);
+    let error = result.expect_err("code should fail");
+    assert_eq!(error.kind(), &RuntimeError::DivisionByZero);
+
+    let trace = error.trace().expect("error should have a trace");
+    let calls: Vec<String> = trace.iter().map(|frame| frame.describe()).collect();
+    assert_eq!(calls, vec!["Add(1, 2)", "Divide(1, 0)"]);
+    assert_eq!((trace[0].call_site.line, trace[0].call_site.column), (7, 13));
+    assert_eq!((trace[1].call_site.line, trace[1].call_site.column), (5, 16));
+
+    assert_eq!(
+        error.to_string(),
+        "Traceback (most recent call last):\n  \
+         File \"<input>\", line 7, in <main>\n  \
+         File \"<input>\", line 5, in Add(1, 2)\n  \
+         File \"<input>\", line 2, in Divide(1, 0)\n\
+         <input>:2:12: Runtime error: Division by zero"
+    );
+}
+
+#[test]
+fn test_top_level_error_has_empty_trace() {
+    let (_, result) = run("Int x = 1 / 0;");
+    let error = result.expect_err("code should fail");
+    assert_eq!(error.trace(), Some(&[][..]));
+    assert_eq!(error.to_string(), "<input>:1:9: Runtime error: Division by zero");
+}
+
+#[test]
+fn test_trace_arguments_are_shortened() {
+    let code = r#"
+        func Fail(String s, Array<Int> a) -> Int {
+            return a[20];
+        }
+        Fail("text", [1, 2, 3, 4, 5, 6, 7, 8, 9, 10, 11, 12, 13]);
+    "#;
+    let (_, result) = run(code);
+    let trace = result.expect_err("code should fail").trace().unwrap().to_vec();
+    assert_eq!(trace[0].describe(), "Fail(\"text\", [1, 2, 3, 4, 5, 6, 7, 8, 9,...)");
+}
+
+#[test]
+fn test_interpreter_recovers_after_error() {
+    let tokens = Lexer::new("func F() -> Int { return 1 / 0; }".to_string()).tokenize();
+    let mut interpreter = Interpreter::new();
+    interpreter.execute(Parser::new(tokens).parse().unwrap()).unwrap();
+
+    let call = || Parser::new(Lexer::new("F();".to_string()).tokenize()).parse().unwrap();
+    let first = interpreter.execute(call()).unwrap_err();
+    let second = interpreter.execute(call()).unwrap_err();
+    assert_eq!(first.trace().map(<[_]>::len), Some(1));
+    assert_eq!(first, second);
+}
+
+// ============================================================================
+// SCOPING AND CLOSURES
+// ============================================================================
+
+#[test]
+fn test_function_cannot_see_caller_locals() {
+    let code = r#"
+        func ReadX() -> Int {
+            return x;
+        }
+        func Caller() -> Int {
+            Int x = 5;
+            return ReadX();
+        }
+        Caller();
+    "#;
+    assert_eq!(
+        runtime_error(code),
+        RuntimeError::UndefinedVariable { name: "x".to_string() }
+    );
+}
+
+#[test]
+fn test_function_uses_definition_scope() {
+    let code = r#"
+        Int x = 1;
+        func ReadX() -> Int {
+            return x;
+        }
+        func Caller() -> Int {
+            Int x = 100;
+            return ReadX();
+        }
+        Int r = Caller();
+    "#;
+    assert_eq!(global(code, "r"), Value::Number(1));
+}
+
+#[test]
+fn test_functions_can_be_called_before_their_declaration() {
+    let code = r#"
+        Int a = Twice(3);
+        func Twice(Int n) -> Int {
+            return Add(n, n);
+        }
+        func Outer() -> Int {
+            Int r = Inner();
+            func Inner() -> Int {
+                return Add(3, 4);
+            }
+            return r;
+        }
+        Int b = Outer();
+        func Add(Int x, Int y) -> Int {
+            return x + y;
+        }
+    "#;
+    let ast = Parser::new(Lexer::new(code.to_string()).tokenize()).parse().unwrap();
+    TypeChecker::new().check_program(&ast).expect("code should type check");
+    assert_eq!(global(code, "a"), Value::Number(6));
+    assert_eq!(global(code, "b"), Value::Number(7));
+}
+
+#[test]
+fn test_nested_function_captures_outer_locals() {
+    let code = r#"
+        Int result = 0;
+        func Outer(Int base) {
+            Int count = base;
+            func Increment() {
+                count = count + 1;
+            }
+            Increment();
+            Increment();
+            result = count;
+        }
+        Outer(40);
+    "#;
+    assert_eq!(global(code, "result"), Value::Number(42));
+}
+
+#[test]
+fn test_returned_function_keeps_captured_state() {
+    let code = r#"
+        func MakeCounter() -> Func<(), Int> {
+            Int count = 0;
+            func Next() -> Int {
+                count = count + 1;
+                return count;
+            }
+            return Next;
+        }
+        Func<(), Int> counter = MakeCounter();
+        counter();
+        counter();
+        Int third = counter();
+    "#;
+    assert_eq!(global(code, "third"), Value::Number(3));
+}
+
+#[test]
+fn test_lambdas_and_higher_order_functions() {
+    let code = r#"
+        func Map(Array<Int> values, Func<(Int), Int> f) -> Array<Int> {
+            for (Int i = 0; i < 3; i += 1) {
+                values[i] = f(values[i]);
+            }
+            return values;
+        }
+        func MakeAdder(Int n) -> Func<(Int), Int> {
+            return func(Int x) -> Int { return x + n; };
+        }
+        Array<Int> doubled = Map([1, 2, 3], func(Int x) -> Int { return x * 2; });
+        Int seven = MakeAdder(3)(4);
+        Array<Func<(Int), Int>> handlers = [MakeAdder(1), MakeAdder(10)];
+        Int eleven = handlers[1](1);
+    "#;
+    assert_eq!(global(code, "doubled").to_string(), "[2, 4, 6]");
+    assert_eq!(global(code, "seven"), Value::Number(7));
+    assert_eq!(global(code, "eleven"), Value::Number(11));
+}
+
+#[test]
+fn test_calling_a_non_function_value() {
+    let code = "Int n = 1; n(2);";
+    assert!(matches!(runtime_error(code), RuntimeError::NotCallable { .. }));
+}
+
+#[test]
+fn test_recursion_through_closure() {
+    let code = r#"
+        func Fact(Int n) -> Int {
+            if (n <= 1) {
+                return 1;
+            }
+            return n * Fact(n - 1);
+        }
+        Int r = Fact(10);
+    "#;
+    assert_eq!(global(code, "r"), Value::Number(3628800));
+}
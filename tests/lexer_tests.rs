@@ -12,11 +12,11 @@
  * -------------------------------------------------------------------------
 */
 
-use remylang::lexer::{Lexer, Token};
+use remylang::lexer::{LexError, Lexer, StringPart, Token};
 
 // Helper function to tokenize input into a Vec
 fn tokenize(input: &str) -> Vec<Token> {
-    Lexer::new(input.to_string()).collect()
+    Lexer::new(input.to_string()).map(|t| t.token).collect()
 }
 
 // =============================================================================
@@ -51,6 +51,40 @@ fn test_whitespace_handling() {
     assert_eq!(tokens, vec![Token::Number(42), Token::Number(100)]);
 }
 
+#[test]
+fn test_float_literals() {
+    let tokens = tokenize("2.75 0.5 1e-3 2.5E+2 7e2");
+    assert_eq!(
+        tokens,
+        vec![
+            Token::FloatLit(2.75),
+            Token::FloatLit(0.5),
+            Token::FloatLit(0.001),
+            Token::FloatLit(250.0),
+            Token::FloatLit(700.0)
+        ]
+    );
+}
+
+#[test]
+fn test_number_followed_by_dot_or_e() {
+    // Without digits after them, '.' and 'e' are not part of the number
+    let tokens = tokenize("1.len 2e 3e+");
+    assert_eq!(
+        tokens,
+        vec![
+            Token::Number(1),
+            Token::Dot,
+            Token::Identifier("len".to_string()),
+            Token::Number(2),
+            Token::Identifier("e".to_string()),
+            Token::Number(3),
+            Token::Identifier("e".to_string()),
+            Token::Plus
+        ]
+    );
+}
+
 // =============================================================================
 // Arithmetic Operators
 // =============================================================================
@@ -201,11 +235,12 @@ fn test_assignment_operators() {
 
 #[test]
 fn test_type_keywords() {
-    let tokens = tokenize("Int String Char Bool Array");
+    let tokens = tokenize("Int Float String Char Bool Array");
     assert_eq!(
         tokens,
         vec![
             Token::Int,
+            Token::Float,
             Token::String,
             Token::Char,
             Token::Bool,
@@ -229,6 +264,50 @@ fn test_control_flow_keywords() {
     );
 }
 
+#[test]
+fn test_loop_keywords() {
+    let tokens = tokenize("while for in break continue");
+    assert_eq!(
+        tokens,
+        vec![Token::While, Token::For, Token::In, Token::Break, Token::Continue]
+    );
+}
+
+#[test]
+fn test_struct_keywords_and_dot() {
+    let tokens = tokenize("struct impl p.x");
+    assert_eq!(
+        tokens,
+        vec![
+            Token::Struct,
+            Token::Impl,
+            Token::Identifier("p".to_string()),
+            Token::Dot,
+            Token::Identifier("x".to_string())
+        ]
+    );
+}
+
+#[test]
+fn test_enum_and_match_tokens() {
+    let tokens = tokenize("enum match Shape::Rect => = > : ::");
+    assert_eq!(
+        tokens,
+        vec![
+            Token::Enum,
+            Token::Match,
+            Token::Identifier("Shape".to_string()),
+            Token::DoubleColon,
+            Token::Identifier("Rect".to_string()),
+            Token::FatArrow,
+            Token::Equal,
+            Token::Greater,
+            Token::Colon,
+            Token::DoubleColon
+        ]
+    );
+}
+
 // =============================================================================
 // Identifiers
 // =============================================================================
@@ -284,6 +363,101 @@ fn test_string_with_spaces() {
     );
 }
 
+#[test]
+fn test_string_escapes() {
+    let tokens = tokenize(r#""a\nb\tc\rd\\e\"f\'g\0h""#);
+    assert_eq!(tokens, vec![Token::StringLit("a\nb\tc\rd\\e\"f'g\0h".to_string())]);
+}
+
+#[test]
+fn test_string_unicode_escapes() {
+    let tokens = tokenize(r#""\u{48}\u{e9}\u{1F600}""#);
+    assert_eq!(tokens, vec![Token::StringLit("Hé😀".to_string())]);
+}
+
+#[test]
+fn test_invalid_string_escapes() {
+    let tokens = tokenize(r#""\q" "\u{110000}" "\u41" "\u{12" "ok""#);
+    assert_eq!(
+        tokens,
+        vec![
+            Token::Error(LexError::InvalidEscape("\\q".to_string())),
+            Token::Error(LexError::InvalidEscape("\\u{110000}".to_string())),
+            Token::Error(LexError::InvalidEscape("\\u".to_string())),
+            Token::Error(LexError::InvalidEscape("\\u{12".to_string())),
+            Token::StringLit("ok".to_string())
+        ]
+    );
+}
+
+#[test]
+fn test_interpolated_string() {
+    let tokens = tokenize(r#""a${x + 1}b${ {} }" "\${x}""#);
+    match &tokens[0] {
+        Token::InterpolatedString(parts) => {
+            assert_eq!(parts.len(), 4);
+            assert_eq!(parts[0], StringPart::Text("a".to_string()));
+            match &parts[1] {
+                StringPart::Code(code) => assert_eq!(
+                    code.iter().map(|t| t.token.clone()).collect::<Vec<_>>(),
+                    vec![Token::Identifier("x".to_string()), Token::Plus, Token::Number(1), Token::EOF]
+                ),
+                other => panic!("Expected code, got {:?}", other),
+            }
+            assert_eq!(parts[2], StringPart::Text("b".to_string()));
+            match &parts[3] {
+                StringPart::Code(code) => assert_eq!(code, &vec![Token::LeftBrace, Token::RightBrace, Token::EOF]),
+                other => panic!("Expected code, got {:?}", other),
+            }
+        }
+        other => panic!("Expected interpolated string, got {:?}", other),
+    }
+    assert_eq!(tokens[1], Token::StringLit("${x}".to_string()));
+    assert_eq!(tokens.len(), 2);
+}
+
+#[test]
+fn test_interpolation_spans_and_nesting() {
+    let mut lexer = Lexer::new(r#""${"in${n}"}" n"#.to_string());
+    let string = lexer.next_token();
+    assert_eq!((string.span.start, string.span.end), (0, 13));
+    let Token::InterpolatedString(parts) = &string.token else {
+        panic!("Expected interpolated string, got {:?}", string.token);
+    };
+    let [StringPart::Code(code)] = parts.as_slice() else {
+        panic!("Expected a single expression, got {:?}", parts);
+    };
+    assert!(matches!(code[0].token, Token::InterpolatedString(_)));
+    assert_eq!((code[0].span.start, code[0].span.end), (3, 11));
+    assert_eq!((code[1].span.start, code[1].span.column), (11, 12));
+    assert_eq!(lexer.next_token(), Token::Identifier("n".to_string()));
+}
+
+#[test]
+fn test_unterminated_interpolation() {
+    let tokens = tokenize(r#""a${x"#);
+    assert_eq!(tokens, vec![Token::Error(LexError::UnterminatedString)]);
+
+    let tokens = tokenize(r#""a${x}"#);
+    assert_eq!(tokens, vec![Token::Error(LexError::UnterminatedString)]);
+}
+
+#[test]
+fn test_unterminated_string() {
+    let tokens = tokenize("x = \"abc;\ny = 1;");
+    assert_eq!(
+        tokens,
+        vec![
+            Token::Identifier("x".to_string()),
+            Token::Equal,
+            Token::Error(LexError::UnterminatedString)
+        ]
+    );
+
+    let tokens = tokenize(r#""abc\""#);
+    assert_eq!(tokens, vec![Token::Error(LexError::UnterminatedString)]);
+}
+
 // =============================================================================
 // Character Literals
 // =============================================================================
@@ -303,6 +477,45 @@ fn test_multiple_chars() {
     );
 }
 
+#[test]
+fn test_char_escapes() {
+    let tokens = tokenize(r"'\n' '\t' '\\' '\'' '\0' '\u{263A}'");
+    assert_eq!(
+        tokens,
+        vec![
+            Token::CharLit('\n'),
+            Token::CharLit('\t'),
+            Token::CharLit('\\'),
+            Token::CharLit('\''),
+            Token::CharLit('\0'),
+            Token::CharLit('☺')
+        ]
+    );
+}
+
+#[test]
+fn test_malformed_char_literals() {
+    let tokens = tokenize(r"'' 'ab' '\x' x");
+    assert_eq!(
+        tokens,
+        vec![
+            Token::Error(LexError::InvalidCharLiteral(String::new())),
+            Token::Error(LexError::InvalidCharLiteral("ab".to_string())),
+            Token::Error(LexError::InvalidEscape("\\x".to_string())),
+            Token::Identifier("x".to_string())
+        ]
+    );
+}
+
+#[test]
+fn test_unterminated_char() {
+    let tokens = tokenize("'a\n;");
+    assert_eq!(tokens, vec![Token::Error(LexError::UnterminatedChar), Token::Semicolon]);
+
+    let tokens = tokenize("'");
+    assert_eq!(tokens, vec![Token::Error(LexError::UnterminatedChar)]);
+}
+
 // =============================================================================
 // Delimiters
 // =============================================================================
@@ -371,6 +584,15 @@ fn test_block_comment() {
     );
 }
 
+#[test]
+fn test_unterminated_block_comment() {
+    let tokens = tokenize("Int x /* never closed\nInt y");
+    assert_eq!(
+        tokens,
+        vec![Token::Int, Token::Identifier("x".to_string()), Token::Error(LexError::UnterminatedComment)]
+    );
+}
+
 #[test]
 fn test_multiline_block_comment() {
     let tokens = tokenize("Int a /* multi\nline\ncomment */ Int b");
@@ -385,6 +607,22 @@ fn test_multiline_block_comment() {
     );
 }
 
+#[test]
+fn test_comments_are_kept_aside() {
+    let mut lexer = Lexer::new("// first\nInt a /* multi\nline */ = 1; // last".to_string());
+    let tokens = lexer.tokenize();
+    assert_eq!(tokens.len(), 6);
+    let comments: Vec<(&str, usize, usize, usize)> = lexer
+        .comments()
+        .iter()
+        .map(|c| (c.text.as_str(), c.span.start, c.span.end, c.span.line))
+        .collect();
+    assert_eq!(
+        comments,
+        vec![("// first", 0, 8, 1), ("/* multi\nline */", 15, 31, 2), ("// last", 37, 44, 3)]
+    );
+}
+
 // =============================================================================
 // Variable Declarations
 // =============================================================================
@@ -655,6 +893,42 @@ fn test_single_pipe() {
     assert_eq!(tokens, vec![Token::Invalid('|')]);
 }
 
+#[test]
+fn test_integer_literal_too_large() {
+    let tokens = tokenize("99999999999999999999;");
+    assert_eq!(
+        tokens,
+        vec![
+            Token::Error(LexError::IntegerTooLarge("99999999999999999999".to_string())),
+            Token::Semicolon
+        ]
+    );
+}
+
+#[test]
+fn test_largest_integer_literal() {
+    let tokens = tokenize("9223372036854775807 9223372036854775808");
+    assert_eq!(
+        tokens,
+        vec![
+            Token::Number(i64::MAX),
+            Token::Error(LexError::IntegerTooLarge("9223372036854775808".to_string()))
+        ]
+    );
+}
+
+#[test]
+fn test_float_literal_too_large() {
+    let tokens = tokenize("1e308 1e309");
+    assert_eq!(
+        tokens,
+        vec![
+            Token::FloatLit(1e308),
+            Token::Error(LexError::FloatTooLarge("1e309".to_string()))
+        ]
+    );
+}
+
 // =============================================================================
 // Complete Program
 // =============================================================================
@@ -686,7 +960,7 @@ func Main() -> Int {
 #[test]
 fn test_iterator() {
     let lexer = Lexer::new("1 + 2".to_string());
-    let tokens: Vec<Token> = lexer.collect();
+    let tokens: Vec<Token> = lexer.map(|t| t.token).collect();
 
     assert_eq!(tokens.len(), 3);
     assert_eq!(tokens[0], Token::Number(1));
@@ -697,9 +971,66 @@ fn test_iterator() {
 #[test]
 fn test_iterator_stops_at_eof() {
     let lexer = Lexer::new("42".to_string());
-    let tokens: Vec<Token> = lexer.collect();
+    let tokens: Vec<Token> = lexer.map(|t| t.token).collect();
 
     assert_eq!(tokens.len(), 1);
     assert_eq!(tokens[0], Token::Number(42));
     // EOF should not be in the collected tokens
 }
+
+// =============================================================================
+// Span Tests
+// =============================================================================
+
+#[test]
+fn test_token_spans() {
+    let mut lexer = Lexer::with_file("Int x = 42;".to_string(), "test.remy");
+
+    let int = lexer.next_token();
+    assert_eq!(int, Token::Int);
+    assert_eq!((int.span.start, int.span.end), (0, 3));
+    assert_eq!((int.span.line, int.span.column), (1, 1));
+
+    let ident = lexer.next_token();
+    assert_eq!((ident.span.start, ident.span.end), (4, 5));
+    assert_eq!(ident.span.column, 5);
+    assert_eq!(&*ident.span.file, "test.remy");
+}
+
+#[test]
+fn test_token_spans_across_lines_and_comments() {
+    let mut lexer = Lexer::new("// comment\n  foo /* block\n */ bar".to_string());
+
+    let foo = lexer.next_token();
+    assert_eq!(foo, Token::Identifier("foo".to_string()));
+    assert_eq!((foo.span.line, foo.span.column), (2, 3));
+
+    let bar = lexer.next_token();
+    assert_eq!(bar, Token::Identifier("bar".to_string()));
+    assert_eq!((bar.span.line, bar.span.column), (3, 5));
+    assert_eq!(bar.span.to_string(), "<input>:3:5");
+}
+
+#[test]
+fn test_unterminated_literal_spans() {
+    let mut lexer = Lexer::new("a /* oops".to_string());
+    lexer.next_token();
+    let comment = lexer.next_token();
+    assert_eq!(comment, Token::Error(LexError::UnterminatedComment));
+    assert_eq!((comment.span.start, comment.span.end), (2, 9));
+    assert_eq!(comment.span.column, 3);
+    assert_eq!(lexer.next_token(), Token::EOF);
+
+    let mut lexer = Lexer::new("\n  \"text".to_string());
+    let string = lexer.next_token();
+    assert_eq!(string, Token::Error(LexError::UnterminatedString));
+    assert_eq!((string.span.line, string.span.column), (2, 3));
+}
+
+#[test]
+fn test_tokenize_includes_eof() {
+    let tokens = Lexer::new("a".to_string()).tokenize();
+    assert_eq!(tokens.len(), 2);
+    assert_eq!(tokens[1], Token::EOF);
+    assert_eq!(tokens[1].span.start, 1);
+}
@@ -0,0 +1,385 @@
+/*
+ * -------------------------------------------------------------------------
+ * RemyLang — LLVM Backend Tests
+ * File : tests/llvm_backend_tests.rs
+ *
+ * Description :
+ *   Integration tests for native code generation: generated IR and
+ *   executables linked with the runtime.
+ *
+ * Author  : Samuel 'Meeast' Bleau
+ * Created : 2026-10-16
+ *
+ * -------------------------------------------------------------------------
+*/
+
+use std::path::PathBuf;
+use std::process::Command;
+use inkwell::context::Context;
+use remylang::ast::Stmt;
+use remylang::lexer::Lexer;
+use remylang::llvm_backend::{self, CodeGen, CodegenError, OutputKind};
+use remylang::parser::Parser;
+use remylang::semantic::TypeChecker;
+
+fn parse(code: &str) -> Vec<Stmt> {
+    let tokens = Lexer::new(code.to_string()).tokenize();
+    let ast = Parser::new(tokens).parse().expect("code should parse");
+    TypeChecker::new().check_program(&ast).expect("code should type check");
+    ast
+}
+
+// Helper returning the textual IR of a program
+fn ir(code: &str) -> String {
+    let context = Context::create();
+    let module = CodeGen::new(&context, "test")
+        .compile_program(&parse(code))
+        .expect("code should compile");
+    module.print_to_string().to_string()
+}
+
+// Helper building an executable and returning its standard output and exit code
+fn run_native(name: &str, code: &str) -> (String, Option<i32>) {
+    let output = std::env::temp_dir().join(format!("remylang-test-{}-{}", name, std::process::id()));
+    llvm_backend::build(&parse(code), name, OutputKind::Executable, &output)
+        .expect("executable should build");
+
+    let result = Command::new(&output).output().expect("executable should run");
+    let _ = std::fs::remove_file(&output);
+    (String::from_utf8_lossy(&result.stdout).into_owned(), result.status.code())
+}
+
+fn example(name: &str) -> String {
+    let path: PathBuf = [env!("CARGO_MANIFEST_DIR"), "examples", name].iter().collect();
+    std::fs::read_to_string(path).expect("example should exist")
+}
+
+// ============================================================================
+// IR GENERATION
+// ============================================================================
+
+#[test]
+fn test_functions_are_defined() {
+    let code = r#"
+        func Add(Int a, Int b) -> Int {
+            return a + b;
+        }
+        func IsZero(Int n) -> Bool {
+            return n == 0;
+        }
+        func Hello() {
+            print("Hello");
+        }
+    "#;
+    let ir = ir(code);
+    assert!(ir.contains("define i64 @remy.Add(i64 %a, i64 %b)"));
+    assert!(ir.contains("define i1 @remy.IsZero(i64 %n)"));
+    assert!(ir.contains("define void @remy.Hello()"));
+    assert!(ir.contains("define i32 @main()"));
+}
+
+#[test]
+fn test_top_level_variables_are_globals() {
+    let ir = ir("Int counter = 1; Char c = 'x'; String s = \"hi\";");
+    assert!(ir.contains("@remy.global.counter = global i64 0"));
+    assert!(ir.contains("@remy.global.c = global i32 0"));
+    assert!(ir.contains("@remy.global.s = global ptr null"));
+}
+
+#[test]
+fn test_control_flow_verifies() {
+    // Statements after return, break and continue must still produce valid IR
+    let code = r#"
+        func Sign(Int n) -> Int {
+            if (n < 0) {
+                return -1;
+            } else if (n == 0) {
+                return 0;
+            }
+            return 1;
+        }
+        Int total = 0;
+        for (Int i = 0; i < 10; i = i + 1) {
+            if (i == 2) {
+                continue;
+            }
+            while (True) {
+                break;
+            }
+            total = total + Sign(i);
+        }
+    "#;
+    let ir = ir(code);
+    assert!(ir.contains("for_cond"));
+    assert!(ir.contains("while_body"));
+}
+
+#[test]
+fn test_unsupported_constructs_are_reported() {
+    let code = r#"
+        func Outer() {
+            func Inner() {
+            }
+        }
+    "#;
+    let context = Context::create();
+    let result = CodeGen::new(&context, "test").compile_program(&parse(code));
+    assert!(matches!(result, Err(CodegenError::Unsupported { .. })));
+}
+
+#[test]
+fn test_structs_are_unsupported() {
+    let code = "func F() -> Int { return 1; }\nstruct Point { Int x; }\nPoint p = Point { x: F() };";
+    let context = Context::create();
+    let result = CodeGen::new(&context, "test").compile_program(&parse(code));
+    match result {
+        Err(CodegenError::Unsupported { feature, span }) => {
+            assert_eq!(feature, "Structs");
+            assert_eq!(span.line, 2);
+        }
+        other => panic!("Expected structs to be unsupported, got {:?}", other.map(|_| ())),
+    }
+}
+
+#[test]
+fn test_enums_and_match_are_unsupported() {
+    let context = Context::create();
+    let code = "Int x = 1;\nenum Shape { Circle(Int) }";
+    match CodeGen::new(&context, "test").compile_program(&parse(code)) {
+        Err(CodegenError::Unsupported { feature, span }) => {
+            assert_eq!(feature, "Enums");
+            assert_eq!(span.line, 2);
+        }
+        other => panic!("Expected enums to be unsupported, got {:?}", other.map(|_| ())),
+    }
+
+    let context = Context::create();
+    let code = "Int x = 1;\nInt y = match (x) { _ => 2 };";
+    match CodeGen::new(&context, "test").compile_program(&parse(code)) {
+        Err(CodegenError::Unsupported { feature, .. }) => assert_eq!(feature, "Pattern matching"),
+        other => panic!("Expected match to be unsupported, got {:?}", other.map(|_| ())),
+    }
+}
+
+#[test]
+fn test_interpolation_is_unsupported() {
+    let context = Context::create();
+    let code = r#"Int x = 1; println("x = ${x}");"#;
+    match CodeGen::new(&context, "test").compile_program(&parse(code)) {
+        Err(CodegenError::Unsupported { feature, span }) => {
+            assert_eq!(feature, "String interpolation");
+            assert_eq!(span.column, 20);
+        }
+        other => panic!("Expected interpolation to be unsupported, got {:?}", other.map(|_| ())),
+    }
+}
+
+#[test]
+fn test_first_class_functions_are_unsupported() {
+    let context = Context::create();
+    let code = "func Double(Int x) -> Int { return x * 2; } Func<(Int), Int> f = Double;";
+    match CodeGen::new(&context, "test").compile_program(&parse(code)) {
+        Err(CodegenError::Unsupported { feature, span }) => {
+            assert_eq!(feature, "First-class functions");
+            assert_eq!(span.column, 66);
+        }
+        other => panic!("Expected function values to be unsupported, got {:?}", other.map(|_| ())),
+    }
+}
+
+#[test]
+fn test_library_functions_are_unsupported() {
+    let context = Context::create();
+    let code = "Int x = 1;\nInt n = len(\"abc\") + x;";
+    match CodeGen::new(&context, "test").compile_program(&parse(code)) {
+        Err(CodegenError::Unsupported { feature, span }) => {
+            assert_eq!(feature, "The 'len' builtin");
+            assert_eq!(span.line, 2);
+        }
+        other => panic!("Expected len to be unsupported, got {:?}", other.map(|_| ())),
+    }
+}
+
+#[test]
+fn test_user_functions_hide_natives() {
+    let code = "func max(Int a, Int b) -> Int {\n    if (a > b) {\n        return a;\n    }\n    return b;\n}\nprintln(max(3, 7));";
+    let ir = ir(code);
+    assert!(ir.contains("define i64 @remy.max(i64 %a, i64 %b)"), "{}", ir);
+    assert!(ir.contains("call i64 @remy.max("), "{}", ir);
+}
+
+#[test]
+fn test_emit_llvm_ir_file() {
+    let output = std::env::temp_dir().join(format!("remylang-test-{}.ll", std::process::id()));
+    llvm_backend::build(&parse("Int x = 1;"), "emit", OutputKind::LlvmIr, &output)
+        .expect("IR should be written");
+    let text = std::fs::read_to_string(&output).expect("IR file should exist");
+    let _ = std::fs::remove_file(&output);
+    assert!(text.contains("define i32 @main()"));
+}
+
+// ============================================================================
+// EXECUTABLES
+// ============================================================================
+
+#[test]
+fn test_functions_example_output() {
+    let (stdout, status) = run_native("functions", &example("test_functions.remy"));
+    assert_eq!(status, Some(0));
+    assert_eq!(stdout, "5 + 3 = 8\n4 * 7 = 28\n2 * 3 + 4 = 10\n13\nHello, RemyLang\n");
+}
+
+#[test]
+fn test_loops_example_output() {
+    let (stdout, status) = run_native("loops", &example("test_loops.remy"));
+    assert_eq!(status, Some(0));
+    assert_eq!(
+        stdout,
+        "while: 0\nwhile: 1\nwhile: 2\nsum of odd numbers up to 10 = 25\nfor-in: Remy\nfor-in: Lang\n"
+    );
+}
+
+#[test]
+fn test_strings_arrays_and_chars() {
+    let code = r#"
+        Array<Array<Int>> grid = [[1, 2], [3]];
+        Array<String> words = ["a", "b"];
+        String joined = words[0] + words[1];
+        println(grid, words, joined, 'é', True, grid[0] == [1, 2], joined == "ab");
+        println(2 ** 10, -7 % 3);
+        println("tab\t\"quoted\"", '\u{e9}' == 'é', ["a\\b"]);
+    "#;
+    let (stdout, status) = run_native("values", code);
+    assert_eq!(status, Some(0));
+    assert_eq!(stdout, "[[1, 2], [3]] [a, b] ab é True True True\n1024 -1\ntab\t\"quoted\" True [a\\b]\n");
+}
+
+#[test]
+fn test_logical_operators_short_circuit() {
+    let code = r#"
+        func Check(Bool b) -> Bool {
+            print("c");
+            return b;
+        }
+        Array<Int> a = [1, 2];
+        Int i = 5;
+        println(i < 2 && a[i] > 0, i >= 2 || a[i] > 0);
+        println(Check(True) && Check(False) || Check(True));
+    "#;
+    let (stdout, status) = run_native("logical", code);
+    assert_eq!(status, Some(0));
+    assert_eq!(stdout, "False True\ncccTrue\n");
+}
+
+#[test]
+fn test_assignments() {
+    let code = r#"
+        Int x = 10;
+        x += 5;
+        x %= 4;
+        String s = "a";
+        s += "b";
+        Array<Array<Int>> grid = [[1, 2], [3, 4]];
+        Array<Array<Int>> copy = grid;
+        grid[1][0] = 30;
+        grid[0][1] *= 5;
+        func Clear(Array<Int> values) {
+            values[0] = 0;
+        }
+        Clear(grid[0]);
+        for row in grid {
+            row[0] = 0;
+            grid[1] = [];
+        }
+        println(x, s, grid, copy);
+    "#;
+    let (stdout, status) = run_native("assignments", code);
+    assert_eq!(status, Some(0));
+    assert_eq!(stdout, "3 ab [[1, 10], []] [[1, 2], [3, 4]]\n");
+
+    let (_, status) = run_native("assign_bounds", "Array<Int> a = [1]; a[1] += 1;");
+    assert_eq!(status, Some(1));
+}
+
+#[test]
+fn test_floats() {
+    let code = r#"
+        Float r = 2.0;
+        Array<Float> values = [0.1, r * 1.5];
+        Float total = 0.0;
+        for v in values {
+            total += v;
+        }
+        println(3.14159 * r ** 2, 7 / 2.0, -r, 7.5 % 2, values, total);
+        println(int(-3.9), float(7), 0.1 + 0.2, 1 / 0.0, values == [0.1, 3.0], 1e21, 1e-7);
+    "#;
+    let (stdout, status) = run_native("floats", code);
+    assert_eq!(status, Some(0));
+    assert_eq!(
+        stdout,
+        "12.56636 3.5 -2.0 1.5 [0.1, 3.0] 3.1\n-3 7.0 0.30000000000000004 inf True 1000000000000000000000.0 0.0000001\n"
+    );
+
+    let (_, status) = run_native("float_to_int", "Float big = 1e30; Int x = int(big);");
+    assert_eq!(status, Some(1));
+}
+
+#[test]
+fn test_runtime_errors_exit_with_failure() {
+    let (stdout, status) = run_native("bounds", "println(1); Array<Int> a = [1]; Int x = a[3];");
+    assert_eq!(stdout, "1\n");
+    assert_eq!(status, Some(1));
+
+    let (_, status) = run_native("division", "Int zero = 0; Int x = 1 / zero;");
+    assert_eq!(status, Some(1));
+}
+
+#[test]
+fn test_integer_overflow_exits_with_failure() {
+    let min = "Int one = 1; Int min = -9223372036854775807 - one;";
+    let cases = [
+        ("add", "Int x = 9223372036854775807; x = x + one;".to_string()),
+        ("neg", format!("{} Int x = -min;", min)),
+        ("div", format!("{} Int x = min / -one;", min)),
+        ("pow", "Int x = 2 ** 63;".to_string()),
+    ];
+    for (name, code) in cases {
+        let (_, status) = run_native(name, &code);
+        assert_eq!(status, Some(1), "{}", code);
+    }
+
+    let (stdout, status) = run_native("rem", &format!("{} println(min % -one);", min));
+    assert_eq!((stdout.as_str(), status), ("0\n", Some(0)));
+}
+
+// ============================================================================
+// JIT
+// ============================================================================
+
+#[test]
+fn test_jit_functions_example_output() {
+    let output = llvm_backend::jit::run_captured(&parse(&example("test_functions.remy")), "functions")
+        .expect("program should run");
+    assert_eq!(output, "5 + 3 = 8\n4 * 7 = 28\n2 * 3 + 4 = 10\n13\nHello, RemyLang\n");
+}
+
+#[test]
+fn test_jit_matches_native_executable() {
+    let code = r#"
+        func Fib(Int n) -> Int {
+            if (n < 2) {
+                return n;
+            }
+            return Fib(n - 1) + Fib(n - 2);
+        }
+        Array<Array<Int>> grid = [[1, 2], [3]];
+        for row in grid {
+            print(row, "");
+        }
+        println(Fib(20), 'é', "a" + "b", grid == [[1, 2], [3]], 3 ** 4, 0.1 + 0.2, 2 ** 0.5);
+    "#;
+    let jit = llvm_backend::jit::run_captured(&parse(code), "compare").expect("program should run");
+    let (native, status) = run_native("compare", code);
+    assert_eq!(status, Some(0));
+    assert_eq!(jit, native);
+}
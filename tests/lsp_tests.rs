@@ -0,0 +1,361 @@
+/*
+ * -------------------------------------------------------------------------
+ * RemyLang — LSP Tests
+ * File : tests/lsp_tests.rs
+ *
+ * Description :
+ *   Integration tests driving `remylang lsp` with a scripted JSON-RPC
+ *   client: diagnostics, hover, definition, completion, symbols and
+ *   formatting.
+ *
+ * Author  : Samuel 'Meeast' Bleau
+ * Created : 2026-10-16
+ *
+ * -------------------------------------------------------------------------
+*/
+
+use remylang::stdlib;
+use serde_json::{json, Value};
+use std::collections::VecDeque;
+use std::io::{BufRead, BufReader, Read, Write};
+use std::process::{Child, ChildStdin, ChildStdout, Command, Stdio};
+
+const URI: &str = "file:///test.remy";
+
+// Helper client speaking JSON-RPC to a running server
+struct Client {
+    child: Child,
+    stdin: ChildStdin,
+    stdout: BufReader<ChildStdout>,
+    next_id: u64,
+    notifications: VecDeque<Value>,
+}
+
+impl Client {
+    // Starts the server and goes through the initialization handshake
+    fn start() -> Client {
+        let mut child = Command::new(env!("CARGO_BIN_EXE_remylang"))
+            .arg("lsp")
+            .stdin(Stdio::piped())
+            .stdout(Stdio::piped())
+            .stderr(Stdio::piped())
+            .spawn()
+            .expect("remylang lsp should start");
+        let stdin = child.stdin.take().expect("stdin is piped");
+        let stdout = BufReader::new(child.stdout.take().expect("stdout is piped"));
+        let mut client = Client { child, stdin, stdout, next_id: 0, notifications: VecDeque::new() };
+
+        let result = client.request("initialize", json!({ "capabilities": {} }));
+        assert!(result["capabilities"]["hoverProvider"].as_bool().unwrap());
+        client.notify("initialized", json!({}));
+        client
+    }
+
+    // Starts the server with one open document
+    fn with_document(text: &str) -> Client {
+        let mut client = Client::start();
+        client.open(text);
+        client
+    }
+
+    fn send(&mut self, message: Value) {
+        let body = message.to_string();
+        write!(self.stdin, "Content-Length: {}\r\n\r\n{}", body.len(), body).unwrap();
+        self.stdin.flush().unwrap();
+    }
+
+    fn receive(&mut self) -> Value {
+        let mut length = None;
+        loop {
+            let mut line = String::new();
+            self.stdout.read_line(&mut line).expect("header should be readable");
+            let line = line.trim_end();
+            if line.is_empty() {
+                break;
+            }
+            if let Some(value) = line.strip_prefix("Content-Length: ") {
+                length = Some(value.parse::<usize>().unwrap());
+            }
+        }
+        let mut body = vec![0; length.expect("message should have a length")];
+        self.stdout.read_exact(&mut body).unwrap();
+        serde_json::from_slice(&body).unwrap()
+    }
+
+    // Sends a request and returns its result, keeping the notifications received meanwhile
+    fn request(&mut self, method: &str, params: Value) -> Value {
+        self.next_id += 1;
+        let id = self.next_id;
+        self.send(json!({ "jsonrpc": "2.0", "id": id, "method": method, "params": params }));
+        loop {
+            let message = self.receive();
+            if message["id"] == json!(id) {
+                assert!(message.get("error").is_none(), "{} failed: {}", method, message);
+                return message["result"].clone();
+            }
+            self.notifications.push_back(message);
+        }
+    }
+
+    fn notify(&mut self, method: &str, params: Value) {
+        self.send(json!({ "jsonrpc": "2.0", "method": method, "params": params }));
+    }
+
+    fn open(&mut self, text: &str) {
+        let document = json!({ "uri": URI, "languageId": "remylang", "version": 1, "text": text });
+        self.notify("textDocument/didOpen", json!({ "textDocument": document }));
+    }
+
+    fn change(&mut self, version: i32, text: &str) {
+        self.notify(
+            "textDocument/didChange",
+            json!({
+                "textDocument": { "uri": URI, "version": version },
+                "contentChanges": [{ "text": text }]
+            }),
+        );
+    }
+
+    // Next diagnostics published by the server
+    fn diagnostics(&mut self) -> Vec<Value> {
+        loop {
+            let message = self.notifications.pop_front().unwrap_or_else(|| self.receive());
+            if message["method"] == "textDocument/publishDiagnostics" {
+                assert_eq!(message["params"]["uri"], URI);
+                return message["params"]["diagnostics"].as_array().unwrap().clone();
+            }
+        }
+    }
+
+    // Request about a position in the open document
+    fn at(&mut self, method: &str, line: u32, character: u32) -> Value {
+        let params = json!({
+            "textDocument": { "uri": URI },
+            "position": { "line": line, "character": character }
+        });
+        self.request(method, params)
+    }
+
+    fn shutdown(mut self) -> Option<i32> {
+        assert_eq!(self.request("shutdown", Value::Null), Value::Null);
+        self.notify("exit", Value::Null);
+        self.child.wait().unwrap().code()
+    }
+}
+
+// Labels of a completion result, leaving out the standard library
+fn labels(completion: &Value) -> Vec<&str> {
+    completion
+        .as_array()
+        .unwrap()
+        .iter()
+        .map(|item| item["label"].as_str().unwrap())
+        .filter(|label| stdlib::lookup(label).is_none())
+        .collect()
+}
+
+// ============================================================================
+// LIFECYCLE
+// ============================================================================
+
+#[test]
+fn test_initialize_and_shutdown() {
+    let client = Client::start();
+    assert_eq!(client.shutdown(), Some(0));
+}
+
+#[test]
+fn test_unknown_request_is_an_error() {
+    let mut client = Client::start();
+    client.send(json!({ "jsonrpc": "2.0", "id": 99, "method": "workspace/symbol", "params": { "query": "" } }));
+    let response = client.receive();
+    assert_eq!(response["id"], 99);
+    assert_eq!(response["error"]["code"], -32601);
+    assert_eq!(client.shutdown(), Some(0));
+}
+
+#[test]
+fn test_invalid_params_keep_the_server_running() {
+    let mut client = Client::start();
+    client.send(json!({ "jsonrpc": "2.0", "id": 98, "method": "textDocument/hover", "params": { "textDocument": 3 } }));
+    let response = client.receive();
+    assert_eq!(response["id"], 98);
+    assert_eq!(response["error"]["code"], -32602);
+
+    // An invalid notification is dropped, the valid one after it is handled
+    client.notify("textDocument/didOpen", json!({ "textDocument": { "uri": URI } }));
+    client.open("Int x = 1;");
+    assert!(client.diagnostics().is_empty());
+    assert_eq!(client.at("textDocument/hover", 0, 4)["contents"]["value"], "```remylang\nInt x\n```");
+    assert_eq!(client.shutdown(), Some(0));
+}
+
+// ============================================================================
+// DIAGNOSTICS
+// ============================================================================
+
+#[test]
+fn test_diagnostics_follow_changes() {
+    let mut client = Client::with_document("Int x = True;");
+    let diagnostics = client.diagnostics();
+    assert_eq!(diagnostics.len(), 1);
+    assert_eq!(diagnostics[0]["message"], "Expected Int, found Bool");
+    assert_eq!(diagnostics[0]["source"], "remylang");
+    assert_eq!(diagnostics[0]["code"], "type");
+    assert_eq!(diagnostics[0]["range"], json!({ "start": { "line": 0, "character": 8 }, "end": { "line": 0, "character": 12 } }));
+
+    client.change(2, "Int x = 1;\nInt y = ;");
+    let diagnostics = client.diagnostics();
+    assert_eq!(diagnostics.len(), 1);
+    assert_eq!(diagnostics[0]["code"], "parse");
+    assert_eq!(diagnostics[0]["range"]["start"]["line"], 1);
+
+    client.change(3, "Int x = 1;");
+    assert!(client.diagnostics().is_empty());
+    assert_eq!(client.shutdown(), Some(0));
+}
+
+#[test]
+fn test_diagnostics_include_help() {
+    let mut client = Client::with_document("println(y);");
+    let diagnostics = client.diagnostics();
+    assert!(diagnostics[0]["message"].as_str().unwrap().contains("\nhelp: declare it before using it"));
+}
+
+#[test]
+fn test_closing_clears_diagnostics() {
+    let mut client = Client::with_document("Int x = True;");
+    assert_eq!(client.diagnostics().len(), 1);
+    client.notify("textDocument/didClose", json!({ "textDocument": { "uri": URI } }));
+    assert!(client.diagnostics().is_empty());
+    assert!(client.at("textDocument/hover", 0, 4).is_null());
+}
+
+// ============================================================================
+// HOVER AND DEFINITION
+// ============================================================================
+
+const PROGRAM: &str = "func Double(Int n) -> Int {\n    return n * 2;\n}\n\nString name = \"é\";\nInt x = Double(3);\nx += 1;\n";
+
+#[test]
+fn test_hover_shows_types() {
+    let mut client = Client::with_document(PROGRAM);
+    let hover = client.at("textDocument/hover", 1, 11);
+    assert_eq!(hover["contents"]["value"], "```remylang\nInt n\n```");
+    assert_eq!(hover["range"], json!({ "start": { "line": 1, "character": 11 }, "end": { "line": 1, "character": 12 } }));
+
+    let hover = client.at("textDocument/hover", 5, 9);
+    assert_eq!(hover["contents"]["value"], "```remylang\nFunc<(Int), Int> Double\n```");
+    // Declarations and assigned variables have a type too
+    assert_eq!(client.at("textDocument/hover", 4, 8)["contents"]["value"], "```remylang\nString name\n```");
+    assert_eq!(client.at("textDocument/hover", 6, 0)["contents"]["value"], "```remylang\nInt x\n```");
+    assert!(client.at("textDocument/hover", 3, 0).is_null());
+}
+
+#[test]
+fn test_definition() {
+    let mut client = Client::with_document(PROGRAM);
+    let definition = client.at("textDocument/definition", 5, 10);
+    assert_eq!(definition["uri"], URI);
+    assert_eq!(definition["range"], json!({ "start": { "line": 0, "character": 5 }, "end": { "line": 0, "character": 11 } }));
+
+    let definition = client.at("textDocument/definition", 1, 11);
+    assert_eq!(definition["range"]["start"], json!({ "line": 0, "character": 16 }));
+    let definition = client.at("textDocument/definition", 6, 1);
+    assert_eq!(definition["range"]["start"], json!({ "line": 5, "character": 4 }));
+}
+
+#[test]
+fn test_definition_of_shadowed_variable() {
+    let mut client = Client::with_document("Int x = 1;\nif (True) {\n    String x = \"a\";\n    println(x);\n}\nprintln(x);\n");
+    assert_eq!(client.at("textDocument/definition", 3, 12)["range"]["start"], json!({ "line": 2, "character": 11 }));
+    assert_eq!(client.at("textDocument/definition", 5, 8)["range"]["start"], json!({ "line": 0, "character": 4 }));
+}
+
+#[test]
+fn test_positions_count_utf16() {
+    let mut client = Client::with_document("String s = \"😀\"; Int y = 1;\nprintln(y);");
+    let definition = client.at("textDocument/definition", 1, 8);
+    // The emoji takes two UTF-16 code units
+    assert_eq!(definition["range"]["start"], json!({ "line": 0, "character": 21 }));
+    assert_eq!(client.at("textDocument/hover", 0, 21)["contents"]["value"], "```remylang\nInt y\n```");
+}
+
+// ============================================================================
+// COMPLETION
+// ============================================================================
+
+#[test]
+fn test_completion_lists_visible_names() {
+    let code = "Int a = 1;\nfunc F(Int p) {\n    Int b = 2;\n    \n}\nInt c = 3;\n";
+    let mut client = Client::with_document(code);
+
+    let completion = client.at("textDocument/completion", 3, 4);
+    assert_eq!(labels(&completion), ["F", "a", "b", "p"]);
+    let item = completion.as_array().unwrap().iter().find(|item| item["label"] == "F").unwrap();
+    assert_eq!(item["kind"], 3);
+    assert_eq!(item["detail"], "Func<(Int)>");
+
+    let completion = client.at("textDocument/completion", 6, 0);
+    assert_eq!(labels(&completion), ["F", "a", "c"]);
+}
+
+#[test]
+fn test_completion_while_typing() {
+    let mut client = Client::with_document("Int total = 1;\nInt y = tot");
+    assert_eq!(client.diagnostics().len(), 1);
+    let completion = client.at("textDocument/completion", 1, 11);
+    assert!(labels(&completion).contains(&"total"));
+}
+
+#[test]
+fn test_builtins_are_completed_unless_shadowed() {
+    let mut client = Client::with_document("Int print = 1;\n");
+    let completion = client.at("textDocument/completion", 1, 0);
+    let items = completion.as_array().unwrap();
+    let print: Vec<&Value> = items.iter().filter(|item| item["label"] == "print").collect();
+    assert_eq!(print.len(), 1);
+    assert_eq!(print[0]["detail"], "Int");
+    let len = items.iter().find(|item| item["label"] == "len").unwrap();
+    assert_eq!(len["kind"], 3);
+    assert_eq!(len["detail"], "(String or Array) -> Int");
+}
+
+// ============================================================================
+// SYMBOLS AND FORMATTING
+// ============================================================================
+
+#[test]
+fn test_document_symbols() {
+    let code = "struct P { Int x; }\nimpl P {\n    func Get() -> Int {\n        return self.x;\n    }\n}\nfunc Main() {}\n";
+    let mut client = Client::with_document(code);
+    let symbols = client.request("textDocument/documentSymbol", json!({ "textDocument": { "uri": URI } }));
+    let symbols = symbols.as_array().unwrap();
+    assert_eq!(symbols.len(), 2);
+    assert_eq!(symbols[0]["name"], "P.Get");
+    assert_eq!(symbols[0]["kind"], 6);
+    assert_eq!(symbols[0]["detail"], "Func<(), Int>");
+    assert_eq!(symbols[0]["selectionRange"]["start"], json!({ "line": 2, "character": 9 }));
+    assert_eq!(symbols[1]["name"], "Main");
+    assert_eq!(symbols[1]["kind"], 12);
+    assert_eq!(symbols[1]["range"]["start"], json!({ "line": 6, "character": 0 }));
+}
+
+#[test]
+fn test_formatting() {
+    let mut client = Client::with_document("Int x=1;\nprintln( x );");
+    let params = json!({ "textDocument": { "uri": URI }, "options": { "tabSize": 4, "insertSpaces": true } });
+    let edits = client.request("textDocument/formatting", params.clone());
+    assert_eq!(
+        edits,
+        json!([{
+            "range": { "start": { "line": 0, "character": 0 }, "end": { "line": 1, "character": 13 } },
+            "newText": "Int x = 1;\nprintln(x);\n"
+        }])
+    );
+
+    client.change(2, "Int x = 1;\n");
+    assert_eq!(client.request("textDocument/formatting", params.clone()), json!([]));
+    client.change(3, "Int x = ;");
+    assert!(client.request("textDocument/formatting", params).is_null());
+}
@@ -0,0 +1,560 @@
+/*
+ * -------------------------------------------------------------------------
+ * RemyLang — Machine Tests
+ * File : tests/machine_tests.rs
+ *
+ * Description :
+ *   Integration tests for the bytecode compiler and stack machine,
+ *   including parity checks against the tree-walking interpreter.
+ *
+ * Author  : Samuel 'Meeast' Bleau
+ * Created : 2026-10-16
+ *
+ * -------------------------------------------------------------------------
+*/
+
+use remylang::ast::Stmt;
+use remylang::lexer::Lexer;
+use remylang::parser::Parser;
+use remylang::vm::{Compiler, Interpreter, Machine, RuntimeError, RuntimeResult, Value};
+
+fn parse(code: &str) -> Vec<Stmt> {
+    let tokens = Lexer::new(code.to_string()).tokenize();
+    Parser::new(tokens).parse().expect("code should parse")
+}
+
+// Helper compiling and running code, returning the machine for inspection
+fn run(code: &str) -> (Machine, RuntimeResult<()>) {
+    let mut machine = Machine::new();
+    let result = Compiler::new()
+        .compile(&parse(code))
+        .and_then(|program| machine.run(&program));
+    (machine, result)
+}
+
+// Helper returning the value of a global variable after running code
+fn global(code: &str, name: &str) -> Value {
+    let (machine, result) = run(code);
+    result.expect("code should run");
+    machine.global(name).expect("variable should exist")
+}
+
+// Helper returning the error raised by code, without its location
+fn runtime_error(code: &str) -> RuntimeError {
+    let (_, result) = run(code);
+    result.expect_err("code should fail").kind().clone()
+}
+
+// Helper running code on both backends and comparing every global
+fn assert_same_as_interpreter(code: &str) {
+    let ast = parse(code);
+    let program = Compiler::new().compile(&ast).expect("code should compile");
+    let mut machine = Machine::new();
+    let machine_result = machine.run(&program);
+
+    let mut interpreter = Interpreter::new();
+    let interpreter_result = interpreter.execute(ast);
+    assert_eq!(machine_result, interpreter_result);
+
+    // Functions have a different representation in each backend
+    for name in &program.globals {
+        let expected = interpreter.environment().get(name).ok().map(|v| v.to_string());
+        let found = machine.global(name).map(|v| v.to_string());
+        assert_eq!(found, expected, "global '{}' differs", name);
+    }
+}
+
+// ============================================================================
+// PARITY
+// ============================================================================
+
+#[test]
+fn test_examples_match_interpreter() {
+    for example in ["test_functions", "test_loops", "test_returns"] {
+        let path = format!("{}/examples/{}.remy", env!("CARGO_MANIFEST_DIR"), example);
+        let code = std::fs::read_to_string(&path).expect("example should exist");
+        assert_same_as_interpreter(&code);
+    }
+}
+
+#[test]
+fn test_call_depth_matches_interpreter() {
+    let code = r#"
+        func Sum(Int n) -> Int {
+            if (n == 0) {
+                return 0;
+            }
+            return n + Sum(n - 1);
+        }
+        Int s = Sum(5000);
+    "#;
+    assert_same_as_interpreter(code);
+    assert_same_as_interpreter(&code.replace("5000", "10001"));
+}
+
+#[test]
+fn test_hoisted_functions_match_interpreter() {
+    let code = r#"
+        func Outer() -> Int {
+            Int base = 1;
+            func Inner() -> Int {
+                return Helper() + base;
+            }
+            Int r = Inner();
+            func Helper() -> Int {
+                return 2;
+            }
+            return r;
+        }
+        Int r = Outer();
+    "#;
+    assert_same_as_interpreter(code);
+    assert_eq!(global(code, "r"), Value::Number(3));
+
+    let code = "Int a = abs(-1); func abs(Int x) -> Int { return x * 10; }";
+    assert_same_as_interpreter(code);
+    assert_eq!(global(code, "a"), Value::Number(-10));
+}
+
+#[test]
+fn test_programs_match_interpreter() {
+    assert_same_as_interpreter(r#"
+        func Fib(Int n) -> Int {
+            if (n < 2) {
+                return n;
+            }
+            return Fib(n - 1) + Fib(n - 2);
+        }
+        Int fib = Fib(15);
+        Array<Int> values = [fib, -fib, 2 ** 10, 17 % 5];
+        String text = "Remy" + "Lang";
+        Bool flag = !(fib > 100) || values[2] == 1024;
+    "#);
+    assert_same_as_interpreter(r#"
+        Int total = 0;
+        for (Int i = 0; i < 5; i = i + 1) {
+            Int j = 0;
+            while (True) {
+                j = j + 1;
+                if (j > i) {
+                    break;
+                }
+                if (j % 2 == 0) {
+                    continue;
+                }
+                total = total + j;
+            }
+        }
+    "#);
+    assert_same_as_interpreter("Array<Int> a = [1, 2]; Int x = a[5];");
+    assert_same_as_interpreter(r#"
+        Int calls = 0;
+        func Check(Bool b) -> Bool {
+            calls = calls + 1;
+            return b;
+        }
+        Array<Int> a = [1, 2];
+        Int i = 5;
+        Bool guard = i < 2 && a[i] > 0;
+        Bool chain = Check(False) && Check(True) || Check(True) || Check(False);
+        Bool mixed = 1 || True;
+    "#);
+}
+
+#[test]
+fn test_assignments_match_interpreter() {
+    assert_same_as_interpreter(r#"
+        Int x = 10;
+        x += 5;
+        x %= 4;
+        String s = "a";
+        s += "b";
+        Array<Array<Int>> grid = [[1, 2], [3, 4]];
+        Array<Array<Int>> copy = grid;
+        grid[1][0] = 30;
+        grid[0][1] *= 5;
+        Int counter = 0;
+        func Next() -> Int {
+            counter += 1;
+            return counter;
+        }
+        Array<Int> a = [0, 0, 0];
+        a[Next()] = Next() * 10;
+        for (Int i = 0; i < 3; i += 1) {
+            a[i] -= i;
+        }
+    "#);
+    assert_same_as_interpreter("Array<Int> a = [1]; a[0] = 2; a[1] += 1;");
+}
+
+#[test]
+fn test_floats_match_interpreter() {
+    assert_same_as_interpreter(r#"
+        Float pi = 3.14159;
+        Float area = pi * 2 ** 2;
+        Float ratio = 10 / 4.0;
+        Int whole = int(-area);
+        Array<Float> values = [1.5, -ratio, 1e-3];
+        Float total = 0.0;
+        for (v in values) {
+            total += v;
+        }
+        Bool close = total < 0 && total > -1;
+        Bool same = 1.0 == 1 && ratio != 2;
+        String label = match (ratio) { 2.5 => "exact", _ => "other" };
+    "#);
+    assert_same_as_interpreter("Int x = int(1.0 / 0);");
+}
+
+#[test]
+fn test_interpolation_matches_interpreter() {
+    assert_same_as_interpreter(r#"
+        struct Point { Int x; Int y; }
+        enum Shape { Circle(Float), Empty }
+        Point p = Point { x: 1, y: -2 };
+        Array<Shape> shapes = [Shape::Circle(1.5), Shape::Empty];
+        String s = "";
+        for (i in [0, 1]) {
+            s = s + "${i}: ${shapes[i]} at ${p} ${"(${p.x + p.y})"}\n";
+        }
+        String t = "${[1, 2] == [1, 2]}, ${1 / 3.0}";
+    "#);
+    assert_same_as_interpreter(r#"Array<Int> a = [1]; String s = "${a[2]}";"#);
+}
+
+#[test]
+fn test_structs_match_interpreter() {
+    assert_same_as_interpreter(r#"
+        func Origin() -> Point {
+            return Point { y: 0, x: 0 };
+        }
+        struct Point { Int x; Int y; }
+        struct Line { Point a; Point b; }
+        impl Point {
+            func Add(Point other) -> Point {
+                return Point { x: self.x + other.x, y: self.y + other.y };
+            }
+            func Moved(Int dx) -> Point {
+                self.x += dx;
+                return self;
+            }
+        }
+        Point p = Point { x: 3, y: 4 };
+        Line l = Line { a: p, b: Origin() };
+        l.b.y = l.a.Add(p).x;
+        Array<Line> lines = [l, l];
+        lines[1].a.x *= 10;
+        Point moved = p.Moved(5);
+        Bool same = lines[0] == l;
+    "#);
+    assert_same_as_interpreter("struct P { Int x; } impl P { func F() -> Int { return self.y; } } Int y = P { x: 1 }.F();");
+}
+
+#[test]
+fn test_enums_match_interpreter() {
+    assert_same_as_interpreter(r#"
+        enum Shape { Circle(Int), Rect(Int, Int), Empty }
+        enum Tree { Leaf(Int), Node(Shape, Bool) }
+        impl Shape {
+            func Area() -> Int {
+                return match (self) {
+                    Shape::Circle(r) => 3 * r * r,
+                    Shape::Rect(w, h) => w * h,
+                    Shape::Empty => 0,
+                };
+            }
+        }
+        func Weight(Tree t, Int scale) -> Int {
+            Int base = 100;
+            // The operands already pushed stay below the match's locals
+            return base + scale * match (t) {
+                Tree::Leaf(n) => n,
+                Tree::Node(Shape::Rect(w, _), True) => w + match (w > 2) { True => 1, False => 2 },
+                Tree::Node(s, flag) => s.Area(),
+            };
+        }
+        Array<Tree> trees = [Tree::Leaf(4), Tree::Node(Shape::Rect(3, 9), True), Tree::Node(Shape::Circle(1), False)];
+        Array<Int> weights = [];
+        Int squares = 0;
+        for t in trees {
+            Int w = Weight(t, 2);
+            weights = weights + [w];
+            match (t) {
+                Tree::Leaf(n) => {
+                    func Double() -> Int {
+                        return n * 2;
+                    }
+                    squares += Double();
+                }
+                Tree::Node(Shape::Empty, _) => { break; }
+                _ => { continue; }
+            }
+        }
+        String last = match (trees[2]) { Tree::Node(s, b) => "" + match (b) { True => "yes", False => "no" }, _ => "leaf" };
+    "#);
+    assert_same_as_interpreter("enum E { A, B } Int n = match (E::B) { E::A => 1 };");
+}
+
+// ============================================================================
+// VARIABLES AND SCOPES
+// ============================================================================
+
+#[test]
+fn test_block_locals_shadow_globals() {
+    let code = r#"
+        Int x = 1;
+        Int seen = 0;
+        {
+            Int x = 2;
+            seen = x;
+        }
+        Int after = x;
+    "#;
+    assert_eq!(global(code, "seen"), Value::Number(2));
+    assert_eq!(global(code, "after"), Value::Number(1));
+}
+
+#[test]
+fn test_for_in_with_locals_and_break() {
+    let code = r#"
+        Int sum = 0;
+        for item in [1, 2, 3, 4, 5] {
+            Int doubled = item * 2;
+            if (item == 2) {
+                continue;
+            }
+            if (item == 4) {
+                break;
+            }
+            sum = sum + doubled;
+        }
+    "#;
+    assert_eq!(global(code, "sum"), Value::Number(8));
+}
+
+#[test]
+fn test_globals_persist_between_runs() {
+    let mut compiler = Compiler::new();
+    let mut machine = Machine::new();
+    let first = compiler.compile(&parse("Int x = 40;")).unwrap();
+    machine.run(&first).unwrap();
+    let second = compiler.compile(&parse("Int y = x + 2;")).unwrap();
+    machine.run(&second).unwrap();
+    assert_eq!(machine.global("y"), Some(Value::Number(42)));
+}
+
+// ============================================================================
+// CLOSURES
+// ============================================================================
+
+#[test]
+fn test_nested_function_captures_outer_locals() {
+    let code = r#"
+        Int result = 0;
+        func Outer(Int base) {
+            Int count = base;
+            func Increment() {
+                count = count + 1;
+            }
+            Increment();
+            Increment();
+            result = count;
+        }
+        Outer(40);
+    "#;
+    assert_eq!(global(code, "result"), Value::Number(42));
+}
+
+#[test]
+fn test_returned_function_keeps_captured_state() {
+    let code = r#"
+        func MakeCounter() -> Func<(), Int> {
+            Int count = 0;
+            func Next() -> Int {
+                count = count + 1;
+                return count;
+            }
+            return Next;
+        }
+        Func<(), Int> counter = MakeCounter();
+        counter();
+        counter();
+        Int third = counter();
+    "#;
+    assert_eq!(global(code, "third"), Value::Number(3));
+}
+
+#[test]
+fn test_each_iteration_captures_a_fresh_variable() {
+    let code = r#"
+        Func<(), Int> first = func() -> Int { return 0; };
+        Func<(), Int> last = first;
+        for item in [1, 2, 3] {
+            func Get() -> Int {
+                return item;
+            }
+            if (item == 1) {
+                first = Get;
+            }
+            last = Get;
+        }
+        Int a = first();
+        Int b = last();
+    "#;
+    assert_eq!(global(code, "a"), Value::Number(1));
+    assert_eq!(global(code, "b"), Value::Number(3));
+}
+
+#[test]
+fn test_element_assignment_to_captured_array() {
+    let code = r#"
+        Int result = 0;
+        func Outer() {
+            Array<Int> values = [1, 2];
+            func Set(Int i, Int v) {
+                values[i] += v;
+            }
+            Set(1, 40);
+            result = values[1];
+        }
+        Outer();
+        Array<Int> globals = [1];
+        func SetGlobal() {
+            globals[0] = 5;
+        }
+        SetGlobal();
+    "#;
+    assert_eq!(global(code, "result"), Value::Number(42));
+    assert_eq!(global(code, "globals").to_string(), "[5]");
+}
+
+#[test]
+fn test_lambdas_match_interpreter() {
+    assert_same_as_interpreter(r#"
+        func Compose(Func<(Int), Int> f, Func<(Int), Int> g) -> Func<(Int), Int> {
+            return func(Int x) -> Int { return g(f(x)); };
+        }
+        Int total = 0;
+        Func<(Int)> add = func(Int x) { total += x; };
+        Array<Func<(Int), Int>> steps = [func(Int x) -> Int { return x + 1; }, func(Int x) -> Int { return x * 3; }];
+        Int r = Compose(steps[0], steps[1])(4);
+        add(r);
+        add(steps[1](2));
+        Int n = func(Int a, Int b) -> Int { return a - b; }(10, 3);
+    "#);
+    assert_same_as_interpreter("Int n = 1; Int m = n(2);");
+}
+
+#[test]
+fn test_local_function_recursion() {
+    let code = r#"
+        func Outer() -> Int {
+            func Fact(Int n) -> Int {
+                if (n <= 1) {
+                    return 1;
+                }
+                return n * Fact(n - 1);
+            }
+            return Fact(6);
+        }
+        Int r = Outer();
+    "#;
+    assert_eq!(global(code, "r"), Value::Number(720));
+}
+
+#[test]
+fn test_function_cannot_see_caller_locals() {
+    let code = r#"
+        func ReadX() -> Int {
+            return x;
+        }
+        func Caller() -> Int {
+            Int x = 5;
+            return ReadX();
+        }
+        Caller();
+    "#;
+    assert_eq!(
+        runtime_error(code),
+        RuntimeError::UndefinedVariable { name: "x".to_string() }
+    );
+}
+
+// ============================================================================
+// ERRORS
+// ============================================================================
+
+#[test]
+fn test_runtime_errors() {
+    assert_eq!(runtime_error("Int x = 1 / 0;"), RuntimeError::DivisionByZero);
+    assert_eq!(
+        runtime_error("Int x = 9223372036854775807; x = x + 1;"),
+        RuntimeError::IntegerOverflow { operation: "addition".to_string() }
+    );
+    assert_eq!(
+        runtime_error("y = 1;"),
+        RuntimeError::AssignmentToUndefined { name: "y".to_string() }
+    );
+    assert_eq!(
+        runtime_error("for x in 5 { }"),
+        RuntimeError::NotIterable { value_type: "Int".to_string() }
+    );
+    assert_eq!(
+        runtime_error("func F(Int a) { } F();"),
+        RuntimeError::ArgumentCountMismatch {
+            expected: 1,
+            found: 0,
+            function_name: "F".to_string(),
+        }
+    );
+    assert_eq!(
+        runtime_error("Int x = 1; x();"),
+        RuntimeError::NotCallable { value_type: "Int".to_string() }
+    );
+}
+
+#[test]
+fn test_control_flow_errors_at_compile_time() {
+    assert_eq!(runtime_error("return 1;"), RuntimeError::ReturnOutsideFunction);
+    assert_eq!(runtime_error("break;"), RuntimeError::BreakOutsideLoop);
+    assert_eq!(
+        runtime_error("while (True) { func F() { continue; } }"),
+        RuntimeError::ContinueOutsideLoop
+    );
+}
+
+#[test]
+fn test_unbounded_recursion_overflows() {
+    let code = r#"
+        func Loop(Int n) -> Int {
+            return Loop(n + 1);
+        }
+        Loop(0);
+    "#;
+    assert_eq!(
+        runtime_error(code),
+        RuntimeError::StackOverflow { depth: 10_000, function: "Loop".to_string() }
+    );
+}
+
+#[test]
+fn test_error_has_trace() {
+    let code = "func Divide(Int a, Int b) -> Int {\n    return a / b;\n}\nfunc Add(Int a, Int b) -> Int {\n    return a + Divide(a, b - b);\n}\nInt r = Add(Add(1, 2), 3);";
+    let (_, result) = run(code);
+    let error = result.expect_err("code should fail");
+    let trace = error.trace().expect("error should have a trace");
+    let calls: Vec<String> = trace.iter().map(|frame| frame.describe()).collect();
+    assert_eq!(calls, vec!["Add(1, 2)", "Divide(1, 0)"]);
+    assert_eq!((trace[0].call_site.line, trace[0].call_site.column), (7, 13));
+    assert_eq!((trace[1].call_site.line, trace[1].call_site.column), (5, 16));
+    assert_same_as_interpreter(code);
+}
+
+#[test]
+fn test_error_has_location_inside_function() {
+    let code = "func F() -> Int {\n    return 1 / 0;\n}\nF();";
+    let (_, result) = run(code);
+    let error = result.expect_err("code should fail");
+    let span = error.span().expect("error should have a location");
+    assert_eq!((span.line, span.column), (2, 12));
+}
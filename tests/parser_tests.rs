@@ -42,21 +42,60 @@ fn test_literal_number() {
     let ast = parse(code).unwrap();
     assert_eq!(ast.len(), 1);
     
-    match &ast[0] {
-        Stmt::Expression(Expr::Literal(Literal::Number(n))) => {
+    match &ast[0].kind {
+        StmtKind::Expression(Expr { kind: ExprKind::Literal(Literal::Number(n)), .. }) => {
             assert_eq!(*n, 42);
         }
         _ => panic!("Expected number literal expression"),
     }
 }
 
+#[test]
+fn test_literal_float() {
+    let code = "2.5;";
+    let ast = parse(code).unwrap();
+
+    match &ast[0].kind {
+        StmtKind::Expression(Expr { kind: ExprKind::Literal(Literal::Float(x)), .. }) => {
+            assert_eq!(*x, 2.5);
+        }
+        _ => panic!("Expected float literal expression"),
+    }
+}
+
+#[test]
+fn test_interpolated_string() {
+    let code = r#""Hello ${name}, next year ${age + 1}";"#;
+    let ast = parse(code).unwrap();
+
+    match &ast[0].kind {
+        StmtKind::Expression(Expr { kind: ExprKind::Interpolated(parts), .. }) => {
+            assert_eq!(parts.len(), 4);
+            assert_eq!(parts[0], InterpolatedPart::Text("Hello ".to_string()));
+            match &parts[1] {
+                InterpolatedPart::Expr(expr) => {
+                    assert_eq!(expr.kind, ExprKind::Variable("name".to_string()));
+                    assert_eq!((expr.span.start, expr.span.end), (9, 13));
+                }
+                _ => panic!("Expected interpolated expression"),
+            }
+            assert_eq!(parts[2], InterpolatedPart::Text(", next year ".to_string()));
+            assert!(matches!(
+                &parts[3],
+                InterpolatedPart::Expr(Expr { kind: ExprKind::Binary { op: BinaryOp::Add, .. }, .. })
+            ));
+        }
+        _ => panic!("Expected interpolated string"),
+    }
+}
+
 #[test]
 fn test_literal_string() {
     let code = r#""Hello, World!";"#;
     let ast = parse(code).unwrap();
     
-    match &ast[0] {
-        Stmt::Expression(Expr::Literal(Literal::String(s))) => {
+    match &ast[0].kind {
+        StmtKind::Expression(Expr { kind: ExprKind::Literal(Literal::String(s)), .. }) => {
             assert_eq!(s, "Hello, World!");
         }
         _ => panic!("Expected string literal"),
@@ -68,8 +107,8 @@ fn test_literal_char() {
     let code = "'a';";
     let ast = parse(code).unwrap();
     
-    match &ast[0] {
-        Stmt::Expression(Expr::Literal(Literal::Char(c))) => {
+    match &ast[0].kind {
+        StmtKind::Expression(Expr { kind: ExprKind::Literal(Literal::Char(c)), .. }) => {
             assert_eq!(*c, 'a');
         }
         _ => panic!("Expected char literal"),
@@ -81,9 +120,9 @@ fn test_literal_bool_true() {
     let code = "True;";
     let ast = parse(code).unwrap();
     
-    match &ast[0] {
-        Stmt::Expression(Expr::Literal(Literal::Bool(b))) => {
-            assert_eq!(*b, true);
+    match &ast[0].kind {
+        StmtKind::Expression(Expr { kind: ExprKind::Literal(Literal::Bool(b)), .. }) => {
+            assert!(*b);
         }
         _ => panic!("Expected bool literal"),
     }
@@ -94,9 +133,9 @@ fn test_literal_bool_false() {
     let code = "False;";
     let ast = parse(code).unwrap();
     
-    match &ast[0] {
-        Stmt::Expression(Expr::Literal(Literal::Bool(b))) => {
-            assert_eq!(*b, false);
+    match &ast[0].kind {
+        StmtKind::Expression(Expr { kind: ExprKind::Literal(Literal::Bool(b)), .. }) => {
+            assert!(!*b);
         }
         _ => panic!("Expected bool literal"),
     }
@@ -107,8 +146,8 @@ fn test_variable() {
     let code = "myVar;";
     let ast = parse(code).unwrap();
     
-    match &ast[0] {
-        Stmt::Expression(Expr::Variable(name)) => {
+    match &ast[0].kind {
+        StmtKind::Expression(Expr { kind: ExprKind::Variable(name), .. }) => {
             assert_eq!(name, "myVar");
         }
         _ => panic!("Expected variable"),
@@ -124,11 +163,11 @@ fn test_addition() {
     let code = "1 + 2;";
     let ast = parse(code).unwrap();
     
-    match &ast[0] {
-        Stmt::Expression(Expr::Binary { left, op, right }) => {
+    match &ast[0].kind {
+        StmtKind::Expression(Expr { kind: ExprKind::Binary { left, op, right }, .. }) => {
             assert_eq!(*op, BinaryOp::Add);
-            assert!(matches!(**left, Expr::Literal(Literal::Number(1))));
-            assert!(matches!(**right, Expr::Literal(Literal::Number(2))));
+            assert!(matches!(left.kind, ExprKind::Literal(Literal::Number(1))));
+            assert!(matches!(right.kind, ExprKind::Literal(Literal::Number(2))));
         }
         _ => panic!("Expected binary operation"),
     }
@@ -139,17 +178,17 @@ fn test_precedence_multiplication_over_addition() {
     let code = "1 + 2 * 3;";
     let ast = parse(code).unwrap();
     
-    match &ast[0] {
-        Stmt::Expression(Expr::Binary { left, op, right }) => {
+    match &ast[0].kind {
+        StmtKind::Expression(Expr { kind: ExprKind::Binary { left, op, right }, .. }) => {
             assert_eq!(*op, BinaryOp::Add);
-            assert!(matches!(**left, Expr::Literal(Literal::Number(1))));
+            assert!(matches!(left.kind, ExprKind::Literal(Literal::Number(1))));
             
             // Right side should be 2 * 3
-            match &**right {
-                Expr::Binary { left: l2, op: op2, right: r2 } => {
+            match &right.kind {
+                ExprKind::Binary { left: l2, op: op2, right: r2 } => {
                     assert_eq!(*op2, BinaryOp::Mul);
-                    assert!(matches!(**l2, Expr::Literal(Literal::Number(2))));
-                    assert!(matches!(**r2, Expr::Literal(Literal::Number(3))));
+                    assert!(matches!(l2.kind, ExprKind::Literal(Literal::Number(2))));
+                    assert!(matches!(r2.kind, ExprKind::Literal(Literal::Number(3))));
                 }
                 _ => panic!("Expected multiplication on right"),
             }
@@ -164,17 +203,17 @@ fn test_power_right_associativity() {
     let ast = parse(code).unwrap();
     
     // Should parse as 2 ** (3 ** 2) = 2 ** 9 = 512
-    match &ast[0] {
-        Stmt::Expression(Expr::Binary { left, op, right }) => {
+    match &ast[0].kind {
+        StmtKind::Expression(Expr { kind: ExprKind::Binary { left, op, right }, .. }) => {
             assert_eq!(*op, BinaryOp::Pow);
-            assert!(matches!(**left, Expr::Literal(Literal::Number(2))));
+            assert!(matches!(left.kind, ExprKind::Literal(Literal::Number(2))));
             
             // Right side should be 3 ** 2
-            match &**right {
-                Expr::Binary { left: l2, op: op2, right: r2 } => {
+            match &right.kind {
+                ExprKind::Binary { left: l2, op: op2, right: r2 } => {
                     assert_eq!(*op2, BinaryOp::Pow);
-                    assert!(matches!(**l2, Expr::Literal(Literal::Number(3))));
-                    assert!(matches!(**r2, Expr::Literal(Literal::Number(2))));
+                    assert!(matches!(l2.kind, ExprKind::Literal(Literal::Number(3))));
+                    assert!(matches!(r2.kind, ExprKind::Literal(Literal::Number(2))));
                 }
                 _ => panic!("Expected power on right"),
             }
@@ -188,11 +227,11 @@ fn test_comparison() {
     let code = "x > 10;";
     let ast = parse(code).unwrap();
     
-    match &ast[0] {
-        Stmt::Expression(Expr::Binary { left, op, right }) => {
+    match &ast[0].kind {
+        StmtKind::Expression(Expr { kind: ExprKind::Binary { left, op, right }, .. }) => {
             assert_eq!(*op, BinaryOp::Greater);
-            assert!(matches!(**left, Expr::Variable(_)));
-            assert!(matches!(**right, Expr::Literal(Literal::Number(10))));
+            assert!(matches!(left.kind, ExprKind::Variable(_)));
+            assert!(matches!(right.kind, ExprKind::Literal(Literal::Number(10))));
         }
         _ => panic!("Expected comparison"),
     }
@@ -203,11 +242,11 @@ fn test_logical_and() {
     let code = "True && False;";
     let ast = parse(code).unwrap();
     
-    match &ast[0] {
-        Stmt::Expression(Expr::Binary { left, op, right }) => {
+    match &ast[0].kind {
+        StmtKind::Expression(Expr { kind: ExprKind::Binary { left, op, right }, .. }) => {
             assert_eq!(*op, BinaryOp::And);
-            assert!(matches!(**left, Expr::Literal(Literal::Bool(true))));
-            assert!(matches!(**right, Expr::Literal(Literal::Bool(false))));
+            assert!(matches!(left.kind, ExprKind::Literal(Literal::Bool(true))));
+            assert!(matches!(right.kind, ExprKind::Literal(Literal::Bool(false))));
         }
         _ => panic!("Expected logical AND"),
     }
@@ -218,8 +257,8 @@ fn test_logical_or() {
     let code = "True || False;";
     let ast = parse(code).unwrap();
     
-    match &ast[0] {
-        Stmt::Expression(Expr::Binary { op, .. }) => {
+    match &ast[0].kind {
+        StmtKind::Expression(Expr { kind: ExprKind::Binary { op, .. }, .. }) => {
             assert_eq!(*op, BinaryOp::Or);
         }
         _ => panic!("Expected logical OR"),
@@ -235,24 +274,41 @@ fn test_unary_minus() {
     let code = "-42;";
     let ast = parse(code).unwrap();
     
-    match &ast[0] {
-        Stmt::Expression(Expr::Unary { op, right }) => {
+    match &ast[0].kind {
+        StmtKind::Expression(Expr { kind: ExprKind::Unary { op, right }, .. }) => {
             assert_eq!(*op, UnaryOp::Minus);
-            assert!(matches!(**right, Expr::Literal(Literal::Number(42))));
+            assert!(matches!(right.kind, ExprKind::Literal(Literal::Number(42))));
         }
         _ => panic!("Expected unary minus"),
     }
 }
 
+#[test]
+fn test_smallest_integer_literal() {
+    let ast = parse("-9223372036854775808;").unwrap();
+    assert!(matches!(
+        ast[0].kind,
+        StmtKind::Expression(Expr { kind: ExprKind::Literal(Literal::Number(i64::MIN)), .. })
+    ));
+    let ast = parse("match (x) { -9223372036854775808 => 0, _ => 1, }").unwrap();
+    let StmtKind::Match { arms, .. } = &ast[0].kind else { panic!("Expected match statement") };
+    assert_eq!(arms[0].pattern, Pattern::Literal(Literal::Number(i64::MIN)));
+
+    // Its magnitude is only valid right after a minus sign
+    for code in ["9223372036854775808;", "1 - 9223372036854775808;", "-9223372036854775808[0];", "-9223372036854775809;"] {
+        assert!(matches!(parse(code), Err(remylang::parser::ParseError::Lex { .. })), "{}", code);
+    }
+}
+
 #[test]
 fn test_unary_not() {
     let code = "!True;";
     let ast = parse(code).unwrap();
     
-    match &ast[0] {
-        Stmt::Expression(Expr::Unary { op, right }) => {
+    match &ast[0].kind {
+        StmtKind::Expression(Expr { kind: ExprKind::Unary { op, right }, .. }) => {
             assert_eq!(*op, UnaryOp::Not);
-            assert!(matches!(**right, Expr::Literal(Literal::Bool(true))));
+            assert!(matches!(right.kind, ExprKind::Literal(Literal::Bool(true))));
         }
         _ => panic!("Expected unary not"),
     }
@@ -267,21 +323,21 @@ fn test_parentheses() {
     let code = "(1 + 2) * 3;";
     let ast = parse(code).unwrap();
     
-    match &ast[0] {
-        Stmt::Expression(Expr::Binary { left, op, right }) => {
+    match &ast[0].kind {
+        StmtKind::Expression(Expr { kind: ExprKind::Binary { left, op, right }, .. }) => {
             assert_eq!(*op, BinaryOp::Mul);
             
             // Left side should be (1 + 2)
-            match &**left {
-                Expr::Binary { left: l2, op: op2, right: r2 } => {
+            match &left.kind {
+                ExprKind::Binary { left: l2, op: op2, right: r2 } => {
                     assert_eq!(*op2, BinaryOp::Add);
-                    assert!(matches!(**l2, Expr::Literal(Literal::Number(1))));
-                    assert!(matches!(**r2, Expr::Literal(Literal::Number(2))));
+                    assert!(matches!(l2.kind, ExprKind::Literal(Literal::Number(1))));
+                    assert!(matches!(r2.kind, ExprKind::Literal(Literal::Number(2))));
                 }
                 _ => panic!("Expected addition in parentheses"),
             }
             
-            assert!(matches!(**right, Expr::Literal(Literal::Number(3))));
+            assert!(matches!(right.kind, ExprKind::Literal(Literal::Number(3))));
         }
         _ => panic!("Expected binary operation"),
     }
@@ -296,9 +352,9 @@ fn test_function_call_no_args() {
     let code = "foo();";
     let ast = parse(code).unwrap();
     
-    match &ast[0] {
-        Stmt::Expression(Expr::Call { callee, args }) => {
-            assert!(matches!(**callee, Expr::Variable(_)));
+    match &ast[0].kind {
+        StmtKind::Expression(Expr { kind: ExprKind::Call { callee, args }, .. }) => {
+            assert!(matches!(callee.kind, ExprKind::Variable(_)));
             assert_eq!(args.len(), 0);
         }
         _ => panic!("Expected function call"),
@@ -310,12 +366,12 @@ fn test_function_call_with_args() {
     let code = "add(1, 2);";
     let ast = parse(code).unwrap();
     
-    match &ast[0] {
-        Stmt::Expression(Expr::Call { callee, args }) => {
-            assert!(matches!(**callee, Expr::Variable(_)));
+    match &ast[0].kind {
+        StmtKind::Expression(Expr { kind: ExprKind::Call { callee, args }, .. }) => {
+            assert!(matches!(callee.kind, ExprKind::Variable(_)));
             assert_eq!(args.len(), 2);
-            assert!(matches!(args[0], Expr::Literal(Literal::Number(1))));
-            assert!(matches!(args[1], Expr::Literal(Literal::Number(2))));
+            assert!(matches!(args[0].kind, ExprKind::Literal(Literal::Number(1))));
+            assert!(matches!(args[1].kind, ExprKind::Literal(Literal::Number(2))));
         }
         _ => panic!("Expected function call"),
     }
@@ -326,16 +382,16 @@ fn test_nested_function_call() {
     let code = "outer(inner(5));";
     let ast = parse(code).unwrap();
     
-    match &ast[0] {
-        Stmt::Expression(Expr::Call { callee, args }) => {
-            assert!(matches!(**callee, Expr::Variable(_)));
+    match &ast[0].kind {
+        StmtKind::Expression(Expr { kind: ExprKind::Call { callee, args }, .. }) => {
+            assert!(matches!(callee.kind, ExprKind::Variable(_)));
             assert_eq!(args.len(), 1);
             
             // First arg should be inner(5)
-            match &args[0] {
-                Expr::Call { args: inner_args, .. } => {
+            match &args[0].kind {
+                ExprKind::Call { args: inner_args, .. } => {
                     assert_eq!(inner_args.len(), 1);
-                    assert!(matches!(inner_args[0], Expr::Literal(Literal::Number(5))));
+                    assert!(matches!(inner_args[0].kind, ExprKind::Literal(Literal::Number(5))));
                 }
                 _ => panic!("Expected nested call"),
             }
@@ -353,8 +409,8 @@ fn test_array_literal_empty() {
     let code = "[];";
     let ast = parse(code).unwrap();
     
-    match &ast[0] {
-        Stmt::Expression(Expr::ArrayLiteral(elements)) => {
+    match &ast[0].kind {
+        StmtKind::Expression(Expr { kind: ExprKind::ArrayLiteral(elements), .. }) => {
             assert_eq!(elements.len(), 0);
         }
         _ => panic!("Expected array literal"),
@@ -366,12 +422,12 @@ fn test_array_literal_with_elements() {
     let code = "[1, 2, 3];";
     let ast = parse(code).unwrap();
     
-    match &ast[0] {
-        Stmt::Expression(Expr::ArrayLiteral(elements)) => {
+    match &ast[0].kind {
+        StmtKind::Expression(Expr { kind: ExprKind::ArrayLiteral(elements), .. }) => {
             assert_eq!(elements.len(), 3);
-            assert!(matches!(elements[0], Expr::Literal(Literal::Number(1))));
-            assert!(matches!(elements[1], Expr::Literal(Literal::Number(2))));
-            assert!(matches!(elements[2], Expr::Literal(Literal::Number(3))));
+            assert!(matches!(elements[0].kind, ExprKind::Literal(Literal::Number(1))));
+            assert!(matches!(elements[1].kind, ExprKind::Literal(Literal::Number(2))));
+            assert!(matches!(elements[2].kind, ExprKind::Literal(Literal::Number(3))));
         }
         _ => panic!("Expected array literal"),
     }
@@ -382,10 +438,10 @@ fn test_array_access() {
     let code = "arr[0];";
     let ast = parse(code).unwrap();
     
-    match &ast[0] {
-        Stmt::Expression(Expr::ArrayAccess { array, index }) => {
-            assert!(matches!(**array, Expr::Variable(_)));
-            assert!(matches!(**index, Expr::Literal(Literal::Number(0))));
+    match &ast[0].kind {
+        StmtKind::Expression(Expr { kind: ExprKind::ArrayAccess { array, index }, .. }) => {
+            assert!(matches!(array.kind, ExprKind::Variable(_)));
+            assert!(matches!(index.kind, ExprKind::Literal(Literal::Number(0))));
         }
         _ => panic!("Expected array access"),
     }
@@ -396,17 +452,17 @@ fn test_nested_array_access() {
     let code = "matrix[i][j];";
     let ast = parse(code).unwrap();
     
-    match &ast[0] {
-        Stmt::Expression(Expr::ArrayAccess { array, index }) => {
+    match &ast[0].kind {
+        StmtKind::Expression(Expr { kind: ExprKind::ArrayAccess { array, index }, .. }) => {
             // array should be matrix[i]
-            match &**array {
-                Expr::ArrayAccess { array: inner_array, index: inner_index } => {
-                    assert!(matches!(**inner_array, Expr::Variable(_)));
-                    assert!(matches!(**inner_index, Expr::Variable(_)));
+            match &array.kind {
+                ExprKind::ArrayAccess { array: inner_array, index: inner_index } => {
+                    assert!(matches!(inner_array.kind, ExprKind::Variable(_)));
+                    assert!(matches!(inner_index.kind, ExprKind::Variable(_)));
                 }
                 _ => panic!("Expected nested array access"),
             }
-            assert!(matches!(**index, Expr::Variable(_)));
+            assert!(matches!(index.kind, ExprKind::Variable(_)));
         }
         _ => panic!("Expected array access"),
     }
@@ -421,11 +477,25 @@ fn test_let_statement() {
     let code = "Int x = 42;";
     let ast = parse(code).unwrap();
     
-    match &ast[0] {
-        Stmt::Let { name, type_annotation, value } => {
+    match &ast[0].kind {
+        StmtKind::Let { name, type_annotation, value } => {
             assert_eq!(name, "x");
             assert_eq!(*type_annotation, Some(Type::Int));
-            assert!(matches!(*value, Expr::Literal(Literal::Number(42))));
+            assert!(matches!(value.kind, ExprKind::Literal(Literal::Number(42))));
+        }
+        _ => panic!("Expected let statement"),
+    }
+}
+
+#[test]
+fn test_float_let_statement() {
+    let code = "Float ratio = 1e-3;";
+    let ast = parse(code).unwrap();
+
+    match &ast[0].kind {
+        StmtKind::Let { type_annotation, value, .. } => {
+            assert_eq!(*type_annotation, Some(Type::Float));
+            assert!(matches!(value.kind, ExprKind::Literal(Literal::Float(x)) if x == 0.001));
         }
         _ => panic!("Expected let statement"),
     }
@@ -436,25 +506,88 @@ fn test_assignment_statement() {
     let code = "x = 100;";
     let ast = parse(code).unwrap();
     
-    match &ast[0] {
-        Stmt::Assignment { name, value } => {
-            assert_eq!(name, "x");
-            assert!(matches!(*value, Expr::Literal(Literal::Number(100))));
+    match &ast[0].kind {
+        StmtKind::Assignment { target, op, value } => {
+            assert_eq!(target, &AssignTarget::Variable("x".to_string()));
+            assert_eq!(op, &None);
+            assert!(matches!(value.kind, ExprKind::Literal(Literal::Number(100))));
+        }
+        _ => panic!("Expected assignment statement"),
+    }
+}
+
+#[test]
+fn test_compound_assignment_statements() {
+    let operators = [
+        ("+=", BinaryOp::Add),
+        ("-=", BinaryOp::Sub),
+        ("*=", BinaryOp::Mul),
+        ("/=", BinaryOp::Div),
+        ("%=", BinaryOp::Mod),
+    ];
+    for (token, expected) in operators {
+        let ast = parse(&format!("x {} 2;", token)).unwrap();
+        match &ast[0].kind {
+            StmtKind::Assignment { target, op, .. } => {
+                assert_eq!(target.name(), "x");
+                assert_eq!(op, &Some(expected), "{}", token);
+            }
+            _ => panic!("Expected assignment statement for {}", token),
+        }
+    }
+}
+
+#[test]
+fn test_indexed_assignment_statement() {
+    let ast = parse("grid[i][j + 1] += 5;").unwrap();
+
+    match &ast[0].kind {
+        StmtKind::Assignment { target, op, value } => {
+            assert_eq!(target.name(), "grid");
+            let path = target.path();
+            assert_eq!(path.len(), 2);
+            assert!(matches!(path[0], Accessor::Index(Expr { kind: ExprKind::Variable(name), .. }) if name == "i"));
+            assert!(matches!(path[1], Accessor::Index(Expr { kind: ExprKind::Binary { op: BinaryOp::Add, .. }, .. })));
+            assert_eq!(op, &Some(BinaryOp::Add));
+            assert!(matches!(value.kind, ExprKind::Literal(Literal::Number(5))));
         }
         _ => panic!("Expected assignment statement"),
     }
 }
 
+#[test]
+fn test_invalid_assignment_target() {
+    for code in ["5 = 3;", "f(x) = 3;", "a + b += 1;"] {
+        let error = parse(code).expect_err(code);
+        assert_eq!(error.message(), "Invalid assignment target", "{}", code);
+    }
+}
+
+#[test]
+fn test_for_increment_compound_assignment() {
+    let ast = parse("for (Int i = 0; i < 10; i += 2) { a[i] = i; }").unwrap();
+
+    match &ast[0].kind {
+        StmtKind::For { increment, .. } => {
+            assert!(matches!(
+                increment.as_ref().unwrap().kind,
+                StmtKind::Assignment { op: Some(BinaryOp::Add), .. }
+            ));
+        }
+        _ => panic!("Expected for statement"),
+    }
+}
+
 #[test]
 fn test_block_statement() {
     let code = "{ Int x = 1; Int y = 2; }";
     let ast = parse(code).unwrap();
     
-    match &ast[0] {
-        Stmt::Block(statements) => {
+    match &ast[0].kind {
+        StmtKind::Block(statements) => {
             assert_eq!(statements.len(), 2);
-            assert!(matches!(statements[0], Stmt::Let { .. }));
-            assert!(matches!(statements[1], Stmt::Let { .. }));
+            assert!(matches!(statements[0].kind, StmtKind::Let { .. }));
+            assert!(matches!(statements[1].kind, StmtKind::Let { .. }));
         }
         _ => panic!("Expected block statement"),
     }
@@ -465,9 +598,9 @@ fn test_return_statement_with_value() {
     let code = "return 42;";
     let ast = parse(code).unwrap();
     
-    match &ast[0] {
-        Stmt::Return(Some(expr)) => {
-            assert!(matches!(*expr, Expr::Literal(Literal::Number(42))));
+    match &ast[0].kind {
+        StmtKind::Return(Some(expr)) => {
+            assert!(matches!(expr.kind, ExprKind::Literal(Literal::Number(42))));
         }
         _ => panic!("Expected return statement with value"),
     }
@@ -478,8 +611,8 @@ fn test_return_statement_without_value() {
     let code = "return;";
     let ast = parse(code).unwrap();
     
-    match &ast[0] {
-        Stmt::Return(None) => {
+    match &ast[0].kind {
+        StmtKind::Return(None) => {
             // Success
         }
         _ => panic!("Expected return statement without value"),
@@ -495,10 +628,10 @@ fn test_if_statement_without_else() {
     let code = "if (x > 0) { return x; }";
     let ast = parse(code).unwrap();
     
-    match &ast[0] {
-        Stmt::If { condition, then_branch, else_branch } => {
-            assert!(matches!(*condition, Expr::Binary { .. }));
-            assert!(matches!(**then_branch, Stmt::Block(_)));
+    match &ast[0].kind {
+        StmtKind::If { condition, then_branch, else_branch } => {
+            assert!(matches!(condition.kind, ExprKind::Binary { .. }));
+            assert!(matches!(then_branch.kind, StmtKind::Block(_)));
             assert!(else_branch.is_none());
         }
         _ => panic!("Expected if statement"),
@@ -510,14 +643,14 @@ fn test_if_statement_with_else() {
     let code = "if (x > 0) { return x; } else { return 0; }";
     let ast = parse(code).unwrap();
     
-    match &ast[0] {
-        Stmt::If { condition, then_branch, else_branch } => {
-            assert!(matches!(*condition, Expr::Binary { .. }));
-            assert!(matches!(**then_branch, Stmt::Block(_)));
+    match &ast[0].kind {
+        StmtKind::If { condition, then_branch, else_branch } => {
+            assert!(matches!(condition.kind, ExprKind::Binary { .. }));
+            assert!(matches!(then_branch.kind, StmtKind::Block(_)));
             assert!(else_branch.is_some());
             
             if let Some(else_b) = else_branch {
-                assert!(matches!(**else_b, Stmt::Block(_)));
+                assert!(matches!(else_b.kind, StmtKind::Block(_)));
             }
         }
         _ => panic!("Expected if statement with else"),
@@ -529,14 +662,14 @@ fn test_if_else_if_chain() {
     let code = "if (x > 10) { return 1; } else if (x > 5) { return 2; } else { return 3; }";
     let ast = parse(code).unwrap();
     
-    match &ast[0] {
-        Stmt::If { condition, then_branch, else_branch } => {
-            assert!(matches!(*condition, Expr::Binary { .. }));
-            assert!(matches!(**then_branch, Stmt::Block(_)));
+    match &ast[0].kind {
+        StmtKind::If { condition, then_branch, else_branch } => {
+            assert!(matches!(condition.kind, ExprKind::Binary { .. }));
+            assert!(matches!(then_branch.kind, StmtKind::Block(_)));
             
             // else_branch should contain another if statement
             if let Some(else_b) = else_branch {
-                assert!(matches!(**else_b, Stmt::If { .. }));
+                assert!(matches!(else_b.kind, StmtKind::If { .. }));
             } else {
                 panic!("Expected else if");
             }
@@ -554,12 +687,12 @@ fn test_function_no_params_no_return() {
     let code = "func Test() { return; }";
     let ast = parse(code).unwrap();
     
-    match &ast[0] {
-        Stmt::FunctionDecl { name, params, return_type, body } => {
+    match &ast[0].kind {
+        StmtKind::FunctionDecl { name, params, return_type, body } => {
             assert_eq!(name, "Test");
             assert_eq!(params.len(), 0);
             assert!(return_type.is_none());
-            assert!(matches!(**body, Stmt::Block(_)));
+            assert!(matches!(body.kind, StmtKind::Block(_)));
         }
         _ => panic!("Expected function declaration"),
     }
@@ -570,8 +703,8 @@ fn test_function_with_params_and_return() {
     let code = "func Add(Int a, Int b) -> Int { return a + b; }";
     let ast = parse(code).unwrap();
     
-    match &ast[0] {
-        Stmt::FunctionDecl { name, params, return_type, body } => {
+    match &ast[0].kind {
+        StmtKind::FunctionDecl { name, params, return_type, body } => {
             assert_eq!(name, "Add");
             assert_eq!(params.len(), 2);
             assert_eq!(params[0].name, "a");
@@ -579,7 +712,7 @@ fn test_function_with_params_and_return() {
             assert_eq!(params[1].name, "b");
             assert_eq!(params[1].type_annotation, Type::Int);
             assert_eq!(*return_type, Some(Type::Int));
-            assert!(matches!(**body, Stmt::Block(_)));
+            assert!(matches!(body.kind, StmtKind::Block(_)));
         }
         _ => panic!("Expected function declaration"),
     }
@@ -590,8 +723,8 @@ fn test_function_with_array_type() {
     let code = "func GetArray() -> Array<Int> { return []; }";
     let ast = parse(code).unwrap();
     
-    match &ast[0] {
-        Stmt::FunctionDecl { name, params, return_type, body } => {
+    match &ast[0].kind {
+        StmtKind::FunctionDecl { name, params, return_type, body } => {
             assert_eq!(name, "GetArray");
             assert_eq!(params.len(), 0);
             
@@ -602,12 +735,64 @@ fn test_function_with_array_type() {
                 _ => panic!("Expected Array<Int> return type"),
             }
             
-            assert!(matches!(**body, Stmt::Block(_)));
+            assert!(matches!(body.kind, StmtKind::Block(_)));
         }
         _ => panic!("Expected function declaration"),
     }
 }
 
+#[test]
+fn test_function_type_annotation() {
+    let code = "Func<(Int, Array<Int>), Bool> f = g;";
+    let ast = parse(code).unwrap();
+
+    match &ast[0].kind {
+        StmtKind::Let { type_annotation, .. } => {
+            assert_eq!(
+                *type_annotation,
+                Some(Type::Function {
+                    params: vec![Type::Int, Type::Array(Box::new(Type::Int))],
+                    return_type: Box::new(Type::Bool),
+                })
+            );
+        }
+        _ => panic!("Expected let statement"),
+    }
+}
+
+#[test]
+fn test_lambda_expression() {
+    let code = "Apply(func(Int x) -> Int { return x + 1; }, 2);";
+    let ast = parse(code).unwrap();
+
+    match &ast[0].kind {
+        StmtKind::Expression(Expr { kind: ExprKind::Call { args, .. }, .. }) => match &args[0].kind {
+            ExprKind::Lambda { params, return_type, body } => {
+                assert_eq!(params.len(), 1);
+                assert_eq!(params[0].name, "x");
+                assert_eq!(*return_type, Some(Type::Int));
+                assert!(matches!(body.kind, StmtKind::Block(_)));
+            }
+            _ => panic!("Expected lambda"),
+        },
+        _ => panic!("Expected function call"),
+    }
+}
+
+#[test]
+fn test_called_lambda_statement() {
+    let code = "func() { println(1); }();";
+    let ast = parse(code).unwrap();
+
+    match &ast[0].kind {
+        StmtKind::Expression(Expr { kind: ExprKind::Call { callee, args }, .. }) => {
+            assert!(matches!(callee.kind, ExprKind::Lambda { .. }));
+            assert!(args.is_empty());
+        }
+        _ => panic!("Expected a call of a lambda"),
+    }
+}
+
 // ============================================================================
 // COMPLEX TESTS
 // ============================================================================
@@ -619,7 +804,7 @@ fn test_complex_expression() {
     
     // Just verify it parses without error
     assert_eq!(ast.len(), 1);
-    assert!(matches!(ast[0], Stmt::Expression(Expr::Binary { .. })));
+    assert!(matches!(ast[0].kind, StmtKind::Expression(Expr { kind: ExprKind::Binary { .. }, .. })));
 }
 
 #[test]
@@ -632,9 +817,9 @@ fn test_multiple_statements() {
     let ast = parse(code).unwrap();
     
     assert_eq!(ast.len(), 3);
-    assert!(matches!(ast[0], Stmt::Let { .. }));
-    assert!(matches!(ast[1], Stmt::Let { .. }));
-    assert!(matches!(ast[2], Stmt::Let { .. }));
+    assert!(matches!(ast[0].kind, StmtKind::Let { .. }));
+    assert!(matches!(ast[1].kind, StmtKind::Let { .. }));
+    assert!(matches!(ast[2].kind, StmtKind::Let { .. }));
 }
 
 #[test]
@@ -656,8 +841,8 @@ fn test_full_program() {
     
     let ast = parse(code).unwrap();
     assert_eq!(ast.len(), 2);
-    assert!(matches!(ast[0], Stmt::FunctionDecl { .. }));
-    assert!(matches!(ast[1], Stmt::FunctionDecl { .. }));
+    assert!(matches!(ast[0].kind, StmtKind::FunctionDecl { .. }));
+    assert!(matches!(ast[1].kind, StmtKind::FunctionDecl { .. }));
 }
 
 // ============================================================================
@@ -691,3 +876,430 @@ fn test_error_missing_function_body() {
     let result = parse(code);
     assert!(result.is_err());
 }
+
+#[test]
+fn test_error_integer_literal_too_large() {
+    let err = parse("Int x = 99999999999999999999;").unwrap_err();
+    match err {
+        remylang::parser::ParseError::Lex { error, span } => {
+            assert_eq!(error, remylang::lexer::LexError::IntegerTooLarge("99999999999999999999".to_string()));
+            assert_eq!(span.column, 9);
+        }
+        other => panic!("Expected lexer error, got {:?}", other),
+    }
+}
+
+#[test]
+fn test_error_invalid_interpolation() {
+    let err = parse(r#"String s = "${}";"#).unwrap_err();
+    assert_eq!(err.span().column, 15);
+    assert!(err.to_string().ends_with("Expected expression, found RightBrace"), "{}", err);
+
+    let err = parse(r#"String s = "${a b}";"#).unwrap_err();
+    assert_eq!(err.span().column, 17);
+    assert!(err.to_string().contains("'}' after interpolated expression"), "{}", err);
+}
+
+// ============================================================================
+// SPAN TESTS
+// ============================================================================
+
+#[test]
+fn test_statement_and_expression_spans() {
+    let code = "Int x = 1;\nprint(x + 2);";
+    let ast = parse(code).unwrap();
+
+    assert_eq!((ast[0].span.start, ast[0].span.end), (0, 10));
+    assert_eq!((ast[1].span.line, ast[1].span.column), (2, 1));
+
+    match &ast[1].kind {
+        StmtKind::Expression(Expr { kind: ExprKind::Call { args, .. }, span }) => {
+            assert_eq!((span.start, span.end), (11, 23));
+            assert_eq!((args[0].span.start, args[0].span.end), (17, 22));
+            assert_eq!(args[0].span.column, 7);
+        }
+        _ => panic!("Expected function call"),
+    }
+}
+
+#[test]
+fn test_error_reports_location() {
+    let code = "Int x = 42;\nInt y = 1\n}";
+    let err = parse(code).unwrap_err();
+
+    assert_eq!((err.span().line, err.span().column), (3, 1));
+    assert!(err.to_string().starts_with("<input>:3:1: Parse error"));
+}
+
+// ============================================================================
+// LOOP TESTS
+// ============================================================================
+
+#[test]
+fn test_while_statement() {
+    let code = "while (x < 10) { x = x + 1; }";
+    let ast = parse(code).unwrap();
+
+    match &ast[0].kind {
+        StmtKind::While { condition, body } => {
+            assert!(matches!(condition.kind, ExprKind::Binary { .. }));
+            assert!(matches!(body.kind, StmtKind::Block(_)));
+        }
+        _ => panic!("Expected while statement"),
+    }
+}
+
+#[test]
+fn test_for_statement() {
+    let code = "for (Int i = 0; i < 10; i = i + 1) { print(i); }";
+    let ast = parse(code).unwrap();
+
+    match &ast[0].kind {
+        StmtKind::For { initializer, condition, increment, body } => {
+            assert!(matches!(initializer.as_ref().unwrap().kind, StmtKind::Let { .. }));
+            assert!(condition.is_some());
+            assert!(matches!(increment.as_ref().unwrap().kind, StmtKind::Assignment { .. }));
+            assert!(matches!(body.kind, StmtKind::Block(_)));
+        }
+        _ => panic!("Expected for statement"),
+    }
+}
+
+#[test]
+fn test_for_statement_empty_clauses() {
+    let code = "for (;;) { break; }";
+    let ast = parse(code).unwrap();
+
+    match &ast[0].kind {
+        StmtKind::For { initializer, condition, increment, .. } => {
+            assert!(initializer.is_none());
+            assert!(condition.is_none());
+            assert!(increment.is_none());
+        }
+        _ => panic!("Expected for statement"),
+    }
+}
+
+#[test]
+fn test_for_in_statement() {
+    for code in ["for item in list { print(item); }", "for (item in list) { print(item); }"] {
+        let ast = parse(code).unwrap();
+        match &ast[0].kind {
+            StmtKind::ForIn { variable, iterable, .. } => {
+                assert_eq!(variable, "item");
+                assert!(matches!(iterable.kind, ExprKind::Variable(_)));
+            }
+            _ => panic!("Expected for-in statement"),
+        }
+    }
+}
+
+#[test]
+fn test_break_and_continue_statements() {
+    let ast = parse("break; continue;").unwrap();
+    assert!(matches!(ast[0].kind, StmtKind::Break));
+    assert!(matches!(ast[1].kind, StmtKind::Continue));
+}
+
+// ============================================================================
+// STRUCT TESTS
+// ============================================================================
+
+#[test]
+fn test_struct_declaration() {
+    let ast = parse("struct Point { Int x; Array<Int> tags; }").unwrap();
+
+    match &ast[0].kind {
+        StmtKind::StructDecl { name, fields } => {
+            assert_eq!(name, "Point");
+            assert_eq!(fields.len(), 2);
+            assert_eq!(fields[0].name, "x");
+            assert_eq!(fields[1].type_annotation, Type::Array(Box::new(Type::Int)));
+        }
+        _ => panic!("Expected struct declaration"),
+    }
+}
+
+#[test]
+fn test_impl_block() {
+    let ast = parse("impl Point { func Len() -> Int { return self.x; } func Reset() { } }").unwrap();
+
+    match &ast[0].kind {
+        StmtKind::Impl { name, methods } => {
+            assert_eq!(name, "Point");
+            assert_eq!(methods.len(), 2);
+            assert!(matches!(&methods[0].kind, StmtKind::FunctionDecl { name, .. } if name == "Len"));
+        }
+        _ => panic!("Expected impl block"),
+    }
+    assert!(parse("impl Point { Int x = 1; }").is_err());
+}
+
+#[test]
+fn test_struct_literal_and_field_access() {
+    let ast = parse("Point p = Point { x: 1, y: f(2), };\nInt z = p.x + line.a.y;").unwrap();
+
+    match &ast[0].kind {
+        StmtKind::Let { type_annotation, value, .. } => {
+            assert_eq!(type_annotation, &Some(Type::Named("Point".to_string())));
+            match &value.kind {
+                ExprKind::StructLiteral { name, fields } => {
+                    assert_eq!(name, "Point");
+                    assert_eq!(fields[0].0, "x");
+                    assert!(matches!(fields[1].1.kind, ExprKind::Call { .. }));
+                }
+                _ => panic!("Expected struct literal"),
+            }
+        }
+        _ => panic!("Expected let statement"),
+    }
+    match &ast[1].kind {
+        StmtKind::Let { value, .. } => match &value.kind {
+            ExprKind::Binary { right, .. } => match &right.kind {
+                ExprKind::FieldAccess { object, field } => {
+                    assert_eq!(field, "y");
+                    assert!(matches!(&object.kind, ExprKind::FieldAccess { field, .. } if field == "a"));
+                }
+                _ => panic!("Expected field access"),
+            },
+            _ => panic!("Expected binary expression"),
+        },
+        _ => panic!("Expected let statement"),
+    }
+}
+
+#[test]
+fn test_method_call() {
+    let ast = parse("p.Add(q).Len();").unwrap();
+
+    match &ast[0].kind {
+        StmtKind::Expression(Expr { kind: ExprKind::Call { callee, args }, .. }) => {
+            assert!(args.is_empty());
+            assert!(matches!(&callee.kind, ExprKind::FieldAccess { field, .. } if field == "Len"));
+        }
+        _ => panic!("Expected method call"),
+    }
+}
+
+#[test]
+fn test_field_assignment_statement() {
+    let ast = parse("lines[0].a.x -= 1;").unwrap();
+
+    match &ast[0].kind {
+        StmtKind::Assignment { target, op, .. } => {
+            assert_eq!(target.name(), "lines");
+            let path = target.path();
+            assert_eq!(path.len(), 3);
+            assert!(matches!(path[0], Accessor::Index(_)));
+            assert_eq!(path[1], Accessor::Field("a"));
+            assert_eq!(path[2], Accessor::Field("x"));
+            assert_eq!(op, &Some(BinaryOp::Sub));
+        }
+        _ => panic!("Expected assignment statement"),
+    }
+}
+
+#[test]
+fn test_brace_after_for_in_iterable_starts_body() {
+    let ast = parse("for p in points { print(p); }").unwrap();
+    assert!(matches!(&ast[0].kind, StmtKind::ForIn { iterable, .. } if matches!(iterable.kind, ExprKind::Variable(_))));
+
+    let ast = parse("for (p in [Point { x: 1 }]) { }").unwrap();
+    assert!(matches!(&ast[0].kind, StmtKind::ForIn { iterable, .. } if matches!(iterable.kind, ExprKind::ArrayLiteral(_))));
+}
+
+#[test]
+fn test_structs_only_at_top_level() {
+    let error = parse("func F() { struct Point { Int x; } }").unwrap_err();
+    assert_eq!(error.message(), "Structs, enums and impl blocks can only be declared at the top level");
+    assert!(parse("{ enum E { A } }").is_err());
+}
+
+// ============================================================================
+// ENUM AND MATCH TESTS
+// ============================================================================
+
+#[test]
+fn test_enum_declaration() {
+    let ast = parse("enum Shape { Circle(Int), Rect(Int, Array<Int>), Empty, }").unwrap();
+
+    match &ast[0].kind {
+        StmtKind::EnumDecl { name, variants } => {
+            assert_eq!(name, "Shape");
+            assert_eq!(variants.len(), 3);
+            assert_eq!(variants[0].fields, vec![Type::Int]);
+            assert_eq!(variants[1].fields[1], Type::Array(Box::new(Type::Int)));
+            assert_eq!(variants[2].name, "Empty");
+            assert!(variants[2].fields.is_empty());
+        }
+        _ => panic!("Expected enum declaration"),
+    }
+}
+
+#[test]
+fn test_variant_construction() {
+    let ast = parse("Shape s = Shape::Rect(1, w + 1);\nShape e = Shape::Empty;").unwrap();
+
+    match &ast[0].kind {
+        StmtKind::Let { value, .. } => match &value.kind {
+            ExprKind::Variant { enum_name, variant, args } => {
+                assert_eq!(enum_name, "Shape");
+                assert_eq!(variant, "Rect");
+                assert_eq!(args.len(), 2);
+            }
+            _ => panic!("Expected variant"),
+        },
+        _ => panic!("Expected let statement"),
+    }
+    assert!(matches!(&ast[1].kind, StmtKind::Let { value, .. } if matches!(&value.kind, ExprKind::Variant { args, .. } if args.is_empty())));
+}
+
+#[test]
+fn test_match_expression() {
+    let ast = parse("Int a = 1 + match (s) { Shape::Rect(w, _) => w, -1 => 0, \"x\" => 1, n => n, };").unwrap();
+
+    let StmtKind::Let { value, .. } = &ast[0].kind else { panic!("Expected let statement") };
+    let ExprKind::Binary { right, .. } = &value.kind else { panic!("Expected binary expression") };
+    match &right.kind {
+        ExprKind::Match { subject, arms } => {
+            assert!(matches!(subject.kind, ExprKind::Variable(_)));
+            assert_eq!(arms.len(), 4);
+            assert_eq!(
+                arms[0].pattern,
+                Pattern::Variant {
+                    enum_name: "Shape".to_string(),
+                    variant: "Rect".to_string(),
+                    fields: vec![Pattern::Binding("w".to_string()), Pattern::Wildcard],
+                }
+            );
+            assert_eq!(arms[1].pattern, Pattern::Literal(Literal::Number(-1)));
+            assert_eq!(arms[2].pattern, Pattern::Literal(Literal::String("x".to_string())));
+            assert_eq!(arms[3].pattern.bindings(), vec!["n"]);
+        }
+        _ => panic!("Expected match expression"),
+    }
+}
+
+#[test]
+fn test_match_statement() {
+    let ast = parse("match (s) { Shape::Empty => { print(1); } True => total += 1, _ => print(2) }").unwrap();
+
+    match &ast[0].kind {
+        StmtKind::Match { arms, .. } => {
+            assert_eq!(arms.len(), 3);
+            assert!(matches!(arms[0].body.kind, StmtKind::Block(_)));
+            assert!(matches!(arms[1].body.kind, StmtKind::Assignment { .. }));
+            assert!(matches!(arms[2].body.kind, StmtKind::Expression(_)));
+            assert_eq!(arms[1].pattern, Pattern::Literal(Literal::Bool(true)));
+        }
+        _ => panic!("Expected match statement"),
+    }
+}
+
+#[test]
+fn test_match_syntax_errors() {
+    assert!(parse("match s { _ => 1 }").is_err());
+    assert!(parse("Int x = match (s) { _ 1 };").is_err());
+    assert!(parse("match (s) { 1 => print(1) 2 => print(2) }").is_err());
+    assert!(parse("Int x = match (s) { Shape:: => 1 };").is_err());
+}
+
+// ============================================================================
+// ERROR RECOVERY TESTS
+// ============================================================================
+
+// Helper returning the partial AST and every error
+fn parse_recovering(code: &str) -> (Vec<Stmt>, Vec<remylang::parser::ParseError>) {
+    let tokens = Lexer::new(code.to_string()).tokenize();
+    Parser::new(tokens).parse_recovering()
+}
+
+#[test]
+fn test_recovery_reports_every_error() {
+    let code = r#"
+        Int a = ;
+        Int b = 2;
+        Int c = 3 + ;
+        print(b);
+        x = = 4;
+    "#;
+    let (ast, errors) = parse_recovering(code);
+    assert_eq!(errors.len(), 3);
+    assert_eq!(errors[0].span().line, 2);
+    assert_eq!(errors[1].span().line, 4);
+    assert_eq!(errors[2].span().line, 6);
+    // `Int b = 2;` and `print(b);` survive
+    assert_eq!(ast.len(), 2);
+}
+
+#[test]
+fn test_recovery_inside_function_body() {
+    let code = r#"
+        func F() -> Int {
+            Int x = 1 +;
+            return 2;
+        }
+        func G() {
+            print(;
+        }
+        Int y = F();
+    "#;
+    let (ast, errors) = parse_recovering(code);
+    assert_eq!(errors.len(), 2);
+    assert_eq!(ast.len(), 3);
+    match &ast[0].kind {
+        StmtKind::FunctionDecl { body, .. } => match &body.kind {
+            StmtKind::Block(statements) => {
+                assert!(matches!(statements[0].kind, StmtKind::Return(_)));
+            }
+            _ => panic!("Expected block"),
+        },
+        _ => panic!("Expected function declaration"),
+    }
+}
+
+#[test]
+fn test_recovery_skips_nested_blocks() {
+    // The broken condition drops the whole if statement, without a
+    // cascade of errors from its body
+    let code = "if (x == ) { print(1); } else { print(2); } Int z = 0;";
+    let (ast, errors) = parse_recovering(code);
+    assert_eq!(errors.len(), 1);
+    assert_eq!(ast.len(), 1);
+}
+
+#[test]
+fn test_recovery_skips_lambdas() {
+    let code = r#"
+        Apply(x +, func(Int a) -> Int { return a; }, 2);
+        Int y = Apply(func(Int a) -> Int { return a; }, 3 +);
+        func F() {}
+    "#;
+    let (ast, errors) = parse_recovering(code);
+    assert_eq!(errors.len(), 2);
+    assert_eq!(errors[1].span().line, 3);
+    assert_eq!(ast.len(), 1);
+    assert!(matches!(ast[0].kind, StmtKind::FunctionDecl { .. }));
+}
+
+#[test]
+fn test_recovery_after_unclosed_parenthesis() {
+    let code = "func F(Int x -> Int {\n    return x;\n}\nInt y = *;\n";
+    let (_, errors) = parse_recovering(code);
+    let lines: Vec<usize> = errors.iter().map(|e| e.span().line).collect();
+    assert_eq!(lines.first(), Some(&1));
+    assert_eq!(lines.last(), Some(&4));
+}
+
+#[test]
+fn test_recovery_stray_closing_brace() {
+    let (ast, errors) = parse_recovering("} Int a = 1; ) Int b = 2;");
+    assert_eq!(errors.len(), 2);
+    assert_eq!(ast.len(), 2);
+}
+
+#[test]
+fn test_parse_returns_first_error() {
+    let error = parse("Int a = ; Int b = ;").unwrap_err();
+    assert_eq!(error.span().column, 9);
+}
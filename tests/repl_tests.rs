@@ -0,0 +1,188 @@
+/*
+ * -------------------------------------------------------------------------
+ * RemyLang — REPL Tests
+ * File : tests/repl_tests.rs
+ *
+ * Description :
+ *   Integration tests for the REPL session, input completion and commands.
+ *
+ * Author  : Samuel 'Meeast' Bleau
+ * Created : 2026-10-16
+ *
+ * -------------------------------------------------------------------------
+*/
+
+use remylang::ast::Type;
+use remylang::repl::{is_complete, Command, CommandError, Session};
+use std::cell::RefCell;
+use std::rc::Rc;
+use std::thread;
+
+// Helper running code in a session, returning the echoed values
+fn eval(session: &mut Session, code: &str) -> Vec<String> {
+    let mut echoed = Vec::new();
+    session
+        .eval(code, "<repl>", |value| echoed.push(value.to_string()))
+        .expect("code should run");
+    echoed
+}
+
+// Helper returning the messages of the errors raised by code
+fn eval_errors(session: &mut Session, code: &str) -> Vec<String> {
+    session
+        .eval(code, "<repl>", |_| {})
+        .expect_err("code should fail")
+        .into_iter()
+        .map(|diagnostic| diagnostic.message)
+        .collect()
+}
+
+// ============================================================================
+// SESSION
+// ============================================================================
+
+#[test]
+fn test_state_persists_between_inputs() {
+    let mut session = Session::new();
+    eval(&mut session, "Int x = 40;");
+    eval(&mut session, "func Add(Int a, Int b) -> Int {\n    return a + b;\n}");
+    assert_eq!(eval(&mut session, "Add(x, 2);"), vec!["42"]);
+    eval(&mut session, "x += 1;");
+    assert_eq!(eval(&mut session, "x"), vec!["41"]);
+}
+
+#[test]
+fn test_only_expressions_with_a_value_are_echoed() {
+    let mut session = Session::new();
+    let echoed = eval(&mut session, r#"
+        Int x = 1;
+        x + 1;
+        func Nothing() {}
+        Nothing();
+        x = 5;
+        "x = ${x}";
+    "#);
+    assert_eq!(echoed, vec!["2", "x = 5"]);
+}
+
+#[test]
+fn test_values_are_echoed_on_the_calling_thread() {
+    let mut session = Session::new();
+    // Shared state that cannot leave this thread
+    let echoed = Rc::new(RefCell::new(Vec::new()));
+    let caller = thread::current().id();
+    let sink = Rc::clone(&echoed);
+    session
+        .eval("1; 2;", "<repl>", |value| {
+            assert_eq!(thread::current().id(), caller);
+            sink.borrow_mut().push(value.to_string());
+        })
+        .expect("code should run");
+    assert_eq!(*echoed.borrow(), vec!["1", "2"]);
+}
+
+#[test]
+fn test_final_semicolon_is_optional() {
+    let mut session = Session::new();
+    assert_eq!(eval(&mut session, "1 + 2"), vec!["3"]);
+    assert_eq!(eval(&mut session, "Int y = 2 // two"), Vec::<String>::new());
+    assert_eq!(eval(&mut session, "y * 10"), vec!["20"]);
+}
+
+#[test]
+fn test_input_with_type_errors_declares_nothing() {
+    let mut session = Session::new();
+    let errors = eval_errors(&mut session, "Int ok = 1; Int bad = True;");
+    assert_eq!(errors, vec!["Expected Int, found Bool"]);
+    assert_eq!(eval_errors(&mut session, "ok"), vec!["Undefined variable 'ok'"]);
+    eval(&mut session, "Bool ok = True;");
+    assert_eq!(eval(&mut session, "ok"), vec!["True"]);
+}
+
+#[test]
+fn test_runtime_error_keeps_earlier_statements() {
+    let mut session = Session::new();
+    let errors = eval_errors(&mut session, "Int a = 1; Int b = a / 0; Int c = 3;");
+    assert_eq!(errors, vec!["Division by zero"]);
+    assert_eq!(eval(&mut session, "a"), vec!["1"]);
+    assert_eq!(eval_errors(&mut session, "c"), vec!["Undefined variable 'c'"]);
+}
+
+#[test]
+fn test_type_of_expression() {
+    let mut session = Session::new();
+    eval(&mut session, "func Double(Int x) -> Int { return x * 2; } Array<Float> values = [1.5];");
+    assert_eq!(session.type_of("Double(2) > 3"), Ok(Type::Bool));
+    assert_eq!(session.type_of("values").unwrap().to_string(), "Array<Float>");
+    assert_eq!(session.type_of("Double").unwrap().to_string(), "Func<(Int), Int>");
+    assert!(session.type_of("Int x = 1;").is_err());
+    assert!(session.type_of("missing").is_err());
+}
+
+#[test]
+fn test_globals_are_listed_by_name() {
+    let mut session = Session::new();
+    eval(&mut session, "String name = \"Remy\"; func Greet() {} Int age = 41;");
+    let globals: Vec<(String, String, Option<String>)> = session
+        .globals()
+        .into_iter()
+        .map(|g| (g.name, g.ty.to_string(), g.value.map(|v| v.to_string())))
+        .collect();
+    assert_eq!(
+        globals,
+        vec![
+            ("Greet".to_string(), "Func<()>".to_string(), Some("<function Greet>".to_string())),
+            ("age".to_string(), "Int".to_string(), Some("41".to_string())),
+            ("name".to_string(), "String".to_string(), Some("Remy".to_string())),
+        ]
+    );
+}
+
+// ============================================================================
+// INPUT COMPLETION
+// ============================================================================
+
+#[test]
+fn test_incomplete_input() {
+    assert!(!is_complete("func Add(Int a, Int b) -> Int {\n"));
+    assert!(!is_complete("if (x > 1) {\n    if (x > 2) {\n    }\n"));
+    assert!(!is_complete("Array<Int> a = [1,\n"));
+    assert!(!is_complete("/* a comment\n"));
+}
+
+#[test]
+fn test_complete_input() {
+    assert!(is_complete("Int x = 1;"));
+    assert!(is_complete("func Add(Int a, Int b) -> Int {\n    return a + b;\n}\n"));
+    assert!(is_complete("String s = \"{ ${1} [\";"));
+    assert!(is_complete("// {\n"));
+    // Extra closing braces are left to the parser to report
+    assert!(is_complete("}"));
+}
+
+// ============================================================================
+// COMMANDS
+// ============================================================================
+
+#[test]
+fn test_parse_commands() {
+    assert_eq!(Command::parse(":help"), Ok(Command::Help));
+    assert_eq!(Command::parse(":quit"), Ok(Command::Quit));
+    assert_eq!(Command::parse(":env"), Ok(Command::Env));
+    assert_eq!(Command::parse(":type  x + 1 "), Ok(Command::Type("x + 1".to_string())));
+    assert_eq!(Command::parse(":ast Int x = 1;"), Ok(Command::Ast("Int x = 1;".to_string())));
+    assert_eq!(Command::parse(":load examples/demo.remy"), Ok(Command::Load("examples/demo.remy".to_string())));
+}
+
+#[test]
+fn test_command_errors() {
+    assert_eq!(Command::parse(":frobnicate"), Err(CommandError::Unknown("frobnicate".to_string())));
+    assert_eq!(
+        Command::parse(":load"),
+        Err(CommandError::MissingArgument { command: "load", argument: "a file name" })
+    );
+    assert_eq!(
+        Command::parse(":type").unwrap_err().to_string(),
+        "':type' expects an expression"
+    );
+}
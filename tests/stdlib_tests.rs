@@ -0,0 +1,275 @@
+/*
+ * -------------------------------------------------------------------------
+ * RemyLang — Standard Library Tests
+ * File : tests/stdlib_tests.rs
+ *
+ * Description :
+ *   Integration tests for the native functions: registry, type checking
+ *   of their calls, and their results on the interpreter and the VM.
+ *
+ * Author  : Samuel 'Meeast' Bleau
+ * Created : 2026-10-16
+ *
+ * -------------------------------------------------------------------------
+*/
+
+use remylang::ast::{Stmt, Type};
+use remylang::lexer::Lexer;
+use remylang::parser::Parser;
+use remylang::semantic::{TypeChecker, TypeError};
+use remylang::stdlib::{self, Param, Returns};
+use remylang::vm::{Compiler, Interpreter, Machine, RuntimeError, RuntimeResult, Value};
+
+fn parse(code: &str) -> Vec<Stmt> {
+    let tokens = Lexer::new(code.to_string()).tokenize();
+    Parser::new(tokens).parse().expect("code should parse")
+}
+
+// Helper running checked code on both backends, which must agree
+fn run(code: &str) -> (Interpreter, RuntimeResult<()>) {
+    let ast = parse(code);
+    TypeChecker::new().check_program(&ast).expect("code should type check");
+
+    let program = Compiler::new().compile(&ast).expect("code should compile");
+    let machine_result = Machine::new().run(&program);
+    let mut interpreter = Interpreter::new();
+    let interpreter_result = interpreter.execute(ast);
+    assert_eq!(
+        machine_result.as_ref().map_err(RuntimeError::kind),
+        interpreter_result.as_ref().map_err(RuntimeError::kind)
+    );
+    (interpreter, interpreter_result)
+}
+
+// Helper returning the value of a global variable after running code
+fn global(code: &str, name: &str) -> Value {
+    let (interpreter, result) = run(code);
+    result.expect("code should run");
+    interpreter.environment().get(name).expect("variable should exist")
+}
+
+// Helper returning the runtime error raised by code, without its location
+fn runtime_error(code: &str) -> RuntimeError {
+    run(code).1.expect_err("code should fail").kind().clone()
+}
+
+// Helper returning the type errors without their locations
+fn errors(code: &str) -> Vec<TypeError> {
+    TypeChecker::new()
+        .check_program(&parse(code))
+        .unwrap_err()
+        .iter()
+        .map(|e| e.kind().clone())
+        .collect()
+}
+
+fn string(s: &str) -> Value {
+    Value::String(s.to_string())
+}
+
+// ============================================================================
+// REGISTRY
+// ============================================================================
+
+#[test]
+fn test_registry() {
+    let names: Vec<&str> = stdlib::natives().iter().map(|native| native.name).collect();
+    for name in ["print", "input", "len", "split", "char_at", "abs", "clamp", "gcd", "to_string", "to_int"] {
+        assert!(names.contains(&name), "{} is missing", name);
+    }
+    assert!(stdlib::lookup("nothing").is_none());
+
+    let split = &stdlib::lookup("split").unwrap().signature;
+    assert_eq!(split.params, vec![Param::Type(Type::String), Param::Type(Type::String)]);
+    assert_eq!(split.returns, Returns::Type(Type::Array(Box::new(Type::String))));
+    assert_eq!(split.to_string(), "(String, String) -> Array<String>");
+    assert_eq!(stdlib::lookup("println").unwrap().signature.to_string(), "(any value...) -> Void");
+    assert_eq!(stdlib::lookup("max").unwrap().signature.to_string(), "(Int or Float, Int or Float) -> Int or Float");
+}
+
+#[test]
+fn test_call_checks_the_argument_count() {
+    let result = stdlib::lookup("trim").unwrap().call(vec![]);
+    assert_eq!(
+        result,
+        Err(RuntimeError::ArgumentCountMismatch { expected: 1, found: 0, function_name: "trim".to_string() })
+    );
+}
+
+// ============================================================================
+// STRINGS
+// ============================================================================
+
+#[test]
+fn test_len() {
+    assert_eq!(global("Int n = len(\"héllo\");", "n"), Value::Number(5));
+    assert_eq!(global("Int n = len([1, 2, 3]);", "n"), Value::Number(3));
+    assert_eq!(global("Array<String> a = []; Int n = len(a);", "n"), Value::Number(0));
+}
+
+#[test]
+fn test_split_and_join() {
+    assert_eq!(
+        global("Array<String> a = split(\"a,b,,c\", \",\");", "a"),
+        Value::Array(vec![string("a"), string("b"), string(""), string("c")])
+    );
+    assert_eq!(
+        global("Array<String> a = split(\"abc\", \"\");", "a"),
+        Value::Array(vec![string("a"), string("b"), string("c")])
+    );
+    assert_eq!(global("String s = join([\"a\", \"b\", \"c\"], \", \");", "s"), string("a, b, c"));
+    assert_eq!(global("String s = join(split(\"1 2 3\", \" \"), \"+\");", "s"), string("1+2+3"));
+}
+
+#[test]
+fn test_string_transformations() {
+    assert_eq!(global("String s = trim(\"  padded\\t\\n\");", "s"), string("padded"));
+    assert_eq!(global("String s = replace(\"a-b-c\", \"-\", \"::\");", "s"), string("a::b::c"));
+    assert_eq!(global("String s = upper(\"Straße\");", "s"), string("STRASSE"));
+    assert_eq!(global("String s = lower(\"ÉTÉ\");", "s"), string("été"));
+    assert_eq!(global("Bool b = contains(\"haystack\", \"st\");", "b"), Value::Bool(true));
+    assert_eq!(global("Bool b = contains(\"haystack\", \"x\");", "b"), Value::Bool(false));
+}
+
+#[test]
+fn test_characters_are_indexed() {
+    assert_eq!(global("Char c = char_at(\"héllo\", 1);", "c"), Value::Char('é'));
+    assert_eq!(global("String s = substring(\"héllo\", 1, 4);", "s"), string("éll"));
+    assert_eq!(global("String s = substring(\"abc\", 3, 3);", "s"), string(""));
+    assert_eq!(runtime_error("Char c = char_at(\"abc\", 3);"), RuntimeError::IndexOutOfBounds { index: 3, length: 3 });
+    assert_eq!(runtime_error("Char c = char_at(\"abc\", -1);"), RuntimeError::IndexOutOfBounds { index: -1, length: 3 });
+    assert_eq!(runtime_error("String s = substring(\"abc\", 1, 5);"), RuntimeError::IndexOutOfBounds { index: 5, length: 3 });
+    assert_eq!(
+        runtime_error("String s = substring(\"abc\", 2, 1);"),
+        RuntimeError::InvalidArgument { function: "substring".to_string(), reason: "the start 2 is after the end 1".to_string() }
+    );
+}
+
+// ============================================================================
+// MATH
+// ============================================================================
+
+#[test]
+fn test_math_keeps_the_number_type() {
+    assert_eq!(global("Int x = abs(-3);", "x"), Value::Number(3));
+    assert_eq!(global("Float x = abs(-2.5);", "x"), Value::Float(2.5));
+    assert_eq!(global("Int x = min(4, -1) + max(4, -1);", "x"), Value::Number(3));
+    assert_eq!(global("Float x = max(0.5, 1.5);", "x"), Value::Float(1.5));
+    assert_eq!(global("Int x = clamp(15, 0, 10);", "x"), Value::Number(10));
+    assert_eq!(global("Float x = clamp(-1.0, 0.0, 1.0);", "x"), Value::Float(0.0));
+}
+
+#[test]
+fn test_gcd() {
+    assert_eq!(global("Int x = gcd(12, 18);", "x"), Value::Number(6));
+    assert_eq!(global("Int x = gcd(-12, 8);", "x"), Value::Number(4));
+    assert_eq!(global("Int x = gcd(0, 0);", "x"), Value::Number(0));
+}
+
+#[test]
+fn test_math_errors() {
+    assert_eq!(
+        runtime_error("Int x = abs(-9223372036854775808);"),
+        RuntimeError::IntegerOverflow { operation: "abs".to_string() }
+    );
+    assert_eq!(
+        runtime_error("Int x = clamp(1, 5, 2);"),
+        RuntimeError::InvalidArgument {
+            function: "clamp".to_string(),
+            reason: "the lower bound 5 is greater than the upper bound 2".to_string(),
+        }
+    );
+    assert!(matches!(runtime_error("Float x = clamp(1.0, 0.0 / 0, 2.0);"), RuntimeError::InvalidArgument { .. }));
+}
+
+// ============================================================================
+// CONVERSIONS
+// ============================================================================
+
+#[test]
+fn test_conversions() {
+    assert_eq!(global("String s = to_string(2.0);", "s"), string("2.0"));
+    assert_eq!(global("String s = to_string([True, False]);", "s"), string("[True, False]"));
+    assert_eq!(global("Int n = to_int(\" -42 \");", "n"), Value::Number(-42));
+    assert_eq!(global("Float x = to_float(\"1.5\");", "x"), Value::Float(1.5));
+    assert_eq!(global("Int n = int(to_float(\"7.9\"));", "n"), Value::Number(7));
+    assert_eq!(
+        runtime_error("Int n = to_int(\"4x\");"),
+        RuntimeError::InvalidConversion { text: "4x".to_string(), target: "Int".to_string() }
+    );
+    assert_eq!(
+        RuntimeError::InvalidConversion { text: "4x".to_string(), target: "Int".to_string() }.message(),
+        "Cannot convert \"4x\" to Int"
+    );
+}
+
+// ============================================================================
+// TYPE CHECKING
+// ============================================================================
+
+#[test]
+fn test_result_types() {
+    assert_eq!(
+        errors("Int a = split(\"a\", \"b\"); Char b = upper(\"x\"); Int c = max(1.0, 2.0);"),
+        vec![
+            TypeError::TypeMismatch { expected: Type::Int, found: Type::Array(Box::new(Type::String)) },
+            TypeError::TypeMismatch { expected: Type::Char, found: Type::String },
+            TypeError::TypeMismatch { expected: Type::Int, found: Type::Float },
+        ]
+    );
+}
+
+#[test]
+fn test_argument_errors() {
+    let code = r#"
+        Int a = len(1);
+        Int b = gcd(1, 2.0);
+        Int c = min(1, 2.0);
+        Int d = abs("x");
+        String e = trim();
+        String f = to_string(println(1));
+        String g = join([1], ",");
+    "#;
+    assert_eq!(
+        errors(code),
+        vec![
+            TypeError::InvalidArgument {
+                function: "len".to_string(),
+                position: 0,
+                expected: "String or Array".to_string(),
+                found: Type::Int,
+            },
+            TypeError::ArgumentTypeMismatch { position: 1, expected: Type::Int, found: Type::Float },
+            TypeError::ArgumentTypeMismatch { position: 1, expected: Type::Int, found: Type::Float },
+            TypeError::InvalidArgument {
+                function: "abs".to_string(),
+                position: 0,
+                expected: "Int or Float".to_string(),
+                found: Type::String,
+            },
+            TypeError::ArgumentCountMismatch { expected: 1, found: 0 },
+            TypeError::VoidValueNotAllowed,
+            TypeError::ArgumentTypeMismatch {
+                position: 0,
+                expected: Type::Array(Box::new(Type::String)),
+                found: Type::Array(Box::new(Type::Int)),
+            },
+        ]
+    );
+    assert_eq!(
+        TypeError::InvalidArgument {
+            function: "len".to_string(),
+            position: 0,
+            expected: "String or Array".to_string(),
+            found: Type::Int,
+        }
+        .message(),
+        "Argument 1 of len should be String or Array, found Int"
+    );
+}
+
+#[test]
+fn test_user_functions_hide_natives() {
+    let code = "func len(Int x) -> Int { return x; } Int n = len(4);";
+    assert_eq!(global(code, "n"), Value::Number(4));
+}
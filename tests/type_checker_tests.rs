@@ -0,0 +1,884 @@
+/*
+ * -------------------------------------------------------------------------
+ * RemyLang — Type Checker Tests
+ * File : tests/type_checker_tests.rs
+ *
+ * Description :
+ *   Integration tests for the semantic type checking pass.
+ *
+ * Author  : Samuel 'Meeast' Bleau
+ * Created : 2026-10-16
+ *
+ * -------------------------------------------------------------------------
+*/
+
+use remylang::ast::{BinaryOp, Type};
+use remylang::lexer::Lexer;
+use remylang::parser::Parser;
+use remylang::semantic::{TypeChecker, TypeError};
+
+// Helper function to parse and type check code
+fn check(code: &str) -> Result<(), Vec<TypeError>> {
+    let tokens = Lexer::new(code.to_string()).tokenize();
+    let ast = Parser::new(tokens).parse().expect("code should parse");
+    TypeChecker::new().check_program(&ast)
+}
+
+// Helper returning the errors without their locations
+fn errors(code: &str) -> Vec<TypeError> {
+    check(code)
+        .unwrap_err()
+        .iter()
+        .map(|e| e.kind().clone())
+        .collect()
+}
+
+// ============================================================================
+// VALID PROGRAMS
+// ============================================================================
+
+#[test]
+fn test_valid_declarations() {
+    let code = r#"
+        Int x = 42;
+        String s = "hello" + " world";
+        Bool b = x > 10 && !False;
+        Char c = 'c';
+        Array<Int> list = [1, 2, 3];
+        Array<Int> empty = [];
+        Int first = list[0];
+        x = x * 2;
+    "#;
+    assert!(check(code).is_ok());
+}
+
+#[test]
+fn test_valid_functions() {
+    let code = r#"
+        func Add(Int a, Int b) -> Int {
+            return a + b;
+        }
+
+        func Greet(String name) {
+            print("Hello,", name);
+        }
+
+        Int result = Add(1, Add(2, 3));
+        Greet("RemyLang");
+    "#;
+    assert!(check(code).is_ok());
+}
+
+#[test]
+fn test_recursive_and_mutually_recursive_functions() {
+    let code = r#"
+        func IsEven(Int n) -> Bool {
+            if (n == 0) {
+                return True;
+            } else {
+                return IsOdd(n - 1);
+            }
+        }
+
+        func IsOdd(Int n) -> Bool {
+            if (n == 0) {
+                return False;
+            } else {
+                return IsEven(n - 1);
+            }
+        }
+    "#;
+    assert!(check(code).is_ok());
+}
+
+// ============================================================================
+// ERRORS
+// ============================================================================
+
+#[test]
+fn test_declaration_type_mismatch() {
+    assert_eq!(
+        errors("Int x = \"text\";"),
+        vec![TypeError::TypeMismatch { expected: Type::Int, found: Type::String }]
+    );
+}
+
+#[test]
+fn test_undefined_variable() {
+    assert_eq!(
+        errors("Int x = y + 1;"),
+        vec![TypeError::UndefinedVariable("y".to_string())]
+    );
+}
+
+#[test]
+fn test_non_bool_condition() {
+    assert_eq!(
+        errors("if (1) { print(1); }"),
+        vec![TypeError::TypeMismatch { expected: Type::Bool, found: Type::Int }]
+    );
+}
+
+#[test]
+fn test_logical_operands_must_be_bool() {
+    assert_eq!(
+        errors("Bool b = 1 && True;"),
+        vec![TypeError::InvalidBinaryOperands { op: BinaryOp::And, left: Type::Int, right: Type::Bool }]
+    );
+    assert_eq!(
+        errors("Bool b = False || \"yes\";"),
+        vec![TypeError::InvalidBinaryOperands { op: BinaryOp::Or, left: Type::Bool, right: Type::String }]
+    );
+}
+
+#[test]
+fn test_valid_assignments() {
+    let code = r#"
+        Int x = 1;
+        x += 2;
+        String s = "a";
+        s += "b";
+        Array<Array<Int>> grid = [[1]];
+        grid[0][0] *= 3;
+        grid[0] = [];
+    "#;
+    assert!(check(code).is_ok());
+}
+
+#[test]
+fn test_assignment_errors() {
+    assert_eq!(
+        errors("Bool b = True; b += False;"),
+        vec![TypeError::InvalidBinaryOperands { op: BinaryOp::Add, left: Type::Bool, right: Type::Bool }]
+    );
+    assert_eq!(
+        errors("String s = \"a\"; s -= \"b\";"),
+        vec![TypeError::InvalidBinaryOperands { op: BinaryOp::Sub, left: Type::String, right: Type::String }]
+    );
+    assert_eq!(
+        errors("Array<Int> a = [1]; a[0] = \"one\";"),
+        vec![TypeError::TypeMismatch { expected: Type::Int, found: Type::String }]
+    );
+    assert_eq!(
+        errors("Array<Int> a = [1]; a[True] = 1;"),
+        vec![TypeError::InvalidIndexType { found: Type::Bool }]
+    );
+    assert_eq!(
+        errors("Int x = 1; x[0] = 1;"),
+        vec![TypeError::NotIndexable { found: Type::Int }]
+    );
+    assert_eq!(
+        errors("func F() { } F[0] += 1;"),
+        vec![TypeError::CannotAssignToFunction("F".to_string())]
+    );
+}
+
+#[test]
+fn test_return_type_mismatch() {
+    assert_eq!(
+        errors("func F() -> Int { return True; }"),
+        vec![TypeError::ReturnTypeMismatch { expected: Type::Int, found: Type::Bool }]
+    );
+}
+
+#[test]
+fn test_return_outside_function() {
+    assert_eq!(errors("return 1;"), vec![TypeError::ReturnOutsideFunction]);
+}
+
+#[test]
+fn test_missing_return() {
+    assert_eq!(
+        errors("func F(Int x) -> Int { if (x > 0) { return x; } }"),
+        vec![TypeError::MissingReturn("F".to_string())]
+    );
+}
+
+#[test]
+fn test_argument_errors() {
+    let code = r#"
+        func Add(Int a, Int b) -> Int {
+            return a + b;
+        }
+        Add(1);
+        Add(1, "two");
+    "#;
+    assert_eq!(
+        errors(code),
+        vec![
+            TypeError::ArgumentCountMismatch { expected: 2, found: 1 },
+            TypeError::ArgumentTypeMismatch { position: 1, expected: Type::Int, found: Type::String },
+        ]
+    );
+}
+
+#[test]
+fn test_heterogeneous_array() {
+    assert_eq!(
+        errors("Array<Int> a = [1, True];"),
+        vec![TypeError::HeterogeneousArray { expected: Type::Int, found: Type::Bool }]
+    );
+}
+
+#[test]
+fn test_reports_all_errors() {
+    let code = r#"
+        Int a = True;
+        func F() {
+            Int b = "b";
+            Bool c = 1;
+        }
+        String d = 4;
+        Int e = a + 1;
+    "#;
+    let errs = check(code).unwrap_err();
+    assert_eq!(errs.len(), 4);
+
+    // Each error points at its statement
+    let lines: Vec<usize> = errs.iter().map(|e| e.span().unwrap().line).collect();
+    assert_eq!(lines, vec![2, 4, 5, 7]);
+}
+
+// ============================================================================
+// LOOPS
+// ============================================================================
+
+#[test]
+fn test_valid_loops() {
+    let code = r#"
+        Int sum = 0;
+        while (sum < 10) {
+            sum = sum + 1;
+            if (sum == 5) {
+                continue;
+            }
+        }
+        for (Int i = 0; i < 3; i = i + 1) {
+            sum = sum + i;
+        }
+        for word in ["a", "b"] {
+            String copy = word;
+            break;
+        }
+    "#;
+    assert!(check(code).is_ok());
+}
+
+#[test]
+fn test_break_outside_loop() {
+    assert_eq!(errors("break;"), vec![TypeError::BreakOutsideLoop]);
+    assert_eq!(errors("if (True) { continue; }"), vec![TypeError::ContinueOutsideLoop]);
+}
+
+#[test]
+fn test_break_in_function_inside_loop() {
+    let code = r#"
+        while (True) {
+            func Inner() {
+                break;
+            }
+        }
+    "#;
+    assert_eq!(errors(code), vec![TypeError::BreakOutsideLoop]);
+}
+
+#[test]
+fn test_loop_condition_must_be_bool() {
+    assert_eq!(
+        errors("while (1) { }"),
+        vec![TypeError::TypeMismatch { expected: Type::Bool, found: Type::Int }]
+    );
+}
+
+#[test]
+fn test_for_in_element_type() {
+    assert_eq!(
+        errors("for x in [1, 2] { String s = x; }"),
+        vec![TypeError::TypeMismatch { expected: Type::String, found: Type::Int }]
+    );
+    assert_eq!(errors("for x in 3 { }"), vec![TypeError::NotIterable { found: Type::Int }]);
+}
+
+// ============================================================================
+// SCOPING
+// ============================================================================
+
+#[test]
+fn test_nested_function_sees_outer_locals() {
+    let code = r#"
+        func Outer(Int base) -> Int {
+            Int offset = 2;
+            func Inner(Int x) -> Int {
+                return x + base + offset;
+            }
+            return Inner(1);
+        }
+    "#;
+    assert!(check(code).is_ok());
+}
+
+#[test]
+fn test_function_does_not_see_later_caller_locals() {
+    let code = r#"
+        func ReadX() -> Int {
+            return x;
+        }
+        func Caller() -> Int {
+            Int x = 5;
+            return ReadX();
+        }
+    "#;
+    assert_eq!(errors(code), vec![TypeError::UndefinedVariable("x".to_string())]);
+}
+
+// ============================================================================
+// FLOATS
+// ============================================================================
+
+#[test]
+fn test_valid_floats() {
+    let code = r#"
+        Float pi = 3.14;
+        Float area = pi * 2 ** 2;
+        Float half = 1 / 2.0;
+        Bool bigger = area > 12 && -pi < 0;
+        Int rounded = int(area);
+        Float widened = float(rounded) + 0.5;
+        Array<Float> values = [1.5, 2e3];
+        area += 1;
+        area /= 2;
+    "#;
+    assert!(check(code).is_ok());
+}
+
+#[test]
+fn test_mixed_arithmetic_gives_a_float() {
+    let code = r#"
+        Int x = 1 + 2.0;
+        Int i = 0;
+        i += 0.5;
+        Array<Float> values = [1.5, 2];
+    "#;
+    assert_eq!(
+        errors(code),
+        vec![
+            TypeError::TypeMismatch { expected: Type::Int, found: Type::Float },
+            TypeError::TypeMismatch { expected: Type::Int, found: Type::Float },
+            TypeError::HeterogeneousArray { expected: Type::Float, found: Type::Int },
+        ]
+    );
+}
+
+#[test]
+fn test_mixed_comparisons() {
+    assert!(check("Bool a = 1 < 2.5; Bool b = 1.0 == 1; Bool c = 2 != 2.5; Bool d = 1.5 >= 1;").is_ok());
+}
+
+#[test]
+fn test_float_errors() {
+    let code = r#"
+        Bool same = 1.0 == "1";
+        Float f = "1.5" * 2.0;
+        Int n = int("3");
+        Float g = float(1, 2);
+    "#;
+    assert_eq!(
+        errors(code),
+        vec![
+            TypeError::InvalidBinaryOperands { op: BinaryOp::Equal, left: Type::Float, right: Type::String },
+            TypeError::InvalidBinaryOperands { op: BinaryOp::Mul, left: Type::String, right: Type::Float },
+            TypeError::InvalidArgument {
+                function: "int".to_string(),
+                position: 0,
+                expected: "Int or Float".to_string(),
+                found: Type::String,
+            },
+            TypeError::ArgumentCountMismatch { expected: 1, found: 2 },
+        ]
+    );
+}
+
+#[test]
+fn test_program_arguments() {
+    assert!(check("Array<String> a = args(); String first = args()[0];").is_ok());
+    assert_eq!(
+        errors("Array<String> a = args(1); Array<Int> b = args();"),
+        vec![
+            TypeError::ArgumentCountMismatch { expected: 0, found: 1 },
+            TypeError::TypeMismatch {
+                expected: Type::Array(Box::new(Type::Int)),
+                found: Type::Array(Box::new(Type::String)),
+            },
+        ]
+    );
+}
+
+// ============================================================================
+// STRING INTERPOLATION
+// ============================================================================
+
+#[test]
+fn test_valid_interpolation() {
+    let code = r#"
+        struct Point { Int x; Int y; }
+        Point p = Point { x: 1, y: 2 };
+        Array<Float> values = [0.5];
+        String s = "${p} ${values} ${'c'} ${True} ${"nested ${p.x + 1}"}";
+    "#;
+    assert!(check(code).is_ok());
+}
+
+#[test]
+fn test_interpolation_errors() {
+    let code = r#"
+        func Nothing() {}
+        String s = "${Nothing()}";
+        Int n = "${1}";
+        String t = "${missing}";
+    "#;
+    assert_eq!(
+        errors(code),
+        vec![
+            TypeError::VoidValueNotAllowed,
+            TypeError::TypeMismatch { expected: Type::Int, found: Type::String },
+            TypeError::UndefinedVariable("missing".to_string()),
+        ]
+    );
+}
+
+// ============================================================================
+// FUNCTIONS AS VALUES
+// ============================================================================
+
+#[test]
+fn test_valid_function_values() {
+    let code = r#"
+        func Double(Int x) -> Int {
+            return x * 2;
+        }
+        func Apply(Func<(Int), Int> f, Int x) -> Int {
+            return f(x);
+        }
+        func MakeAdder(Int n) -> Func<(Int), Int> {
+            return func(Int x) -> Int { return x + n; };
+        }
+        Func<(Int), Int> f = Double;
+        Int a = Apply(f, 1) + Apply(MakeAdder(2), 3) + MakeAdder(1)(1);
+        Array<Func<(Int), Int>> handlers = [Double, MakeAdder(5)];
+        Int b = handlers[1](a);
+        Func<()> hello = func() { println("hello"); };
+        hello();
+    "#;
+    assert!(check(code).is_ok());
+}
+
+#[test]
+fn test_function_value_errors() {
+    let code = r#"
+        func Double(Int x) -> Int {
+            return x * 2;
+        }
+        Func<(Int), Bool> f = Double;
+        Int n = 1;
+        n(2);
+        Func<(Int), Int> g = Double;
+        g("two");
+        Bool same = g == Double;
+        Func<(Int), Int> h = func(Int x) -> Int { return "x"; };
+    "#;
+    let func_type = Type::Function { params: vec![Type::Int], return_type: Box::new(Type::Int) };
+    assert_eq!(
+        errors(code),
+        vec![
+            TypeError::TypeMismatch {
+                expected: Type::Function { params: vec![Type::Int], return_type: Box::new(Type::Bool) },
+                found: func_type.clone(),
+            },
+            TypeError::NotCallable,
+            TypeError::ArgumentTypeMismatch { position: 0, expected: Type::Int, found: Type::String },
+            TypeError::InvalidBinaryOperands { op: BinaryOp::Equal, left: func_type.clone(), right: func_type },
+            TypeError::ReturnTypeMismatch { expected: Type::Int, found: Type::String },
+        ]
+    );
+}
+
+#[test]
+fn test_function_type_display() {
+    let unary = Type::Function { params: vec![Type::Int], return_type: Box::new(Type::Bool) };
+    assert_eq!(unary.to_string(), "Func<(Int), Bool>");
+    let action = Type::Function { params: vec![Type::String, Type::Float], return_type: Box::new(Type::Void) };
+    assert_eq!(action.to_string(), "Func<(String, Float)>");
+}
+
+// ============================================================================
+// STRUCTS
+// ============================================================================
+
+// Declarations shared by the struct tests
+const SHAPES: &str = r#"
+    struct Point { Int x; Int y; }
+    struct Line { Point a; Point b; }
+    impl Point {
+        func Add(Point other) -> Point {
+            return Point { x: self.x + other.x, y: self.y + other.y };
+        }
+        func Len2() -> Int {
+            return self.x * self.x + self.y * self.y;
+        }
+    }
+"#;
+
+// Helper returning the errors of code using the shared declarations
+fn struct_errors(code: &str) -> Vec<TypeError> {
+    errors(&format!("{}{}", SHAPES, code))
+}
+
+fn point() -> Type {
+    Type::Named("Point".to_string())
+}
+
+#[test]
+fn test_valid_structs() {
+    let code = format!("{}{}", SHAPES, r#"
+        Point p = Point { y: 2, x: 1 };
+        Line l = Line { a: p, b: p.Add(p) };
+        l.b.x += l.a.Len2();
+        Array<Point> points = [p, l.b];
+        points[0].y = points[1].x;
+        Bool same = p == l.a;
+        func Mid(Line line) -> Point {
+            return Point { x: (line.a.x + line.b.x) / 2, y: (line.a.y + line.b.y) / 2 };
+        }
+    "#);
+    assert!(check(&code).is_ok());
+}
+
+#[test]
+fn test_structs_can_be_used_before_their_declaration() {
+    let code = r#"
+        func Origin() -> Point {
+            return Point { x: 0 };
+        }
+        struct Point { Int x; }
+    "#;
+    assert!(check(code).is_ok());
+}
+
+#[test]
+fn test_recursive_structs() {
+    let recursive = |name: &str, field: &str| TypeError::RecursiveStruct { name: name.to_string(), field: field.to_string() };
+    assert_eq!(errors("struct A { Int x; A a; }"), vec![recursive("A", "a")]);
+    assert_eq!(
+        errors("struct A { B b; } struct B { Int x; C c; } struct C { A a; }"),
+        vec![recursive("A", "b"), recursive("B", "c"), recursive("C", "a")]
+    );
+    // Arrays and enums may stop the recursion
+    assert!(check("struct Node { Int value; Array<Node> children; } enum List { Nil, Cons(Int, List) }").is_ok());
+    assert!(check("struct Tree { Node root; } struct Node { Array<Tree> forest; }").is_ok());
+}
+
+#[test]
+fn test_struct_literal_errors() {
+    assert_eq!(
+        struct_errors("Point p = Point { x: 1 };"),
+        vec![TypeError::MissingField { type_name: "Point".to_string(), field: "y".to_string() }]
+    );
+    assert_eq!(
+        struct_errors("Point p = Point { x: 1, y: 2, z: 3 };"),
+        vec![TypeError::UndefinedField { found: point(), field: "z".to_string() }]
+    );
+    assert_eq!(
+        struct_errors("Point p = Point { x: 1, x: 2, y: 3 };"),
+        vec![TypeError::DuplicateField("x".to_string())]
+    );
+    assert_eq!(
+        struct_errors("Point p = Point { x: True, y: 2 };"),
+        vec![TypeError::TypeMismatch { expected: Type::Int, found: Type::Bool }]
+    );
+    assert_eq!(
+        errors("Int x = Circle { r: 1 };"),
+        vec![TypeError::UndefinedType("Circle".to_string())]
+    );
+}
+
+#[test]
+fn test_field_and_method_errors() {
+    assert_eq!(
+        struct_errors("Point p = Point { x: 1, y: 2 }; Int z = p.z;"),
+        vec![TypeError::UndefinedField { found: point(), field: "z".to_string() }]
+    );
+    assert_eq!(
+        struct_errors("Int n = 1; Int z = n.x;"),
+        vec![TypeError::UndefinedField { found: Type::Int, field: "x".to_string() }]
+    );
+    assert_eq!(
+        struct_errors("Point p = Point { x: 1, y: 2 }; p.x = \"one\";"),
+        vec![TypeError::TypeMismatch { expected: Type::Int, found: Type::String }]
+    );
+    assert_eq!(
+        struct_errors("Point p = Point { x: 1, y: 2 }; p.Scale(2);"),
+        vec![TypeError::UndefinedMethod { found: point(), method: "Scale".to_string() }]
+    );
+    assert_eq!(
+        struct_errors("Point p = Point { x: 1, y: 2 }; p.Add(1);"),
+        vec![TypeError::ArgumentTypeMismatch { position: 0, expected: point(), found: Type::Int }]
+    );
+}
+
+#[test]
+fn test_declaration_errors() {
+    assert_eq!(
+        errors("struct P { Int x; Bool x; }"),
+        vec![TypeError::DuplicateField("x".to_string())]
+    );
+    assert_eq!(
+        errors("struct P { Circle c; }"),
+        vec![TypeError::UndefinedType("Circle".to_string())]
+    );
+    assert_eq!(
+        errors("struct P { Int x; } struct P { Int y; }"),
+        vec![TypeError::DuplicateDefinition("P".to_string())]
+    );
+    assert_eq!(
+        errors("impl Circle { func R() -> Int { return 1; } }"),
+        vec![TypeError::UndefinedType("Circle".to_string())]
+    );
+    assert_eq!(
+        errors("struct P { Int x; } impl P { func F() { } func F() { } }"),
+        vec![TypeError::DuplicateDefinition("P.F".to_string())]
+    );
+    assert_eq!(
+        errors("Array<Circle> circles = [];"),
+        vec![TypeError::UndefinedType("Circle".to_string())]
+    );
+}
+
+#[test]
+fn test_method_bodies_are_checked() {
+    assert_eq!(
+        errors("struct P { Int x; } impl P { func F() -> Bool { return self.x; } }"),
+        vec![TypeError::ReturnTypeMismatch { expected: Type::Bool, found: Type::Int }]
+    );
+    assert_eq!(
+        errors("struct P { Int x; } func F() -> Int { return self.x; }"),
+        vec![TypeError::UndefinedVariable("self".to_string())]
+    );
+}
+
+// ============================================================================
+// ENUMS AND MATCH
+// ============================================================================
+
+// Declarations shared by the enum tests
+const ENUMS: &str = r#"
+    enum Shape { Circle(Int), Rect(Int, Int), Empty }
+    enum Tree { Leaf(Bool), Node(Shape, Shape) }
+"#;
+
+// Helper returning the errors of code using the shared enums
+fn enum_errors(code: &str) -> Vec<TypeError> {
+    errors(&format!("{}{}", ENUMS, code))
+}
+
+fn non_exhaustive(missing: &[&str]) -> Vec<TypeError> {
+    vec![TypeError::NonExhaustiveMatch { missing: missing.iter().map(|m| m.to_string()).collect() }]
+}
+
+#[test]
+fn test_valid_enums_and_matches() {
+    let code = format!("{}{}", ENUMS, r#"
+        impl Shape {
+            func Area() -> Int {
+                return match (self) {
+                    Shape::Circle(r) => 3 * r * r,
+                    Shape::Rect(w, h) => w * h,
+                    Shape::Empty => 0,
+                };
+            }
+        }
+        func Name(Tree t) -> String {
+            match (t) {
+                Tree::Leaf(True) => { return "yes"; }
+                Tree::Leaf(False) => { return "no"; }
+                Tree::Node(Shape::Empty, _) => { return "empty"; }
+                Tree::Node(left, right) => { return "node"; }
+            }
+        }
+        Shape s = Shape::Rect(1, 2);
+        Int n = 4;
+        String size = match (n) { 0 => "none", 1 => "one", _ => "many" };
+        Bool same = s == Shape::Empty;
+        match (s.Area()) {
+            0 => n += 1,
+            area => print(area)
+        }
+    "#);
+    assert!(check(&code).is_ok(), "{:?}", check(&code));
+}
+
+#[test]
+fn test_non_exhaustive_matches() {
+    assert_eq!(
+        enum_errors("Int a = match (Shape::Empty) { Shape::Circle(r) => r };"),
+        non_exhaustive(&["Shape::Rect(_, _)", "Shape::Empty"])
+    );
+    assert_eq!(
+        enum_errors("Tree t = Tree::Leaf(True); match (t) { Tree::Leaf(True) => print(1), Tree::Node(Shape::Circle(_), _) => print(2) }"),
+        non_exhaustive(&["Tree::Leaf(False)", "Tree::Node(Shape::Rect(_, _), _)"])
+    );
+    assert_eq!(errors("Int a = match (3) { 1 => 1, 2 => 2 };"), non_exhaustive(&["_"]));
+    assert_eq!(errors("Int a = match (True) { True => 1 };"), non_exhaustive(&["False"]));
+    // A binding covers every value left
+    assert!(check(&format!("{}Int a = match (Shape::Empty) {{ Shape::Empty => 0, s => 1 }};", ENUMS)).is_ok());
+}
+
+#[test]
+fn test_unreachable_arms() {
+    let unreachable = |pattern: &str| vec![TypeError::UnreachableArm { pattern: pattern.to_string() }];
+    assert_eq!(enum_errors("Int a = match (Shape::Empty) { s => 0, Shape::Empty => 1 };"), unreachable("Shape::Empty"));
+    assert_eq!(
+        enum_errors("Tree t = Tree::Leaf(True); match (t) { Tree::Leaf(_) => print(1), Tree::Node(_, _) => print(2), Tree::Leaf(True) => print(3) }"),
+        unreachable("Tree::Leaf(True)")
+    );
+    assert_eq!(errors("Int a = match (3) { 1 => 1, _ => 2, 1 => 3 };"), unreachable("1"));
+    assert_eq!(errors("Int a = match (3) { 1 => 1, 1 => 2, _ => 3 };"), unreachable("1"));
+    assert_eq!(errors("Int a = match (True) { True => 1, False => 2, _ => 3 };"), unreachable("_"));
+}
+
+#[test]
+fn test_bindings_named_like_variants_warn() {
+    let code = "enum E { A, B } E e = E::B; Int a = match (e) { A => 1, E::B => 2 }; Int b = match (e) { C => 1 };";
+    let ast = Parser::new(Lexer::new(code.to_string()).tokenize()).parse().expect("code should parse");
+    let mut checker = TypeChecker::new();
+    assert_eq!(
+        checker.check_program(&ast).unwrap_err().iter().map(TypeError::kind).cloned().collect::<Vec<_>>(),
+        vec![TypeError::UnreachableArm { pattern: "E::B".to_string() }]
+    );
+    let warnings: Vec<TypeError> = checker.warnings().iter().map(TypeError::kind).cloned().collect();
+    assert_eq!(
+        warnings,
+        vec![TypeError::BindingNamedLikeVariant { name: "A".to_string(), enum_name: "E".to_string() }]
+    );
+}
+
+#[test]
+fn test_pattern_errors() {
+    assert_eq!(
+        enum_errors("Int a = match (Shape::Empty) { Shape::Square(x) => x, _ => 0 };"),
+        vec![TypeError::UndefinedVariant { enum_name: "Shape".to_string(), variant: "Square".to_string() }]
+    );
+    assert_eq!(
+        enum_errors("Int a = match (Shape::Empty) { Shape::Rect(x) => x, _ => 0 };"),
+        vec![TypeError::VariantFieldCount { variant: "Shape::Rect".to_string(), expected: 2, found: 1 }]
+    );
+    assert_eq!(
+        enum_errors("Int a = match (Shape::Empty) { Tree::Leaf(_) => 1, _ => 0 };"),
+        vec![TypeError::TypeMismatch { expected: Type::Named("Shape".to_string()), found: Type::Named("Tree".to_string()) }]
+    );
+    assert_eq!(
+        enum_errors("Int a = match (1) { \"one\" => 1, _ => 0 };"),
+        vec![TypeError::TypeMismatch { expected: Type::Int, found: Type::String }]
+    );
+    assert_eq!(
+        enum_errors("Int a = match (Shape::Empty) { Shape::Rect(x, x) => x, _ => 0 };"),
+        vec![TypeError::DuplicateDefinition("x".to_string())]
+    );
+}
+
+#[test]
+fn test_match_arms_must_agree() {
+    assert_eq!(
+        enum_errors("Int a = match (Shape::Empty) { Shape::Empty => 0, _ => \"many\" };"),
+        vec![TypeError::TypeMismatch { expected: Type::Int, found: Type::String }]
+    );
+    // Bindings are scoped to their arm
+    assert_eq!(
+        enum_errors("Int a = match (Shape::Circle(1)) { Shape::Circle(r) => r, _ => r };"),
+        vec![TypeError::UndefinedVariable("r".to_string())]
+    );
+}
+
+#[test]
+fn test_variant_construction_errors() {
+    assert_eq!(
+        enum_errors("Shape s = Shape::Rect(1);"),
+        vec![TypeError::VariantFieldCount { variant: "Shape::Rect".to_string(), expected: 2, found: 1 }]
+    );
+    assert_eq!(
+        enum_errors("Shape s = Shape::Circle(True);"),
+        vec![TypeError::ArgumentTypeMismatch { position: 0, expected: Type::Int, found: Type::Bool }]
+    );
+    assert_eq!(
+        errors("Int x = Color::Red;"),
+        vec![TypeError::UndefinedType("Color".to_string())]
+    );
+    assert_eq!(
+        errors("enum E { A, A } struct E { Int x; }"),
+        vec![TypeError::DuplicateDefinition("E".to_string()), TypeError::DuplicateDefinition("E::A".to_string())]
+    );
+}
+
+// ============================================================================
+// DECLARATIONS AND REFERENCES
+// ============================================================================
+
+// Helper checking code, returning the checker to look at what it recorded
+fn checked(code: &str) -> TypeChecker {
+    let tokens = Lexer::new(code.to_string()).tokenize();
+    let ast = Parser::new(tokens).parse().expect("code should parse");
+    let mut checker = TypeChecker::new();
+    checker.check_program(&ast).expect("code should type check");
+    checker
+}
+
+#[test]
+fn test_declarations_are_recorded() {
+    let checker = checked("func F(Int n) -> Int { return n; } Array<Int> a = [1]; for x in a {}");
+    let declarations: Vec<(&str, String, usize)> = checker
+        .declarations()
+        .iter()
+        .map(|d| (d.name.as_str(), d.ty.to_string(), d.span.start))
+        .collect();
+    assert_eq!(
+        declarations,
+        vec![
+            ("F", "Func<(Int), Int>".to_string(), 0),
+            ("n", "Int".to_string(), 0),
+            ("a", "Array<Int>".to_string(), 35),
+            ("x", "Int".to_string(), 55),
+        ]
+    );
+}
+
+#[test]
+fn test_references_point_at_their_declaration() {
+    let checker = checked("Int x = 1; { String x = \"s\"; println(x); } x += F(); func F() -> Int { return x; }");
+    let references: Vec<(&str, usize, usize)> = checker
+        .references()
+        .iter()
+        .map(|r| (r.name.as_str(), r.span.start, r.declaration.start))
+        .collect();
+    // Builtins are not references, the assigned variable is
+    assert_eq!(references, vec![("x", 37, 13), ("x", 43, 0), ("F", 48, 53), ("x", 78, 0)]);
+    assert_eq!(checker.references()[0].ty, Type::String);
+    assert_eq!(checker.references()[1].span.end, 44);
+}
+
+#[test]
+fn test_probed_scope() {
+    let code = "Int a = 1; func F(Int p) { Int b = 2; } Int c = 3;";
+    let tokens = Lexer::new(code.to_string()).tokenize();
+    let ast = Parser::new(tokens).parse().unwrap();
+    let names = |offset| {
+        let mut checker = TypeChecker::new();
+        checker.probe_scope(offset);
+        checker.check_program(&ast).unwrap();
+        let mut names: Vec<String> = checker.probed_scope().iter().map(|(name, _)| name.clone()).collect();
+        names.sort();
+        names
+    };
+    assert_eq!(names(0), ["F"]);
+    assert_eq!(names(38), ["F", "a", "b", "p"]);
+    assert_eq!(names(40), ["F", "a"]);
+    assert_eq!(names(51), ["F", "a", "c"]);
+}